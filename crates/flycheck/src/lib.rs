@@ -8,7 +8,13 @@
 
 #![warn(rust_2018_idioms, unused_lifetimes)]
 
-use std::{fmt, io, path::Path, process::Command, time::Duration};
+use std::{
+    fmt, io,
+    path::Path,
+    process::Command,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
 
 use crossbeam_channel::{never, select, unbounded, Receiver, Sender};
 use paths::{AbsPath, AbsPathBuf, Utf8PathBuf};
@@ -94,10 +100,26 @@ pub enum FlycheckConfig {
         invocation_location: InvocationLocation,
     },
     VerusCommand {
-        args: Vec<String>,
+        options: VerusOptions,
     },
 }
 
+/// Settings of the `verus.*` configuration section.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VerusOptions {
+    /// Falls back to `VERUS_BINARY_PATH`, then to `verus` in the `PATH`.
+    pub binary_path: Option<Utf8PathBuf>,
+    pub extra_args: Vec<String>,
+    pub rlimit: Option<usize>,
+    /// Verify each function in a separate solver instance.
+    pub per_function: bool,
+    pub z3_path: Option<Utf8PathBuf>,
+    pub cvc5_path: Option<Utf8PathBuf>,
+    /// `crate=path` pairs of verified crates to import.
+    pub import: Vec<String>,
+    pub export: Option<Utf8PathBuf>,
+}
+
 impl fmt::Display for FlycheckConfig {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -105,7 +127,9 @@ impl fmt::Display for FlycheckConfig {
             FlycheckConfig::CustomCommand { command, args, .. } => {
                 write!(f, "{command} {}", args.join(" "))
             }
-            FlycheckConfig::VerusCommand { args } => write!(f, "verus {}", args.join(" ")),
+            FlycheckConfig::VerusCommand { options } => {
+                write!(f, "verus {}", options.extra_args.join(" "))
+            }
         }
     }
 }
@@ -155,8 +179,13 @@ impl FlycheckHandle {
 
     /// Schedule a re-start of the cargo check worker.
     pub fn restart_verus(&self, file: String) {
-        tracing::debug!("restart verus for {:?}", file);
-        self.sender.send(StateChange::RestartVerus(file)).unwrap();
+        self.restart_verus_target(file, VerusTarget::File);
+    }
+
+    /// Schedule a Verus run on `file`, narrowed down to `target`.
+    pub fn restart_verus_target(&self, file: String, target: VerusTarget) {
+        tracing::debug!("restart verus for {:?} ({:?})", file, target);
+        self.sender.send(StateChange::RestartVerus(file, target)).unwrap();
     }
 
     /// Stop this cargo check worker.
@@ -170,11 +199,20 @@ impl FlycheckHandle {
 }
 
 pub enum Message {
-    /// Request adding a diagnostic with fixes included to a file
-    AddDiagnostic { id: usize, workspace_root: AbsPathBuf, diagnostic: Diagnostic },
+    /// Request adding a diagnostic with fixes included to a file, as soon as the run reports it
+    AddDiagnostic {
+        id: usize,
+        /// The run reporting the diagnostic
+        generation: u64,
+        workspace_root: AbsPathBuf,
+        diagnostic: Diagnostic,
+    },
 
     /// Request clearing all previous diagnostics
-    ClearDiagnostics { id: usize },
+    ClearDiagnostics { id: usize, generation: u64 },
+
+    /// Per-function results of a finished Verus run
+    VerusFunctionResults { id: usize, results: Vec<VerusFunctionResult> },
 
     /// Request check progress notification to client
     Progress {
@@ -187,15 +225,23 @@ pub enum Message {
 impl fmt::Debug for Message {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Message::AddDiagnostic { id, workspace_root, diagnostic } => f
+            Message::AddDiagnostic { id, generation, workspace_root, diagnostic } => f
                 .debug_struct("AddDiagnostic")
                 .field("id", id)
+                .field("generation", generation)
                 .field("workspace_root", workspace_root)
                 .field("diagnostic_code", &diagnostic.code.as_ref().map(|it| &it.code))
                 .finish(),
-            Message::ClearDiagnostics { id } => {
-                f.debug_struct("ClearDiagnostics").field("id", id).finish()
-            }
+            Message::ClearDiagnostics { id, generation } => f
+                .debug_struct("ClearDiagnostics")
+                .field("id", id)
+                .field("generation", generation)
+                .finish(),
+            Message::VerusFunctionResults { id, results } => f
+                .debug_struct("VerusFunctionResults")
+                .field("id", id)
+                .field("num_results", &results.len())
+                .finish(),
             Message::Progress { id, progress } => {
                 f.debug_struct("Progress").field("id", id).field("progress", progress).finish()
             }
@@ -203,6 +249,18 @@ impl fmt::Debug for Message {
     }
 }
 
+/// Verification outcome of a single function, as reported by Verus'
+/// `--output-json --time-expanded` summary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerusFunctionResult {
+    /// Verus' path of the function, e.g. `crate::module::lemma_foo`.
+    pub function: String,
+    pub success: bool,
+    pub time_ms: u64,
+    /// Solver resources spent on the function, if Verus reported them.
+    pub rlimit: Option<u64>,
+}
+
 #[derive(Debug)]
 pub enum Progress {
     DidStart,
@@ -216,7 +274,18 @@ pub enum Progress {
 enum StateChange {
     Restart { package: Option<String>, saved_file: Option<AbsPathBuf> },
     Cancel,
-    RestartVerus(String),
+    RestartVerus(String, VerusTarget),
+}
+
+/// What part of the crate a Verus run should verify.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerusTarget {
+    /// The module backing the file, or the whole crate if it is the crate root.
+    File,
+    /// The module with the given path, e.g. `foo::bar`.
+    Module(String),
+    /// The function with the given name, in the module backing the file.
+    Function(String),
 }
 
 /// A [`FlycheckActor`] is a single check instance of a workspace.
@@ -240,6 +309,21 @@ struct FlycheckActor {
     command_receiver: Option<Receiver<CargoCheckMessage>>,
 
     status: FlycheckStatus,
+    /// Non-diagnostic output of the running Verus process, parsed once it exits.
+    verus_output: String,
+    /// The `run_verus` span of the running Verus process and when it started, its duration is
+    /// recorded once it exits.
+    verus_run: Option<(tracing::Span, Instant)>,
+    /// Numbers the running process, so that the diagnostics of a run can be told apart from the
+    /// ones of the runs before it.
+    generation: u64,
+}
+
+/// Run numbers grow across all flychecks, so that the runs of a flycheck replaced by a reload are
+/// older than those of the new one.
+fn next_generation() -> u64 {
+    static NEXT: AtomicU64 = AtomicU64::new(1);
+    NEXT.fetch_add(1, Ordering::Relaxed)
 }
 
 enum Event {
@@ -276,6 +360,9 @@ impl FlycheckActor {
             command_handle: None,
             command_receiver: None,
             status: FlycheckStatus::Finished,
+            verus_output: String::new(),
+            verus_run: None,
+            generation: 0,
         }
     }
 
@@ -325,6 +412,7 @@ impl FlycheckActor {
                             tracing::debug!(command = formatted_command, "did restart flycheck");
                             self.command_handle = Some(command_handle);
                             self.command_receiver = Some(receiver);
+                            self.generation = next_generation();
                             self.report_progress(Progress::DidStart);
                             self.status = FlycheckStatus::Started;
                         }
@@ -336,7 +424,7 @@ impl FlycheckActor {
                         }
                     }
                 }
-                Event::RequestStateChange(StateChange::RestartVerus(filename)) => {
+                Event::RequestStateChange(StateChange::RestartVerus(filename, target)) => {
                     // verus: copied from above `Event::RequestStateChange(StateChange::Restart)`
                     // Cancel the previously spawned process
                     self.cancel_check_process();
@@ -347,14 +435,26 @@ impl FlycheckActor {
                         }
                     }
 
-                    let command = self.run_verus(filename.clone());
+                    let span = tracing::info_span!(
+                        "run_verus",
+                        flycheck_id = self.id,
+                        file = %filename,
+                        ?target,
+                        elapsed_ms = tracing::field::Empty,
+                        success = tracing::field::Empty,
+                    );
+                    let _p = span.clone().entered();
+                    let command = self.run_verus(filename.clone(), target);
                     let formatted_command = format!("{command:?}");
-                    tracing::info!(?command, "will restart flycheck");
+                    self.verus_output.clear();
+                    tracing::debug!(?command, "will run verus");
                     let (sender, receiver) = unbounded();
                     match CommandHandle::spawn(command, sender) {
                         Ok(command_handle) => {
+                            self.verus_run = Some((span, Instant::now()));
                             self.command_handle = Some(command_handle);
                             self.command_receiver = Some(receiver);
+                            self.generation = next_generation();
                             // self.report_progress(Progress::VerusResult(format!(
                             //     //"Started running the following Verus command: {:?}",
                             //     "Running Verus...",
@@ -388,7 +488,22 @@ impl FlycheckActor {
                         );
                     }
                     if self.status == FlycheckStatus::Started {
-                        self.send(Message::ClearDiagnostics { id: self.id });
+                        self.send(Message::ClearDiagnostics {
+                            id: self.id,
+                            generation: self.generation,
+                        });
+                    }
+                    if let Some((span, started)) = self.verus_run.take() {
+                        span.record("elapsed_ms", started.elapsed().as_millis() as u64);
+                        span.record("success", res.is_ok());
+                        span.in_scope(|| tracing::debug!("verus finished"));
+                    }
+                    if matches!(self.config, FlycheckConfig::VerusCommand { .. }) {
+                        let output = std::mem::take(&mut self.verus_output);
+                        self.send(Message::VerusFunctionResults {
+                            id: self.id,
+                            results: parse_verus_function_results(&output),
+                        });
                     }
                     self.report_progress(Progress::DidFinish(res));
                     self.status = FlycheckStatus::Finished;
@@ -410,10 +525,14 @@ impl FlycheckActor {
                             "diagnostic received"
                         );
                         if self.status == FlycheckStatus::Started {
-                            self.send(Message::ClearDiagnostics { id: self.id });
+                            self.send(Message::ClearDiagnostics {
+                                id: self.id,
+                                generation: self.generation,
+                            });
                         }
                         self.send(Message::AddDiagnostic {
                             id: self.id,
+                            generation: self.generation,
                             workspace_root: self.root.clone(),
                             diagnostic: msg,
                         });
@@ -422,6 +541,12 @@ impl FlycheckActor {
                     CargoCheckMessage::VerusResult(res) => {
                         self.report_progress(Progress::VerusResult(res));
                     }
+                    CargoCheckMessage::VerusOutput(line) => {
+                        if matches!(self.config, FlycheckConfig::VerusCommand { .. }) {
+                            self.verus_output.push_str(&line);
+                            self.verus_output.push('\n');
+                        }
+                    }
                 },
             }
         }
@@ -437,6 +562,7 @@ impl FlycheckActor {
             );
             command_handle.cancel();
             self.command_receiver.take();
+            self.verus_run = None;
             self.report_progress(Progress::DidCancel);
             self.status = FlycheckStatus::Finished;
         }
@@ -532,7 +658,7 @@ impl FlycheckActor {
                     (cmd, args.clone())
                 }
             }
-            FlycheckConfig::VerusCommand { args: _ } => {
+            FlycheckConfig::VerusCommand { .. } => {
                 return None;
             } // Verus doesn't have a check mode (yet)
         };
@@ -542,142 +668,273 @@ impl FlycheckActor {
     }
 
     // copied from above check_command
-    fn run_verus(&self, file: String) -> Command {
-        let (mut cmd, args) = match &self.config {
+    fn run_verus(&self, file: String, target: VerusTarget) -> Command {
+        match &self.config {
             FlycheckConfig::CargoCommand { .. } => {
                 panic!("verus analyzer does not yet support cargo commands")
             }
             FlycheckConfig::CustomCommand { .. } => {
                 panic!("verus analyzer does not yet support custom commands")
             }
-            FlycheckConfig::VerusCommand { args } => {
-                let verus_binary_str = match std::env::var("VERUS_BINARY_PATH") {
-                    Ok(path) => path,
-                    Err(_) => {
-                        tracing::warn!("VERUS_BINARY_PATH was not set!");
-                        "verus".to_string() // Hope that it's in the PATH
-                    }
-                };
-                dbg!(&verus_binary_str);
-                tracing::info!("Using Verus binary: {}", &verus_binary_str);
-
-                let verus_exec_path = Path::new(&verus_binary_str)
-                    .canonicalize()
-                    .expect("We expect to succeed with canonicalizing the Verus binary path");
-                let mut cmd = Command::new(verus_exec_path);
-
-                // Try to locate a Cargo.toml file that might contain custom Verus arguments
-                let file = Path::new(&file);
-                let mut toml_dir: Option<std::path::PathBuf> = None;
-                let mut extra_args_from_toml = Vec::new();
-                for ans in file.ancestors() {
-                    if ans.join("Cargo.toml").exists() {
-                        let toml = std::fs::read_to_string(ans.join("Cargo.toml")).unwrap();
-                        let mut found_verus_settings = false;
-                        for line in toml.lines() {
-                            if found_verus_settings {
-                                if line.contains("extra_args") {
-                                    let start = "extra_args".len() + 1;
-                                    let mut arguments =
-                                        line[start..line.len() - 1].trim().to_string();
-                                    if arguments.starts_with("=") {
-                                        arguments.remove(0);
-                                        arguments = arguments.trim().to_string();
-                                    }
-                                    if arguments.starts_with("\"") {
-                                        arguments.remove(0);
-                                    }
-                                    if arguments.ends_with("\"") {
-                                        arguments.remove(arguments.len() - 1);
-                                    }
-
-                                    let arguments_vec = arguments
-                                        .split(" ")
-                                        .map(|it| it.to_string())
-                                        .collect::<Vec<_>>();
-                                    extra_args_from_toml.extend(arguments_vec);
-                                }
-                                break;
-                            }
-                            if line.contains("[package.metadata.verus.ide]") {
-                                found_verus_settings = true;
-                            }
-                        }
-                        toml_dir = Some(ans.to_path_buf());
-                        break;
-                    }
-                }
+            FlycheckConfig::VerusCommand { options } => {
+                let mut cmd = verus_command(&self.root, options, &file, target);
+                cmd.args(VERUS_OUTPUT_ARGS);
+                cmd
+            }
+        }
+    }
 
-                // We may need to add additional arguments
-                let mut args = args.to_vec();
-                match toml_dir {
-                    None => {
-                        // This file doesn't appear to be part of a larger project
-                        // Try to invoke Verus on it directly, but try to avoid
-                        // complaints about missing `fn main()`
-                        args.push("--crate-type".to_string());
-                        args.push("lib".to_string());
-                    }
-                    Some(toml_dir) => {
-                        // This file appears to be part of a Rust project.
-                        // If it's not the root file, then we need to
-                        // invoke Verus on the root file and then filter for results in the current file
-                        let root_file = if toml_dir.join("src").join("main.rs").exists() {
-                            Some(toml_dir.join("src").join("main.rs"))
-                        } else if toml_dir.join("src").join("lib.rs").exists() {
-                            args.push("--crate-type".to_string());
-                            args.push("lib".to_string());
-                            Some(toml_dir.join("src").join("lib.rs"))
-                        } else {
-                            None
-                        };
+    fn send(&self, check_task: Message) {
+        (self.sender)(check_task);
+    }
+}
 
-                        match root_file {
-                            Some(root_file) => {
-                                let file_as_module = Some(
-                                    file.strip_prefix(toml_dir.join("src"))
-                                        .unwrap()
-                                        .to_str()
-                                        .unwrap()
-                                        .replace(std::path::MAIN_SEPARATOR_STR, "::")
-                                        .replace(".rs", ""),
-                                );
-
-                                args.insert(0, root_file.to_str().unwrap().to_string());
-                                if file == root_file {
-                                    tracing::info!("file == root_file");
-                                } else {
-                                    tracing::info!(?root_file, "root_file");
-                                    args.insert(1, "--verify-module".to_string());
-                                    args.insert(2, file_as_module.unwrap().to_string());
-                                }
-                            }
-                            None => {
-                                // Puzzling -- we found a Cargo.toml but no root file.
-                                // Do our best by trying to run directly on the file supplied
-                                args.insert(0, file.to_str().unwrap().to_string());
-                                args.push("--crate-type".to_string());
-                                args.push("lib".to_string());
-                            }
+/// Asks Verus for JSON diagnostics and for the JSON summary with a per-function time breakdown,
+/// see `parse_verus_function_results`.
+const VERUS_OUTPUT_ARGS: [&str; 4] =
+    ["--output-json", "--time-expanded", "--", "--error-format=json"];
+
+/// Builds the Verus invocation verifying `target` in `file`, without any output format
+/// flags, so that it can also be run by the user.
+pub fn verus_command(
+    root: &AbsPath,
+    options: &VerusOptions,
+    file: &str,
+    target: VerusTarget,
+) -> Command {
+    let verus_binary_str = match (&options.binary_path, std::env::var("VERUS_BINARY_PATH")) {
+        (Some(path), _) => path.to_string(),
+        (None, Ok(path)) => path,
+        (None, Err(_)) => {
+            tracing::warn!("Neither verus.binaryPath nor VERUS_BINARY_PATH was set!");
+            "verus".to_string() // Hope that it's in the PATH
+        }
+    };
+    tracing::debug!(binary = %verus_binary_str, "using verus binary");
+
+    let verus_exec_path = Path::new(&verus_binary_str)
+        .canonicalize()
+        .unwrap_or_else(|_| Path::new(&verus_binary_str).to_path_buf());
+    let mut cmd = Command::new(verus_exec_path);
+
+    if let Some(path) = &options.z3_path {
+        cmd.env("VERUS_Z3_PATH", path);
+    }
+    if let Some(path) = &options.cvc5_path {
+        cmd.env("VERUS_CVC5_PATH", path);
+    }
+
+    let file = Path::new(file);
+    let toml_dir = file.ancestors().find(|it| it.join("Cargo.toml").exists());
+    let toml_args = toml_dir.map(extra_args_from_toml).unwrap_or_default();
+
+    // We may need to add additional arguments
+    let mut args = options.extra_args.clone();
+    match toml_dir {
+        None => {
+            // This file doesn't appear to be part of a larger project
+            // Try to invoke Verus on it directly, but try to avoid
+            // complaints about missing `fn main()`
+            args.push("--crate-type".to_string());
+            args.push("lib".to_string());
+        }
+        Some(toml_dir) => {
+            // This file appears to be part of a Rust project.
+            // If it's not the root file, then we need to
+            // invoke Verus on the root file and then filter for results in the current file
+            let root_file = if toml_dir.join("src").join("main.rs").exists() {
+                Some(toml_dir.join("src").join("main.rs"))
+            } else if toml_dir.join("src").join("lib.rs").exists() {
+                args.push("--crate-type".to_string());
+                args.push("lib".to_string());
+                Some(toml_dir.join("src").join("lib.rs"))
+            } else {
+                None
+            };
+
+            match root_file {
+                Some(root_file) => {
+                    let file_as_module = Some(
+                        file.strip_prefix(toml_dir.join("src"))
+                            .unwrap()
+                            .to_str()
+                            .unwrap()
+                            .replace(std::path::MAIN_SEPARATOR_STR, "::")
+                            .replace(".rs", ""),
+                    );
+
+                    let module = match &target {
+                        VerusTarget::Module(module) => Some(module.clone()),
+                        _ if file == root_file => None,
+                        _ => file_as_module,
+                    };
+
+                    args.insert(0, root_file.to_str().unwrap().to_string());
+                    match module {
+                        None => tracing::debug!(?root_file, "verifying the root file"),
+                        Some(module) => {
+                            tracing::debug!(?root_file, %module, "verifying a module");
+                            args.insert(1, "--verify-module".to_string());
+                            args.insert(2, module);
                         }
                     }
                 }
+                None => {
+                    // Puzzling -- we found a Cargo.toml but no root file.
+                    // Do our best by trying to run directly on the file supplied
+                    args.insert(0, file.to_str().unwrap().to_string());
+                    args.push("--crate-type".to_string());
+                    args.push("lib".to_string());
+                }
+            }
+        }
+    }
 
-                args.append(&mut extra_args_from_toml);
-                args.push("--".to_string());
-                args.push("--error-format=json".to_string());
-
-                cmd.current_dir(&self.root);
-                (cmd, args)
+    // Narrow the run down to what was asked for, if the root file logic
+    // above did not already do so.
+    let has_module = args.iter().any(|it| it == "--verify-module");
+    match target {
+        VerusTarget::File => (),
+        VerusTarget::Module(module) => {
+            if !has_module {
+                args.push("--verify-module".to_string());
+                args.push(module);
             }
-        };
+        }
+        VerusTarget::Function(function) => {
+            if !has_module {
+                args.push("--verify-root".to_string());
+            }
+            args.push("--verify-function".to_string());
+            args.push(function);
+        }
+    }
 
-        cmd.args(args);
-        cmd
+    args.extend(toml_args);
+    if let Some(rlimit) = options.rlimit {
+        args.push("--rlimit".to_string());
+        args.push(rlimit.to_string());
     }
+    if options.per_function {
+        args.push("--spinoff-all".to_string());
+    }
+    for import in &options.import {
+        args.push("--import".to_string());
+        args.push(import.clone());
+    }
+    if let Some(export) = &options.export {
+        args.push("--export".to_string());
+        args.push(export.to_string());
+    }
+    cmd.current_dir(root);
+    cmd.args(args);
+    cmd
+}
 
-    fn send(&self, check_task: Message) {
-        (self.sender)(check_task);
+/// The outcome of a Verus run made without a flycheck, see [`verify`].
+#[derive(Debug)]
+pub struct VerusReport {
+    /// Whether Verus exited successfully: everything compiled and verified.
+    pub success: bool,
+    pub diagnostics: Vec<Diagnostic>,
+    pub functions: Vec<VerusFunctionResult>,
+    pub elapsed: Duration,
+}
+
+/// Runs Verus on `target` in `file` and waits for it to finish, for batch use outside of an
+/// editor.
+pub fn verify(
+    root: &AbsPath,
+    options: &VerusOptions,
+    file: &str,
+    target: VerusTarget,
+) -> io::Result<VerusReport> {
+    let _p = tracing::info_span!("verify", file, ?target).entered();
+    let mut cmd = verus_command(root, options, file, target);
+    cmd.args(VERUS_OUTPUT_ARGS);
+    let started = Instant::now();
+    let output = cmd.output()?;
+    let elapsed = started.elapsed();
+
+    let mut diagnostics = Vec::new();
+    let mut verus_output = String::new();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    for line in stdout.lines().chain(stderr.lines()) {
+        match CargoCheckMessage::from_line(line, &mut String::new()) {
+            Some(CargoCheckMessage::Diagnostic(it)) => diagnostics.push(it),
+            Some(CargoCheckMessage::VerusOutput(line)) => {
+                verus_output.push_str(&line);
+                verus_output.push('\n');
+            }
+            _ => (),
+        }
+    }
+    Ok(VerusReport {
+        success: output.status.success(),
+        diagnostics,
+        functions: parse_verus_function_results(&verus_output),
+        elapsed,
+    })
+}
+
+/// The `extra_args` of the `[package.metadata.verus.ide]` section of the `Cargo.toml` in
+/// `toml_dir`, deprecated in favor of `verus.extraArgs`.
+fn extra_args_from_toml(toml_dir: &Path) -> Vec<String> {
+    let Ok(toml) = std::fs::read_to_string(toml_dir.join("Cargo.toml")) else {
+        return vec![];
+    };
+    let arguments = toml
+        .lines()
+        .skip_while(|line| !line.contains("[package.metadata.verus.ide]"))
+        .skip(1)
+        .take_while(|line| !line.trim_start().starts_with('['))
+        .find_map(|line| line.trim().strip_prefix("extra_args")?.trim().strip_prefix('='));
+    let Some(arguments) = arguments else {
+        return vec![];
+    };
+    tracing::warn!(
+        toml = %toml_dir.join("Cargo.toml").display(),
+        "`[package.metadata.verus.ide] extra_args` is deprecated, use the `verus.extraArgs` setting instead"
+    );
+    arguments.trim().trim_matches('"').split_whitespace().map(|it| it.to_string()).collect()
+}
+
+const WARM_UP_FILE: &str = "use vstd::prelude::*;\n\nverus! {\n\nproof fn warm_up() {}\n\n}\n";
+
+/// Runs Verus on a trivial file using `vstd`, so that Verus, the solver and `vstd` have been
+/// loaded once before the first verification the user asks for. Returns how long it took.
+pub fn warm_up_verus(options: &VerusOptions) -> Result<Duration, String> {
+    let _p = tracing::info_span!("warm_up_verus").entered();
+    let dir = std::env::temp_dir().join(format!("verus-analyzer-warm-up-{}", std::process::id()));
+    let file = dir.join("warm_up.rs");
+    std::fs::create_dir_all(&dir)
+        .and_then(|()| std::fs::write(&file, WARM_UP_FILE))
+        .map_err(|err| format!("failed to write {}: {err}", file.display()))?;
+    let root = AbsPathBuf::try_from(dir.clone())
+        .map_err(|dir| format!("{} is not an absolute UTF-8 path", dir.display()))?;
+
+    // The warm-up must not overwrite the specifications exported by the actual runs.
+    let options = VerusOptions { export: None, ..options.clone() };
+    let started = Instant::now();
+    let output = verus_command(&root, &options, &file.to_string_lossy(), VerusTarget::File)
+        .output()
+        .map_err(|err| format!("failed to run Verus: {err}"));
+    let elapsed = started.elapsed();
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let output = output?;
+    tracing::debug!(
+        elapsed_ms = elapsed.as_millis() as u64,
+        success = output.status.success(),
+        "warm-up finished"
+    );
+    if output.status.success() {
+        Ok(elapsed)
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr.lines().find(|it| it.starts_with("error")).unwrap_or(stderr.trim());
+        Err(format!("Verus failed on a trivial file: {reason}"))
     }
 }
 
@@ -686,6 +943,7 @@ enum CargoCheckMessage {
     CompilerArtifact(cargo_metadata::Artifact),
     Diagnostic(Diagnostic),
     VerusResult(String),
+    VerusOutput(String),
 }
 
 impl ParseFromLine for CargoCheckMessage {
@@ -706,20 +964,18 @@ impl ParseFromLine for CargoCheckMessage {
                 },
                 JsonMessage::Rustc(message) => Some(CargoCheckMessage::Diagnostic(message)),
             };
-        } else {
-            // verus
-            // forward verification result if present
-            // TODO: We should ask Verus for json output and then parse it properly here
-            if line.contains("verification results::") {
-                Some(CargoCheckMessage::VerusResult(line.to_string()));
-            } else {
-                tracing::error!("deserialize error: {:?}", line);
-            }
         }
 
+        // verus
+        // forward verification result if present
+        if line.contains("verification results::") {
+            return Some(CargoCheckMessage::VerusResult(line.to_owned()));
+        }
         error.push_str(line);
         error.push('\n');
-        None
+        // Verus pretty-prints its JSON summary over many lines,
+        // so the actor reassembles it once the process exits.
+        Some(CargoCheckMessage::VerusOutput(line.to_owned()))
     }
 
     fn from_eof() -> Option<Self> {
@@ -733,3 +989,50 @@ enum JsonMessage {
     Cargo(cargo_metadata::Message),
     Rustc(Diagnostic),
 }
+
+/// Extracts the per-function breakdown from the JSON summary Verus prints with
+/// `--output-json --time-expanded`. Every `function-breakdown` entry looks like
+/// `{ "function": "crate::foo", "time": 12, "rlimit": 3456, "success": true, .. }`.
+fn parse_verus_function_results(output: &str) -> Vec<VerusFunctionResult> {
+    fn collect(value: &serde_json::Value, acc: &mut Vec<VerusFunctionResult>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                if let Some(serde_json::Value::Array(entries)) = map.get("function-breakdown") {
+                    for entry in entries {
+                        let Some(function) = entry.get("function").and_then(|it| it.as_str())
+                        else {
+                            continue;
+                        };
+                        acc.push(VerusFunctionResult {
+                            function: function.to_owned(),
+                            success: entry
+                                .get("success")
+                                .and_then(|it| it.as_bool())
+                                .unwrap_or(true),
+                            time_ms: entry.get("time").and_then(|it| it.as_u64()).unwrap_or(0),
+                            rlimit: entry.get("rlimit").and_then(|it| it.as_u64()),
+                        });
+                    }
+                }
+                map.values().for_each(|it| collect(it, acc));
+            }
+            serde_json::Value::Array(values) => values.iter().for_each(|it| collect(it, acc)),
+            _ => (),
+        }
+    }
+
+    let mut results = Vec::new();
+    // The summary is the only JSON object starting at the beginning of a line.
+    for (start, _) in output.match_indices('{') {
+        if start != 0 && !output[..start].ends_with('\n') {
+            continue;
+        }
+        let mut stream =
+            serde_json::Deserializer::from_str(&output[start..]).into_iter::<serde_json::Value>();
+        if let Some(Ok(value)) = stream.next() {
+            collect(&value, &mut results);
+            break;
+        }
+    }
+    results
+}
@@ -29,6 +29,8 @@ use crate::{
     BlockId, DefWithBodyId, HasModule, Lookup,
 };
 
+use self::lower::FnContract;
+
 /// The body of an item (function, const etc.).
 #[derive(Debug, Eq, PartialEq)]
 pub struct Body {
@@ -49,6 +51,15 @@ pub struct Body {
     pub self_param: Option<BindingId>,
     /// The `ExprId` of the actual body expression.
     pub body_expr: ExprId,
+    /// The expressions of the function's `requires` clause, with the parameters in scope.
+    pub requires: Box<[ExprId]>,
+    /// The expressions of the function's `ensures` clause, with the parameters and `ret_pat` in
+    /// scope.
+    pub ensures: Box<[ExprId]>,
+    /// The pattern naming the return value, `r` in `fn f() -> (r: u32)`.
+    pub ret_pat: Option<PatId>,
+    /// The `proof { .. }` blocks of the body, lowered to `Expr::Block`s.
+    pub proof_blocks: Vec<ExprId>,
     /// Block expressions in this body that may contain inner items.
     block_scopes: Vec<BlockId>,
 }
@@ -126,6 +137,7 @@ impl Body {
     ) -> (Arc<Body>, Arc<BodySourceMap>) {
         let _p = tracing::info_span!("body_with_source_map_query").entered();
         let mut params = None;
+        let mut contract = FnContract::default();
 
         let mut is_async_fn = false;
         let InFile { file_id, value: body } = {
@@ -149,6 +161,7 @@ impl Body {
                         )
                     });
                     is_async_fn = data.has_async_kw();
+                    contract = FnContract::new(&src.value);
                     src.map(|it| it.body().map(ast::Expr::from))
                 }
                 DefWithBodyId::ConstId(c) => {
@@ -172,7 +185,7 @@ impl Body {
         let module = def.module(db);
         let expander = Expander::new(db, file_id, module);
         let (mut body, mut source_map) =
-            Body::new(db, def, expander, params, body, module.krate, is_async_fn);
+            Body::new(db, def, expander, params, contract, body, module.krate, is_async_fn);
         body.shrink_to_fit();
         source_map.shrink_to_fit();
 
@@ -209,11 +222,12 @@ impl Body {
         owner: DefWithBodyId,
         expander: Expander,
         params: Option<(ast::ParamList, impl Iterator<Item = bool>)>,
+        contract: FnContract,
         body: Option<ast::Expr>,
         krate: CrateId,
         is_async_fn: bool,
     ) -> (Body, BodySourceMap) {
-        lower::lower(db, owner, expander, params, body, krate, is_async_fn)
+        lower::lower(db, owner, expander, params, contract, body, krate, is_async_fn)
     }
 
     fn shrink_to_fit(&mut self) {
@@ -221,6 +235,10 @@ impl Body {
             body_expr: _,
             params: _,
             self_param: _,
+            requires,
+            ensures,
+            ret_pat: _,
+            proof_blocks,
             block_scopes,
             exprs,
             labels,
@@ -229,6 +247,9 @@ impl Body {
             binding_owners,
         } = self;
         block_scopes.shrink_to_fit();
+        requires.shrink_to_fit();
+        ensures.shrink_to_fit();
+        proof_blocks.shrink_to_fit();
         exprs.shrink_to_fit();
         labels.shrink_to_fit();
         pats.shrink_to_fit();
@@ -302,6 +323,10 @@ impl Default for Body {
             block_scopes: Default::default(),
             binding_owners: Default::default(),
             self_param: Default::default(),
+            requires: Default::default(),
+            ensures: Default::default(),
+            ret_pat: Default::default(),
+            proof_blocks: Default::default(),
         }
     }
 }
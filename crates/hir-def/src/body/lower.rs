@@ -18,7 +18,7 @@ use syntax::{
         self, ArrayExprKind, AstChildren, BlockExpr, HasArgList, HasAttrs, HasLoopBody, HasName,
         RangeItem, SlicePatComponents,
     },
-    AstNode, AstPtr, AstToken as _, SyntaxNodePtr,
+    AstNode, AstPtr, AstToken as _, SyntaxNodePtr, T,
 };
 use triomphe::Arc;
 
@@ -35,8 +35,9 @@ use crate::{
             FormatPlaceholder, FormatSign, FormatTrait,
         },
         Array, Binding, BindingAnnotation, BindingId, BindingProblems, CaptureBy, ClosureKind,
-        Expr, ExprId, InlineAsm, Label, LabelId, Literal, LiteralOrConst, MatchArm, Movability,
-        OffsetOf, Pat, PatId, RecordFieldPat, RecordLitField, Statement,
+        Expr, ExprId, GhostMode, InlineAsm, Label, LabelId, Literal, LiteralOrConst, MatchArm,
+        Movability, OffsetOf, Pat, PatId, QuantifierKind, RecordFieldPat, RecordLitField,
+        Statement,
     },
     item_scope::BuiltinShadowMode,
     lang_item::LangItem,
@@ -54,6 +55,7 @@ pub(super) fn lower(
     owner: DefWithBodyId,
     expander: Expander,
     params: Option<(ast::ParamList, impl Iterator<Item = bool>)>,
+    contract: FnContract,
     body: Option<ast::Expr>,
     krate: CrateId,
     is_async_fn: bool,
@@ -73,7 +75,25 @@ pub(super) fn lower(
         label_ribs: Vec::new(),
         current_binding_owner: None,
     }
-    .collect(params, body, is_async_fn)
+    .collect(params, contract, body, is_async_fn)
+}
+
+/// The verus specification clauses of a function, lowered as part of its body.
+#[derive(Default)]
+pub(crate) struct FnContract {
+    requires: Vec<ast::Expr>,
+    ensures: Vec<ast::Expr>,
+    ret_pat: Option<ast::Pat>,
+}
+
+impl FnContract {
+    pub(crate) fn new(func: &ast::Fn) -> FnContract {
+        FnContract {
+            requires: func.requires_clause().into_iter().flat_map(|it| it.exprs()).collect(),
+            ensures: func.ensures_clause().into_iter().flat_map(|it| it.exprs()).collect(),
+            ret_pat: func.ret_type().and_then(|it| it.pat()),
+        }
+    }
 }
 
 struct ExprCollector<'a> {
@@ -177,6 +197,7 @@ impl ExprCollector<'_> {
     fn collect(
         mut self,
         param_list: Option<(ast::ParamList, impl Iterator<Item = bool>)>,
+        contract: FnContract,
         body: Option<ast::Expr>,
         is_async_fn: bool,
     ) -> (Body, BodySourceMap) {
@@ -189,6 +210,10 @@ impl ExprCollector<'_> {
                     self_param.mut_token().is_some() && self_param.amp_token().is_none();
                 let binding_id: la_arena::Idx<Binding> =
                     self.alloc_binding(name![self], BindingAnnotation::new(is_mutable, false));
+                // verus: `tracked self`
+                if self_param.syntax().children_with_tokens().any(|it| it.kind() == T![tracked]) {
+                    self.body.bindings[binding_id].ghost = Some(GhostMode::Tracked);
+                }
                 self.body.self_param = Some(binding_id);
                 self.source_map.self_param = Some(self.expander.in_file(AstPtr::new(&self_param)));
             }
@@ -196,10 +221,19 @@ impl ExprCollector<'_> {
             for (param, _) in param_list.params().zip(attr_enabled).filter(|(_, enabled)| *enabled)
             {
                 let param_pat = self.collect_pat_top(param.pat());
+                if param.tracked_token().is_some() {
+                    self.mark_ghost_bindings(param_pat, GhostMode::Tracked);
+                }
                 params.push(param_pat);
             }
             self.body.params = params.into_boxed_slice();
         };
+        let FnContract { requires, ensures, ret_pat } = contract;
+        self.body.requires = requires.into_iter().map(|it| self.collect_clause_expr(it)).collect();
+        if let Some(ret_pat) = ret_pat {
+            self.body.ret_pat = Some(self.collect_pat_top(Some(ret_pat)));
+        }
+        self.body.ensures = ensures.into_iter().map(|it| self.collect_clause_expr(it)).collect();
         self.body.body_expr = self.with_label_rib(RibKind::Closure, |this| {
             if is_async_fn {
                 match body {
@@ -221,6 +255,31 @@ impl ExprCollector<'_> {
         (self.body, self.source_map)
     }
 
+    /// Quantifier binders are ordinary bindings of the enclosing body, scoped to the quantifier.
+    fn collect_quantifier(
+        &mut self,
+        e: ast::ClosureExpr,
+        kind: QuantifierKind,
+        syntax_ptr: AstPtr<ast::Expr>,
+    ) -> ExprId {
+        let mut args = Vec::new();
+        let mut arg_types = Vec::new();
+        for param in e.param_list().into_iter().flat_map(|it| it.params()) {
+            args.push(self.collect_pat_top(param.pat()));
+            arg_types.push(param.ty().map(|it| Interned::new(TypeRef::from_ast(&self.ctx(), it))));
+        }
+        let body = self.with_label_rib(RibKind::Closure, |this| this.collect_expr_opt(e.body()));
+        self.alloc_expr(
+            Expr::Quantifier { kind, args: args.into(), arg_types: arg_types.into(), body },
+            syntax_ptr,
+        )
+    }
+
+    /// Clauses, like closures, are not part of the body's control flow.
+    fn collect_clause_expr(&mut self, expr: ast::Expr) -> ExprId {
+        self.with_label_rib(RibKind::Closure, |this| this.collect_expr(expr))
+    }
+
     fn ctx(&self) -> LowerCtx<'_> {
         self.expander.ctx(self.db)
     }
@@ -418,6 +477,23 @@ impl ExprCollector<'_> {
                 let expr = e.expr().map(|e| self.collect_expr(e));
                 self.alloc_expr(Expr::Yeet { expr }, syntax_ptr)
             }
+            // `proof { .. }` parses as a record expression, whose fields are the statements of the
+            // block
+            ast::Expr::RecordExpr(e) if is_proof_block(&e) => {
+                let statements = e
+                    .record_expr_field_list()
+                    .into_iter()
+                    .flat_map(|it| it.fields())
+                    .filter_map(|field| field.expr())
+                    .map(|it| Statement::Expr { expr: self.collect_expr(it), has_semi: true })
+                    .collect();
+                let block = self.alloc_expr(
+                    Expr::Block { id: None, statements, tail: None, label: None },
+                    syntax_ptr,
+                );
+                self.body.proof_blocks.push(block);
+                block
+            }
             ast::Expr::RecordExpr(e) => {
                 let path =
                     e.path().and_then(|path| self.expander.parse_path(self.db, path)).map(Box::new);
@@ -497,6 +573,12 @@ impl ExprCollector<'_> {
                     None => self.alloc_expr(Expr::Missing, syntax_ptr),
                 }
             }
+            ast::Expr::ClosureExpr(e) if e.forall_token().is_some() => {
+                self.collect_quantifier(e, QuantifierKind::Forall, syntax_ptr)
+            }
+            ast::Expr::ClosureExpr(e) if e.exists_token().is_some() => {
+                self.collect_quantifier(e, QuantifierKind::Exists, syntax_ptr)
+            }
             ast::Expr::ClosureExpr(e) => self.with_label_rib(RibKind::Closure, |this| {
                 let (result_expr_id, prev_binding_owner) =
                     this.initialize_binding_owner(syntax_ptr);
@@ -1109,6 +1191,11 @@ impl ExprCollector<'_> {
                     return;
                 }
                 let pat = self.collect_pat_top(stmt.pat());
+                if stmt.tracked_token().is_some() {
+                    self.mark_ghost_bindings(pat, GhostMode::Tracked);
+                } else if stmt.ghost_token().is_some() {
+                    self.mark_ghost_bindings(pat, GhostMode::Ghost);
+                }
                 let type_ref =
                     stmt.ty().map(|it| Interned::new(TypeRef::from_ast(&self.ctx(), it)));
                 let initializer = stmt.initializer().map(|e| self.collect_expr(e));
@@ -1299,6 +1386,15 @@ impl ExprCollector<'_> {
                     comma_follows_token(p.l_paren_token()),
                     binding_list,
                 );
+                // `Ghost(x)` and `Tracked(x)` bind the wrapped value as a ghost variable.
+                let ghost = match path.as_ref().and_then(|it| it.segments().last()) {
+                    Some(it) if it.name.as_str() == Some("Ghost") => Some(GhostMode::Ghost),
+                    Some(it) if it.name.as_str() == Some("Tracked") => Some(GhostMode::Tracked),
+                    _ => None,
+                };
+                if let Some(mode) = ghost {
+                    args.iter().for_each(|&arg| self.mark_ghost_bindings(arg, mode));
+                }
                 Pat::TupleStruct { path, args, ellipsis }
             }
             ast::Pat::RefPat(p) => {
@@ -2036,12 +2132,21 @@ impl ExprCollector<'_> {
         self.alloc_expr_desugared(Expr::Missing)
     }
 
+    fn mark_ghost_bindings(&mut self, pat: PatId, mode: GhostMode) {
+        let mut bindings = Vec::new();
+        self.body.walk_bindings_in_pat(pat, |binding| bindings.push(binding));
+        for binding in bindings {
+            self.body.bindings[binding].ghost = Some(mode);
+        }
+    }
+
     fn alloc_binding(&mut self, name: Name, mode: BindingAnnotation) -> BindingId {
         let binding = self.body.bindings.alloc(Binding {
             name,
             mode,
             definitions: SmallVec::new(),
             problems: None,
+            ghost: None,
         });
         if let Some(owner) = self.current_binding_owner {
             self.body.binding_owners.insert(binding, owner);
@@ -2082,6 +2187,14 @@ fn comma_follows_token(t: Option<syntax::SyntaxToken>) -> bool {
         .map_or(false, |it| it.kind() == syntax::T![,])
 }
 
+/// Whether `expr` is a `proof { .. }` block of verus.
+fn is_proof_block(expr: &ast::RecordExpr) -> bool {
+    expr.path().map_or(false, |path| {
+        path.qualifier().is_none()
+            && path.segment().and_then(|it| it.name_ref()).map_or(false, |it| it.text() == "proof")
+    })
+}
+
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 enum ArgumentType {
     Format(FormatTrait),
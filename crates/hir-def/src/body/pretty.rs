@@ -7,7 +7,7 @@ use itertools::Itertools;
 use crate::{
     hir::{
         Array, BindingAnnotation, CaptureBy, ClosureKind, Literal, LiteralOrConst, Movability,
-        Statement,
+        QuantifierKind, Statement,
     },
     pretty::{print_generic_args, print_path, print_type_ref},
     type_ref::TypeRef,
@@ -494,6 +494,24 @@ impl Printer<'_> {
                 w!(self, "view ");
                 self.print_expr(*condition);
             }
+            Expr::Quantifier { kind, args, arg_types, body } => {
+                match kind {
+                    QuantifierKind::Forall => w!(self, "forall|"),
+                    QuantifierKind::Exists => w!(self, "exists|"),
+                }
+                for (i, (pat, ty)) in args.iter().zip(arg_types.iter()).enumerate() {
+                    if i != 0 {
+                        w!(self, ", ");
+                    }
+                    self.print_pat(*pat);
+                    if let Some(ty) = ty {
+                        w!(self, ": ");
+                        self.print_type_ref(ty);
+                    }
+                }
+                w!(self, "| ");
+                self.print_expr(*body);
+            }
             Expr::IsExpr { expr, type_ref } => {
                 self.print_expr(*expr);
                 w!(self, " is ");
@@ -105,7 +105,20 @@ impl ExprScopes {
             scopes.add_bindings(body, root, self_param);
         }
         scopes.add_params_bindings(body, root, &body.params);
+        let params = root;
         compute_expr_scopes(body.body_expr, body, &mut scopes, &mut root, resolve_const_block);
+        for &expr in body.requires.iter() {
+            let mut scope = scopes.new_scope(params);
+            compute_expr_scopes(expr, body, &mut scopes, &mut scope, resolve_const_block);
+        }
+        let ensures_root = scopes.new_scope(params);
+        if let Some(ret_pat) = body.ret_pat {
+            scopes.add_pat_bindings(body, ensures_root, ret_pat);
+        }
+        for &expr in body.ensures.iter() {
+            let mut scope = scopes.new_scope(ensures_root);
+            compute_expr_scopes(expr, body, &mut scopes, &mut scope, resolve_const_block);
+        }
         scopes
     }
 
@@ -252,7 +265,8 @@ fn compute_expr_scopes(
             let mut scope = scopes.new_labeled_scope(*scope, make_label(label));
             compute_expr_scopes(scopes, *body_expr, &mut scope);
         }
-        Expr::Closure { args, body: body_expr, .. } => {
+        Expr::Closure { args, body: body_expr, .. }
+        | Expr::Quantifier { args, body: body_expr, .. } => {
             let mut scope = scopes.new_scope(*scope);
             scopes.add_params_bindings(body, scope, args);
             compute_expr_scopes(scopes, *body_expr, &mut scope);
@@ -420,6 +434,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_requires_scope() {
+        do_check(
+            r"
+            fn quux(x: u32) -> (r: u32)
+                requires x > $0,
+            {
+                let y = x;
+                y
+            }",
+            &["x"],
+        );
+    }
+
+    #[test]
+    fn test_ensures_scope() {
+        do_check(
+            r"
+            fn quux(x: u32) -> (r: u32)
+                ensures r > $0,
+            {
+                x
+            }",
+            &["r", "x"],
+        );
+    }
+
     #[test]
     fn test_bindings_after_at() {
         do_check(
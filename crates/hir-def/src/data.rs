@@ -144,6 +144,16 @@ impl FunctionData {
     pub fn is_varargs(&self) -> bool {
         self.flags.contains(FnFlags::IS_VARARGS)
     }
+
+    /// Whether this is a verus `spec fn`, whose body is never executed.
+    pub fn is_spec(&self) -> bool {
+        self.flags.contains(FnFlags::IS_SPEC)
+    }
+
+    /// Whether this is a verus `proof fn`.
+    pub fn is_proof(&self) -> bool {
+        self.flags.contains(FnFlags::IS_PROOF)
+    }
 }
 
 fn parse_rustc_legacy_const_generics(tt: &crate::tt::Subtree) -> Box<[u32]> {
@@ -173,6 +173,13 @@ pub enum Expr {
     View {
         condition: ExprId,
     },
+    /// `forall|x: int| body` or `exists|x: int| body`, the binders are bound in `body`.
+    Quantifier {
+        kind: QuantifierKind,
+        args: Box<[PatId]>,
+        arg_types: Box<[Option<Interned<TypeRef>>]>,
+        body: ExprId,
+    },
     IsExpr {
         expr: ExprId,
         type_ref: Interned<TypeRef>,
@@ -327,6 +334,12 @@ pub struct InlineAsm {
     pub e: ExprId,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantifierKind {
+    Forall,
+    Exists,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ClosureKind {
     Closure,
@@ -502,6 +515,9 @@ impl Expr {
             Expr::View { condition } => {
                 f(*condition);
             }
+            Expr::Quantifier { body, .. } => {
+                f(*body);
+            }
             Expr::IsExpr { expr, .. } => {
                 f(*expr);
             }
@@ -548,6 +564,15 @@ impl BindingAnnotation {
     }
 }
 
+/// verus: the mode of a binding that only exists in ghost code.
+#[derive(Clone, PartialEq, Eq, Debug, Copy)]
+pub enum GhostMode {
+    /// `let ghost x`, usable in spec code.
+    Ghost,
+    /// `let tracked x` or a `tracked x` parameter, usable in proof code.
+    Tracked,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum BindingProblems {
     /// <https://doc.rust-lang.org/stable/error_codes/E0416.html>
@@ -564,6 +589,7 @@ pub struct Binding {
     pub mode: BindingAnnotation,
     pub definitions: SmallVec<[PatId; 1]>,
     pub problems: Option<BindingProblems>,
+    pub ghost: Option<GhostMode>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -756,7 +756,7 @@ pub enum ParamAstId {
 
 bitflags::bitflags! {
     #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
-    pub(crate) struct FnFlags: u8 {
+    pub(crate) struct FnFlags: u16 {
         const HAS_SELF_PARAM = 1 << 0;
         const HAS_BODY = 1 << 1;
         const HAS_DEFAULT_KW = 1 << 2;
@@ -764,6 +764,10 @@ bitflags::bitflags! {
         const HAS_ASYNC_KW = 1 << 4;
         const HAS_UNSAFE_KW = 1 << 5;
         const IS_VARARGS = 1 << 6;
+        // verus: `spec fn` and `spec(checked) fn`
+        const IS_SPEC = 1 << 7;
+        // verus: `proof fn`
+        const IS_PROOF = 1 << 8;
     }
 }
 
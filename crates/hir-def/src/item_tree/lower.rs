@@ -422,6 +422,15 @@ impl<'a> Ctx<'a> {
         if func.unsafe_token().is_some() {
             flags |= FnFlags::HAS_UNSAFE_KW;
         }
+        if func
+            .fn_mode()
+            .map_or(false, |it| it.spec_token().is_some() || it.mode_spec_checked().is_some())
+        {
+            flags |= FnFlags::IS_SPEC;
+        }
+        if func.fn_mode().map_or(false, |it| it.proof_token().is_some()) {
+            flags |= FnFlags::IS_PROOF;
+        }
 
         let res = Function {
             name,
@@ -489,10 +498,7 @@ impl<'a> Ctx<'a> {
         id(self.data().broadcast_groups.alloc(res)).into()
     }
 
-    fn lower_broadcast_use(
-        &mut self,
-        bu: &ast::BroadcastUse,
-    ) -> FileItemTreeId<BroadcastUse> {
+    fn lower_broadcast_use(&mut self, bu: &ast::BroadcastUse) -> FileItemTreeId<BroadcastUse> {
         let ast_id = self.source_ast_id_map.ast_id(bu);
         let res = BroadcastUse { ast_id };
         id(self.data().broadcast_uses.alloc(res)).into()
@@ -272,6 +272,12 @@ impl Printer<'_> {
                 if flags.contains(FnFlags::HAS_UNSAFE_KW) {
                     w!(self, "unsafe ");
                 }
+                if flags.contains(FnFlags::IS_SPEC) {
+                    w!(self, "spec ");
+                }
+                if flags.contains(FnFlags::IS_PROOF) {
+                    w!(self, "proof ");
+                }
                 if let Some(abi) = abi {
                     w!(self, "extern \"{}\" ", abi);
                 }
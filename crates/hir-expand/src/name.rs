@@ -280,6 +280,9 @@ pub mod known {
         bool,
         char,
         str,
+        // Verus mathematical integers
+        int,
+        nat,
         // Special names
         macro_rules,
         doc,
@@ -361,6 +364,9 @@ pub mod known {
         new,
         new_v1_formatted,
         none,
+        view,
+        deep_view,
+        spec_index,
         // Builtin macros
         asm,
         assert,
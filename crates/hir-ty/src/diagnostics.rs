@@ -2,6 +2,8 @@
 mod decl_check;
 mod expr;
 mod match_check;
+mod mode_check;
+mod tracked_moves;
 mod unsafe_check;
 
 pub use crate::diagnostics::{
@@ -9,5 +11,7 @@ pub use crate::diagnostics::{
     expr::{
         record_literal_missing_fields, record_pattern_missing_fields, BodyValidationDiagnostic,
     },
+    mode_check::{mode_mismatches, referenced_bindings, Mode, ModeMismatch},
+    tracked_moves::{tracked_use_after_moves, TrackedUseAfterMove},
     unsafe_check::{missing_unsafe, unsafe_expressions, UnsafeExpr},
 };
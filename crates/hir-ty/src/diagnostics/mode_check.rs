@@ -0,0 +1,227 @@
+//! Checks the verus modes of a body: exec functions cannot be called from spec or proof code, and
+//! ghost variables cannot be used by exec code.
+
+use hir_def::{
+    body::Body,
+    hir::{BindingId, Expr, ExprId, GhostMode, Statement},
+    resolver::{resolver_for_expr, ResolveValueResult, Resolver, ValueNs},
+    DefWithBodyId, FunctionId, HasModule,
+};
+use rustc_hash::FxHashSet;
+
+use crate::{db::HirDatabase, utils::is_verus_builtin_crate, InferenceResult, TyExt};
+
+/// The verus mode of a function, or of the code within its body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Spec,
+    Proof,
+    Exec,
+}
+
+impl Mode {
+    pub fn of_fn(db: &dyn HirDatabase, func: FunctionId) -> Mode {
+        let data = db.function_data(func);
+        if data.is_spec() {
+            Mode::Spec
+        } else if data.is_proof() {
+            Mode::Proof
+        } else {
+            Mode::Exec
+        }
+    }
+
+    /// The mode of code nested in `self` that is itself `inner`: spec code stays spec code.
+    fn enter(self, inner: Mode) -> Mode {
+        match self {
+            Mode::Spec => Mode::Spec,
+            _ => inner,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ModeMismatch {
+    /// A call to an exec function from spec or proof code.
+    ExecCall { expr: ExprId, callee: FunctionId, mode: Mode },
+    /// A use of a `ghost` or `tracked` variable by exec code.
+    GhostVariable { expr: ExprId, binding: BindingId },
+}
+
+pub fn mode_mismatches(db: &dyn HirDatabase, def: DefWithBodyId) -> Vec<ModeMismatch> {
+    let _p = tracing::info_span!("mode_mismatches").entered();
+
+    let mode = match def {
+        DefWithBodyId::FunctionId(it) => Mode::of_fn(db, it),
+        DefWithBodyId::StaticId(_)
+        | DefWithBodyId::ConstId(_)
+        | DefWithBodyId::VariantId(_)
+        | DefWithBodyId::InTypeConstId(_) => Mode::Exec,
+    };
+    let body = db.body(def);
+    let infer = db.infer(def);
+    let mut checker = ModeChecker {
+        db,
+        infer: &infer,
+        body: &body,
+        def,
+        resolver: resolver_for_expr(db.upcast(), def, body.body_expr),
+        res: Vec::new(),
+    };
+    checker.walk(body.body_expr, mode);
+    for &clause in body.requires.iter().chain(body.ensures.iter()) {
+        checker.walk(clause, Mode::Spec);
+    }
+    checker.res
+}
+
+/// The bindings referenced anywhere in the body, including from the ghost code that is not lowered
+/// to MIR: contract clauses, assertions and quantifiers.
+pub fn referenced_bindings(db: &dyn HirDatabase, def: DefWithBodyId) -> FxHashSet<BindingId> {
+    let body = db.body(def);
+    let mut resolver = resolver_for_expr(db.upcast(), def, body.body_expr);
+    let mut res = FxHashSet::default();
+    for (expr, it) in body.exprs.iter() {
+        let Expr::Path(path) = it else { continue };
+        let g = resolver.update_to_inner_scope(db.upcast(), def, expr);
+        let value = resolver.resolve_path_in_value_ns(db.upcast(), path);
+        if let Some(ResolveValueResult::ValueNs(ValueNs::LocalBinding(binding), _)) = value {
+            res.insert(binding);
+        }
+        resolver.reset_to_guard(g);
+    }
+    res
+}
+
+struct ModeChecker<'a> {
+    db: &'a dyn HirDatabase,
+    infer: &'a InferenceResult,
+    body: &'a Body,
+    def: DefWithBodyId,
+    resolver: Resolver,
+    res: Vec<ModeMismatch>,
+}
+
+impl ModeChecker<'_> {
+    fn walk(&mut self, current: ExprId, mode: Mode) {
+        let body = self.body;
+        let expr = &body.exprs[current];
+        match expr {
+            &Expr::Call { callee, ref args, .. } => {
+                if let Some(inner) =
+                    ghost_wrapper(self.db, &mut self.resolver, self.def, body, callee)
+                {
+                    self.walk(callee, mode);
+                    args.iter().for_each(|&arg| self.walk(arg, mode.enter(inner)));
+                    return;
+                }
+                if let Some(func) = self.infer[callee].as_fn_def(self.db) {
+                    self.check_call(current, func, mode);
+                }
+            }
+            Expr::MethodCall { .. } => {
+                if let Some((func, _)) = self.infer.method_resolution(current) {
+                    self.check_call(current, func, mode);
+                }
+            }
+            Expr::Path(path) if mode == Mode::Exec => {
+                let g = self.resolver.update_to_inner_scope(self.db.upcast(), self.def, current);
+                let value = self.resolver.resolve_path_in_value_ns(self.db.upcast(), path);
+                if let Some(ResolveValueResult::ValueNs(ValueNs::LocalBinding(binding), _)) = value
+                {
+                    if body.bindings[binding].ghost.is_some() {
+                        self.res.push(ModeMismatch::GhostVariable { expr: current, binding });
+                    }
+                }
+                self.resolver.reset_to_guard(g);
+            }
+            Expr::Block { statements, tail, .. } | Expr::Unsafe { statements, tail, .. } => {
+                let mode = if body.proof_blocks.contains(&current) {
+                    mode.enter(Mode::Proof)
+                } else {
+                    mode
+                };
+                for stmt in statements.iter() {
+                    match stmt {
+                        Statement::Let { pat, initializer, else_branch, .. } => {
+                            let mut ghost = None;
+                            body.walk_bindings_in_pat(*pat, |it| {
+                                ghost = ghost.or(body.bindings[it].ghost)
+                            });
+                            let inner = match ghost {
+                                Some(GhostMode::Ghost) => mode.enter(Mode::Spec),
+                                Some(GhostMode::Tracked) => mode.enter(Mode::Proof),
+                                None => mode,
+                            };
+                            initializer.iter().for_each(|&it| self.walk(it, inner));
+                            else_branch.iter().for_each(|&it| self.walk(it, mode));
+                        }
+                        Statement::Expr { expr, .. } => self.walk(*expr, mode),
+                        Statement::Item => (),
+                    }
+                }
+                tail.iter().for_each(|&it| self.walk(it, mode));
+                return;
+            }
+            &Expr::Assert { condition, body: by_block } => {
+                self.walk(condition, Mode::Spec);
+                by_block.iter().for_each(|&it| self.walk(it, mode.enter(Mode::Proof)));
+                return;
+            }
+            &Expr::Assume { condition } | &Expr::Quantifier { body: condition, .. } => {
+                self.walk(condition, Mode::Spec);
+                return;
+            }
+            _ => {}
+        }
+
+        expr.walk_child_exprs(|child| self.walk(child, mode));
+    }
+
+    fn check_call(&mut self, expr: ExprId, callee: FunctionId, mode: Mode) {
+        if mode == Mode::Exec || Mode::of_fn(self.db, callee) != Mode::Exec {
+            return;
+        }
+        // The exec functions of libraries, such as `Vec::len`, get their spec meaning from the
+        // specifications vstd assumes for them, which are not tracked here.
+        let krate = callee.module(self.db.upcast()).krate();
+        if !self.db.crate_graph()[krate].origin.is_local() {
+            return;
+        }
+        // `#[verifier::when_used_as_spec(..)]` exec functions have a spec counterpart.
+        let data = self.db.function_data(callee);
+        let has_spec_counterpart = data.attrs.iter().any(|attr| {
+            attr.path().segments().last().and_then(|it| it.as_str()) == Some("when_used_as_spec")
+        });
+        if !has_spec_counterpart {
+            self.res.push(ModeMismatch::ExecCall { expr, callee, mode });
+        }
+    }
+}
+
+/// `Ghost(..)` and `Tracked(..)` of builtin move ghost values in and out of exec code.
+pub(super) fn ghost_wrapper(
+    db: &dyn HirDatabase,
+    resolver: &mut Resolver,
+    def: DefWithBodyId,
+    body: &Body,
+    callee: ExprId,
+) -> Option<Mode> {
+    let Expr::Path(path) = &body.exprs[callee] else { return None };
+    let g = resolver.update_to_inner_scope(db.upcast(), def, callee);
+    let value = resolver.resolve_path_in_value_ns_fully(db.upcast(), path);
+    resolver.reset_to_guard(g);
+    let (name, module) = match value? {
+        ValueNs::StructId(it) => (db.struct_data(it).name.clone(), it.module(db.upcast())),
+        ValueNs::FunctionId(it) => (db.function_data(it).name.clone(), it.module(db.upcast())),
+        _ => return None,
+    };
+    if !is_verus_builtin_crate(db.upcast(), module.krate()) {
+        return None;
+    }
+    match name.as_str()? {
+        "Ghost" => Some(Mode::Spec),
+        "Tracked" => Some(Mode::Proof),
+        _ => None,
+    }
+}
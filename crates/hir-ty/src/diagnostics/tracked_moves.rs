@@ -0,0 +1,307 @@
+//! Checks that `tracked` variables are not used after they were moved. Tracked values are linear
+//! like ordinary Rust values, but the proof code using them is not lowered to MIR, so the borrow
+//! checker never sees these moves.
+
+use hir_def::{
+    body::Body,
+    hir::{BinaryOp, BindingId, Expr, ExprId, GhostMode, Statement},
+    resolver::{resolver_for_expr, ResolveValueResult, Resolver, ValueNs},
+    DefWithBodyId, FunctionId,
+};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    db::HirDatabase,
+    diagnostics::mode_check::{ghost_wrapper, Mode},
+    Adjust, InferenceResult, TyExt,
+};
+
+#[derive(Debug)]
+pub struct TrackedUseAfterMove {
+    pub expr: ExprId,
+    pub binding: BindingId,
+    /// The expression that moved the value out of `binding`.
+    pub moved_at: ExprId,
+}
+
+pub fn tracked_use_after_moves(
+    db: &dyn HirDatabase,
+    def: DefWithBodyId,
+) -> Vec<TrackedUseAfterMove> {
+    let _p = tracing::info_span!("tracked_use_after_moves").entered();
+
+    // Spec code only ever copies ghost values.
+    if let DefWithBodyId::FunctionId(it) = def {
+        if Mode::of_fn(db, it) == Mode::Spec {
+            return Vec::new();
+        }
+    }
+    let body = db.body(def);
+    let infer = db.infer(def);
+    let mut checker = MoveChecker {
+        db,
+        infer: &infer,
+        body: &body,
+        def,
+        resolver: resolver_for_expr(db.upcast(), def, body.body_expr),
+        moved: FxHashMap::default(),
+        continued: Vec::new(),
+        broken: Vec::new(),
+        res: Vec::new(),
+    };
+    checker.walk(body.body_expr, Use::Move);
+    checker.res
+}
+
+/// How an expression's value is used by its parent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Use {
+    /// The value is moved out, e.g. passed by value to a `tracked` parameter.
+    Move,
+    /// Only the place is used, e.g. borrowed or projected.
+    Place,
+    /// The value is used by spec code, which never consumes it.
+    Spec,
+}
+
+struct MoveChecker<'a> {
+    db: &'a dyn HirDatabase,
+    infer: &'a InferenceResult,
+    body: &'a Body,
+    def: DefWithBodyId,
+    resolver: Resolver,
+    moved: FxHashMap<BindingId, ExprId>,
+    /// The moved values at each `continue` of the innermost loop.
+    continued: Vec<FxHashMap<BindingId, ExprId>>,
+    /// The moved values at each `break` of the innermost loop.
+    broken: Vec<FxHashMap<BindingId, ExprId>>,
+    res: Vec<TrackedUseAfterMove>,
+}
+
+impl MoveChecker<'_> {
+    fn walk(&mut self, current: ExprId, use_: Use) {
+        if use_ == Use::Spec {
+            return;
+        }
+        let body = self.body;
+        match &body.exprs[current] {
+            Expr::Path(_) => {
+                if let Some(binding) = self.local_binding(current) {
+                    self.use_binding(current, binding, use_);
+                }
+            }
+            &Expr::Call { callee, ref args, .. } => {
+                self.walk(callee, Use::Move);
+                if let Some(inner) =
+                    ghost_wrapper(self.db, &mut self.resolver, self.def, body, callee)
+                {
+                    let use_ = if inner == Mode::Spec { Use::Spec } else { Use::Move };
+                    args.iter().for_each(|&arg| self.walk(arg, use_));
+                    return;
+                }
+                match self.infer[callee].as_fn_def(self.db) {
+                    Some(func) => {
+                        // Methods called by path take their receiver as the first argument.
+                        let offset = usize::from(self.db.body(func.into()).self_param.is_some());
+                        for (idx, &arg) in args.iter().enumerate() {
+                            self.walk(arg, self.arg_use(func, idx.checked_sub(offset)));
+                        }
+                    }
+                    None => args.iter().for_each(|&arg| self.walk(arg, Use::Move)),
+                }
+            }
+            &Expr::MethodCall { receiver, ref args, .. } => {
+                match self.infer.method_resolution(current) {
+                    Some((func, _)) => {
+                        self.walk(receiver, self.arg_use(func, None));
+                        for (idx, &arg) in args.iter().enumerate() {
+                            self.walk(arg, self.arg_use(func, Some(idx)));
+                        }
+                    }
+                    None => {
+                        self.walk(receiver, Use::Move);
+                        args.iter().for_each(|&arg| self.walk(arg, Use::Move));
+                    }
+                }
+            }
+            &Expr::Ref { expr, .. } | &Expr::Field { expr, .. } | &Expr::Let { expr, .. } => {
+                self.walk(expr, Use::Place);
+            }
+            &Expr::Index { base, index, .. } => {
+                self.walk(base, Use::Place);
+                self.walk(index, Use::Move);
+            }
+            &Expr::BinaryOp { lhs, rhs, op: Some(BinaryOp::Assignment { op: None }) } => {
+                self.walk(rhs, Use::Move);
+                // Assigning to a moved variable gives it a new value.
+                match self.local_binding(lhs) {
+                    Some(binding) => {
+                        self.moved.remove(&binding);
+                    }
+                    None => self.walk(lhs, Use::Place),
+                }
+            }
+            &Expr::BinaryOp { lhs, rhs, op: Some(BinaryOp::CmpOp(_)) } => {
+                self.walk(lhs, Use::Place);
+                self.walk(rhs, Use::Place);
+            }
+            Expr::Block { statements, tail, .. } | Expr::Unsafe { statements, tail, .. } => {
+                for stmt in statements.iter() {
+                    match stmt {
+                        Statement::Let { pat, initializer, else_branch, .. } => {
+                            let mut ghost = false;
+                            body.walk_bindings_in_pat(*pat, |it| {
+                                ghost |= body.bindings[it].ghost == Some(GhostMode::Ghost)
+                            });
+                            let use_ = if ghost { Use::Spec } else { Use::Move };
+                            initializer.iter().for_each(|&it| self.walk(it, use_));
+                            else_branch.iter().for_each(|&it| self.walk(it, Use::Move));
+                        }
+                        Statement::Expr { expr, .. } => self.walk(*expr, Use::Move),
+                        Statement::Item => (),
+                    }
+                }
+                tail.iter().for_each(|&it| self.walk(it, use_));
+            }
+            &Expr::If { condition, then_branch, else_branch } => {
+                self.walk(condition, Use::Move);
+                let before = self.moved.clone();
+                self.walk(then_branch, use_);
+                let after_then = std::mem::replace(&mut self.moved, before);
+                else_branch.iter().for_each(|&it| self.walk(it, use_));
+                if else_branch.map_or(false, |it| self.diverges(it)) {
+                    self.moved = after_then;
+                } else if !self.diverges(then_branch) {
+                    self.merge(after_then);
+                }
+            }
+            Expr::Match { expr, arms } => {
+                self.walk(*expr, Use::Place);
+                let before = self.moved.clone();
+                let mut after = before.clone();
+                for arm in arms.iter() {
+                    self.moved = before.clone();
+                    arm.guard.iter().for_each(|&it| self.walk(it, Use::Move));
+                    self.walk(arm.expr, use_);
+                    if !self.diverges(arm.expr) {
+                        after.extend(std::mem::take(&mut self.moved));
+                    }
+                }
+                self.moved = after;
+            }
+            &Expr::Loop { body: loop_body, .. } => {
+                let outer_continued = std::mem::take(&mut self.continued);
+                let outer_broken = std::mem::take(&mut self.broken);
+                self.walk(loop_body, Use::Move);
+                // The second pass catches values moved by one iteration and used by the next,
+                // which starts at the end of the body or at a `continue`.
+                let mut next = (!self.diverges(loop_body)).then(|| self.moved.clone());
+                for continued in std::mem::take(&mut self.continued) {
+                    let next = next.get_or_insert_with(FxHashMap::default);
+                    for (binding, moved_at) in continued {
+                        next.entry(binding).or_insert(moved_at);
+                    }
+                }
+                if let Some(next) = next {
+                    self.moved = next;
+                    self.walk(loop_body, Use::Move);
+                }
+                // The loop is left at a `break`.
+                let mut after = FxHashMap::default();
+                for broken in std::mem::replace(&mut self.broken, outer_broken) {
+                    for (binding, moved_at) in broken {
+                        after.entry(binding).or_insert(moved_at);
+                    }
+                }
+                self.moved = after;
+                self.continued = outer_continued;
+            }
+            &Expr::Break { expr, .. } => {
+                expr.iter().for_each(|&it| self.walk(it, Use::Move));
+                self.broken.push(self.moved.clone());
+            }
+            Expr::Continue { .. } => self.continued.push(self.moved.clone()),
+            &Expr::Assert { body: by_block, .. } => {
+                by_block.iter().for_each(|&it| self.walk(it, Use::Move));
+            }
+            // Closures are checked by the borrow checker, quantifiers are spec code.
+            Expr::Assume { .. } | Expr::Quantifier { .. } | Expr::Closure { .. } => (),
+            expr => expr.walk_child_exprs(|child| self.walk(child, Use::Move)),
+        }
+    }
+
+    fn use_binding(&mut self, expr: ExprId, binding: BindingId, use_: Use) {
+        if self.body.bindings[binding].ghost != Some(GhostMode::Tracked)
+            || self.infer.type_of_binding[binding].clone().is_copy(self.db, self.def)
+        {
+            return;
+        }
+        if let Some(&moved_at) = self.moved.get(&binding) {
+            if !self.res.iter().any(|it| it.expr == expr) {
+                self.res.push(TrackedUseAfterMove { expr, binding, moved_at });
+            }
+            return;
+        }
+        let autoref = self.infer.expr_adjustments.get(&expr).map_or(false, |adjustments| {
+            adjustments.iter().any(|it| matches!(it.kind, Adjust::Borrow(_)))
+        });
+        if use_ == Use::Move && !autoref {
+            self.moved.insert(binding, expr);
+        }
+    }
+
+    /// How a call to `callee` uses the argument for its `param`-th parameter, `None` being `self`.
+    fn arg_use(&self, callee: FunctionId, param: Option<usize>) -> Use {
+        match Mode::of_fn(self.db, callee) {
+            Mode::Spec => Use::Spec,
+            Mode::Exec => Use::Move,
+            // Only `tracked` parameters of proof functions, `tracked self` included, consume their
+            // arguments, the others are ghost.
+            Mode::Proof => {
+                let body = self.db.body(callee.into());
+                let Some(param) = param else {
+                    return match body.self_param {
+                        Some(it) if body.bindings[it].ghost == Some(GhostMode::Tracked) => {
+                            Use::Move
+                        }
+                        _ => Use::Spec,
+                    };
+                };
+                let mut tracked = false;
+                if let Some(&pat) = body.params.get(param) {
+                    body.walk_bindings_in_pat(pat, |it| {
+                        tracked |= body.bindings[it].ghost == Some(GhostMode::Tracked)
+                    });
+                }
+                if tracked {
+                    Use::Move
+                } else {
+                    Use::Spec
+                }
+            }
+        }
+    }
+
+    fn local_binding(&mut self, expr: ExprId) -> Option<BindingId> {
+        let Expr::Path(path) = &self.body.exprs[expr] else { return None };
+        let g = self.resolver.update_to_inner_scope(self.db.upcast(), self.def, expr);
+        let value = self.resolver.resolve_path_in_value_ns(self.db.upcast(), path);
+        self.resolver.reset_to_guard(g);
+        match value {
+            Some(ResolveValueResult::ValueNs(ValueNs::LocalBinding(binding), _)) => Some(binding),
+            _ => None,
+        }
+    }
+
+    /// Whether control never leaves `expr` normally, as it returns, breaks or continues.
+    fn diverges(&self, expr: ExprId) -> bool {
+        self.infer[expr].is_never()
+    }
+
+    /// A value moved on either branch is moved after them.
+    fn merge(&mut self, other: FxHashMap<BindingId, ExprId>) {
+        for (binding, moved_at) in other {
+            self.moved.entry(binding).or_insert(moved_at);
+        }
+    }
+}
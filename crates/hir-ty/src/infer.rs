@@ -549,6 +549,9 @@ pub(crate) struct InferenceContext<'a> {
     resume_yield_tys: Option<(Ty, Ty)>,
     diverges: Diverges,
     breakables: Vec<BreakableContext>,
+    /// Whether we are within spec code of a non-spec body: a contract clause, the condition of an
+    /// `assert` or `assume`, or a quantifier.
+    in_spec: bool,
 
     deferred_cast_checks: Vec<CastCheck>,
 
@@ -628,6 +631,7 @@ impl<'a> InferenceContext<'a> {
             resolver,
             diverges: Diverges::Maybe,
             breakables: Vec::new(),
+            in_spec: false,
             deferred_cast_checks: Vec::new(),
             current_captures: Vec::new(),
             current_closure: None,
@@ -854,6 +858,9 @@ impl<'a> InferenceContext<'a> {
 
         self.return_ty = self.normalize_associated_types_in(return_ty);
         self.return_coercion = Some(CoerceMany::new(self.return_ty.clone()));
+        if let Some(ret_pat) = self.body.ret_pat {
+            self.infer_top_pat(ret_pat, &self.return_ty.clone());
+        }
 
         // Functions might be associated items that define ATPITs.
         // To define an ATPITs, that ATPIT must appear in the function's signatures.
@@ -1016,6 +1023,10 @@ impl<'a> InferenceContext<'a> {
                 )
             }
         }
+        let bool_ty = self.result.standard_types.bool_.clone();
+        for &clause in self.body.requires.iter().chain(self.body.ensures.iter()) {
+            self.infer_spec_expr_coerce(clause, &Expectation::has_type(bool_ty.clone()));
+        }
     }
 
     fn write_expr_ty(&mut self, expr: ExprId, ty: Ty) {
@@ -715,6 +715,9 @@ impl InferenceContext<'_> {
             Expr::View { condition } => {
                 self.consume_expr(*condition);
             }
+            Expr::Quantifier { body, .. } => {
+                self.consume_expr(*body);
+            }
             Expr::IsExpr { expr, .. } => {
                 self.consume_expr(*expr);
             }
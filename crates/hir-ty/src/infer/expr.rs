@@ -12,8 +12,10 @@ use hir_def::{
         ArithOp, Array, BinaryOp, ClosureKind, Expr, ExprId, LabelId, Literal, Statement, UnaryOp,
     },
     lang_item::{LangItem, LangItemTarget},
-    path::{GenericArgs, Path},
-    BlockId, FieldId, GenericDefId, GenericParamId, ItemContainerId, Lookup, TupleFieldId, TupleId,
+    path::{GenericArgs, ModPath, Path, PathKind},
+    resolver::TypeNs,
+    BlockId, DefWithBodyId, FieldId, GenericDefId, GenericParamId, ItemContainerId, Lookup,
+    TupleFieldId, TupleId,
 };
 use hir_expand::name::{name, Name};
 use stdx::always;
@@ -40,6 +42,7 @@ use crate::{
     primitive::{self, UintTy},
     static_lifetime, to_chalk_trait_id,
     traits::FnTrait,
+    utils::is_verus_builtin_crate,
     Adjust, Adjustment, AdtId, AutoBorrow, Binders, CallableDefId, FnAbi, FnPointer, FnSig,
     FnSubst, Interner, Rawness, Scalar, Substitution, TraitEnvironment, TraitRef, Ty, TyBuilder,
     TyExt, TyKind,
@@ -767,52 +770,56 @@ impl InferenceContext<'_> {
             }
             Expr::Index { base, index, is_assignee_expr } => {
                 let base_ty = self.infer_expr_inner(*base, &Expectation::none());
-                let index_ty = self.infer_expr(*index, &Expectation::none());
-
-                if let Some(index_trait) = self.resolve_lang_trait(LangItem::Index) {
-                    let canonicalized = self.canonicalize(base_ty.clone());
-                    let receiver_adjustments = method_resolution::resolve_indexing_op(
-                        self.db,
-                        self.table.trait_env.clone(),
-                        canonicalized,
-                        index_trait,
-                    );
-                    let (self_ty, mut adj) = receiver_adjustments
-                        .map_or((self.err_ty(), Vec::new()), |adj| {
-                            adj.apply(&mut self.table, base_ty)
-                        });
-                    // mutability will be fixed up in `InferenceContext::infer_mut`;
-                    adj.push(Adjustment::borrow(Mutability::Not, self_ty.clone()));
-                    self.write_expr_adj(*base, adj);
-                    if let Some(func) =
-                        self.db.trait_data(index_trait).method_by_name(&name!(index))
-                    {
-                        let substs = TyBuilder::subst_for_def(self.db, index_trait, None)
-                            .push(self_ty.clone())
-                            .push(index_ty.clone())
-                            .build();
-                        self.write_method_resolution(tgt_expr, func, substs);
-                    }
-                    let assoc = self.resolve_ops_index_output();
-                    let res = self.resolve_associated_type_with_params(
-                        self_ty.clone(),
-                        assoc,
-                        &[index_ty.clone().cast(Interner)],
-                    );
-
-                    if *is_assignee_expr {
-                        if let Some(index_trait) = self.resolve_lang_trait(LangItem::IndexMut) {
-                            let trait_ref = TyBuilder::trait_ref(self.db, index_trait)
-                                .push(self_ty)
-                                .fill(|_| index_ty.clone().cast(Interner))
+                if let Some(ty) = self.infer_spec_index(tgt_expr, *base, &base_ty, *index) {
+                    ty
+                } else {
+                    let index_ty = self.infer_expr(*index, &Expectation::none());
+
+                    if let Some(index_trait) = self.resolve_lang_trait(LangItem::Index) {
+                        let canonicalized = self.canonicalize(base_ty.clone());
+                        let receiver_adjustments = method_resolution::resolve_indexing_op(
+                            self.db,
+                            self.table.trait_env.clone(),
+                            canonicalized,
+                            index_trait,
+                        );
+                        let (self_ty, mut adj) = receiver_adjustments
+                            .map_or((self.err_ty(), Vec::new()), |adj| {
+                                adj.apply(&mut self.table, base_ty)
+                            });
+                        // mutability will be fixed up in `InferenceContext::infer_mut`;
+                        adj.push(Adjustment::borrow(Mutability::Not, self_ty.clone()));
+                        self.write_expr_adj(*base, adj);
+                        if let Some(func) =
+                            self.db.trait_data(index_trait).method_by_name(&name!(index))
+                        {
+                            let substs = TyBuilder::subst_for_def(self.db, index_trait, None)
+                                .push(self_ty.clone())
+                                .push(index_ty.clone())
                                 .build();
-                            self.push_obligation(trait_ref.cast(Interner));
+                            self.write_method_resolution(tgt_expr, func, substs);
                         }
-                    }
+                        let assoc = self.resolve_ops_index_output();
+                        let res = self.resolve_associated_type_with_params(
+                            self_ty.clone(),
+                            assoc,
+                            &[index_ty.clone().cast(Interner)],
+                        );
 
-                    res
-                } else {
-                    self.err_ty()
+                        if *is_assignee_expr {
+                            if let Some(index_trait) = self.resolve_lang_trait(LangItem::IndexMut) {
+                                let trait_ref = TyBuilder::trait_ref(self.db, index_trait)
+                                    .push(self_ty)
+                                    .fill(|_| index_ty.clone().cast(Interner))
+                                    .build();
+                                self.push_obligation(trait_ref.cast(Interner));
+                            }
+                        }
+
+                        res
+                    } else {
+                        self.err_ty()
+                    }
                 }
             }
             Expr::Tuple { exprs, .. } => {
@@ -875,14 +882,14 @@ impl InferenceContext<'_> {
                         TyKind::Scalar(Scalar::Int(primitive::int_ty_from_builtin(*int_ty)))
                             .intern(Interner)
                     }
-                    None => self.table.new_integer_var(),
+                    None => self.infer_unsuffixed_int_literal(expected),
                 },
                 Literal::Uint(_v, ty) => match ty {
                     Some(int_ty) => {
                         TyKind::Scalar(Scalar::Uint(primitive::uint_ty_from_builtin(*int_ty)))
                             .intern(Interner)
                     }
-                    None => self.table.new_integer_var(),
+                    None => self.infer_unsuffixed_int_literal(expected),
                 },
                 Literal::Float(_v, ty) => match ty {
                     Some(float_ty) => {
@@ -918,12 +925,13 @@ impl InferenceContext<'_> {
                 // );
                 // coerce.complete(self)
                 let bool_ty = self.result.standard_types.bool_.clone();
-                self.infer_expr_coerce(*condition, &Expectation::HasType(bool_ty.clone()));
+                self.infer_spec_expr_coerce(
+                    *condition,
+                    &Expectation::HasType(bool_ty.clone()),
+                );
                 bool_ty
             }
-            Expr::View { .. } => {
-                self.err_ty() // TODO
-            }
+            Expr::View { condition } => self.infer_view(tgt_expr, *condition, expected),
             Expr::IsExpr { expr, type_ref } => {
                 let ty = self.make_ty(type_ref);
                 let ty = self.insert_type_vars_shallow(ty);
@@ -944,7 +952,25 @@ impl InferenceContext<'_> {
             }
             Expr::Assume { condition } => {
                 let bool_ty = self.result.standard_types.bool_.clone();
-                self.infer_expr_coerce(*condition, &Expectation::HasType(bool_ty.clone()));
+                self.infer_spec_expr_coerce(
+                    *condition,
+                    &Expectation::HasType(bool_ty.clone()),
+                );
+                bool_ty
+            }
+            Expr::Quantifier { args, arg_types, body, .. } => {
+                for (&arg, arg_type) in args.iter().zip(arg_types.iter()) {
+                    let ty = match arg_type {
+                        Some(type_ref) => self.make_ty(type_ref),
+                        None => self.table.new_type_var(),
+                    };
+                    self.infer_top_pat(arg, &ty);
+                }
+                let bool_ty = self.result.standard_types.bool_.clone();
+                self.infer_spec_expr_coerce(
+                    *body,
+                    &Expectation::HasType(bool_ty.clone()),
+                );
                 bool_ty
             }
         };
@@ -1297,6 +1323,9 @@ impl InferenceContext<'_> {
     ) -> Ty {
         let lhs_expectation = Expectation::none();
         let lhs_ty = self.infer_expr(lhs, &lhs_expectation);
+        if let Some(ty) = self.infer_math_int_binop(&lhs_ty, op, rhs) {
+            return ty;
+        }
         let rhs_ty = self.table.new_type_var();
 
         let trait_func = lang_items_for_bin_op(op).and_then(|(name, lang_item)| {
@@ -1722,6 +1751,79 @@ impl InferenceContext<'_> {
         self.check_method_call(tgt_expr, args, method_ty, substs, receiver_ty, expected)
     }
 
+    /// verus: `expr@` is sugar for `expr.view()`, with `View::V` as its type. Types that only
+    /// implement `DeepView` fall back to `expr.deep_view()`.
+    fn infer_view(&mut self, tgt_expr: ExprId, receiver: ExprId, expected: &Expectation) -> Ty {
+        let receiver_ty = self.infer_expr_inner(receiver, &Expectation::none());
+        let canonicalized_receiver = self.canonicalize(receiver_ty.clone());
+
+        let resolved = [name![view], name![deep_view]].iter().find_map(|method_name| {
+            method_resolution::lookup_method(
+                self.db,
+                &canonicalized_receiver,
+                self.table.trait_env.clone(),
+                self.get_traits_in_scope().as_ref().left_or_else(|&it| it),
+                VisibleFromModule::Filter(self.resolver.module()),
+                method_name,
+            )
+        });
+        let Some((adjust, func, _visible)) = resolved else {
+            return self.err_ty();
+        };
+        let (receiver_ty, adjustments) = adjust.apply(&mut self.table, receiver_ty);
+        let generics = generics(self.db.upcast(), func.into());
+        let substs = self.substs_for_method_call(generics, None);
+        self.write_expr_adj(receiver, adjustments);
+        self.write_method_resolution(tgt_expr, func, substs.clone());
+        let method_ty = self.db.value_ty(func.into()).unwrap();
+        self.check_method_call(tgt_expr, &[], method_ty, substs, receiver_ty, expected)
+    }
+
+    /// verus: indexing a type without an `Index` impl, like `Seq` or `Map`, is sugar for
+    /// `base.spec_index(index)`.
+    fn infer_spec_index(
+        &mut self,
+        tgt_expr: ExprId,
+        base: ExprId,
+        base_ty: &Ty,
+        index: ExprId,
+    ) -> Option<Ty> {
+        let canonicalized_base = self.canonicalize(base_ty.clone());
+        if let Some(index_trait) = self.resolve_lang_trait(LangItem::Index) {
+            let indexable = method_resolution::resolve_indexing_op(
+                self.db,
+                self.table.trait_env.clone(),
+                canonicalized_base.clone(),
+                index_trait,
+            );
+            if indexable.is_some() {
+                return None;
+            }
+        }
+        let (adjust, func, _visible) = method_resolution::lookup_method(
+            self.db,
+            &canonicalized_base,
+            self.table.trait_env.clone(),
+            self.get_traits_in_scope().as_ref().left_or_else(|&it| it),
+            VisibleFromModule::Filter(self.resolver.module()),
+            &name![spec_index],
+        )?;
+        let (receiver_ty, adjustments) = adjust.apply(&mut self.table, base_ty.clone());
+        let generics = generics(self.db.upcast(), func.into());
+        let substs = self.substs_for_method_call(generics, None);
+        self.write_expr_adj(base, adjustments);
+        self.write_method_resolution(tgt_expr, func, substs.clone());
+        let method_ty = self.db.value_ty(func.into())?;
+        Some(self.check_method_call(
+            tgt_expr,
+            &[index],
+            method_ty,
+            substs,
+            receiver_ty,
+            &Expectation::none(),
+        ))
+    }
+
     fn check_method_call(
         &mut self,
         tgt_expr: ExprId,
@@ -2079,6 +2181,104 @@ impl InferenceContext<'_> {
         }
     }
 
+    // verus: `int` and `nat` are plain structs in `builtin`, their literals and operators are
+    // built into the verifier rather than implemented through traits.
+
+    /// Returns the verus `int` or `nat` type if it is in scope.
+    fn math_int_ty(&self, name: Name) -> Option<Ty> {
+        let path = ModPath::from_segments(PathKind::Plain, Some(name.clone()));
+        let path = Path::from_known_path_with_no_generic(path);
+        match self.resolver.resolve_path_in_type_ns_fully(self.db.upcast(), &path)? {
+            TypeNs::AdtId(hir_def::AdtId::StructId(it))
+                if self.math_int_struct(it).as_ref() == Some(&name) =>
+            {
+                Some(TyKind::Adt(AdtId(it.into()), Substitution::empty(Interner)).intern(Interner))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns `name![int]` or `name![nat]` if `ty` is one of the verus mathematical integers.
+    fn math_int_name(&mut self, ty: &Ty) -> Option<Name> {
+        let ty = self.resolve_ty_shallow(ty);
+        let TyKind::Adt(AdtId(hir_def::AdtId::StructId(it)), _) = ty.kind(Interner) else {
+            return None;
+        };
+        self.math_int_struct(*it)
+    }
+
+    /// Returns the name of `it` if it is the `int` or `nat` of `builtin`, which `vstd` reexports.
+    fn math_int_struct(&self, it: hir_def::StructId) -> Option<Name> {
+        let name = self.db.struct_data(it).name.clone();
+        if name != name![int] && name != name![nat] {
+            return None;
+        }
+        let krate = it.lookup(self.db.upcast()).container.krate();
+        is_verus_builtin_crate(self.db.upcast(), krate).then_some(name)
+    }
+
+    fn in_spec_fn(&self) -> bool {
+        match self.owner {
+            DefWithBodyId::FunctionId(it) => self.db.function_data(it).is_spec(),
+            _ => false,
+        }
+    }
+
+    /// Infers `expr` as spec code, where unconstrained integer literals are `int`s.
+    pub(super) fn infer_spec_expr_coerce(&mut self, expr: ExprId, expected: &Expectation) -> Ty {
+        let prev_in_spec = mem::replace(&mut self.in_spec, true);
+        let ty = self.infer_expr_coerce(expr, expected);
+        self.in_spec = prev_in_spec;
+        ty
+    }
+
+    /// An unsuffixed literal takes the type of an expected `int` or `nat`, and defaults to `int`
+    /// in spec code unless something else constrains it.
+    fn infer_unsuffixed_int_literal(&mut self, expected: &Expectation) -> Ty {
+        let expected = expected.to_option(&mut self.table);
+        if let Some(ty) = &expected {
+            if self.math_int_name(ty).is_some() {
+                return ty.clone();
+            }
+        }
+        let unconstrained = expected.map_or(true, |ty| {
+            matches!(ty.kind(Interner), TyKind::InferenceVar(_, TyVariableKind::General))
+        });
+        if unconstrained && (self.in_spec || self.in_spec_fn()) {
+            if let Some(ty) = self.math_int_ty(name![int]) {
+                return ty;
+            }
+        }
+        self.table.new_integer_var()
+    }
+
+    /// Infers arithmetic and comparisons whose left operand is an `int` or a `nat`. These never
+    /// overflow: `+`, `-` and `*` produce an `int`, except that adding or multiplying two `nat`s
+    /// stays a `nat`, as do `/` and `%` on `nat`s.
+    fn infer_math_int_binop(&mut self, lhs_ty: &Ty, op: BinaryOp, rhs: ExprId) -> Option<Ty> {
+        let lhs_name = self.math_int_name(lhs_ty)?;
+        let arith_op = match op {
+            BinaryOp::ArithOp(
+                op @ (ArithOp::Add | ArithOp::Sub | ArithOp::Mul | ArithOp::Div | ArithOp::Rem),
+            ) => Some(op),
+            BinaryOp::CmpOp(_) => None,
+            _ => return None,
+        };
+        // The operands don't have to agree, `x + 1` is fine for any integer `x`, so only guide
+        // literals on the right towards the type on the left.
+        let rhs_ty = self.infer_expr(rhs, &Expectation::Castable(lhs_ty.clone()));
+        let Some(arith_op) = arith_op else {
+            return Some(self.result.standard_types.bool_.clone());
+        };
+        let both_nat = lhs_name == name![nat] && self.math_int_name(&rhs_ty) == Some(name![nat]);
+        let name = match arith_op {
+            ArithOp::Sub => name![int],
+            _ if both_nat => name![nat],
+            _ => name![int],
+        };
+        Some(self.math_int_ty(name).unwrap_or_else(|| lhs_ty.clone()))
+    }
+
     fn is_builtin_binop(&mut self, lhs: &Ty, rhs: &Ty, op: BinaryOp) -> bool {
         // Special-case a single layer of referencing, so that things like `5.0 + &6.0f32` work (See rust-lang/rust#57447).
         let lhs = self.deref_ty_if_possible(lhs);
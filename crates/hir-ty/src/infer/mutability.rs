@@ -175,7 +175,7 @@ impl InferenceContext<'_> {
                 self.infer_mut_expr(*lhs, Mutability::Not);
                 self.infer_mut_expr(*rhs, Mutability::Not);
             }
-            Expr::Closure { body, .. } => {
+            Expr::Closure { body, .. } | Expr::Quantifier { body, .. } => {
                 self.infer_mut_expr(*body, Mutability::Not);
             }
             Expr::Tuple { exprs, is_assignee_expr: _ }
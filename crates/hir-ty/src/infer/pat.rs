@@ -6,6 +6,7 @@ use hir_def::{
     body::Body,
     hir::{Binding, BindingAnnotation, BindingId, Expr, ExprId, ExprOrPatId, Literal, Pat, PatId},
     path::Path,
+    Lookup, VariantId,
 };
 use hir_expand::name::Name;
 use stdx::TupleExt;
@@ -16,7 +17,9 @@ use crate::{
     infer::{BindingMode, Expectation, InferenceContext, TypeMismatch},
     lower::lower_to_chalk_mutability,
     primitive::UintTy,
-    static_lifetime, InferenceDiagnostic, Interner, Mutability, Scalar, Substitution, Ty,
+    static_lifetime,
+    utils::is_verus_builtin_crate,
+    InferenceDiagnostic, Interner, Mutability, Scalar, Substitution, Ty,
     TyBuilder, TyExt, TyKind,
 };
 
@@ -72,6 +75,15 @@ impl InferenceContext<'_> {
         subs: &[T],
     ) -> Ty {
         let (ty, def) = self.resolve_variant(path, true);
+        if let ([sub], None) = (subs, ellipsis) {
+            if let Some(inner) = self.ghost_wrapped_ty(def, &ty, expected) {
+                if let Some(variant) = def {
+                    self.write_variant_resolution(id.into(), variant);
+                }
+                T::infer(self, *sub, &inner, default_bm);
+                return ty;
+            }
+        }
         let var_data = def.map(|it| it.variant_data(self.db.upcast()));
         if let Some(variant) = def {
             self.write_variant_resolution(id.into(), variant);
@@ -142,6 +154,23 @@ impl InferenceContext<'_> {
         ty
     }
 
+    /// verus: `Ghost(x)` and `Tracked(x)` unwrap the `A` of the `Ghost<A>` or `Tracked<A>` of
+    /// builtin, whatever the fields of the wrapper are.
+    fn ghost_wrapped_ty(&mut self, def: Option<VariantId>, ty: &Ty, expected: &Ty) -> Option<Ty> {
+        let Some(VariantId::StructId(strukt)) = def else { return None };
+        if !matches!(self.db.struct_data(strukt).name.as_str(), Some("Ghost" | "Tracked")) {
+            return None;
+        }
+        let krate = strukt.lookup(self.db.upcast()).container.krate();
+        if !is_verus_builtin_crate(self.db.upcast(), krate) {
+            return None;
+        }
+        self.unify(ty, expected);
+        let ty = self.resolve_ty_shallow(ty);
+        let (_, substs) = ty.as_adt()?;
+        substs.as_slice(Interner).first()?.ty(Interner).cloned()
+    }
+
     /// Infers type for record pattern or its corresponding assignee expression.
     pub(super) fn infer_record_pat_like<T: PatLike>(
         &mut self,
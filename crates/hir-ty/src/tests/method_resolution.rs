@@ -2050,3 +2050,77 @@ fn test() {
 "#,
     );
 }
+
+#[test]
+fn verus_view_operator() {
+    check_types(
+        r#"
+trait View {
+    type V;
+    fn view(&self) -> Self::V;
+}
+trait DeepView {
+    type V;
+    fn deep_view(&self) -> Self::V;
+}
+struct Seq<T>(T);
+struct Vec<T>(T);
+struct Bytes;
+impl<T> View for Vec<T> {
+    type V = Seq<T>;
+    fn view(&self) -> Seq<T> {
+        loop {}
+    }
+}
+impl DeepView for Bytes {
+    type V = Seq<u8>;
+    fn deep_view(&self) -> Seq<u8> {
+        loop {}
+    }
+}
+
+fn test(v: &Vec<u8>, b: Bytes) {
+    let t = (v@, b@);
+    t;
+  //^ (Seq<u8>, Seq<u8>)
+}
+"#,
+    );
+}
+
+#[test]
+fn verus_spec_index() {
+    check_types(
+        r#"
+//- minicore: index
+//- /main.rs crate:main deps:builtin
+use builtin::int;
+struct Seq<A>(A);
+impl<A> Seq<A> {
+    spec fn spec_index(self, i: int) -> A {
+        loop {}
+    }
+}
+struct Map<K, V>(K, V);
+impl<K, V> Map<K, V> {
+    spec fn spec_index(self, key: K) -> V {
+        loop {}
+    }
+}
+struct Elem;
+impl Elem {
+    fn get(self) -> u8 {
+        0
+    }
+}
+
+spec fn test(s: Seq<Elem>, m: Map<u32, bool>, a: [u16; 2]) {
+    let t = (s[0], m[1], s[0].get(), a[0]);
+    t;
+  //^ (Elem, bool, u8, u16)
+}
+//- /builtin.rs crate:builtin
+pub struct int;
+"#,
+    );
+}
@@ -1225,3 +1225,42 @@ fn main() {
             "#,
     );
 }
+
+#[test]
+fn ghost_and_tracked_unwrapping() {
+    check_types(
+        r#"
+//- /main.rs crate:main deps:builtin
+use builtin::{Ghost, Tracked};
+struct S;
+
+fn test(Tracked(t): Tracked<S>, Ghost(g): Ghost<u32>) {
+    t;
+  //^ S
+    g;
+  //^ u32
+    let Tracked((a, b)) = Tracked::<(S, bool)> { phantom: 0 as *const _ };
+    b;
+  //^ bool
+}
+//- /builtin.rs crate:builtin
+pub struct Ghost<A> { pub phantom: *const A }
+pub struct Tracked<A> { pub phantom: *const A }
+"#,
+    );
+}
+
+#[test]
+fn user_defined_ghost_is_not_unwrapped() {
+    check_types(
+        r#"
+struct Vec<T>(T);
+struct Ghost<T>(Vec<T>);
+
+fn test<T>(Ghost(v): Ghost<T>) {
+    v;
+  //^ Vec<T>
+}
+"#,
+    );
+}
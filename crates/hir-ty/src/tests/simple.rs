@@ -18,6 +18,110 @@ fn test() {
     );
 }
 
+#[test]
+fn verus_math_ints() {
+    check_types(
+        r#"
+//- /main.rs crate:main deps:builtin
+use builtin::{int, nat};
+
+spec fn test(x: int, n: nat, u: u64) -> bool {
+    let t = (1, x + 1, n * n, n - n, n / 2, u as int, 2 as nat);
+    t;
+  //^ (int, int, nat, int, nat, int, nat)
+    x > 0
+}
+
+fn exec() {
+    let a = 1;
+    a;
+  //^ i32
+}
+//- /builtin.rs crate:builtin
+pub struct int;
+pub struct nat;
+"#,
+    );
+}
+
+#[test]
+fn verus_math_ints_in_spec_code() {
+    check_types(
+        r#"
+//- /main.rs crate:main deps:builtin
+use builtin::int;
+
+fn exec(x: u32)
+    requires
+        0 < 1,
+      //^ int
+{
+    let a = 1;
+    a;
+  //^ i32
+    assert(2 == 3);
+         //^ int
+    assert(x == 3);
+              //^ u32
+}
+//- /builtin.rs crate:builtin
+pub struct int;
+"#,
+    );
+}
+
+#[test]
+fn verus_math_ints_only_from_builtin() {
+    check_types(
+        r#"
+struct nat;
+
+spec fn test() -> bool {
+    let n: nat = 2;
+               //^ i32
+    true
+}
+"#,
+    );
+}
+
+#[test]
+fn verus_contract_clauses() {
+    check_types(
+        r#"
+fn f(x: u32) -> (r: u64)
+    requires
+        x > 0,
+      //^ u32
+    ensures
+        r > 1,
+      //^ u64
+        r != x as u64,
+      //^^^^^^^^^^^^^ bool
+{
+    1
+}
+"#,
+    );
+}
+
+#[test]
+fn verus_quantifiers() {
+    check(
+        r#"
+struct int;
+
+proof fn test(s: int) {
+    let all = forall|i: int, b| b && i == s;
+      //^^^ type: bool
+    exists|i: int| i == s;
+    forall|i: int| i;
+                 //^ expected bool, got int
+}
+"#,
+    );
+}
+
 #[test]
 fn self_in_struct_lit() {
     check_infer(
@@ -251,6 +251,14 @@ impl<'a> ClosureSubst<'a> {
     }
 }
 
+/// Whether `krate` is `builtin` or `vstd`, which define the verus types and wrappers, rather than
+/// a crate defining items of the same names.
+pub(crate) fn is_verus_builtin_crate(db: &dyn DefDatabase, krate: CrateId) -> bool {
+    let crate_graph = db.crate_graph();
+    let name = crate_graph[krate].display_name.as_ref().map(|it| it.canonical_name());
+    matches!(name, Some("builtin" | "vstd"))
+}
+
 pub fn is_fn_unsafe_to_call(db: &dyn HirDatabase, func: FunctionId) -> bool {
     let data = db.function_data(func);
     if data.has_unsafe_kw() {
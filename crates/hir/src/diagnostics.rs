@@ -3,7 +3,7 @@
 //!
 //! This probably isn't the best way to do this -- ideally, diagnostics should
 //! be expressed in terms of hir types themselves.
-pub use hir_ty::diagnostics::{CaseType, IncorrectCase};
+pub use hir_ty::diagnostics::{CaseType, IncorrectCase, Mode};
 use hir_ty::{db::HirDatabase, diagnostics::BodyValidationDiagnostic, InferenceDiagnostic};
 
 use base_db::CrateId;
@@ -15,7 +15,7 @@ use hir_expand::{name::Name, HirFileId, InFile};
 use syntax::{ast, AstPtr, SyntaxError, SyntaxNodePtr, TextRange};
 use triomphe::Arc;
 
-use crate::{AssocItem, Field, Local, MacroKind, Trait, Type};
+use crate::{AssocItem, Field, Function, Local, MacroKind, Trait, Type};
 
 macro_rules! diagnostics {
     ($($diag:ident,)*) => {
@@ -50,7 +50,9 @@ macro_rules! diagnostics {
 
 diagnostics![
     BreakOutsideOfLoop,
+    ExecCallInGhostCode,
     ExpectedFunction,
+    GhostVariableInExecCode,
     InactiveCode,
     IncoherentImpl,
     IncorrectCase,
@@ -73,6 +75,7 @@ diagnostics![
     RemoveTrailingReturn,
     RemoveUnnecessaryElse,
     ReplaceFilterMapNextWithFindMap,
+    TrackedUseAfterMove,
     TraitImplIncorrectSafety,
     TraitImplMissingAssocItems,
     TraitImplOrphan,
@@ -262,6 +265,25 @@ pub struct MissingUnsafe {
     pub expr: InFile<AstPtr<ast::Expr>>,
 }
 
+#[derive(Debug)]
+pub struct ExecCallInGhostCode {
+    pub expr: InFile<AstPtr<ast::Expr>>,
+    pub callee: Function,
+    pub mode: Mode,
+}
+
+#[derive(Debug)]
+pub struct GhostVariableInExecCode {
+    pub expr: InFile<AstPtr<ast::Expr>>,
+    pub local: Local,
+}
+
+#[derive(Debug)]
+pub struct TrackedUseAfterMove {
+    pub expr: InFile<AstPtr<ast::Expr>>,
+    pub local: Local,
+}
+
 #[derive(Debug)]
 pub struct MissingFields {
     pub file: HirFileId,
@@ -1786,6 +1786,31 @@ impl DefWithBody {
             }
         }
 
+        for mismatch in hir_ty::diagnostics::mode_mismatches(db, self.into()) {
+            match mismatch {
+                hir_ty::diagnostics::ModeMismatch::ExecCall { expr, callee, mode } => {
+                    if let Ok(expr) = source_map.expr_syntax(expr) {
+                        acc.push(ExecCallInGhostCode { expr, callee: callee.into(), mode }.into());
+                    }
+                }
+                hir_ty::diagnostics::ModeMismatch::GhostVariable { expr, binding } => {
+                    if let Ok(expr) = source_map.expr_syntax(expr) {
+                        let local = Local { parent: self.into(), binding_id: binding };
+                        acc.push(GhostVariableInExecCode { expr, local }.into());
+                    }
+                }
+            }
+        }
+
+        for moved in hir_ty::diagnostics::tracked_use_after_moves(db, self.into()) {
+            if let Ok(expr) = source_map.expr_syntax(moved.expr) {
+                let local = Local { parent: self.into(), binding_id: moved.binding };
+                acc.push(TrackedUseAfterMove { expr, local }.into());
+            }
+        }
+
+        // Ghost code is not lowered to MIR, so uses from there are looked up in the body instead.
+        let referenced = hir_ty::diagnostics::referenced_bindings(db, self.into());
         if let Ok(borrowck_results) = db.borrowck(self.into()) {
             for borrowck_result in borrowck_results.iter() {
                 let mir_body = &borrowck_result.mir_body;
@@ -1838,7 +1863,9 @@ impl DefWithBody {
 
                     match (need_mut, is_mut) {
                         (mir::MutabilityReason::Unused, _) => {
-                            let should_ignore = matches!(body[binding_id].name.as_str(), Some(it) if it.starts_with('_'));
+                            let should_ignore = matches!(body[binding_id].name.as_str(), Some(it) if it.starts_with('_'))
+                                || body[binding_id].ghost.is_some()
+                                || referenced.contains(&binding_id);
                             if !should_ignore {
                                 acc.push(UnusedVariable { local }.into())
                             }
@@ -1879,6 +1906,19 @@ impl DefWithBody {
             }
         }
 
+        for (binding_id, binding_data) in body.bindings.iter() {
+            if binding_data.ghost.is_none()
+                || body.self_param == Some(binding_id)
+                || binding_data.problems.is_some()
+                || referenced.contains(&binding_id)
+                || matches!(binding_data.name.as_str(), Some(it) if it.starts_with('_'))
+                || binding_data.definitions.iter().any(|&pat| source_map.pat_syntax(pat).is_err())
+            {
+                continue;
+            }
+            acc.push(UnusedVariable { local: Local { parent: self.into(), binding_id } }.into());
+        }
+
         for diagnostic in BodyValidationDiagnostic::collect(db, self.into(), style_lints) {
             acc.extend(AnyDiagnostic::body_validation_diagnostic(db, diagnostic, &source_map));
         }
@@ -2081,6 +2121,16 @@ impl Function {
         db.function_data(self.id).has_async_kw()
     }
 
+    /// Whether this is a verus `spec fn`.
+    pub fn is_spec(self, db: &dyn HirDatabase) -> bool {
+        db.function_data(self.id).is_spec()
+    }
+
+    /// Whether this is a verus `proof fn`.
+    pub fn is_proof(self, db: &dyn HirDatabase) -> bool {
+        db.function_data(self.id).is_proof()
+    }
+
     /// Does this function have `#[test]` attribute?
     pub fn is_test(self, db: &dyn HirDatabase) -> bool {
         db.function_data(self.id).attrs.is_test()
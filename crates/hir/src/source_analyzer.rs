@@ -43,7 +43,7 @@ use hir_ty::{
 use itertools::Itertools;
 use smallvec::SmallVec;
 use syntax::{
-    ast::{self, AstNode},
+    ast::{self, AstNode, HasAttrs, HasName},
     SyntaxKind, SyntaxNode, TextRange, TextSize,
 };
 use triomphe::Arc;
@@ -76,7 +76,8 @@ impl SourceAnalyzer {
         let scope = match offset {
             None => scope_for(&scopes, &source_map, node),
             Some(offset) => scope_for_offset(db, &scopes, &source_map, node.file_id, offset),
-        };
+        }
+        .or_else(|| signature_clause_scope(&scopes, &body, node));
         let resolver = resolver_for_scope(db.upcast(), def, scope);
         SourceAnalyzer {
             resolver,
@@ -97,7 +98,8 @@ impl SourceAnalyzer {
         let scope = match offset {
             None => scope_for(&scopes, &source_map, node),
             Some(offset) => scope_for_offset(db, &scopes, &source_map, node.file_id, offset),
-        };
+        }
+        .or_else(|| signature_clause_scope(&scopes, &body, node));
         let resolver = resolver_for_scope(db.upcast(), def, scope);
         SourceAnalyzer { resolver, def: Some((def, body, source_map)), infer: None, file_id }
     }
@@ -575,6 +577,24 @@ impl SourceAnalyzer {
         }
     }
 
+    /// Whether `path` names a parameter of an enclosing closure that is not part of the body, like
+    /// a quantifier in a Verus `ensures` clause. Such names must not resolve to outer bindings.
+    fn is_bound_by_unlowered_closure(&self, db: &dyn HirDatabase, path: &ast::Path) -> bool {
+        let Some(name_ref) = path.as_single_name_ref() else { return false };
+        path.syntax()
+            .ancestors()
+            .filter_map(ast::ClosureExpr::cast)
+            .take_while(|closure| self.expr_id(db, &closure.clone().into()).is_none())
+            .filter_map(|closure| closure.param_list())
+            .filter(|it| !it.syntax().text_range().contains_range(path.syntax().text_range()))
+            .flat_map(|it| it.params())
+            .filter_map(|param| match param.pat()? {
+                ast::Pat::IdentPat(it) => it.name(),
+                _ => None,
+            })
+            .any(|name| name.text() == name_ref.text())
+    }
+
     pub(crate) fn resolve_path(
         &self,
         db: &dyn HirDatabase,
@@ -661,6 +681,9 @@ impl SourceAnalyzer {
         if resolved.is_some() {
             return resolved;
         }
+        if self.is_bound_by_unlowered_closure(db, path) {
+            return None;
+        }
 
         // This must be a normal source file rather than macro file.
         let ctx = LowerCtx::new(db.upcast(), self.file_id);
@@ -962,10 +985,52 @@ fn scope_for(
     node.value
         .ancestors()
         .filter_map(ast::Expr::cast)
-        .filter_map(|it| source_map.node_expr(InFile::new(node.file_id, &it)))
+        .filter_map(|it| {
+            // Verus trigger attributes (`forall|x| #![trigger f(x)] ..`) are children of the
+            // closure rather than of its body, but they see the closure's parameters.
+            let it = match &it {
+                ast::Expr::ClosureExpr(closure) if in_closure_attr(closure, node.value) => {
+                    closure.body()?
+                }
+                _ => it,
+            };
+            source_map.node_expr(InFile::new(node.file_id, &it))
+        })
         .find_map(|it| scopes.scope_for(it))
 }
 
+fn in_closure_attr(closure: &ast::ClosureExpr, node: &SyntaxNode) -> bool {
+    let Some(param_list) = closure.param_list() else { return false };
+    closure
+        .attrs()
+        .filter(|attr| attr.syntax().text_range().start() >= param_list.syntax().text_range().end())
+        .any(|attr| attr.syntax().text_range().contains_range(node.text_range()))
+}
+
+/// Verus specification clauses in a function signature (`requires`, `ensures`, ...) are not
+/// lowered into the body, but they may still mention the function's parameters. Resolve names
+/// inside them in the root scope of the body, which holds the parameter bindings.
+fn signature_clause_scope(
+    scopes: &ExprScopes,
+    body: &Body,
+    node: InFile<&SyntaxNode>,
+) -> Option<ScopeId> {
+    let in_clause =
+        node.value.ancestors().take_while(|it| !ast::Fn::can_cast(it.kind())).any(|it| {
+            let kind = it.kind();
+            ast::RequiresClause::can_cast(kind)
+                || ast::RecommendsClause::can_cast(kind)
+                || ast::EnsuresClause::can_cast(kind)
+                || ast::SignatureDecreases::can_cast(kind)
+                || ast::OpensInvariantsClause::can_cast(kind)
+                || ast::NoUnwindClause::can_cast(kind)
+        });
+    if !in_clause {
+        return None;
+    }
+    scopes.scope_chain(scopes.scope_for(body.body_expr)).last()
+}
+
 fn scope_for_offset(
     db: &dyn HirDatabase,
     scopes: &ExprScopes,
@@ -4,13 +4,17 @@ use base_db::FileRange;
 use hir_def::{
     db::DefDatabase,
     item_scope::ItemInNs,
+    nameres::ModuleSource,
     src::{HasChildSource, HasSource},
     AdtId, AssocItemId, DefWithBodyId, HasModule, ImplId, Lookup, MacroId, ModuleDefId, ModuleId,
     TraitId,
 };
 use hir_expand::{HirFileId, InFile};
 use hir_ty::{db::HirDatabase, display::HirDisplay};
-use syntax::{ast::HasName, AstNode, AstPtr, SmolStr, SyntaxNode, SyntaxNodePtr};
+use syntax::{
+    ast::{self, HasName},
+    AstNode, AstPtr, SmolStr, SyntaxNode, SyntaxNodePtr,
+};
 
 use crate::{Module, ModuleDef, Semantics};
 
@@ -25,6 +29,64 @@ pub struct FileSymbol {
     /// Whether this symbol is a doc alias for the original symbol.
     pub is_alias: bool,
     pub is_assoc: bool,
+    /// The Verus category of a `spec` or `proof` function symbol.
+    pub verus_category: Option<VerusSymbolCategory>,
+}
+
+/// A `broadcast group` or `global` directive. These aren't definitions, so they are indexed apart
+/// from the [`FileSymbol`]s.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VerusDirectiveSymbol {
+    pub name: SmolStr,
+    /// The module declaring the directive.
+    pub module: Module,
+    /// The file id for both the `ptr` and `name_ptr`.
+    pub hir_file_id: HirFileId,
+    /// This points to the whole syntax node of the directive.
+    pub ptr: SyntaxNodePtr,
+    /// This points to the [`syntax::ast::BroadcastGroupIdentifier`] of a `broadcast group`, or to
+    /// the whole of a `global` directive.
+    pub name_ptr: SyntaxNodePtr,
+    pub container_name: Option<SmolStr>,
+    /// Either [`VerusSymbolCategory::BroadcastGroup`] or [`VerusSymbolCategory::Global`].
+    pub category: VerusSymbolCategory,
+}
+
+/// Verus categories that workspace symbol queries can be restricted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VerusSymbolCategory {
+    /// `spec fn` and `spec(checked) fn`.
+    Spec,
+    /// `proof fn`, i.e. a lemma.
+    Proof,
+    /// `broadcast proof fn`.
+    BroadcastProof,
+    /// `broadcast group`.
+    BroadcastGroup,
+    /// `global` layout directives, named after what they constrain, e.g. `size_of usize`.
+    Global,
+}
+
+impl VerusSymbolCategory {
+    /// The category of the `spec` or `proof` function or `spec const` `node`.
+    pub fn of(node: &SyntaxNode) -> Option<VerusSymbolCategory> {
+        if ast::Const::can_cast(node.kind()) {
+            // The mode of a `spec const` is parsed like the one of a function.
+            let mode = node.children().find_map(ast::FnMode::cast)?;
+            return mode.spec_token().is_some().then_some(VerusSymbolCategory::Spec);
+        }
+        let func = ast::Fn::cast(node.clone())?;
+        let mode = func.fn_mode()?;
+        if mode.spec_token().is_some() || mode.mode_spec_checked().is_some() {
+            Some(VerusSymbolCategory::Spec)
+        } else if mode.proof_token().is_none() {
+            None
+        } else if func.broadcast_token().is_some() {
+            Some(VerusSymbolCategory::BroadcastProof)
+        } else {
+            Some(VerusSymbolCategory::Proof)
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -72,6 +134,7 @@ struct SymbolCollectorWork {
 pub struct SymbolCollector<'a> {
     db: &'a dyn HirDatabase,
     symbols: Vec<FileSymbol>,
+    directives: Vec<VerusDirectiveSymbol>,
     work: Vec<SymbolCollectorWork>,
     current_container_name: Option<SmolStr>,
 }
@@ -83,6 +146,7 @@ impl<'a> SymbolCollector<'a> {
         SymbolCollector {
             db,
             symbols: Default::default(),
+            directives: Default::default(),
             work: Default::default(),
             current_container_name: None,
         }
@@ -102,6 +166,11 @@ impl<'a> SymbolCollector<'a> {
         self.symbols
     }
 
+    /// The symbols collected, along with the Verus directives.
+    pub fn finish_with_directives(self) -> (Vec<FileSymbol>, Vec<VerusDirectiveSymbol>) {
+        (self.symbols, self.directives)
+    }
+
     pub fn collect_module(db: &dyn HirDatabase, module: Module) -> Vec<FileSymbol> {
         let mut symbol_collector = SymbolCollector::new(db);
         symbol_collector.collect(module);
@@ -191,6 +260,7 @@ impl<'a> SymbolCollector<'a> {
                     loc: dec_loc,
                     is_alias: false,
                     is_assoc: false,
+                    verus_category: None,
                 });
             });
         }
@@ -199,6 +269,8 @@ impl<'a> SymbolCollector<'a> {
             self.collect_from_body(const_id);
         }
 
+        self.collect_verus_directives(module_id);
+
         for (_, id) in scope.legacy_macros() {
             for &id in id {
                 if id.module(self.db.upcast()) == module_id {
@@ -212,6 +284,52 @@ impl<'a> SymbolCollector<'a> {
         }
     }
 
+    /// `broadcast group`s and `global` directives aren't collected into the `DefMap`, so they are
+    /// read from the module's syntax.
+    fn collect_verus_directives(&mut self, module_id: ModuleId) {
+        let def_map = module_id.def_map(self.db.upcast());
+        let source = def_map[module_id.local_id].definition_source(self.db.upcast());
+        let items: Vec<ast::Item> = match &source.value {
+            ModuleSource::SourceFile(it) => it.items().collect(),
+            ModuleSource::Module(it) => {
+                it.item_list().map(|it| it.items().collect()).unwrap_or_default()
+            }
+            ModuleSource::BlockExpr(it) => it
+                .statements()
+                .filter_map(|it| match it {
+                    ast::Stmt::Item(it) => Some(it),
+                    _ => None,
+                })
+                .collect(),
+        };
+
+        for item in items {
+            let (name, name_node, category) = match &item {
+                ast::Item::BroadcastGroup(it) => {
+                    let Some(ident) = it.broadcast_group_identifier() else { continue };
+                    let name = SmolStr::new(ident.syntax().text().to_string());
+                    (name, ident.syntax().clone(), VerusSymbolCategory::BroadcastGroup)
+                }
+                ast::Item::VerusGlobal(it) => {
+                    let Some(ty) = it.ty() else { continue };
+                    let kind = if it.layout_token().is_some() { "layout" } else { "size_of" };
+                    let name = SmolStr::new(format!("{kind} {}", ty.syntax()));
+                    (name, it.syntax().clone(), VerusSymbolCategory::Global)
+                }
+                _ => continue,
+            };
+            self.directives.push(VerusDirectiveSymbol {
+                name,
+                module: module_id.into(),
+                hir_file_id: source.file_id,
+                ptr: SyntaxNodePtr::new(item.syntax()),
+                name_ptr: SyntaxNodePtr::new(&name_node),
+                container_name: self.current_container_name.clone(),
+                category,
+            });
+        }
+    }
+
     fn collect_from_body(&mut self, body_id: impl Into<DefWithBodyId>) {
         let body_id = body_id.into();
         let body = self.db.body(body_id);
@@ -284,6 +402,7 @@ impl<'a> SymbolCollector<'a> {
         let source = loc.source(self.db.upcast());
         let Some(name_node) = source.value.name() else { return };
         let def = ModuleDef::from(id.into());
+        let verus_category = VerusSymbolCategory::of(source.value.syntax());
         let dec_loc = DeclarationLocation {
             hir_file_id: source.file_id,
             ptr: SyntaxNodePtr::new(source.value.syntax()),
@@ -299,6 +418,7 @@ impl<'a> SymbolCollector<'a> {
                     container_name: self.current_container_name.clone(),
                     is_alias: true,
                     is_assoc,
+                    verus_category,
                 });
             }
         }
@@ -310,6 +430,7 @@ impl<'a> SymbolCollector<'a> {
             loc: dec_loc,
             is_alias: false,
             is_assoc,
+            verus_category,
         });
     }
 
@@ -336,6 +457,7 @@ impl<'a> SymbolCollector<'a> {
                     container_name: self.current_container_name.clone(),
                     is_alias: true,
                     is_assoc: false,
+                    verus_category: None,
                 });
             }
         }
@@ -347,6 +469,7 @@ impl<'a> SymbolCollector<'a> {
             loc: dec_loc,
             is_alias: false,
             is_assoc: false,
+            verus_category: None,
         });
     }
 }
@@ -17,4 +17,6 @@ pub struct AssistConfig {
     pub prefer_prelude: bool,
     pub assist_emit_must_use: bool,
     pub term_search_fuel: u64,
+    /// The `verus.binaryPath` setting, the Verus binary proof actions run.
+    pub verus_binary_path: Option<String>,
 }
@@ -1,5 +1,7 @@
 //! See [`AssistContext`].
 
+use std::sync::Arc;
+
 use hir::Semantics;
 use ide_db::base_db::{FileId, FileRange};
 use ide_db::{label::Label, RootDatabase};
@@ -9,7 +11,10 @@ use syntax::{
     TextSize, TokenAtOffset,
 };
 
-use crate::proof_plumber_api::verus_error::VerusError;
+use crate::proof_plumber_api::{
+    verifier::{Verifier, VerusBinary},
+    verus_error::VerusError,
+};
 use crate::{
     assist_config::AssistConfig, Assist, AssistId, AssistKind, AssistResolveStrategy, GroupLabel,
 };
@@ -59,6 +64,8 @@ pub struct AssistContext<'a> {
     trimmed_range: TextRange,
     pub source_file: SourceFile,
     pub verus_errors: Vec<VerusError>,
+    /// Checks the rewrites of proof actions, tests replace it.
+    pub(crate) verifier: Arc<dyn Verifier>,
 }
 
 impl<'a> AssistContext<'a> {
@@ -87,7 +94,15 @@ impl<'a> AssistContext<'a> {
             _ => frange.range,
         };
 
-        AssistContext { config, sema, frange, source_file, trimmed_range, verus_errors }
+        AssistContext {
+            config,
+            sema,
+            frange,
+            source_file,
+            trimmed_range,
+            verus_errors,
+            verifier: Arc::new(VerusBinary { binary_path: config.verus_binary_path.clone() }),
+        }
     }
 
     pub(crate) fn db(&self) -> &RootDatabase {
@@ -1,21 +1,45 @@
 #[allow(dead_code)]
 pub(crate) mod apply_induction;
+pub(crate) mod case_split_on_bool;
+pub(crate) mod convert_fn_mode;
 pub(crate) mod convert_imply_to_if;
 #[allow(dead_code)]
 pub(crate) mod decompose_failing_assert;
+pub(crate) mod extract_lemma;
+pub(crate) mod inline_spec_fn_call;
 pub(crate) mod insert_assert_by_block;
 pub(crate) mod insert_failing_postcondition;
 pub(crate) mod insert_failing_precondition;
+pub(crate) mod instantiate_forall;
+pub(crate) mod intro_assert_forall_ensures;
 pub(crate) mod intro_assume_false;
+pub(crate) mod intro_calc;
+pub(crate) mod intro_decreases;
 pub(crate) mod intro_forall;
 pub(crate) mod intro_forall_implies;
+pub(crate) mod intro_loop_invariants;
 #[allow(dead_code)]
 pub(crate) mod intro_matching_assertions;
+pub(crate) mod lift_assert_to_requires;
+pub(crate) mod minimize_assume;
+pub(crate) mod minimize_fuel;
+pub(crate) mod name_repeated_subexpr;
+pub(crate) mod prove_by_bit_vector;
+pub(crate) mod prove_by_nonlinear_arith;
+pub(crate) mod propagate_requires;
+pub(crate) mod remove_assert_by_block;
 #[allow(dead_code)]
 pub(crate) mod remove_redundant_assertion;
+pub(crate) mod remove_unused_lemma_params;
+pub(crate) mod reveal_all_opaque;
 pub(crate) mod reveal_opaque_above;
 pub(crate) mod reveal_opaque_in_by_block;
+pub(crate) mod select_trigger;
 pub(crate) mod seq_index_inbound;
+pub(crate) mod skolemize_exists;
+pub(crate) mod split_conjunctive_assert;
 pub(crate) mod split_imply_ensures;
 pub(crate) mod split_smaller_or_equal_to;
+pub(crate) mod weaken_ensures;
 pub(crate) mod weakest_pre_step;
+pub(crate) mod wrap_proof_block;
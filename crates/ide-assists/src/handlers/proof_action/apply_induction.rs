@@ -7,12 +7,9 @@ use syntax::{
 };
 
 pub(crate) fn apply_induction(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
-    let func: ast::Fn = ctx.find_node_at_offset::<ast::Fn>()?;
-    let body: ast::BlockExpr = func.body()?;
-    let func: Fn = Fn::try_from(func).ok()?;
-    if func.signature_decreases.is_none() {
-        return None;
-    }
+    let func_cst: ast::Fn = ctx.find_node_at_offset::<ast::Fn>()?;
+    let body: ast::BlockExpr = func_cst.body()?;
+    let func: Fn = Fn::try_from(func_cst.clone()).ok()?;
 
     let mut new_fn = func.clone();
     let param_list = &(*func.param_list?).params;
@@ -21,7 +18,7 @@ pub(crate) fn apply_induction(acc: &mut Assists, ctx: &AssistContext<'_>) -> Opt
         .map(|p| {
             let p = p.pat.clone()?;
             if let Pat::IdentPat(pat) = *p {
-                pat.name.ident_token
+                pat.name.ident_token.map(String::from)
             } else {
                 None
             }
@@ -34,18 +31,25 @@ pub(crate) fn apply_induction(acc: &mut Assists, ctx: &AssistContext<'_>) -> Opt
         p.cst.as_ref().unwrap().syntax().text_range().contains_range(ctx.selection_trimmed())
     })?;
 
-    let pty = param_list[index].ty.as_ref()?;
-    let mut result = BlockExpr::new(StmtList::new());
+    let pty = param_list[index].ty.as_ref()?.to_string();
+    let id = param_names[index].clone();
 
-    if pty.to_string().trim() == "nat" {
-        result = apply_induction_on_nat(ctx, func.name.to_string(), param_names, index)?;
+    let result = if pty.trim() == "nat" || pty.trim() == "int" {
+        let is_int = pty.trim() == "int";
+        apply_induction_on_num(ctx, func.name.to_string(), param_names, index, is_int)?
     } else {
         let p = param_list[index].pat.as_ref()?.as_ref();
-        if let Some(en) = ctx.type_of_pat_enum(p) {
-            let bty = format!("Box<{}>", param_list[index].ty.as_ref()?.to_string().trim());
-            result =
-                apply_induction_on_enum(ctx, func.name.to_string(), param_names, index, &en, bty)?;
-        }
+        let en = ctx.type_of_pat_enum(p)?;
+        let bty = format!("Box<{}>", param_list[index].ty.as_ref()?.to_string().trim());
+        apply_induction_on_enum(ctx, func.name.to_string(), param_names, index, &en, bty)?
+    };
+
+    // without a decreases clause, the recursion decreases on the selected argument
+    let has_decreases = func.signature_decreases.is_some();
+    if !has_decreases {
+        let mut clause = DecreasesClause::new();
+        clause.exprs.push(ctx.vst_expr_from_text(&id)?);
+        new_fn.signature_decreases = Some(Box::new(SignatureDecreases::new(clause)));
     }
 
     // now check if proof now goes through, and make sure it is fast
@@ -55,24 +59,36 @@ pub(crate) fn apply_induction(acc: &mut Assists, ctx: &AssistContext<'_>) -> Opt
         return None;
     }
 
-    let result = ctx.fmt(body.clone(), result.to_string())?;
+    let (target, result) = if has_decreases {
+        (body.syntax().text_range(), ctx.fmt(body.clone(), result.to_string())?)
+    } else {
+        (func_cst.syntax().text_range(), ctx.fmt(func_cst.clone(), new_fn.to_string())?)
+    };
     return acc.add(
         AssistId("apply_induction", AssistKind::RefactorRewrite),
         "Apply Induction",
         body.syntax().text_range(),
-        |edit| edit.replace(body.syntax().text_range(), result),
+        |edit| edit.replace(target, result),
     );
 }
 
-fn apply_induction_on_nat(
+/// Induction on a `nat` or an `int`. As `decreases` on an `int` only holds while it is not
+/// negative, the base case of an `int` covers all the values up to `0`.
+fn apply_induction_on_num(
     ctx: &AssistContext<'_>,
     fn_name: String,
     param_names: Vec<String>,
     index: usize,
+    is_int: bool,
 ) -> Option<BlockExpr> {
     let id = param_names[index].clone();
-    let cond = ctx.vst_expr_from_text(format!("{} == 0", id.clone()).as_ref())?;
-    let sub = ctx.vst_expr_from_text(format!("({} - 1) as nat", id.clone()).as_ref())?;
+    let (cond, sub) = if is_int {
+        (format!("{} <= 0", id), format!("{} - 1", id))
+    } else {
+        (format!("{} == 0", id), format!("({} - 1) as nat", id))
+    };
+    let cond = ctx.vst_expr_from_text(&cond)?;
+    let sub = ctx.vst_expr_from_text(&sub)?;
 
     // build arguments for recursive call
     let mut args = ArgList::new();
@@ -223,6 +239,60 @@ proof fn sum_equal(n: nat, m: nat) by(nonlinear_arith)
 }
 
 
+fn main() {}
+"#,
+        );
+    }
+
+    #[test]
+    fn apply_induction_on_int() {
+        check_assist(
+            apply_induction,
+            r#"
+use vstd::prelude::*;
+
+spec fn triangle(n: int) -> int
+    decreases n,
+{
+    if n <= 0 {
+        0
+    } else {
+        n + triangle(n - 1)
+    }
+}
+
+proof fn triangle_nonneg($0n: int)
+    ensures
+        triangle(n) >= 0,
+{
+}
+
+fn main() {}
+"#,
+            r#"
+use vstd::prelude::*;
+
+spec fn triangle(n: int) -> int
+    decreases n,
+{
+    if n <= 0 {
+        0
+    } else {
+        n + triangle(n - 1)
+    }
+}
+
+proof fn triangle_nonneg(n: int)
+    ensures
+        triangle(n) >= 0,
+    decreases n,
+{
+    if n <= 0 {
+    } else {
+        triangle_nonneg(n - 1);
+    };
+}
+
 fn main() {}
 "#,
         );
@@ -0,0 +1,225 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    AssistId, AssistKind,
+};
+use syntax::{
+    ast::{self, vst::*, AstNode},
+    T,
+};
+
+/// Proof action: case split an assertion on a boolean sub-expression
+/// assert(x > 0 ==> f(x, b) > 0);
+/// into (with the cursor on `b`)
+/// if b {
+///     assert(x > 0 ==> f(x, b) > 0);
+/// } else {
+///     assert(x > 0 ==> f(x, b) > 0);
+/// }
+///
+/// This is the boolean analogue of `intro_match`. Once the action is picked, Verus is run on each
+/// branch on its own, and the assertion is marked with a comment in the branches it still fails.
+pub(crate) fn case_split_on_bool(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // retrieve the assertion of interest, along with its statement
+    let expr: ast::AssertExpr = ctx.find_node_at_offset()?;
+    let stmt = ast::ExprStmt::cast(expr.syntax().parent()?)?;
+    let pred = expr.expr()?;
+
+    // the innermost boolean expression under the cursor, short of the whole predicate, and
+    // outside of any quantifier, whose bound variables would escape
+    let cond = ctx
+        .find_node_at_offset::<ast::Expr>()?
+        .syntax()
+        .ancestors()
+        .take_while(|it| it != pred.syntax())
+        .filter_map(ast::Expr::cast)
+        .find(|it| is_bool(ctx, it) && !is_under_quantifier(it, &pred))?;
+
+    // lift CST into TOST node
+    let assert: AssertExpr = AssertExpr::try_from(expr.clone()).ok()?;
+    let v_cond = Expr::try_from(cond.clone()).ok()?;
+    if assert.by_token {
+        return None;
+    }
+
+    acc.add(
+        AssistId("case_split_on_bool", AssistKind::RefactorRewrite),
+        format!("Case split assertion on `{cond}`"),
+        cond.syntax().text_range(),
+        |edit| {
+            // Run Verus only when the user explicitly wants it
+            let (then_fails, else_fails) =
+                failing_branches(ctx, &assert, &v_cond).unwrap_or((false, false));
+            let branch = |failing: bool| {
+                let comment = if failing { " // assertion failed" } else { "" };
+                format!("{{\n{assert};{comment}\n}}")
+            };
+            let result = format!("if {v_cond} {} else {}", branch(then_fails), branch(else_fails));
+            let result = ctx.fmt(stmt.clone(), result).expect("fmt"); // pretty-print
+            edit.replace(stmt.syntax().text_range(), result);
+        },
+    )
+}
+
+pub(crate) fn vst_rewriter_case_split_on_bool(
+    cond: Expr,
+    then_branch: Stmt,
+    else_branch: Stmt,
+) -> IfExpr {
+    let block = |stmt: Stmt| {
+        let mut stmts = StmtList::new();
+        stmts.statements.push(stmt);
+        BlockExpr::new(stmts)
+    };
+    let mut if_expr = IfExpr::new(cond, block(then_branch));
+    if_expr.set_else_branch(ElseBranch::Block(Box::new(block(else_branch))));
+    if_expr
+}
+
+fn is_bool(ctx: &AssistContext<'_>, expr: &ast::Expr) -> bool {
+    let by_syntax = match expr {
+        ast::Expr::BinExpr(it) => {
+            matches!(it.op_kind(), Some(ast::BinaryOp::LogicOp(_) | ast::BinaryOp::CmpOp(_)))
+        }
+        ast::Expr::PrefixExpr(it) => it.op_kind() == Some(ast::UnaryOp::Not),
+        ast::Expr::Literal(it) => matches!(it.kind(), ast::LiteralKind::Bool(_)),
+        _ => false,
+    };
+    by_syntax || ctx.sema.type_of_expr(expr).map_or(false, |it| it.original.is_bool())
+}
+
+/// Whether `expr` is within a quantifier of `pred`.
+fn is_under_quantifier(expr: &ast::Expr, pred: &ast::Expr) -> bool {
+    expr.syntax()
+        .ancestors()
+        .skip(1)
+        .take_while(|it| it != pred.syntax())
+        .any(|it| ast::ClosureExpr::can_cast(it.kind()))
+}
+
+/// Whether the assertion fails in the `then` branch and in the `else` branch, each checked with
+/// the other branch assumed unreachable, in a single Verus run.
+fn failing_branches(
+    ctx: &AssistContext<'_>,
+    assert: &AssertExpr,
+    cond: &Expr,
+) -> Option<(bool, bool)> {
+    let this_fn = ctx.vst_find_node_at_offset::<Fn, ast::Fn>()?;
+    let assume_false: Stmt = AssumeExpr::new(ctx.vst_expr_from_text("false")?).into();
+    let probe = |then_branch: Stmt, else_branch: Stmt| {
+        let if_expr = vst_rewriter_case_split_on_bool(cond.clone(), then_branch, else_branch);
+        ctx.replace_statement(&this_fn, assert.clone(), Expr::from(if_expr))
+    };
+    let probes = [
+        probe(assert.clone().into(), assume_false.clone())?,
+        probe(assume_false, assert.clone().into())?,
+    ];
+    let mut results = ctx.try_verus_batch(&probes).into_iter();
+    let (then_result, else_result) = (results.next()??, results.next()??);
+    Some((then_result.is_failing(assert), else_result.is_failing(assert)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist, check_assist_not_applicable, check_assist_with_verifier},
+    };
+
+    #[test]
+    fn split_on_variable() {
+        check_assist(
+            case_split_on_bool,
+            "
+use vstd::prelude::*;
+proof fn foo(x: int, b: bool)
+    requires
+        x > 0,
+{
+    assert(x > 0 || $0b);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn foo(x: int, b: bool)
+    requires
+        x > 0,
+{
+    if b {
+        assert(x > 0 || b);
+    } else {
+        assert(x > 0 || b);
+    }
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn mark_failing_branch() {
+        check_assist_with_verifier(
+            case_split_on_bool,
+            // the assertion only fails when `x > 10` does not hold
+            MockVerifier::new(|item| match (item.find("assume"), item.find("assert")) {
+                (Some(assume), Some(assert)) if assume < assert => {
+                    vec!["assert(x > 10 && x * x > 100)".to_owned()]
+                }
+                _ => vec![],
+            }),
+            "
+use vstd::prelude::*;
+proof fn foo(x: int) {
+    assert(x $0> 10 && x * x > 100);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn foo(x: int) {
+    if x > 10 {
+        assert(x > 10 && x * x > 100);
+    } else {
+        assert(x > 10 && x * x > 100); // assertion failed
+    }
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn under_quantifier() {
+        check_assist_not_applicable(
+            case_split_on_bool,
+            "
+use vstd::prelude::*;
+proof fn foo(x: int) {
+    assert(forall|i: int| 0 <= i && $0i < x ==> i < x + 1);
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn whole_predicate() {
+        check_assist_not_applicable(
+            case_split_on_bool,
+            "
+use vstd::prelude::*;
+proof fn foo(x: int) {
+    assert(x $0> 10);
+}
+
+fn main() {}
+",
+        );
+    }
+}
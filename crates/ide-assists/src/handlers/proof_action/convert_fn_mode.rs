@@ -0,0 +1,465 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    AssistId, AssistKind,
+};
+use hir::{ModuleDef, PathResolution};
+use ide_db::{
+    defs::Definition,
+    search::SearchScope,
+    syntax_helpers::node_ext::{is_in_proof_block, is_in_spec_code},
+    FxHashSet,
+};
+use syntax::{
+    algo::find_node_at_range,
+    ast::{self, AstNode, HasArgList, HasName},
+    SyntaxKind, SyntaxNode, TextRange, T,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Proof,
+    Spec,
+}
+
+/// Proof action: turn an exec function into a proof function
+/// fn foo(x: &mut u64) {
+///     let ghost y = *x;
+/// }
+/// into
+/// proof fn foo(tracked x: &mut u64) {
+///     let y = *x;
+/// }
+///
+/// `&mut` parameters become tracked, and the `ghost` of local variables is dropped. Loops and
+/// calls to exec functions of the same file are flagged with a `FIXME` comment, as they are not
+/// allowed in proof code. Calls to the function in exec code of the same file are wrapped in a
+/// `proof` block; the action is not offered if such a call is not a statement on its own.
+pub(crate) fn convert_to_proof_fn(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    convert_fn_mode(acc, ctx, Mode::Proof)
+}
+
+/// Proof action: turn an exec function into a spec function
+/// fn double(x: &mut u64) -> u64
+///     requires
+///         *x < 100,
+/// {
+///     *x * 2
+/// }
+/// into
+/// spec fn double(x: u64) -> u64
+///     recommends
+///         x < 100,
+/// {
+///     x * 2
+/// }
+///
+/// `&mut` parameters are taken by value, along with the arguments of the calls of the same file.
+/// The `requires` clause becomes a `recommends` clause. The `ensures` clause, loops and calls to
+/// exec functions are flagged with a `FIXME` comment, as a spec function cannot have them.
+/// The action is not offered for a function assigning variables or declaring mutable ones, nor
+/// for one called in exec code of the same file.
+pub(crate) fn convert_to_spec_fn(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    convert_fn_mode(acc, ctx, Mode::Spec)
+}
+
+fn convert_fn_mode(acc: &mut Assists, ctx: &AssistContext<'_>, mode: Mode) -> Option<()> {
+    // trigger on "fn" of an exec function
+    let _ = ctx.at_this_token(T![fn])?;
+    let fn_token = ctx.find_token_syntax_at_offset(T![fn])?;
+    let func: ast::Fn = ctx.find_node_at_offset()?;
+    if func.fn_token()? != fn_token || !is_exec(&func) || func.name()?.text() == "main" {
+        return None;
+    }
+    if mode == Mode::Spec && mutates(&func) {
+        return None;
+    }
+    let fn_range = func.syntax().text_range();
+
+    let keyword = match mode {
+        Mode::Proof => "proof",
+        Mode::Spec => "spec",
+    };
+    let mut edits: Vec<(TextRange, String)> = match func.fn_mode() {
+        Some(fn_mode) => vec![(fn_mode.syntax().text_range(), keyword.to_owned())],
+        None => vec![(TextRange::empty(fn_token.text_range().start()), format!("{keyword} "))],
+    };
+    edits.extend(signature_edits(&func, mode));
+    edits.extend(body_edits(ctx, &func, mode));
+    let fn_text = apply_edits(func.syntax(), edits)?;
+    let caller_edits = caller_edits(ctx, &func, mode)?;
+
+    let (id, label) = match mode {
+        Mode::Proof => ("convert_to_proof_fn", "Convert to proof fn"),
+        Mode::Spec => ("convert_to_spec_fn", "Convert to spec fn"),
+    };
+    acc.add(AssistId(id, AssistKind::RefactorRewrite), label, fn_token.text_range(), |edit| {
+        edit.replace(fn_range, fn_text);
+        for (range, text) in caller_edits {
+            edit.replace(range, text);
+        }
+    })
+}
+
+fn is_exec(func: &ast::Fn) -> bool {
+    func.fn_mode().map_or(true, |it| it.exec_token().is_some())
+}
+
+/// The edits to the parameters and to the specification of `func`.
+fn signature_edits(func: &ast::Fn, mode: Mode) -> Vec<(TextRange, String)> {
+    let mut edits = vec![];
+    for param in func.param_list().into_iter().flat_map(|it| it.params()) {
+        let Some(ast::Type::RefType(ty)) = param.ty() else { continue };
+        if ty.mut_token().is_none() {
+            continue;
+        }
+        match mode {
+            Mode::Proof if param.tracked_token().is_none() => edits
+                .push((TextRange::empty(param.syntax().text_range().start()), "tracked ".into())),
+            Mode::Proof => (),
+            Mode::Spec => {
+                let Some(inner) = ty.ty() else { continue };
+                edits.push((ty.syntax().text_range(), inner.syntax().to_string()));
+            }
+        }
+    }
+    if mode == Mode::Spec {
+        if let Some(requires) = func.requires_clause().and_then(|it| it.requires_token()) {
+            edits.push((requires.text_range(), "recommends".into()));
+        }
+        if let Some(ensures) = func.ensures_clause() {
+            edits.push(flag(ensures.syntax(), "a spec fn cannot have an ensures clause"));
+        }
+        let params = mut_ref_params(func);
+        edits.extend(
+            func.syntax()
+                .descendants()
+                .filter_map(ast::PrefixExpr::cast)
+                .filter(|it| it.op_kind() == Some(ast::UnaryOp::Deref))
+                .filter_map(|it| {
+                    let inner = it.expr()?;
+                    let ast::Expr::PathExpr(path) = &inner else { return None };
+                    let name = path.path()?.as_single_name_ref()?;
+                    params
+                        .contains(name.text().as_str())
+                        .then(|| (it.syntax().text_range(), inner.syntax().to_string()))
+                }),
+        );
+    }
+    edits
+}
+
+/// The edits to the body of `func`: `ghost` variables, and the exec code flagged.
+fn body_edits(ctx: &AssistContext<'_>, func: &ast::Fn, mode: Mode) -> Vec<(TextRange, String)> {
+    let Some(body) = func.body() else { return vec![] };
+    let mut edits = vec![];
+    for let_stmt in body.syntax().descendants().filter_map(ast::LetStmt::cast) {
+        let Some(ghost) = let_stmt.ghost_token() else { continue };
+        let end = ghost.next_token().map_or(ghost.text_range().end(), |it| match it.kind() {
+            SyntaxKind::WHITESPACE => it.text_range().end(),
+            _ => ghost.text_range().end(),
+        });
+        edits.push((TextRange::new(ghost.text_range().start(), end), String::new()));
+    }
+
+    let message = match mode {
+        Mode::Proof => "exec code in a proof fn",
+        Mode::Spec => "exec code in a spec fn",
+    };
+    let mut flagged: FxHashSet<TextRange> = FxHashSet::default();
+    for node in body.syntax().descendants() {
+        if !is_exec_code(ctx, func, &node) || is_in_spec_code(&node) {
+            continue;
+        }
+        let stmt = statement_of(&node, body.syntax());
+        if flagged.insert(stmt.text_range()) {
+            edits.push(flag(&stmt, message));
+        }
+    }
+    edits
+}
+
+/// Whether `node` is a loop, or a call to an exec function other than `func`.
+fn is_exec_code(ctx: &AssistContext<'_>, func: &ast::Fn, node: &SyntaxNode) -> bool {
+    if ast::WhileExpr::can_cast(node.kind())
+        || ast::LoopExpr::can_cast(node.kind())
+        || ast::ForExpr::can_cast(node.kind())
+    {
+        return true;
+    }
+    let Some(call) = ast::CallExpr::cast(node.clone()) else { return false };
+    let Some(ast::Expr::PathExpr(callee)) = call.expr() else { return false };
+    let Some(PathResolution::Def(ModuleDef::Function(callee))) =
+        callee.path().and_then(|it| ctx.sema.resolve_path(&it))
+    else {
+        return false;
+    };
+    ctx.sema.source(callee).map_or(false, |it| it.value != *func && is_exec(&it.value))
+}
+
+/// The statement of the block `body` containing `node`.
+fn statement_of(node: &SyntaxNode, body: &SyntaxNode) -> SyntaxNode {
+    node.ancestors()
+        .take_while(|it| it != body)
+        .filter(|it| it.parent().map_or(false, |it| ast::StmtList::can_cast(it.kind())))
+        .last()
+        .unwrap_or_else(|| node.clone())
+}
+
+/// Whether the body of `func` assigns a variable or declares a mutable one.
+fn mutates(func: &ast::Fn) -> bool {
+    let Some(body) = func.body() else { return false };
+    body.syntax().descendants().any(|node| {
+        if let Some(bin_expr) = ast::BinExpr::cast(node.clone()) {
+            return matches!(bin_expr.op_kind(), Some(ast::BinaryOp::Assignment { .. }));
+        }
+        ast::LetStmt::cast(node).and_then(|it| it.pat()).map_or(false, |pat| {
+            pat.syntax()
+                .descendants()
+                .filter_map(ast::IdentPat::cast)
+                .any(|it| it.mut_token().is_some())
+        })
+    })
+}
+
+/// The edits to the calls of `func` in the current file, out of `func` itself. `None` if a call
+/// cannot be kept in the new mode.
+fn caller_edits(
+    ctx: &AssistContext<'_>,
+    func: &ast::Fn,
+    mode: Mode,
+) -> Option<Vec<(TextRange, String)>> {
+    let fn_def = Definition::Function(ctx.sema.to_def(func)?);
+    let scope = SearchScope::single_file(ctx.file_id());
+    let source_file = ctx.sema.parse(ctx.file_id());
+    let mut edits = vec![];
+    for (_, references) in fn_def.usages(&ctx.sema).in_scope(&scope).all() {
+        for reference in references {
+            if func.syntax().text_range().contains_range(reference.range) {
+                continue;
+            }
+            let Some(call) =
+                find_node_at_range::<ast::CallExpr>(source_file.syntax(), reference.range)
+            else {
+                continue;
+            };
+            let in_ghost_code = is_in_proof_block(call.syntax()) || is_in_spec_code(call.syntax());
+            match mode {
+                Mode::Proof if in_ghost_code => (),
+                Mode::Proof => {
+                    // only a call on its own can be moved into a `proof` block
+                    let stmt = call.syntax().parent().and_then(ast::ExprStmt::cast)?;
+                    edits.push((stmt.syntax().text_range(), format!("proof {{ {stmt} }}")));
+                }
+                // exec code cannot call a spec function
+                Mode::Spec if !in_ghost_code => return None,
+                Mode::Spec => {
+                    for arg in call.arg_list().into_iter().flat_map(|it| it.args()) {
+                        let ast::Expr::RefExpr(arg) = arg else { continue };
+                        let Some(inner) = arg.expr().filter(|_| arg.mut_token().is_some()) else {
+                            continue;
+                        };
+                        edits.push((arg.syntax().text_range(), inner.syntax().to_string()));
+                    }
+                }
+            }
+        }
+    }
+    Some(edits)
+}
+
+fn mut_ref_params(func: &ast::Fn) -> FxHashSet<String> {
+    func.param_list()
+        .into_iter()
+        .flat_map(|it| it.params())
+        .filter(|it| matches!(it.ty(), Some(ast::Type::RefType(ty)) if ty.mut_token().is_some()))
+        .filter_map(|param| match param.pat()? {
+            ast::Pat::IdentPat(it) => Some(it.name()?.text().to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A `FIXME` comment with `message` on the line before `node`.
+fn flag(node: &SyntaxNode, message: &str) -> (TextRange, String) {
+    let indent = node
+        .prev_sibling_or_token()
+        .filter(|it| it.kind() == SyntaxKind::WHITESPACE)
+        .map(|it| it.to_string().rsplit('\n').next().unwrap_or_default().to_owned())
+        .unwrap_or_default();
+    (TextRange::empty(node.text_range().start()), format!("// FIXME: {message}\n{indent}"))
+}
+
+/// The text of `node` with `edits` applied. An insertion goes before a replacement at the same
+/// offset.
+fn apply_edits(node: &SyntaxNode, mut edits: Vec<(TextRange, String)>) -> Option<String> {
+    let start = node.text_range().start();
+    let mut text = node.to_string();
+    edits.sort_by_key(|(range, _)| (range.start(), range.end()));
+    for (range, new) in edits.into_iter().rev() {
+        let range: std::ops::Range<usize> = range.checked_sub(start)?.into();
+        text.replace_range(range, &new);
+    }
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    #[test]
+    fn to_proof_fn() {
+        check_assist(
+            convert_to_proof_fn,
+            "
+use vstd::prelude::*;
+fn helper(x: u64) -> u64 {
+    x
+}
+
+f$0n foo(x: &mut u64, y: u64)
+    requires
+        y > 0,
+{
+    let ghost z = y + 1;
+    assert(z > 1);
+    let w = helper(y);
+}
+
+fn bar() {
+    let mut a = 1;
+    foo(&mut a, 2);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+fn helper(x: u64) -> u64 {
+    x
+}
+
+proof fn foo(tracked x: &mut u64, y: u64)
+    requires
+        y > 0,
+{
+    let z = y + 1;
+    assert(z > 1);
+    // FIXME: exec code in a proof fn
+    let w = helper(y);
+}
+
+fn bar() {
+    let mut a = 1;
+    proof { foo(&mut a, 2); }
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn to_spec_fn() {
+        check_assist(
+            convert_to_spec_fn,
+            "
+use vstd::prelude::*;
+f$0n double(x: &mut u64) -> u64
+    requires
+        *x < 100,
+{
+    *x * 2
+}
+
+proof fn user() {
+    let mut a: u64 = 1;
+    let b = double(&mut a);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+spec fn double(x: u64) -> u64
+    recommends
+        x < 100,
+{
+    x * 2
+}
+
+proof fn user() {
+    let mut a: u64 = 1;
+    let b = double(a);
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn spec_fn_called_in_exec_code() {
+        check_assist_not_applicable(
+            convert_to_spec_fn,
+            "
+use vstd::prelude::*;
+f$0n double(x: u64) -> u64 {
+    x * 2
+}
+
+fn main() {
+    let b = double(1);
+}
+",
+        );
+    }
+
+    #[test]
+    fn spec_fn_assigning() {
+        check_assist_not_applicable(
+            convert_to_spec_fn,
+            "
+use vstd::prelude::*;
+f$0n bump(x: &mut u64) -> u64 {
+    *x = *x + 1;
+    *x
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn proof_fn_call_in_expression() {
+        check_assist_not_applicable(
+            convert_to_proof_fn,
+            "
+use vstd::prelude::*;
+f$0n get(x: u64) -> u64 {
+    x
+}
+
+fn main() {
+    let y = get(1);
+}
+",
+        );
+    }
+
+    #[test]
+    fn already_proof_fn() {
+        check_assist_not_applicable(
+            convert_to_spec_fn,
+            "
+use vstd::prelude::*;
+proof f$0n foo(x: int) {
+    assert(x == x);
+}
+
+fn main() {}
+",
+        );
+    }
+}
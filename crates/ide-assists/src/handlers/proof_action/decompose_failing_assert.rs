@@ -172,13 +172,14 @@ pub(crate) fn vst_rewriter_localize_error_minimized(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::check_assist;
+    use crate::{proof_plumber_api::verifier::MockVerifier, tests::check_assist_with_verifier};
 
     // TEST: && (1)
     #[test]
     fn decompose_conjunct_failure() {
-        check_assist(
+        check_assist_with_verifier(
             localize_error,
+            MockVerifier::failing(&["assert(a > 10)"]),
             // before
             r#"
 use vstd::prelude::*;
@@ -208,8 +209,9 @@ fn main() {}
     // TEST: && (2)
     #[test]
     fn decompose_conjunct_failure2() {
-        check_assist(
+        check_assist_with_verifier(
             localize_error,
+            MockVerifier::failing(&["assert(y > 0)"]),
             // before
             r#"
 use vstd::prelude::*;
@@ -263,8 +265,9 @@ fn main() {}
     // TEST: inline
     #[test]
     fn decompose_function_inline() {
-        check_assist(
+        check_assist_with_verifier(
             localize_error,
+            MockVerifier::failing(&["assert(long_seq(ss))"]),
             r#"
 use vstd::prelude::*;
 use vstd::seq::*;
@@ -0,0 +1,385 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    AssistId, AssistKind,
+};
+use hir::{HirDisplay, PathResolution};
+use ide_db::{
+    syntax_helpers::node_ext::{enclosing_proof_block, is_in_proof_block},
+    FxHashSet,
+};
+use itertools::Itertools;
+use syntax::{
+    ast::{self, vst::*, AstNode, HasName},
+    SyntaxNode, T,
+};
+
+/// Proof action: extract a failing assertion into a lemma
+/// assert(x * y <= 100);
+/// into
+/// lemma_foo(x, y);
+/// along with
+/// proof fn lemma_foo(x: nat, y: nat)
+///     requires
+///         x <= 10,
+///         y <= 10,
+///     ensures
+///         x * y <= 100,
+/// {
+/// }
+///
+/// The lemma takes the variables of the assertion. It requires the preconditions of the function
+/// and the conditions of the enclosing `if`s that only mention these variables, and ensures the
+/// asserted predicate. It is added after the current function. Outside of proof code, the call
+/// to the lemma is put in a `proof` block; inside one, it replaces the assertion as it is.
+/// Verus is run before offering the edit, as only a failing assertion is worth extracting.
+pub(crate) fn extract_lemma(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "assert"
+    let _ = ctx.at_this_token(T![assert])?;
+
+    // retrieve the assertion of interest, along with its statement
+    let expr: ast::AssertExpr = ctx.find_node_at_offset()?;
+    // a `proof { .. }` block parses as a record expression, whose fields are its statements
+    // without their `;`
+    let in_proof_block = enclosing_proof_block(expr.syntax()).is_some();
+    let stmt = if in_proof_block {
+        expr.syntax().clone()
+    } else {
+        ast::ExprStmt::cast(expr.syntax().parent()?)?.syntax().clone()
+    };
+    let func: ast::Fn = ctx.find_node_at_offset()?;
+    let cond = expr.expr()?;
+
+    // lift CST into TOST node
+    let assert: AssertExpr = AssertExpr::try_from(expr.clone()).ok()?;
+    let v_func = Fn::try_from(func.clone()).ok()?;
+
+    // the proof of a prover like `bit_vector` cannot be moved into the body of a lemma
+    if assert.name.is_some() {
+        return None;
+    }
+
+    let params = lemma_params(ctx, &cond)?;
+    let mut hypotheses: Vec<String> = ctx
+        .requires_bounding(&v_func, &assert.expr)
+        .iter()
+        .map(|it| it.cst().map_or_else(|| it.to_string(), |it| it.syntax().to_string()))
+        .collect();
+    let vars = name_refs(cond.syntax());
+    hypotheses.extend(
+        dominating_conditions(&stmt)
+            .into_iter()
+            .filter(|it| {
+                let used = name_refs(it.syntax());
+                !used.is_empty() && used.is_subset(&vars)
+            })
+            .map(|it| it.to_string()),
+    );
+    let hypotheses: Vec<String> = hypotheses.into_iter().unique().collect();
+
+    let verif_result = ctx.try_verus(&v_func)?;
+    if !verif_result.is_failing(&assert) {
+        return None;
+    }
+
+    let name = lemma_name(&func)?;
+    let args = params.iter().map(|(name, _)| name).join(", ");
+    let call = if in_proof_block {
+        format!("{name}({args})")
+    } else if is_in_proof_block(&stmt) {
+        format!("{name}({args});")
+    } else {
+        format!("proof {{ {name}({args}); }}")
+    };
+
+    // replace the assertion in the text of the function, then add the lemma after it
+    let fn_range = func.syntax().text_range();
+    let stmt_range: std::ops::Range<usize> =
+        stmt.text_range().checked_sub(fn_range.start())?.into();
+    let mut fn_text = func.syntax().to_string();
+    fn_text.replace_range(stmt_range, &call);
+    let lemma = lemma_text(&name, &params, &hypotheses, &expr)?;
+    let result = ctx.fmt(func.clone(), format!("{fn_text}\n\n{lemma}"))?; // pretty-print
+
+    acc.add(
+        AssistId("extract_lemma", AssistKind::RefactorExtract),
+        "Extract assertion into a lemma",
+        stmt.text_range(),
+        |edit| {
+            edit.replace(fn_range, result);
+        },
+    )
+}
+
+/// The text of a lemma named `name` taking `params`, requiring `hypotheses` and ensuring the
+/// predicate of `assert`. The body of the lemma is the proof of the assertion, if any.
+fn lemma_text(
+    name: &str,
+    params: &[(String, String)],
+    hypotheses: &[String],
+    assert: &ast::AssertExpr,
+) -> Option<String> {
+    let params = params.iter().map(|(name, ty)| format!("{name}: {ty}")).join(", ");
+    let requires = if hypotheses.is_empty() {
+        String::new()
+    } else {
+        format!("requires {},", hypotheses.join(", "))
+    };
+    let body = assert.block_expr().map_or_else(|| "{}".to_owned(), |it| it.syntax().to_string());
+    let ensures = assert.expr()?;
+    Some(format!("proof fn {name}({params}) {requires} ensures {ensures}, {body}"))
+}
+
+/// The local variables mentioned by `cond`, along with their types, in order of appearance.
+/// `None` if the type of one of them cannot be spelled out.
+fn lemma_params(ctx: &AssistContext<'_>, cond: &ast::Expr) -> Option<Vec<(String, String)>> {
+    let mut params: Vec<(String, String)> = vec![];
+    for path in cond.syntax().descendants().filter_map(ast::Path::cast) {
+        let Some(PathResolution::Local(local)) = ctx.sema.resolve_path(&path) else { continue };
+        let name = local.name(ctx.db()).display(ctx.db()).to_string();
+        if params.iter().any(|(it, _)| *it == name) {
+            continue;
+        }
+        // `self` cannot be passed on as it is
+        let pat = local.primary_source(ctx.db()).into_ident_pat()?;
+        let declared = pat.syntax().parent().and_then(|it| {
+            if let Some(param) = ast::Param::cast(it.clone()) {
+                param.ty()
+            } else {
+                ast::LetStmt::cast(it)?.ty()
+            }
+        });
+        let ty = match declared {
+            Some(ty) => ty.to_string(),
+            None => {
+                let ty = local.ty(ctx.db());
+                if ty.contains_unknown() {
+                    return None;
+                }
+                ty.display(ctx.db()).to_string()
+            }
+        };
+        params.push((name, ty));
+    }
+    Some(params)
+}
+
+/// The conditions known to hold at `node`: the condition of each `if` it is in the then branch
+/// of, and the negation of the condition of each `if` it is in the else branch of.
+fn dominating_conditions(node: &SyntaxNode) -> Vec<ast::Expr> {
+    let mut conditions = vec![];
+    for ancestor in node.ancestors().take_while(|it| !ast::Fn::can_cast(it.kind())) {
+        let Some(if_expr) = ancestor.parent().and_then(ast::IfExpr::cast) else { continue };
+        let Some(condition) = if_expr.condition() else { continue };
+        // the bindings of `if let` are not in scope of the lemma
+        if condition.syntax().descendants().any(|it| ast::LetExpr::can_cast(it.kind())) {
+            continue;
+        }
+        let in_else = match if_expr.else_branch() {
+            Some(ast::ElseBranch::Block(it)) => *it.syntax() == ancestor,
+            Some(ast::ElseBranch::IfExpr(it)) => *it.syntax() == ancestor,
+            None => false,
+        };
+        if if_expr.then_branch().map_or(false, |it| *it.syntax() == ancestor) {
+            conditions.push(condition);
+        } else if in_else {
+            conditions.push(negate(condition));
+        }
+    }
+    conditions.reverse();
+    conditions
+}
+
+fn negate(expr: ast::Expr) -> ast::Expr {
+    match expr {
+        ast::Expr::PathExpr(_)
+        | ast::Expr::ParenExpr(_)
+        | ast::Expr::CallExpr(_)
+        | ast::Expr::MethodCallExpr(_) => ast::make::expr_prefix(T![!], expr),
+        _ => ast::make::expr_prefix(T![!], ast::make::expr_paren(expr)),
+    }
+}
+
+fn name_refs(node: &SyntaxNode) -> FxHashSet<String> {
+    node.descendants().filter_map(ast::NameRef::cast).map(|it| it.text().to_string()).collect()
+}
+
+/// `lemma_` followed by the name of `func`, with a suffix if a function of that name exists.
+fn lemma_name(func: &ast::Fn) -> Option<String> {
+    let root = func.syntax().ancestors().last()?;
+    let existing: FxHashSet<String> = root
+        .descendants()
+        .filter_map(ast::Fn::cast)
+        .filter_map(|it| it.name())
+        .map(|it| it.text().to_string())
+        .collect();
+    let base = format!("lemma_{}", func.name()?.text());
+    let mut name = base.clone();
+    let mut suffix = 1;
+    while existing.contains(&name) {
+        suffix += 1;
+        name = format!("{base}_{suffix}");
+    }
+    Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist_not_applicable, check_assist_with_verifier},
+    };
+
+    #[test]
+    fn extract_with_hypotheses() {
+        check_assist_with_verifier(
+            extract_lemma,
+            MockVerifier::failing(&["assert(x * y <= 100)"]),
+            "
+use vstd::prelude::*;
+proof fn foo(x: nat, y: nat, z: nat)
+    requires
+        x <= 10,
+        z > x,
+{
+    if y <= 10 {
+        ass$0ert(x * y <= 100);
+    }
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn foo(x: nat, y: nat, z: nat)
+    requires
+        x <= 10,
+        z > x,
+{
+    if y <= 10 {
+        lemma_foo(x, y);
+    }
+}
+
+proof fn lemma_foo(x: nat, y: nat)
+    requires
+        x <= 10,
+        y <= 10,
+    ensures
+        x * y <= 100,
+{
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn extract_from_exec_fn() {
+        check_assist_with_verifier(
+            extract_lemma,
+            MockVerifier::failing(&["assert(a + b < 200)"]),
+            "
+use vstd::prelude::*;
+fn lemma_bar() {}
+
+fn bar(a: u64, b: u64)
+    requires
+        a < 100,
+{
+    if b > 100 {
+    } else {
+        ass$0ert(a + b < 200);
+    }
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+fn lemma_bar() {}
+
+fn bar(a: u64, b: u64)
+    requires
+        a < 100,
+{
+    if b > 100 {
+    } else {
+        proof {
+            lemma_bar_2(a, b);
+        }
+    }
+}
+
+proof fn lemma_bar_2(a: u64, b: u64)
+    requires
+        a < 100,
+        !(b > 100),
+    ensures
+        a + b < 200,
+{
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn extract_from_proof_block() {
+        check_assist_with_verifier(
+            extract_lemma,
+            MockVerifier::failing(&["assert(a * a >= a)"]),
+            "
+use vstd::prelude::*;
+fn baz(a: u64)
+    requires
+        a > 0,
+{
+    proof {
+        ass$0ert(a * a >= a);
+    }
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+fn baz(a: u64)
+    requires
+        a > 0,
+{
+    proof {
+        lemma_baz(a);
+    }
+}
+
+proof fn lemma_baz(a: u64)
+    requires
+        a > 0,
+    ensures
+        a * a >= a,
+{
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn verified_assertion() {
+        check_assist_not_applicable(
+            extract_lemma,
+            "
+use vstd::prelude::*;
+proof fn foo(x: nat) {
+    ass$0ert(x + 1 > x);
+}
+
+fn main() {}
+",
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    proof_plumber_api::vst_ext::{vst_map_expr_visitor, vst_parenthesize, vst_subst_expr},
+    AssistId, AssistKind,
+};
+use ide_db::FxHashMap;
+use syntax::{
+    ast::{self, vst::*, AstNode},
+    T,
+};
+
+/// Proof action: inline a spec function call inside an assertion
+/// spec fn is_even(x: int) -> bool { x % 2 == 0 }
+/// assert(is_even(a + 1));
+/// into
+/// assert((a + 1) % 2 == 0);
+///
+/// Triggers on the name of the called function. Every occurrence of the same call in the
+/// assertion is inlined. Only a spec function whose body is a single expression is inlined.
+pub(crate) fn inline_spec_fn_call(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on the name of the callee
+    let _ = ctx.at_this_token(T![ident])?;
+    let name_ref: ast::NameRef = ctx.find_node_at_offset()?;
+    let call: ast::CallExpr = ctx.find_node_at_offset()?;
+    let callee = call.expr()?;
+    if !callee.syntax().text_range().contains_range(name_ref.syntax().text_range()) {
+        return None;
+    }
+
+    // the call has to be inside an assertion, as a statement
+    let expr: ast::AssertExpr = call.syntax().ancestors().find_map(ast::AssertExpr::cast)?;
+    if !expr.expr()?.syntax().text_range().contains_range(call.syntax().text_range()) {
+        return None;
+    }
+    let stmt = ast::ExprStmt::cast(expr.syntax().parent()?)?;
+
+    // lift CST into TOST node
+    let v_call = CallExpr::try_from(call.clone()).ok()?;
+    let assert = AssertExpr::try_from(expr.clone()).ok()?;
+    let func = ctx.vst_find_fn(&v_call)?;
+
+    let mut inlined = vst_rewriter_inline_spec_fn_call(&func, &v_call)?;
+    // the assertion of the call alone needs no parentheses
+    if !ast::AssertExpr::can_cast(call.syntax().parent()?.kind()) {
+        inlined = vst_parenthesize(inlined);
+    }
+    let call_text = v_call.to_string().trim().to_owned();
+    let mut result = assert.clone();
+    result.expr = Box::new(
+        vst_map_expr_visitor(*assert.expr, &mut |e| match e {
+            Expr::CallExpr(it) if it.to_string().trim() == call_text => Ok(inlined.clone()),
+            _ => Ok(e.clone()),
+        })
+        .ok()?,
+    );
+
+    // pretty-print the whole statement
+    let result = ctx.fmt(stmt.clone(), format!("{result};"))?;
+
+    acc.add(
+        AssistId("inline_spec_fn_call", AssistKind::RefactorInline),
+        format!("Inline `{}`", func.name.to_string().trim()),
+        call.syntax().text_range(),
+        |edit| {
+            edit.replace(stmt.syntax().text_range(), result);
+        },
+    )
+}
+
+/// The body of the spec function `func`, with its parameters substituted by the arguments of
+/// `call`.
+pub(crate) fn vst_rewriter_inline_spec_fn_call(func: &Fn, call: &CallExpr) -> Option<Expr> {
+    if !func.fn_mode.as_ref()?.spec_token {
+        return None;
+    }
+    let body = func.body.as_ref()?;
+    if !body.stmt_list.statements.is_empty() {
+        return None;
+    }
+    let body = *body.stmt_list.tail_expr.clone()?;
+
+    let params = &func.param_list.as_ref()?.params;
+    if params.len() != call.arg_list.args.len() {
+        return None;
+    }
+    let mut subst = FxHashMap::default();
+    for (param, arg) in params.iter().zip(&call.arg_list.args) {
+        let Some(Pat::IdentPat(pat)) = param.pat.as_deref() else { return None };
+        subst.insert(pat.name.to_string().trim().to_owned(), arg.clone());
+    }
+    vst_subst_expr(body, &subst).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    #[test]
+    fn inline_in_condition() {
+        check_assist(
+            inline_spec_fn_call,
+            "
+use vstd::prelude::*;
+spec fn is_even(x: int) -> bool {
+    x % 2 == 0
+}
+
+proof fn foo(a: int) {
+    assert(is_e$0ven(a + 1) || a > 0);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+spec fn is_even(x: int) -> bool {
+    x % 2 == 0
+}
+
+proof fn foo(a: int) {
+    assert(((a + 1) % 2 == 0) || a > 0);
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn inline_whole_assertion() {
+        check_assist(
+            inline_spec_fn_call,
+            "
+use vstd::prelude::*;
+spec fn bounded(x: int, n: int) -> bool {
+    0 <= x && !(x >= n)
+}
+
+proof fn foo(a: int) {
+    assert(boun$0ded(a, 10));
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+spec fn bounded(x: int, n: int) -> bool {
+    0 <= x && !(x >= n)
+}
+
+proof fn foo(a: int) {
+    assert(0 <= a && !(a >= 10));
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn inline_if_and_forall() {
+        check_assist(
+            inline_spec_fn_call,
+            "
+use vstd::prelude::*;
+spec fn all_small(s: Seq<int>, i: int) -> bool {
+    if i > 0 {
+        forall|i: int| 0 <= i < s.len() ==> s[i] < 100
+    } else {
+        s.len() == 0
+    }
+}
+
+proof fn foo(t: Seq<int>, k: int) {
+    assert(all_sm$0all(t, k + 1));
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+spec fn all_small(s: Seq<int>, i: int) -> bool {
+    if i > 0 {
+        forall|i: int| 0 <= i < s.len() ==> s[i] < 100
+    } else {
+        s.len() == 0
+    }
+}
+
+proof fn foo(t: Seq<int>, k: int) {
+    assert(if (k + 1) > 0 {
+        forall|i: int| 0 <= i < t.len() ==> t[i] < 100
+    } else {
+        t.len() == 0
+    });
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn not_a_spec_fn() {
+        check_assist_not_applicable(
+            inline_spec_fn_call,
+            "
+use vstd::prelude::*;
+proof fn is_even(x: int) -> bool {
+    x % 2 == 0
+}
+
+proof fn foo(a: int) {
+    assert(is_e$0ven(a));
+}
+
+fn main() {}
+",
+        );
+    }
+}
@@ -0,0 +1,323 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    proof_plumber_api::vst_ext::{vst_subst_expr, vst_walk_expr},
+    AssistId, AssistKind,
+};
+use ide_db::{syntax_helpers::node_ext::quantifier_without_triggers, FxHashMap};
+use itertools::Itertools;
+use syntax::{
+    ast::{self, vst::*, AstNode},
+    Direction, SyntaxNode, T,
+};
+
+/// Proof action: instantiate a quantified hypothesis on the terms of an assertion
+/// requires forall|i: int| 0 <= i < s.len() ==> s[i] > 0,
+/// ...
+/// assert(s[k] + s[k] > 0);
+/// into
+/// assert(0 <= k < s.len() ==> s[k] > 0);
+/// assert(s[k] + s[k] > 0);
+///
+/// The hypotheses are the `forall` quantifiers of the `requires` clause and of the assertions
+/// before this one. The calls and indexing of the quantified variables in a hypothesis are
+/// matched against the sub-expressions of the assertion, and each way of binding all of its
+/// variables is offered as an instantiation.
+pub(crate) fn instantiate_forall(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "assert"
+    let _ = ctx.at_this_token(T![assert])?;
+
+    // retrieve the assertion of interest, along with its statement
+    let expr: ast::AssertExpr = ctx.find_node_at_offset()?;
+    let stmt = ast::ExprStmt::cast(expr.syntax().parent()?)?;
+    let func: ast::Fn = ctx.find_node_at_offset()?;
+
+    // lift CST into TOST node
+    let assert = AssertExpr::try_from(expr.clone()).ok()?;
+    let hypotheses: Vec<ClosureExpr> = forall_hypotheses(&func, stmt.syntax())
+        .into_iter()
+        .filter_map(|it| ClosureExpr::try_from(it).ok())
+        .collect();
+
+    let mut instances: Vec<(String, AssertExpr)> = vec![];
+    for hypothesis in &hypotheses {
+        for binding in instantiations(hypothesis, &assert.expr) {
+            let Some(instance) = vst_rewriter_instantiate_forall(hypothesis, &binding) else {
+                continue;
+            };
+            let label =
+                binding.iter().map(|(var, term)| format!("{var} := {}", term.to_string().trim()));
+            instances.push((label.join(", "), instance));
+        }
+    }
+    let instances = instances
+        .into_iter()
+        .unique_by(|(_, it)| it.to_string())
+        .filter(|(_, it)| it.expr.to_string() != assert.expr.to_string());
+
+    for (label, instance) in instances {
+        acc.add(
+            AssistId("instantiate_forall", AssistKind::RefactorRewrite),
+            format!("Instantiate quantified hypothesis with `{label}`"),
+            stmt.syntax().text_range(),
+            |edit| {
+                let result = format!("{instance};\n{assert};");
+                let result = ctx.fmt(stmt.clone(), result).expect("fmt"); // pretty-print
+                edit.replace(stmt.syntax().text_range(), result);
+            },
+        );
+    }
+    Some(())
+}
+
+/// The body of the quantifier `forall`, with each of its variables replaced by the term `binding`
+/// maps it to, as an assertion.
+pub(crate) fn vst_rewriter_instantiate_forall(
+    forall: &ClosureExpr,
+    binding: &[(String, Expr)],
+) -> Option<AssertExpr> {
+    let subst: FxHashMap<String, Expr> = binding.iter().cloned().collect();
+    let instance = vst_subst_expr(*forall.body.clone(), &subst).ok()?;
+    Some(AssertExpr::new(instance))
+}
+
+/// The `forall` quantifiers known to hold at `stmt`: those of the `requires` clause of `func`,
+/// and those asserted by the statements before `stmt` in its blocks. Triggers are left out, as
+/// they mean nothing outside of a quantifier.
+fn forall_hypotheses(func: &ast::Fn, stmt: &SyntaxNode) -> Vec<ast::ClosureExpr> {
+    let mut hypotheses: Vec<ast::Expr> =
+        func.requires_clause().map(|it| it.exprs().collect()).unwrap_or_default();
+    for ancestor in stmt.ancestors().take_while(|it| !ast::Fn::can_cast(it.kind())) {
+        if ancestor.parent().map_or(true, |it| !ast::StmtList::can_cast(it.kind())) {
+            continue;
+        }
+        let asserted = |stmt: ast::ExprStmt| match stmt.expr()? {
+            ast::Expr::AssertExpr(assert) => assert.expr(),
+            _ => None,
+        };
+        hypotheses.extend(
+            ancestor
+                .siblings(Direction::Prev)
+                .skip(1)
+                .filter_map(ast::ExprStmt::cast)
+                .filter_map(asserted),
+        );
+    }
+    hypotheses
+        .into_iter()
+        .filter_map(|it| match it {
+            ast::Expr::ClosureExpr(closure) if closure.forall_token().is_some() => {
+                Some(quantifier_without_triggers(&closure))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// The ways to bind every variable of the quantifier `forall` to a term of `pred`, such that
+/// calls and indexing of the variables in the body of `forall` become terms of `pred`.
+/// Each binding lists the variables in the order they are declared.
+fn instantiations(forall: &ClosureExpr, pred: &Expr) -> Vec<Vec<(String, Expr)>> {
+    let Some(params) = forall.param_list.as_ref() else { return vec![] };
+    let vars: Vec<String> = params
+        .params
+        .iter()
+        .filter_map(|param| match param.pat.as_deref() {
+            Some(Pat::IdentPat(pat)) => Some(pat.name.to_string().trim().to_owned()),
+            _ => None,
+        })
+        .collect();
+    if vars.is_empty() || vars.len() != params.params.len() {
+        return vec![];
+    }
+
+    // the terms of the body that mention a variable, to be matched like triggers
+    let mut patterns: Vec<Expr> = vec![];
+    vst_walk_expr(&forall.body, &mut |it| {
+        if matches!(it, Expr::CallExpr(_) | Expr::MethodCallExpr(_) | Expr::IndexExpr(_))
+            && mentions(&it, &vars)
+        {
+            patterns.push(it);
+        }
+    });
+    let mut terms: Vec<Expr> = vec![];
+    vst_walk_expr(pred, &mut |it| terms.push(it));
+
+    // each pattern either matches one of the terms, consistently with the others, or none
+    let mut bindings: Vec<FxHashMap<String, Expr>> = vec![FxHashMap::default()];
+    for pattern in &patterns {
+        let mut extended = vec![];
+        for binding in &bindings {
+            for term in &terms {
+                let mut new_binding = binding.clone();
+                if unify(pattern, term, &vars, &mut new_binding)
+                    && new_binding.len() > binding.len()
+                {
+                    extended.push(new_binding);
+                }
+            }
+        }
+        bindings.extend(extended);
+    }
+    bindings
+        .into_iter()
+        .filter(|it| it.len() == vars.len())
+        .map(|binding| vars.iter().map(|var| (var.clone(), binding[var].clone())).collect())
+        .unique_by(|it: &Vec<(String, Expr)>| {
+            it.iter().map(|(_, term)| term.to_string()).collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Whether `pattern`, where `vars` stand for any term, matches `term` under `binding`, which is
+/// extended with the terms the variables newly stand for.
+fn unify(
+    pattern: &Expr,
+    term: &Expr,
+    vars: &[String],
+    binding: &mut FxHashMap<String, Expr>,
+) -> bool {
+    let same = |lhs: &Expr, rhs: &Expr| lhs.to_string().trim() == rhs.to_string().trim();
+    match (pattern, term) {
+        (Expr::PathExpr(path), _) if vars.iter().any(|it| it == path.to_string().trim()) => {
+            match binding.get(path.to_string().trim()) {
+                Some(bound) => same(bound, term),
+                None => {
+                    binding.insert(path.to_string().trim().to_owned(), term.clone());
+                    true
+                }
+            }
+        }
+        (Expr::CallExpr(pattern), Expr::CallExpr(term)) => {
+            same(&pattern.expr, &term.expr)
+                && pattern.arg_list.args.len() == term.arg_list.args.len()
+                && pattern
+                    .arg_list
+                    .args
+                    .iter()
+                    .zip(&term.arg_list.args)
+                    .all(|(pattern, term)| unify(pattern, term, vars, binding))
+        }
+        (Expr::MethodCallExpr(pattern), Expr::MethodCallExpr(term)) => {
+            pattern.name_ref.to_string().trim() == term.name_ref.to_string().trim()
+                && pattern.arg_list.args.len() == term.arg_list.args.len()
+                && unify(&pattern.receiver, &term.receiver, vars, binding)
+                && pattern
+                    .arg_list
+                    .args
+                    .iter()
+                    .zip(&term.arg_list.args)
+                    .all(|(pattern, term)| unify(pattern, term, vars, binding))
+        }
+        (Expr::IndexExpr(pattern), Expr::IndexExpr(term)) => {
+            unify(&pattern.base, &term.base, vars, binding)
+                && unify(&pattern.index, &term.index, vars, binding)
+        }
+        _ => !mentions(pattern, vars) && same(pattern, term),
+    }
+}
+
+fn mentions(expr: &Expr, vars: &[String]) -> bool {
+    let mut found = false;
+    vst_walk_expr(expr, &mut |it| {
+        found |= matches!(&it, Expr::PathExpr(_))
+            && vars.iter().any(|var| *var == it.to_string().trim());
+    });
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{check_assist, check_assist_by_label, check_assist_not_applicable};
+
+    #[test]
+    fn instantiate_requires() {
+        check_assist(
+            instantiate_forall,
+            "
+use vstd::prelude::*;
+proof fn foo(s: Seq<int>, k: int)
+    requires
+        forall|i: int| 0 <= i < s.len() ==> #[trigger] s[i] > 0,
+        0 <= k < s.len(),
+{
+    ass$0ert(s[k] + s[k] > 0);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn foo(s: Seq<int>, k: int)
+    requires
+        forall|i: int| 0 <= i < s.len() ==> #[trigger] s[i] > 0,
+        0 <= k < s.len(),
+{
+    assert(0 <= k < s.len() ==> s[k] > 0);
+    assert(s[k] + s[k] > 0);
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn instantiate_earlier_assertion() {
+        check_assist_by_label(
+            instantiate_forall,
+            "
+use vstd::prelude::*;
+spec fn f(x: int) -> int;
+
+proof fn foo(a: int, b: int) {
+    assert(forall|x: int, y: int| x <= y ==> f(x) <= f(y)) by {
+        admit();
+    }
+    if a <= b {
+        ass$0ert(f(a) <= f(b));
+    }
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+spec fn f(x: int) -> int;
+
+proof fn foo(a: int, b: int) {
+    assert(forall|x: int, y: int| x <= y ==> f(x) <= f(y)) by {
+        admit();
+    }
+    if a <= b {
+        assert(a <= b ==> f(a) <= f(b));
+        assert(f(a) <= f(b));
+    }
+}
+
+fn main() {}
+",
+            "Instantiate quantified hypothesis with `x := a, y := b`",
+        );
+    }
+
+    #[test]
+    fn no_matching_term() {
+        check_assist_not_applicable(
+            instantiate_forall,
+            "
+use vstd::prelude::*;
+spec fn f(x: int) -> int;
+
+proof fn foo(a: int)
+    requires
+        forall|x: int| f(x) > 0,
+{
+    ass$0ert(a > 0);
+}
+
+fn main() {}
+",
+        );
+    }
+}
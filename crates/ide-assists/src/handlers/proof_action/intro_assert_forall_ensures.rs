@@ -0,0 +1,162 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    handlers::proof_action::intro_forall_implies::vst_rewriter_intro_forall_implies,
+    proof_plumber_api::verus_error::filter_post_failuires,
+    AssistId, AssistKind,
+};
+use syntax::{
+    ast::{self, vst::*, AstNode, HasName},
+    T,
+};
+
+/// Proof action: start proving a failing `forall` postcondition
+/// ensures
+///     forall|i: int| 0 <= i < s.len() ==> f(s[i]),
+/// {
+/// }
+/// into
+/// ensures
+///     forall|i: int| 0 <= i < s.len() ==> f(s[i]),
+/// {
+///     assert forall|i: int| 0 <= i < s.len() implies f(s[i]) by {};
+/// }
+///
+/// The counterpart of `intro_forall_implies` for the `ensures` clause. The skeletons go after the
+/// last statement of the body, before its tail expression. A postcondition mentioning the
+/// returned value is left out, as it is not bound in the body.
+pub(crate) fn intro_assert_forall_ensures(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    // trigger on "ensures"
+    let _ = ctx.at_this_token(T![ensures])?;
+    let func: ast::Fn = ctx.find_node_at_offset()?;
+    let body: ast::BlockExpr = func.body()?;
+    let ensures: ast::EnsuresClause = func.ensures_clause()?;
+    let ret_name = func.ret_type().and_then(|it| match it.pat()? {
+        ast::Pat::IdentPat(pat) => Some(pat.name()?.text().to_string()),
+        _ => None,
+    });
+
+    // the failing postconditions that are quantified over, and can be stated in the body
+    let v_func = Fn::try_from(func.clone()).ok()?;
+    let post_fails = filter_post_failuires(&ctx.verus_errors_inside_fn(&v_func)?);
+    let foralls: Vec<ClosureExpr> = ensures
+        .exprs()
+        .filter(|post| post_fails.iter().any(|it| it.failing_post == post.syntax().text_range()))
+        .filter(|post| {
+            !post
+                .syntax()
+                .descendants()
+                .filter_map(ast::NameRef::cast)
+                .any(|it| ret_name.as_deref() == Some(it.text().as_str()))
+        })
+        .filter_map(|post| match Expr::try_from(post).ok()? {
+            Expr::ClosureExpr(closure) if closure.forall_token => Some(*closure),
+            _ => None,
+        })
+        .collect();
+    if foralls.is_empty() {
+        return None;
+    }
+
+    let v_body = BlockExpr::try_from(body.clone()).ok()?;
+    let result = vst_rewriter_intro_assert_forall_ensures(v_body, foralls)?;
+    let result = ctx.fmt(body.clone(), result.to_string())?;
+
+    acc.add(
+        AssistId("intro_assert_forall_ensures", AssistKind::RefactorRewrite),
+        "Insert assert forall for failing ensures clauses",
+        body.syntax().text_range(),
+        |edit| {
+            edit.replace(body.syntax().text_range(), result);
+        },
+    )
+}
+
+/// `blk`, with an `assert forall` with an empty proof for each of `foralls` after its statements.
+/// An implication is split into `implies`, so that its premise is assumed in the proof.
+pub(crate) fn vst_rewriter_intro_assert_forall_ensures(
+    mut blk: BlockExpr,
+    foralls: Vec<ClosureExpr>,
+) -> Option<BlockExpr> {
+    for forall in foralls {
+        let assert = AssertExpr::new(forall.clone());
+        let assert_forall = vst_rewriter_intro_forall_implies(assert)
+            .unwrap_or_else(|| AssertForallExpr::new(forall, BlockExpr::new(StmtList::new())));
+        blk.stmt_list.statements.push(assert_forall.into());
+    }
+    Some(blk)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proof_plumber_api::verus_error::mk_post_failure, tests::check_assist_with_verus_error,
+    };
+
+    #[test]
+    fn forall_implies() {
+        check_assist_with_verus_error(
+            intro_assert_forall_ensures,
+            vec![mk_post_failure(100, 143, 145, 148)],
+            r#"
+spec fn f(x: int) -> bool;
+
+proof fn lemma_f(s: Seq<int>)
+    ens$0ures
+        s.len() >= 0,
+        forall|i: int| 0 <= i < s.len() ==> f(s[i]),
+{
+}
+"#,
+            r#"
+spec fn f(x: int) -> bool;
+
+proof fn lemma_f(s: Seq<int>)
+    ensures
+        s.len() >= 0,
+        forall|i: int| 0 <= i < s.len() ==> f(s[i]),
+{
+    assert forall|i: int| 0 <= i < s.len() implies f(s[i]) by {};
+}
+
+"#,
+        );
+    }
+
+    #[test]
+    fn forall_without_premise() {
+        check_assist_with_verus_error(
+            intro_assert_forall_ensures,
+            vec![mk_post_failure(109, 131, 133, 156)],
+            r#"
+spec fn g(x: int, y: int) -> bool;
+
+proof fn lemma_g(y: int)
+    requires
+        y > 0,
+    ens$0ures
+        forall|x: int| g(x, y),
+{
+    assert(y >= 0);
+}
+"#,
+            r#"
+spec fn g(x: int, y: int) -> bool;
+
+proof fn lemma_g(y: int)
+    requires
+        y > 0,
+    ensures
+        forall|x: int| g(x, y),
+{
+    assert(y >= 0);
+    assert forall|x: int| g(x, y) by {};
+}
+
+"#,
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    AssistId, AssistKind,
+};
+use itertools::Itertools;
+use syntax::{
+    ast::{self, vst::*, AstNode, HasArgList},
+    Direction, SyntaxKind, TextRange, T,
+};
+
+/// Proof action: turn a failing equality into a `calc!` chain
+/// assert(f(a) + f(b) == f(c) + f(d));
+/// into
+/// calc! {
+///     (==)
+///     f(a) + f(b); {}
+///     f(c) + f(b); {}
+///     f(c) + f(d);
+/// }
+///
+/// Both sides are matched against each other, and the steps rewrite their differing
+/// subexpressions one at a time, from left to right. The empty proof of each step is left for
+/// the user to fill in.
+pub(crate) fn intro_calc(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "assert"
+    let _ = ctx.at_this_token(T![assert])?;
+
+    // retrieve the assertion of interest, along with its statement
+    let expr: ast::AssertExpr = ctx.find_node_at_offset()?;
+    let stmt = ast::ExprStmt::cast(expr.syntax().parent()?)?;
+    if expr.by_token().is_some() {
+        return None;
+    }
+    let ast::Expr::BinExpr(eq) = expr.expr()? else { return None };
+    if eq.op_kind()? != ast::BinaryOp::CmpOp(ast::CmpOp::Eq { negated: false }) {
+        return None;
+    }
+    let (lhs, rhs) = (eq.lhs()?, eq.rhs()?);
+
+    // only worth it when the assertion fails
+    let assert = AssertExpr::try_from(expr.clone()).ok()?;
+    let this_fn = ctx.vst_find_node_at_offset::<Fn, ast::Fn>()?;
+    if !ctx.try_verus(&this_fn)?.is_failing(&assert) {
+        return None;
+    }
+
+    let mut diffs = vec![];
+    differences(&lhs, &rhs, &mut diffs);
+    let lhs_start = lhs.syntax().text_range().start();
+    let lhs_text = lhs.syntax().to_string();
+    let mut steps = vec![normalize(&lhs_text)];
+    for idx in 1..diffs.len() {
+        let mut step = lhs_text.clone();
+        for (range, new) in diffs[..idx].iter().rev() {
+            let range: std::ops::Range<usize> = range.checked_sub(lhs_start)?.into();
+            step.replace_range(range, new);
+        }
+        steps.push(normalize(&step));
+    }
+    steps.push(normalize(&rhs.syntax().to_string()));
+
+    let indent = stmt
+        .syntax()
+        .siblings_with_tokens(Direction::Prev)
+        .nth(1)
+        .filter(|it| it.kind() == SyntaxKind::WHITESPACE)
+        .map(|it| it.to_string().rsplit('\n').next().unwrap_or_default().to_owned())
+        .unwrap_or_default();
+    let last = steps.pop()?;
+    let chain = steps.iter().map(|it| format!("{indent}    {it}; {{}}\n")).join("");
+    let result = format!("calc! {{\n{indent}    (==)\n{chain}{indent}    {last};\n{indent}}}");
+
+    acc.add(
+        AssistId("intro_calc", AssistKind::RefactorRewrite),
+        "Rewrite into a calc! chain",
+        expr.syntax().text_range(),
+        |edit| {
+            edit.replace(stmt.syntax().text_range(), result);
+        },
+    )
+}
+
+/// The subexpressions where `lhs` and `rhs` differ, as the range in `lhs` along with the text
+/// from `rhs`, from left to right. The expressions are walked down as long as they have the same
+/// shape.
+fn differences(lhs: &ast::Expr, rhs: &ast::Expr, acc: &mut Vec<(TextRange, String)>) {
+    if normalize(&lhs.syntax().to_string()) == normalize(&rhs.syntax().to_string()) {
+        return;
+    }
+    let children: Option<Vec<(ast::Expr, ast::Expr)>> = match (lhs, rhs) {
+        (ast::Expr::BinExpr(l), ast::Expr::BinExpr(r)) if l.op_kind() == r.op_kind() => {
+            let pairs: Vec<(ast::Expr, ast::Expr)> = [(l.lhs(), r.lhs()), (l.rhs(), r.rhs())]
+                .into_iter()
+                .filter_map(|(l, r)| Some((l?, r?)))
+                .collect();
+            // operands that are variables or literals, as in `x * 2` and `2 * x`, are rewritten
+            // along with the operation
+            let atoms_differ = pairs.iter().any(|(l, r)| {
+                is_atom(l) && is_atom(r) && !same_text(Some(l.clone()), Some(r.clone()))
+            });
+            (!atoms_differ).then_some(pairs)
+        }
+        (ast::Expr::CallExpr(l), ast::Expr::CallExpr(r))
+            if same_text(l.expr(), r.expr()) && same_arity(l.arg_list(), r.arg_list()) =>
+        {
+            Some(args(l.arg_list()).zip(args(r.arg_list())).collect())
+        }
+        (ast::Expr::MethodCallExpr(l), ast::Expr::MethodCallExpr(r))
+            if same_text(l.name_ref(), r.name_ref()) && same_arity(l.arg_list(), r.arg_list()) =>
+        {
+            let receivers = l.receiver().zip(r.receiver());
+            Some(receivers.into_iter().chain(args(l.arg_list()).zip(args(r.arg_list()))).collect())
+        }
+        (ast::Expr::IndexExpr(l), ast::Expr::IndexExpr(r)) => Some(
+            [(l.base(), r.base()), (l.index(), r.index())]
+                .into_iter()
+                .filter_map(|(l, r)| Some((l?, r?)))
+                .collect(),
+        ),
+        (ast::Expr::ParenExpr(l), ast::Expr::ParenExpr(r)) => {
+            l.expr().zip(r.expr()).map(|it| vec![it])
+        }
+        (ast::Expr::PrefixExpr(l), ast::Expr::PrefixExpr(r)) if l.op_kind() == r.op_kind() => {
+            l.expr().zip(r.expr()).map(|it| vec![it])
+        }
+        _ => None,
+    };
+    match children {
+        Some(children) if !children.is_empty() => {
+            for (l, r) in children {
+                differences(&l, &r, acc);
+            }
+        }
+        _ => acc.push((lhs.syntax().text_range(), rhs.syntax().to_string())),
+    }
+}
+
+fn same_text<N: AstNode>(lhs: Option<N>, rhs: Option<N>) -> bool {
+    match (lhs, rhs) {
+        (Some(l), Some(r)) => {
+            normalize(&l.syntax().to_string()) == normalize(&r.syntax().to_string())
+        }
+        _ => false,
+    }
+}
+
+fn is_atom(expr: &ast::Expr) -> bool {
+    matches!(expr, ast::Expr::PathExpr(_) | ast::Expr::Literal(_))
+}
+
+fn args(arg_list: Option<ast::ArgList>) -> impl Iterator<Item = ast::Expr> {
+    arg_list.into_iter().flat_map(|it| it.args())
+}
+
+fn same_arity(lhs: Option<ast::ArgList>, rhs: Option<ast::ArgList>) -> bool {
+    match (lhs, rhs) {
+        (Some(l), Some(r)) => l.args().count() == r.args().count(),
+        _ => false,
+    }
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist_not_applicable, check_assist_with_verifier},
+    };
+
+    #[test]
+    fn calc_two_steps() {
+        check_assist_with_verifier(
+            intro_calc,
+            MockVerifier::failing(&["assert(f(a) + f(b) == f(c) + f(d))"]),
+            "
+use vstd::prelude::*;
+spec fn f(x: int) -> int;
+
+proof fn foo(a: int, b: int, c: int, d: int) {
+    ass$0ert(f(a) + f(b) == f(c) + f(d));
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+spec fn f(x: int) -> int;
+
+proof fn foo(a: int, b: int, c: int, d: int) {
+    calc! {
+        (==)
+        f(a) + f(b); {}
+        f(c) + f(b); {}
+        f(c) + f(d);
+    }
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn calc_nested_call() {
+        check_assist_with_verifier(
+            intro_calc,
+            MockVerifier::failing(&["assert(g(s[i], x * 2) == g(t[i], 2 * x))"]),
+            "
+use vstd::prelude::*;
+spec fn g(x: int, y: int) -> int;
+
+proof fn foo(s: Seq<int>, t: Seq<int>, i: int, x: int) {
+    ass$0ert(g(s[i], x * 2) == g(t[i], 2 * x));
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+spec fn g(x: int, y: int) -> int;
+
+proof fn foo(s: Seq<int>, t: Seq<int>, i: int, x: int) {
+    calc! {
+        (==)
+        g(s[i], x * 2); {}
+        g(t[i], x * 2); {}
+        g(t[i], 2 * x);
+    }
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn not_an_equality() {
+        check_assist_not_applicable(
+            intro_calc,
+            "
+use vstd::prelude::*;
+proof fn foo(a: int, b: int) {
+    ass$0ert(a <= b);
+}
+
+fn main() {}
+",
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    proof_plumber_api::vst_ext::vst_walk_expr,
+    AssistId, AssistKind,
+};
+use syntax::{
+    ast::{self, vst::*, AstNode, HasName},
+    T,
+};
+
+/// Proof action: introduce a `decreases` clause for a recursive function
+/// spec fn sum(n: nat) -> nat {
+///     if n == 0 { 0 } else { n + sum((n - 1) as nat) }
+/// }
+/// into
+/// spec fn sum(n: nat) -> nat
+///     decreases n
+/// {
+///     if n == 0 { 0 } else { n + sum((n - 1) as nat) }
+/// }
+///
+/// Triggers on the name of a recursive `spec` or `proof` function without `decreases`.
+/// The candidate measures come from the recursive calls: a parameter each call passes something
+/// smaller for (a constant less, a part of it, or a variable bound by matching on it), its length
+/// for a sequence, then the tuple of the parameters that change across calls.
+/// The first candidate Verus accepts is inserted, with all of them checked in a single run.
+pub(crate) fn intro_decreases(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on the name of the function
+    let _ = ctx.at_this_token(T![ident])?;
+    let name: ast::Name = ctx.find_node_at_offset()?;
+    let func = ast::Fn::cast(name.syntax().parent()?)?;
+
+    // lift CST into TOST node
+    let v_func = Fn::try_from(func.clone()).ok()?;
+    let fn_mode = v_func.fn_mode.as_ref()?;
+    if !(fn_mode.spec_token || fn_mode.proof_token) || v_func.signature_decreases.is_some() {
+        return None;
+    }
+
+    let calls = recursive_calls(&func)?;
+    if calls.is_empty() {
+        return None;
+    }
+    let candidates = candidate_measures(&v_func, &calls)?;
+    let measure = vst_rewriter_intro_decreases(ctx, &v_func, candidates)?;
+
+    // insert the clause right before the body, and pretty-print the whole function
+    let fn_range = func.syntax().text_range();
+    let body_offset: usize = (func.body()?.syntax().text_range().start() - fn_range.start()).into();
+    let mut fn_text = func.syntax().to_string();
+    fn_text.insert_str(body_offset, &format!("{measure}\n"));
+    let result = ctx.fmt(func.clone(), fn_text)?;
+
+    acc.add(
+        AssistId("intro_decreases", AssistKind::RefactorRewrite),
+        "Introduce decreases clause",
+        name.syntax().text_range(),
+        |edit| {
+            edit.replace(fn_range, result);
+        },
+    )
+}
+
+/// The first of `candidates` that makes `func` verify, as a `decreases` clause.
+pub(crate) fn vst_rewriter_intro_decreases(
+    ctx: &AssistContext<'_>,
+    func: &Fn,
+    candidates: Vec<Vec<Expr>>,
+) -> Option<SignatureDecreases> {
+    let clauses: Vec<SignatureDecreases> = candidates
+        .into_iter()
+        .map(|exprs| {
+            let mut clause = DecreasesClause::new();
+            clause.exprs = exprs;
+            SignatureDecreases::new(clause)
+        })
+        .collect();
+    let probes: Vec<Fn> = clauses
+        .iter()
+        .map(|clause| {
+            let mut probe = func.clone();
+            probe.signature_decreases = Some(Box::new(clause.clone()));
+            probe
+        })
+        .collect();
+    let results = ctx.try_verus_batch(&probes);
+    clauses
+        .into_iter()
+        .zip(results)
+        .find(|(_, result)| result.as_ref().map_or(false, |it| it.is_success))
+        .map(|(clause, _)| clause)
+}
+
+/// The calls of `func` to itself, lifted to VST, along with their CST.
+fn recursive_calls(func: &ast::Fn) -> Option<Vec<(CallExpr, ast::CallExpr)>> {
+    let name = func.name()?.text().to_string();
+    let calls = func
+        .body()?
+        .syntax()
+        .descendants()
+        .filter_map(ast::CallExpr::cast)
+        .filter(|call| match call.expr() {
+            Some(ast::Expr::PathExpr(path)) => path.syntax().text() == name.as_str(),
+            _ => false,
+        })
+        .filter_map(|call| Some((CallExpr::try_from(call.clone()).ok()?, call)))
+        .collect();
+    Some(calls)
+}
+
+fn candidate_measures(func: &Fn, calls: &[(CallExpr, ast::CallExpr)]) -> Option<Vec<Vec<Expr>>> {
+    let params = &func.param_list.as_ref()?.params;
+    let mut candidates: Vec<Vec<Expr>> = vec![];
+    let mut changing: Vec<Expr> = vec![];
+    for (idx, param) in params.iter().enumerate() {
+        let Some(Pat::IdentPat(pat)) = param.pat.as_deref() else { continue };
+        let name = pat.name.to_string().trim().to_owned();
+        let args: Vec<&Expr> =
+            calls.iter().filter_map(|(it, _)| it.arg_list.args.get(idx)).collect();
+        if args.len() != calls.len() {
+            return None;
+        }
+        let param_expr: Expr = PathExpr::new(Path::new(PathSegment::new(name_ref(&name)))).into();
+        if args.iter().any(|arg| arg.to_string().trim() != name) {
+            changing.push(param_expr.clone());
+        }
+        if calls.iter().zip(&args).all(|((_, call), arg)| shrinks(&name, arg, call)) {
+            candidates.push(vec![param_expr.clone()]);
+            let ty = param.ty.as_ref().map(|it| it.to_string()).unwrap_or_default();
+            if ty.trim_start().starts_with("Seq") || ty.trim_start().starts_with("Vec") {
+                let len = MethodCallExpr::new(param_expr, name_ref("len"), ArgList::new());
+                candidates.push(vec![len.into()]);
+            }
+        }
+    }
+    if changing.len() > 1 {
+        candidates.push(changing);
+    }
+    Some(candidates)
+}
+
+/// Whether `arg`, passed by `call` in place of the parameter `param`, is smaller than it:
+/// `param` less a constant, a part of `param`, or a variable bound by matching on `param`.
+fn shrinks(param: &str, arg: &Expr, call: &ast::CallExpr) -> bool {
+    let arg = peel(arg);
+    if arg.to_string().trim() == param {
+        return false;
+    }
+    if let Expr::BinExpr(it) = arg {
+        if it.op == BinaryOp::ArithOp(ast::ArithOp::Sub) {
+            return peel(&it.lhs).to_string().trim() == param
+                && matches!(peel(&it.rhs), Expr::Literal(_));
+        }
+    }
+    let mut mentions_param = false;
+    vst_walk_expr(arg, &mut |it| {
+        mentions_param |= matches!(&it, Expr::PathExpr(_)) && it.to_string().trim() == param;
+    });
+    mentions_param || bound_by_matching(param, arg, call)
+}
+
+/// Whether `arg` is a variable bound by the pattern of a `match` on `param` around `call`.
+fn bound_by_matching(param: &str, arg: &Expr, call: &ast::CallExpr) -> bool {
+    let Expr::PathExpr(_) = arg else { return false };
+    let arg = arg.to_string().trim().to_owned();
+    call.syntax().ancestors().filter_map(ast::MatchArm::cast).any(|arm| {
+        let scrutinee = arm
+            .syntax()
+            .ancestors()
+            .find_map(ast::MatchExpr::cast)
+            .and_then(|it| it.expr())
+            .map(|it| it.syntax().text().to_string());
+        scrutinee.as_deref() == Some(param)
+            && arm.pat().map_or(false, |pat| {
+                pat.syntax()
+                    .descendants()
+                    .filter_map(ast::IdentPat::cast)
+                    .filter_map(|it| it.name())
+                    .any(|it| it.text() == arg.as_str())
+            })
+    })
+}
+
+/// `expr` without the parentheses and casts around it
+fn peel(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::ParenExpr(it) => peel(&it.expr),
+        Expr::CastExpr(it) => peel(&it.expr),
+        _ => expr,
+    }
+}
+
+fn name_ref(name: &str) -> NameRef {
+    let mut name_ref = NameRef::new();
+    name_ref.ident_token = Some(name.into());
+    name_ref
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist, check_assist_not_applicable, check_assist_with_verifier},
+    };
+
+    #[test]
+    fn decreases_on_nat() {
+        check_assist(
+            intro_decreases,
+            "
+use vstd::prelude::*;
+spec fn su$0m(n: nat) -> nat {
+    if n == 0 {
+        0
+    } else {
+        n + sum((n - 1) as nat)
+    }
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+spec fn sum(n: nat) -> nat
+    decreases n,
+{
+    if n == 0 {
+        0
+    } else {
+        n + sum((n - 1) as nat)
+    }
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn decreases_on_sequence_length() {
+        check_assist_with_verifier(
+            intro_decreases,
+            // only the length of the sequence decreases
+            MockVerifier::needing("lemma_count", &["decreases s.len()"]),
+            "
+use vstd::prelude::*;
+spec fn count(s: Seq<int>, x: int) -> nat
+    decreases s.len(),
+{
+    if s.len() == 0 {
+        0
+    } else {
+        count(s.drop_first(), x) + if s[0] == x { 1nat } else { 0nat }
+    }
+}
+
+proof fn lem$0ma_count(s: Seq<int>, x: int)
+    ensures
+        count(s, x) <= s.len(),
+{
+    if s.len() > 0 {
+        lemma_count(s.drop_first(), x);
+    }
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+spec fn count(s: Seq<int>, x: int) -> nat
+    decreases s.len(),
+{
+    if s.len() == 0 {
+        0
+    } else {
+        count(s.drop_first(), x) + if s[0] == x { 1nat } else { 0nat }
+    }
+}
+
+proof fn lemma_count(s: Seq<int>, x: int)
+    ensures
+        count(s, x) <= s.len(),
+    decreases s.len(),
+{
+    if s.len() > 0 {
+        lemma_count(s.drop_first(), x);
+    }
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn not_recursive() {
+        check_assist_not_applicable(
+            intro_decreases,
+            "
+use vstd::prelude::*;
+spec fn dou$0ble(n: nat) -> nat {
+    n + n
+}
+
+fn main() {}
+",
+        );
+    }
+}
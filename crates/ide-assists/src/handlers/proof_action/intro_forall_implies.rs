@@ -2,6 +2,7 @@ use crate::{
     assist_context::{AssistContext, Assists},
     AssistId, AssistKind,
 };
+use itertools::Itertools;
 use syntax::{
     ast::{self, vst::*, AstNode, LogicOp},
     T,
@@ -9,6 +10,9 @@ use syntax::{
 
 /// Change `assert(forall || P ==> Q)` into
 /// `assert forall || P implies Q`
+///
+/// The rewrite works on the syntax of the assertion, so that the triggers of the quantifier are
+/// kept as written. Nested implications `P ==> Q ==> R` become `P && Q implies R`.
 pub(crate) fn intro_forall_implies(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
     // if the function name is not inside an assertExpr, return None
     let assert_expr: ast::AssertExpr = ctx.find_node_at_offset()?;
@@ -21,10 +25,8 @@ pub(crate) fn intro_forall_implies(acc: &mut Assists, ctx: &AssistContext<'_>) -
         return None;
     }
 
-    // now convert to vst nodes
-    let assert = AssertExpr::try_from(assert_expr.clone()).ok()?;
-    let result = vst_rewriter_intro_forall_implies(assert.clone())?;
-    let result = ctx.fmt(assert_expr.clone(), result.to_string())?;
+    let result = rewriter_intro_forall_implies(&assert_expr)?;
+    let result = ctx.fmt(assert_expr.clone(), result)?;
 
     acc.add(
         AssistId("intro_forall_implies", AssistKind::RefactorRewrite),
@@ -36,6 +38,84 @@ pub(crate) fn intro_forall_implies(acc: &mut Assists, ctx: &AssistContext<'_>) -
     )
 }
 
+fn rewriter_intro_forall_implies(assert_expr: &ast::AssertExpr) -> Option<String> {
+    let ast::Expr::ClosureExpr(closure) = assert_expr.expr()? else { return None };
+    let _ = closure.forall_token()?;
+    let body = closure.body()?;
+    let (hypotheses, conclusion) = split_implication(&body)?;
+
+    // everything up to the body, parameters and triggers included
+    let head_len = body.syntax().text_range().start() - closure.syntax().text_range().start();
+    let head = &closure.syntax().to_string()[..usize::from(head_len)];
+    let hypothesis = match hypotheses.as_slice() {
+        [hypothesis] => hypothesis.syntax().to_string(),
+        _ => hypotheses.iter().map(conjunct).join(" && "),
+    };
+    let proof = assert_expr.block_expr().map_or_else(|| "{}".to_owned(), |it| it.to_string());
+    Some(format!(
+        "assert {head}{hypothesis} implies {conclusion} by {proof}",
+        conclusion = conclusion.syntax()
+    ))
+}
+
+/// The hypotheses and the conclusion of the implication `expr`.
+/// The parser reads `P ==> Q ==> R` as `(P ==> Q) ==> R`, while Verus reads it as
+/// `P ==> (Q ==> R)`, so both shapes are flattened into `[P, Q]` and `R`.
+fn split_implication(expr: &ast::Expr) -> Option<(Vec<ast::Expr>, ast::Expr)> {
+    let ast::Expr::BinExpr(bin) = strip_parens(expr.clone()) else { return None };
+    if bin.op_kind()? != ast::BinaryOp::LogicOp(LogicOp::Imply) {
+        return None;
+    }
+    let mut hypotheses = vec![];
+    let mut lhs = bin.lhs()?;
+    while let ast::Expr::BinExpr(it) = &lhs {
+        if it.op_kind()? != ast::BinaryOp::LogicOp(LogicOp::Imply) {
+            break;
+        }
+        hypotheses.push(it.rhs()?);
+        lhs = it.lhs()?;
+    }
+    hypotheses.push(lhs);
+    hypotheses.reverse();
+
+    let conclusion = bin.rhs()?;
+    match split_implication(&conclusion) {
+        Some((more, conclusion)) => {
+            hypotheses.extend(more);
+            Some((hypotheses, conclusion))
+        }
+        None => Some((hypotheses, conclusion)),
+    }
+}
+
+fn strip_parens(expr: ast::Expr) -> ast::Expr {
+    if let ast::Expr::ParenExpr(it) = &expr {
+        if let Some(inner) = it.expr() {
+            return strip_parens(inner);
+        }
+    }
+    expr
+}
+
+/// The text of `expr` as an operand of `&&`.
+fn conjunct(expr: &ast::Expr) -> String {
+    let needs_parens = match expr {
+        ast::Expr::BinExpr(it) => matches!(
+            it.op_kind(),
+            Some(ast::BinaryOp::LogicOp(
+                LogicOp::Or | LogicOp::Imply | LogicOp::RevImply | LogicOp::Iff
+            ))
+        ),
+        ast::Expr::ClosureExpr(_) => true,
+        _ => false,
+    };
+    if needs_parens {
+        format!("({expr})")
+    } else {
+        expr.syntax().to_string()
+    }
+}
+
 pub(crate) fn vst_rewriter_intro_forall_implies(assert: AssertExpr) -> Option<AssertForallExpr> {
     // if assertion's expression's top level is not implication, return None
     let assert_forall_expr = match *assert.expr {
@@ -144,6 +224,48 @@ proof fn test_intro_forall_implies2() {
   assert forall|i: int, j: int| i == j implies f1(i, j) && f1(i, j) by {};
 
 }
+",
+        )
+    }
+
+    #[test]
+    fn intro_forall_implies_keeps_trigger() {
+        check_assist(
+            intro_forall_implies,
+            "
+spec fn f(x: int) -> bool;
+
+proof fn foo() {
+    assert(for$0all|i: int| #![trigger f(i)] 0 <= i ==> f(i));
+}
+",
+            "
+spec fn f(x: int) -> bool;
+
+proof fn foo() {
+    assert forall|i: int| #![trigger f(i)] 0 <= i implies f(i) by {};
+}
+",
+        )
+    }
+
+    #[test]
+    fn intro_forall_implies_nested() {
+        check_assist(
+            intro_forall_implies,
+            "
+spec fn f(x: int) -> bool;
+
+proof fn foo(n: int) {
+    assert(for$0all|i: int| 0 <= i ==> i < n ==> f(i));
+}
+",
+            "
+spec fn f(x: int) -> bool;
+
+proof fn foo(n: int) {
+    assert forall|i: int| 0 <= i && i < n implies f(i) by {};
+}
 ",
         )
     }
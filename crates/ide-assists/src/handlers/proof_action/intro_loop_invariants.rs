@@ -0,0 +1,377 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    AssistId, AssistKind,
+};
+use syntax::{
+    ast::{self, vst::*, AstNode, CmpOp, HasName},
+    T,
+};
+
+/// Proof action: introduce loop invariants from the postcondition
+/// Proposes invariants for the `while` loop under the cursor: each `ensures` clause of the
+/// enclosing function that does not mention its return value, and the bound the loop condition
+/// leaves on exit (`i <= n` for `while i < n`).
+/// Each candidate is verified on its own, in a single Verus run. The postconditions of the function
+/// are dropped for these runs, so that a candidate is kept whenever the loop verifies with it,
+/// even if the function needs more to meet its postconditions.
+pub(crate) fn intro_loop_invariants(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on `while` keyword
+    let _ = ctx.at_this_token(T![while])?;
+
+    let while_expr: ast::WhileExpr = ctx.find_node_at_offset()?;
+    let func: ast::Fn = ctx.find_node_at_offset()?;
+    let v_while = WhileExpr::try_from(while_expr.clone()).ok()?;
+    let v_func = Fn::try_from(func.clone()).ok()?;
+
+    let candidates = candidate_invariants(&func, &v_while);
+    let result = vst_rewriter_intro_loop_invariants(ctx, &v_func, v_while, candidates)?;
+    let result = ctx.fmt(while_expr.clone(), result.to_string())?;
+
+    acc.add(
+        AssistId("intro_loop_invariants", AssistKind::RefactorRewrite),
+        "Introduce Loop Invariants from Postcondition",
+        while_expr.syntax().text_range(),
+        |edit| {
+            edit.replace(while_expr.syntax().text_range(), result);
+        },
+    )
+}
+
+/// The `ensures` clauses of `func` that may hold throughout the loop, followed by the bound given
+/// by the loop condition, leaving out the invariants the loop already has.
+fn candidate_invariants(func: &ast::Fn, while_expr: &WhileExpr) -> Vec<Expr> {
+    // the return value is only bound after the loop
+    let ret_name = match func.ret_type().and_then(|it| it.pat()) {
+        Some(ast::Pat::IdentPat(it)) => it.name().map(|it| it.text().to_string()),
+        _ => None,
+    };
+    let ensures = func
+        .ensures_clause()
+        .into_iter()
+        .flat_map(|it| it.exprs())
+        .filter(|it| {
+            let Some(ret_name) = &ret_name else { return true };
+            !it.syntax()
+                .descendants()
+                .filter_map(ast::NameRef::cast)
+                .any(|name_ref| name_ref.text().as_str() == ret_name)
+        })
+        .filter_map(|it| Expr::try_from(it).ok());
+
+    let mut seen: Vec<String> =
+        invariants(while_expr).map(|it| it.to_string().trim().to_owned()).collect();
+    let mut candidates = vec![];
+    for candidate in ensures.chain(exit_bound(&while_expr.condition)) {
+        let text = candidate.to_string().trim().to_owned();
+        if !seen.contains(&text) {
+            seen.push(text);
+            candidates.push(candidate);
+        }
+    }
+    candidates
+}
+
+/// `i <= n` for `i < n`, and `i >= n` for `i > n`.
+fn exit_bound(condition: &Expr) -> Option<Expr> {
+    let Expr::BinExpr(cond) = condition else { return None };
+    let BinaryOp::CmpOp(CmpOp::Ord { ordering, strict: true }) = cond.op else { return None };
+    Some(
+        BinExpr::new(
+            *cond.lhs.clone(),
+            BinaryOp::CmpOp(CmpOp::Ord { ordering, strict: false }),
+            *cond.rhs.clone(),
+        )
+        .into(),
+    )
+}
+
+pub(crate) fn vst_rewriter_intro_loop_invariants(
+    ctx: &AssistContext<'_>,
+    func: &Fn,
+    while_expr: WhileExpr,
+    candidates: Vec<Expr>,
+) -> Option<WhileExpr> {
+    if candidates.is_empty() {
+        return None;
+    }
+
+    // Only check the loop: without the candidates that turn out to hold,
+    // the postconditions would fail regardless.
+    let mut loop_only = func.clone();
+    loop_only.ensures_clause = None;
+    let mut probes = vec![];
+    for candidate in &candidates {
+        let with_candidate = add_invariants(while_expr.clone(), vec![candidate.clone()]);
+        probes.push(replace_loop(&loop_only, &while_expr, with_candidate)?);
+    }
+    let results = ctx.try_verus_batch(&probes);
+
+    let holding: Vec<Expr> = candidates
+        .into_iter()
+        .zip(results)
+        .filter(|(_, result)| result.as_ref().map_or(false, |it| it.is_success))
+        .map(|(candidate, _)| candidate)
+        .collect();
+    if holding.is_empty() {
+        return None;
+    }
+    Some(add_invariants(while_expr, holding))
+}
+
+fn invariants(while_expr: &WhileExpr) -> impl Iterator<Item = &Expr> {
+    while_expr.loop_clauses.iter().flat_map(|it| match it {
+        LoopClause::InvariantClause(it) => it.exprs.as_slice(),
+        _ => &[][..],
+    })
+}
+
+fn add_invariants(mut while_expr: WhileExpr, exprs: Vec<Expr>) -> WhileExpr {
+    let clause = while_expr.loop_clauses.iter_mut().find_map(|it| match it {
+        LoopClause::InvariantClause(it) => Some(it),
+        _ => None,
+    });
+    match clause {
+        Some(clause) => clause.exprs.extend(exprs),
+        None => {
+            let mut clause = InvariantClause::new();
+            clause.exprs = exprs;
+            // `invariant` comes after `invariant_except_break`, and before the other clauses
+            let idx = while_expr
+                .loop_clauses
+                .iter()
+                .take_while(|it| matches!(it, LoopClause::InvariantExceptBreakClause(_)))
+                .count();
+            while_expr.loop_clauses.insert(idx, LoopClause::InvariantClause(Box::new(clause)));
+        }
+    }
+    while_expr
+}
+
+/// `func` with the loop `old` replaced by `new`. `None` if the loop is not found.
+fn replace_loop(func: &Fn, old: &WhileExpr, new: WhileExpr) -> Option<Fn> {
+    let mut func = func.clone();
+    let mut body: Expr = Expr::BlockExpr(func.body.clone()?);
+    if !replace_loop_in(&mut body, old.to_string().trim(), &new) {
+        return None;
+    }
+    match body {
+        Expr::BlockExpr(it) => func.body = Some(it),
+        _ => return None,
+    }
+    Some(func)
+}
+
+fn replace_loop_in(expr: &mut Expr, old: &str, new: &WhileExpr) -> bool {
+    match expr {
+        Expr::WhileExpr(it) if it.to_string().trim() == old => {
+            **it = new.clone();
+            true
+        }
+        Expr::WhileExpr(it) => replace_loop_in_block(&mut it.loop_body, old, new),
+        Expr::LoopExpr(it) => replace_loop_in_block(&mut it.loop_body, old, new),
+        Expr::BlockExpr(it) => replace_loop_in_block(it, old, new),
+        Expr::IfExpr(it) => {
+            replace_loop_in_block(&mut it.then_branch, old, new)
+                || match it.else_branch.as_deref_mut() {
+                    Some(ElseBranch::Block(it)) => replace_loop_in_block(it, old, new),
+                    Some(ElseBranch::IfExpr(it)) => {
+                        let mut else_if = Expr::IfExpr(it.clone());
+                        let replaced = replace_loop_in(&mut else_if, old, new);
+                        if let Expr::IfExpr(else_if) = else_if {
+                            *it = else_if;
+                        }
+                        replaced
+                    }
+                    None => false,
+                }
+        }
+        _ => false,
+    }
+}
+
+fn replace_loop_in_block(block: &mut BlockExpr, old: &str, new: &WhileExpr) -> bool {
+    let stmt_list = &mut *block.stmt_list;
+    let mut exprs = stmt_list
+        .statements
+        .iter_mut()
+        .filter_map(|it| match it {
+            Stmt::ExprStmt(it) => Some(&mut *it.expr),
+            _ => None,
+        })
+        .chain(stmt_list.tail_expr.as_deref_mut());
+    exprs.any(|it| replace_loop_in(it, old, new))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist_not_applicable, check_assist_with_verifier},
+    };
+
+    #[test]
+    fn intro_holding_invariants() {
+        check_assist_with_verifier(
+            intro_loop_invariants,
+            MockVerifier::verified(),
+            "
+use vstd::prelude::*;
+fn count(n: u64) -> (r: u64)
+    requires
+        n > 0,
+    ensures
+        n > 0,
+        r == n,
+{
+    let mut i: u64 = 0;
+    wh$0ile i < n
+    {
+        i = i + 1;
+    }
+    i
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+fn count(n: u64) -> (r: u64)
+    requires
+        n > 0,
+    ensures
+        n > 0,
+        r == n,
+{
+    let mut i: u64 = 0;
+    while i < n
+        invariant
+            n > 0,
+            i <= n,
+    {
+        i = i + 1;
+    }
+    i
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn drop_failing_invariants() {
+        check_assist_with_verifier(
+            intro_loop_invariants,
+            MockVerifier::failing(&["v.len() == i, v.len() == 0"]),
+            "
+use vstd::prelude::*;
+fn fill(v: &mut Vec<u64>, n: usize)
+    requires
+        old(v).len() == 0,
+    ensures
+        v.len() == 0,
+{
+    let mut i: usize = 0;
+    wh$0ile i < n
+        invariant
+            v.len() == i,
+    {
+        v.push(0);
+        i = i + 1;
+    }
+    v.clear();
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+fn fill(v: &mut Vec<u64>, n: usize)
+    requires
+        old(v).len() == 0,
+    ensures
+        v.len() == 0,
+{
+    let mut i: usize = 0;
+    while i < n
+        invariant
+            v.len() == i,
+            i <= n,
+    {
+        v.push(0);
+        i = i + 1;
+    }
+    v.clear();
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn loop_failing_without_invariants() {
+        check_assist_with_verifier(
+            intro_loop_invariants,
+            MockVerifier::needing("walk", &["i <= n"]),
+            "
+use vstd::prelude::*;
+fn walk(n: u64) -> (r: u64)
+    requires
+        n > 0,
+    ensures
+        n > 0,
+        r == n,
+{
+    let mut i: u64 = 0;
+    wh$0ile i < n
+    {
+        i = i + 1;
+    }
+    i
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+fn walk(n: u64) -> (r: u64)
+    requires
+        n > 0,
+    ensures
+        n > 0,
+        r == n,
+{
+    let mut i: u64 = 0;
+    while i < n
+        invariant
+            i <= n,
+    {
+        i = i + 1;
+    }
+    i
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn no_candidate() {
+        check_assist_not_applicable(
+            intro_loop_invariants,
+            "
+use vstd::prelude::*;
+fn spin(b: bool) {
+    wh$0ile b
+    {
+    }
+}
+
+fn main() {}
+",
+        );
+    }
+}
@@ -53,7 +53,13 @@ pub(crate) fn vst_rewriter_intro_match(
     let en = ctx.type_of_expr_enum(enum_expr_inside_assertion)?;
     let mut match_arms: Vec<MatchArm> = vec![];
     for variant in &en.variant_list.variants {
-        let vst_pat = Literal::new(format!("{}::{}(..)", en.name, variant.name));
+        // unit variants take no pattern for their fields
+        let fields = match variant.field_list.as_deref() {
+            None => "",
+            Some(FieldList::RecordFieldList(_)) => " { .. }",
+            Some(FieldList::TupleFieldList(_)) => "(..)",
+        };
+        let vst_pat = Literal::new(format!("{}::{}{}", en.name, variant.name, fields));
         let vst_pat = LiteralPat::new(vst_pat);
         let arm = MatchArm::new(vst_pat.into(), assert.clone());
         match_arms.push(arm);
@@ -106,15 +112,16 @@ pub(crate) fn vst_rewriter_intro_match(
 
 #[cfg(test)]
 mod tests {
-    use crate::tests::check_assist;
+    use crate::{proof_plumber_api::verifier::MockVerifier, tests::check_assist_with_verifier};
 
     use super::*;
 
     // TEST1
     #[test]
     fn intro_match1() {
-        check_assist(
+        check_assist_with_verifier(
             intro_match,
+            MockVerifier::failing(&["assert(is_good_move(m))"]),
             // before
             r#"
 use vstd::prelude::*;
@@ -166,8 +173,9 @@ fn main() {}
     // TEST2
     #[test]
     fn intro_match2() {
-        check_assist(
+        check_assist_with_verifier(
             intro_match,
+            MockVerifier::failing(&["assert(is_good_move(m, 100))"]),
             // Before
             r#"
 use vstd::prelude::*;
@@ -218,8 +226,18 @@ fn main() {}
 
     #[test]
     fn intro_match3() {
-        check_assist(
+        // Only the `Move` case fails, the others are ruled out by the precondition.
+        let verifier = MockVerifier::new(|item| {
+            let item: String = item.chars().filter(|c| !c.is_whitespace()).collect();
+            if item.contains("Message::Move{..}") {
+                vec!["assert(message_well_formed(new_msg))".to_owned()]
+            } else {
+                Vec::new()
+            }
+        });
+        check_assist_with_verifier(
             intro_match,
+            verifier,
             r#"
 use vstd::prelude::*;
 #[derive(PartialEq, Eq, Clone)] 
@@ -279,11 +297,67 @@ fn update_msg(msg: Message)
   };
 
   match new_msg {
-        Message::Move(..) => assert(message_well_formed(new_msg)),
+        Message::Move { .. } => assert(message_well_formed(new_msg)),
         _ => {},
     };
 }
 fn main() {}
+"#,
+        );
+    }
+
+    #[test]
+    fn intro_match_mixed_variants() {
+        check_assist_with_verifier(
+            intro_match,
+            MockVerifier::failing(&["assert(is_small(s))"]),
+            r#"
+use vstd::prelude::*;
+enum Shape {
+    Empty,
+    Circle(u32),
+    Rect { w: u32, h: u32 },
+}
+
+spec fn is_small(s: Shape) -> bool {
+    match s {
+        Shape::Empty => true,
+        Shape::Circle(r) => r < 10,
+        Shape::Rect { w, h } => w < 10 && h < 10,
+    }
+}
+
+proof fn small_shape(s: Shape)
+{
+    ass$0ert(is_small(s));
+}
+fn main() {}
+"#,
+            r#"
+use vstd::prelude::*;
+enum Shape {
+    Empty,
+    Circle(u32),
+    Rect { w: u32, h: u32 },
+}
+
+spec fn is_small(s: Shape) -> bool {
+    match s {
+        Shape::Empty => true,
+        Shape::Circle(r) => r < 10,
+        Shape::Rect { w, h } => w < 10 && h < 10,
+    }
+}
+
+proof fn small_shape(s: Shape)
+{
+    match s {
+        Shape::Empty => assert(is_small(s)),
+        Shape::Circle(..) => assert(is_small(s)),
+        Shape::Rect { .. } => assert(is_small(s)),
+    };
+}
+fn main() {}
 "#,
         );
     }
@@ -0,0 +1,186 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    proof_plumber_api::vst_ext::vst_subst_expr,
+    AssistId, AssistKind,
+};
+use hir::PathResolution;
+use ide_db::FxHashMap;
+use syntax::{
+    ast::{self, vst::*, AstNode},
+    T,
+};
+
+/// Proof action: add a failing assertion to the preconditions of its function
+/// proof fn foo(x: nat, y: nat) {
+///     let z = y + 1;
+///     assert(x * z <= 100);
+/// }
+/// into
+/// proof fn foo(x: nat, y: nat)
+///     requires
+///         x * (y + 1) <= 100,
+/// {
+///     let z = y + 1;
+///     assert(x * z <= 100);
+/// }
+///
+/// The reverse of `intro_failing_requires`, for a `proof fn`. The local variables of the
+/// assertion are replaced by their definitions until only parameters are left, which fails on a
+/// variable that is mutable or bound by a pattern. Verus is run before offering the edit, as only
+/// a failing assertion is worth lifting, and only when the precondition makes it hold.
+pub(crate) fn lift_assert_to_requires(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "assert"
+    let _ = ctx.at_this_token(T![assert])?;
+
+    // retrieve the assertion of interest, along with its function
+    let expr: ast::AssertExpr = ctx.find_node_at_offset()?;
+    let func: ast::Fn = ctx.find_node_at_offset()?;
+
+    // lift CST into TOST node
+    let assert = AssertExpr::try_from(expr.clone()).ok()?;
+    let v_func = Fn::try_from(func.clone()).ok()?;
+    if !v_func.fn_mode.as_ref()?.proof_token {
+        return None;
+    }
+
+    let pred = in_terms_of_params(ctx, &expr.expr()?)?;
+    let lifted = vst_rewriter_lift_assert_to_requires(v_func.clone(), pred)?;
+
+    // the assertion before and after, in a single Verus run
+    let results = ctx.try_verus_batch(&[v_func, lifted.clone()]);
+    let [Some(before), Some(after)] = results.as_slice() else { return None };
+    if !before.is_failing(&assert) || after.is_failing(&assert) {
+        return None;
+    }
+    let result = ctx.fmt(func.clone(), lifted.to_string())?; // pretty-print
+
+    acc.add(
+        AssistId("lift_assert_to_requires", AssistKind::RefactorRewrite),
+        "Add assertion to the preconditions",
+        expr.syntax().text_range(),
+        |edit| {
+            edit.replace(func.syntax().text_range(), result);
+        },
+    )
+}
+
+pub(crate) fn vst_rewriter_lift_assert_to_requires(mut func: Fn, pred: Expr) -> Option<Fn> {
+    let requires = func.requires_clause.get_or_insert_with(|| Box::new(RequiresClause::new()));
+    // a precondition already there is not added twice
+    if requires.exprs.iter().any(|it| it.to_string().trim() == pred.to_string().trim()) {
+        return None;
+    }
+    requires.exprs.push(pred);
+    Some(func)
+}
+
+/// `expr`, with each local variable replaced by its initializer, until only the parameters of
+/// the function and of the quantifiers are left. `None` if a variable has no such definition.
+fn in_terms_of_params(ctx: &AssistContext<'_>, expr: &ast::Expr) -> Option<Expr> {
+    let mut subst = FxHashMap::default();
+    for path in expr.syntax().descendants().filter_map(ast::Path::cast) {
+        let Some(PathResolution::Local(local)) = ctx.sema.resolve_path(&path) else { continue };
+        let name = path.syntax().to_string();
+        if subst.contains_key(&name) {
+            continue;
+        }
+        // `self` is a parameter as well
+        let Some(pat) = local.primary_source(ctx.db()).into_ident_pat() else { continue };
+        let parent = pat.syntax().parent()?;
+        if ast::Param::can_cast(parent.kind()) {
+            continue;
+        }
+        let let_stmt = ast::LetStmt::cast(parent)?;
+        if pat.mut_token().is_some() {
+            return None;
+        }
+        subst.insert(name, in_terms_of_params(ctx, &let_stmt.initializer()?)?);
+    }
+    vst_subst_expr(Expr::try_from(expr.clone()).ok()?, &subst).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{
+            check_assist_not_applicable, check_assist_not_applicable_with_verifier,
+            check_assist_with_verifier,
+        },
+    };
+
+    #[test]
+    fn lift_with_local() {
+        check_assist_with_verifier(
+            lift_assert_to_requires,
+            // the assertion holds once it is required
+            MockVerifier::new(|item| {
+                let item: String = item.chars().filter(|c| !c.is_whitespace()).collect();
+                if item.contains("x*(y+1)<=100") {
+                    vec![]
+                } else {
+                    vec!["assert(x * z <= 100)".to_owned()]
+                }
+            }),
+            "
+use vstd::prelude::*;
+proof fn foo(x: nat, y: nat)
+    requires
+        x <= 10,
+{
+    let z = y + 1;
+    ass$0ert(x * z <= 100);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn foo(x: nat, y: nat)
+    requires
+        x <= 10,
+        x * (y + 1) <= 100,
+{
+    let z = y + 1;
+    assert(x * z <= 100);
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn mutable_local() {
+        check_assist_not_applicable_with_verifier(
+            lift_assert_to_requires,
+            MockVerifier::failing(&["assert(x * z <= 100)"]),
+            "
+use vstd::prelude::*;
+proof fn foo(x: nat, y: nat) {
+    let mut z = y;
+    z = z + 1;
+    ass$0ert(x * z <= 100);
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn verified_assertion() {
+        check_assist_not_applicable(
+            lift_assert_to_requires,
+            "
+use vstd::prelude::*;
+proof fn foo(x: nat) {
+    ass$0ert(x + 1 > x);
+}
+
+fn main() {}
+",
+        );
+    }
+}
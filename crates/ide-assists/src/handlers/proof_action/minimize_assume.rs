@@ -0,0 +1,394 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    AssistId, AssistKind,
+};
+use ide_db::FxHashSet;
+use itertools::Itertools;
+use syntax::{
+    ast::{self, vst::*, AstNode, HasName},
+    TextSize, T,
+};
+
+/// Proof action: replace an assumption with a single fact the proof is missing
+/// proof fn foo(x: int, y: int) {
+///     assume(false);
+///     assert(x + y > 0 && x > 0);
+/// }
+/// into
+/// proof fn foo(x: int, y: int) {
+///     assume(x + y > 0);
+///     assert(x + y > 0 && x > 0);
+/// }
+///
+/// The candidates are the conjuncts of the obligations around the assumption: the assertion whose
+/// `by` block it is in, the assertions after it, and the postconditions. Verus is run on the
+/// function without the assumption, to only keep the assertions failing there, and on each
+/// candidate in place of the assumption. Of the candidates that make the function verify, only the
+/// weakest is offered: the first that the others imply, as checked by another Verus run.
+pub(crate) fn minimize_assume(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "assume"
+    let _ = ctx.at_this_token(T![assume])?;
+
+    // retrieve the assumption of interest, along with its function
+    let expr: ast::AssumeExpr = ctx.find_node_at_offset()?;
+    let func: ast::Fn = ctx.find_node_at_offset()?;
+    let enclosing = expr
+        .syntax()
+        .ancestors()
+        .take_while(|it| !ast::Fn::can_cast(it.kind()))
+        .filter_map(ast::AssertExpr::cast)
+        .find(|it| it.by_token().is_some());
+
+    // lift CST into TOST node
+    let assume = AssumeExpr::try_from(expr.clone()).ok()?;
+    let v_func = Fn::try_from(func.clone()).ok()?;
+    let v_enclosing = match &enclosing {
+        Some(it) => Some(AssertExpr::try_from(it.clone()).ok()?),
+        None => None,
+    };
+
+    let candidates: Vec<(Expr, Option<AssertExpr>)> = obligations(&func, &expr, enclosing)
+        .into_iter()
+        .flat_map(|(obligation, source)| {
+            // Verus reports an assertion without its proof
+            let source = source
+                .and_then(|it| AssertExpr::try_from(it).ok())
+                .map(|it| AssertExpr::new(*it.expr));
+            conjuncts(obligation).into_iter().map(move |it| (it, source.clone()))
+        })
+        .filter_map(|(it, source)| Some((Expr::try_from(it).ok()?, source)))
+        .filter(|(it, _)| !matches!(it.to_string().trim(), "true" | "false"))
+        .filter(|(it, _)| it.to_string().trim() != assume.expr.to_string().trim())
+        .unique_by(|(it, _)| it.to_string().trim().to_owned())
+        .collect();
+    if candidates.is_empty() {
+        return None;
+    }
+
+    // the function without the assumption, then with each candidate, in a single Verus run
+    let without = vst_rewriter_replace_assume(&assume, ctx.vst_expr_from_text("true")?);
+    let mut probes =
+        vec![with_assume(ctx, &v_func, &assume, v_enclosing.as_ref(), without.into())?];
+    for (candidate, _) in &candidates {
+        let replaced = vst_rewriter_replace_assume(&assume, candidate.clone());
+        probes.push(with_assume(ctx, &v_func, &assume, v_enclosing.as_ref(), replaced.into())?);
+    }
+    let mut results = ctx.try_verus_batch(&probes).into_iter();
+    let without = results.next()??;
+    if without.is_success {
+        return None;
+    }
+
+    let successful: Vec<Expr> = candidates
+        .into_iter()
+        .zip(results)
+        .filter_map(|((candidate, source), result)| {
+            // an assertion that holds anyway is not what is missing
+            if source.map_or(false, |it| !without.is_failing(&it)) {
+                return None;
+            }
+            result?.is_success.then_some(candidate)
+        })
+        .collect();
+    let candidate = weakest(ctx, &v_func, &assume, v_enclosing.as_ref(), successful)?;
+    let replaced = vst_rewriter_replace_assume(&assume, candidate);
+    // pretty-print
+    let text = ctx.fmt(expr.clone(), replaced.to_string())?;
+    acc.add(
+        AssistId("minimize_assume", AssistKind::RefactorRewrite),
+        format!("Replace with `{}`", replaced.to_string().trim()),
+        expr.syntax().text_range(),
+        |edit| {
+            edit.replace(expr.syntax().text_range(), text);
+        },
+    )
+}
+
+/// The weakest of `candidates`: the first that the others imply in place of the assumption
+/// `assume`, or the first of them if there is no such candidate.
+fn weakest(
+    ctx: &AssistContext<'_>,
+    func: &Fn,
+    assume: &AssumeExpr,
+    enclosing: Option<&AssertExpr>,
+    mut candidates: Vec<Expr>,
+) -> Option<Expr> {
+    if candidates.len() <= 1 {
+        return candidates.pop();
+    }
+    let implications: Vec<AssertExpr> = (0..candidates.len())
+        .map(|idx| {
+            let others = candidates
+                .iter()
+                .enumerate()
+                .filter(|(it, _)| *it != idx)
+                .map(|(_, it)| format!("({})", it.to_string().trim()))
+                .join(" || ");
+            let implied = candidates[idx].to_string();
+            let implication = format!("({others}) ==> ({})", implied.trim());
+            Some(AssertExpr::new(ctx.vst_expr_from_text(&implication)?))
+        })
+        .collect::<Option<_>>()?;
+    let probes: Vec<Fn> = implications
+        .iter()
+        .map(|it| with_assume(ctx, func, assume, enclosing, it.clone().into()))
+        .collect::<Option<_>>()?;
+    let idx = implications
+        .iter()
+        .zip(ctx.try_verus_batch(&probes))
+        .position(|(it, result)| result.map_or(false, |result| !result.is_failing(it)))
+        .unwrap_or(0);
+    Some(candidates.swap_remove(idx))
+}
+
+pub(crate) fn vst_rewriter_replace_assume(assume: &AssumeExpr, pred: Expr) -> AssumeExpr {
+    let mut assume = assume.clone();
+    assume.expr = Box::new(pred);
+    assume
+}
+
+/// `func`, with `old` replaced by `new`. An assumption in the `by` block of `enclosing` is
+/// replaced along with the assertion.
+fn with_assume(
+    ctx: &AssistContext<'_>,
+    func: &Fn,
+    old: &AssumeExpr,
+    enclosing: Option<&AssertExpr>,
+    new: Stmt,
+) -> Option<Fn> {
+    let Some(assert) = enclosing else { return ctx.replace_statement(func, old.clone(), new) };
+    let old: Stmt = old.clone().into();
+    let mut new_assert = assert.clone();
+    for stmt in new_assert.block_expr.as_mut()?.stmt_list.statements.iter_mut() {
+        if stmt.to_string().trim() == old.to_string().trim() {
+            *stmt = new.clone();
+        }
+    }
+    ctx.replace_statement(func, assert.clone(), new_assert)
+}
+
+/// The obligations the assumption `assume` may stand for, along with the assertion stating each,
+/// if any. Those mentioning a variable that is not in scope at `assume` are left out.
+fn obligations(
+    func: &ast::Fn,
+    assume: &ast::AssumeExpr,
+    enclosing: Option<ast::AssertExpr>,
+) -> Vec<(ast::Expr, Option<ast::AssertExpr>)> {
+    let after = assume.syntax().text_range().end();
+    let mut obligations: Vec<(ast::Expr, Option<ast::AssertExpr>)> = vec![];
+    if let Some(assert) = enclosing {
+        obligations.extend(assert.expr().map(|it| (it, Some(assert))));
+    }
+    if let Some(body) = func.body() {
+        let asserts = body
+            .syntax()
+            .descendants()
+            .filter_map(ast::AssertExpr::cast)
+            .filter(|it| it.syntax().text_range().start() >= after);
+        obligations.extend(asserts.filter_map(|it| Some((it.expr()?, Some(it)))));
+    }
+    if let Some(ensures) = func.ensures_clause() {
+        obligations.extend(ensures.exprs().map(|it| (it, None)));
+    }
+
+    let mut out_of_scope = bound_after(func, after);
+    let ret_name = func.ret_type().and_then(|it| match it.pat()? {
+        ast::Pat::IdentPat(pat) => Some(pat.name()?.text().to_string()),
+        _ => None,
+    });
+    out_of_scope.extend(ret_name);
+    obligations
+        .into_iter()
+        .filter(|(it, _)| {
+            !it.syntax()
+                .descendants()
+                .filter_map(ast::NameRef::cast)
+                .any(|it| out_of_scope.contains(it.text().as_str()))
+        })
+        .collect()
+}
+
+/// The names of the local variables of `func` bound after `offset`.
+fn bound_after(func: &ast::Fn, offset: TextSize) -> FxHashSet<String> {
+    let Some(body) = func.body() else { return FxHashSet::default() };
+    body.syntax()
+        .descendants()
+        .filter_map(ast::IdentPat::cast)
+        .filter(|it| it.syntax().text_range().start() >= offset)
+        // variables of quantifiers are only bound in their body
+        .filter(|it| !it.syntax().ancestors().any(|it| ast::ClosureExpr::can_cast(it.kind())))
+        .filter_map(|it| Some(it.name()?.text().to_string()))
+        .collect()
+}
+
+fn conjuncts(expr: ast::Expr) -> Vec<ast::Expr> {
+    match &expr {
+        ast::Expr::BinExpr(bin)
+            if bin.op_kind() == Some(ast::BinaryOp::LogicOp(ast::LogicOp::And)) =>
+        {
+            let lhs = bin.lhs().map(conjuncts).unwrap_or_default();
+            let rhs = bin.rhs().map(conjuncts).unwrap_or_default();
+            lhs.into_iter().chain(rhs).collect()
+        }
+        ast::Expr::ParenExpr(paren) => match paren.expr() {
+            Some(inner @ ast::Expr::BinExpr(_)) => conjuncts(inner),
+            _ => vec![expr],
+        },
+        _ => vec![expr],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist_not_applicable, check_assist_with_verifier},
+    };
+
+    #[test]
+    fn missing_conjunct() {
+        check_assist_with_verifier(
+            minimize_assume,
+            // only `x + y > 0` is missing, `x > 0` is required
+            MockVerifier::new(|item| {
+                let item: String = item.chars().filter(|c| !c.is_whitespace()).collect();
+                if item.contains("assume(x+y>0)") || item.contains("assume(false)") {
+                    vec![]
+                } else {
+                    vec!["assert(x + y > 0 && x > 0)".to_owned()]
+                }
+            }),
+            "
+use vstd::prelude::*;
+proof fn foo(x: int, y: int)
+    requires
+        x > 0,
+{
+    ass$0ume(false);
+    assert(x + y > 0 && x > 0);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn foo(x: int, y: int)
+    requires
+        x > 0,
+{
+    assume(x + y > 0);
+    assert(x + y > 0 && x > 0);
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn in_by_block() {
+        check_assist_with_verifier(
+            minimize_assume,
+            MockVerifier::new(|item| {
+                let item: String = item.chars().filter(|c| !c.is_whitespace()).collect();
+                if item.contains("assume(f(x))") || item.contains("assume(false)") {
+                    vec![]
+                } else {
+                    vec!["assert(f(x) && g(x))".to_owned()]
+                }
+            }),
+            "
+use vstd::prelude::*;
+spec fn f(x: int) -> bool;
+spec fn g(x: int) -> bool;
+
+proof fn foo(x: int)
+    requires
+        g(x),
+{
+    assert(f(x) && g(x)) by {
+        ass$0ume(false);
+    };
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+spec fn f(x: int) -> bool;
+spec fn g(x: int) -> bool;
+
+proof fn foo(x: int)
+    requires
+        g(x),
+{
+    assert(f(x) && g(x)) by {
+        assume(f(x));
+    };
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn weakest_candidate() {
+        check_assist_with_verifier(
+            minimize_assume,
+            // either candidate makes the function verify, and they imply each other
+            MockVerifier::new(|item| {
+                let item: String = item.chars().filter(|c| !c.is_whitespace()).collect();
+                if item.contains("assume(true)") {
+                    vec!["assert(x > 0)".to_owned()]
+                } else {
+                    vec![]
+                }
+            }),
+            "
+use vstd::prelude::*;
+proof fn foo(x: int)
+    ensures
+        x >= 1,
+{
+    ass$0ume(false);
+    assert(x > 0);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn foo(x: int)
+    ensures
+        x >= 1,
+{
+    assume(x > 0);
+    assert(x > 0);
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn unneeded_assumption() {
+        check_assist_not_applicable(
+            minimize_assume,
+            "
+use vstd::prelude::*;
+proof fn foo(x: int)
+    requires
+        x > 0,
+{
+    ass$0ume(false);
+    assert(x > 0);
+}
+
+fn main() {}
+",
+        );
+    }
+}
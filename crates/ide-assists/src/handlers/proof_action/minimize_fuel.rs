@@ -0,0 +1,171 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    AssistId, AssistKind,
+};
+use syntax::{
+    ast::{self, vst::*, AstNode, HasArgList},
+    ted,
+};
+
+/// Proof action: lower the fuel of a `reveal_with_fuel` to what the proof needs
+/// reveal_with_fuel(fibo, 10);
+/// assert(fibo(3) == 2);
+/// into
+/// reveal_with_fuel(fibo, 3);
+/// assert(fibo(3) == 2);
+///
+/// Triggered on `reveal_with_fuel`. Before offering the action, Verus checks that the function
+/// still verifies with one less fuel. Once it is picked, the smallest fuel that verifies is
+/// searched for by bisection, as more fuel never makes a proof fail.
+pub(crate) fn minimize_reveal_fuel(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "reveal_with_fuel"
+    let call: ast::CallExpr = ctx.find_node_at_offset()?;
+    let callee = call.expr()?;
+    if callee.syntax().text().to_string().trim() != "reveal_with_fuel"
+        || !callee.syntax().text_range().contains_range(ctx.selection_trimmed())
+    {
+        return None;
+    }
+    let func: ast::Fn = ctx.find_node_at_offset()?;
+
+    // the fuel has to be a literal
+    let args: Vec<ast::Expr> = call.arg_list()?.args().collect();
+    let [_, ast::Expr::Literal(fuel)] = args.as_slice() else { return None };
+    let fuel_value: u64 = fuel.syntax().text().to_string().parse().ok()?;
+    if fuel_value <= 1 {
+        return None;
+    }
+
+    // only worth offering when less fuel is enough
+    if !ctx.try_verus(&vst_rewriter_set_fuel(&func, fuel, fuel_value - 1)?)?.is_success {
+        return None;
+    }
+
+    acc.add(
+        AssistId("minimize_reveal_fuel", AssistKind::RefactorRewrite),
+        "Minimize fuel of this reveal",
+        fuel.syntax().text_range(),
+        |edit| {
+            // Run Verus repeatedly only when the user explicitly wants it
+            let (mut lo, mut hi) = (1, fuel_value - 1);
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                let verifies = vst_rewriter_set_fuel(&func, fuel, mid)
+                    .and_then(|it| ctx.try_verus(&it))
+                    .map_or(false, |it| it.is_success);
+                if verifies {
+                    hi = mid;
+                } else {
+                    lo = mid + 1;
+                }
+            }
+            edit.replace(fuel.syntax().text_range(), hi.to_string());
+        },
+    )
+}
+
+/// `func`, with `fuel_value` as its literal `fuel`.
+pub(crate) fn vst_rewriter_set_fuel(
+    func: &ast::Fn,
+    fuel: &ast::Literal,
+    fuel_value: u64,
+) -> Option<Fn> {
+    let func = func.clone_for_update();
+    let fuel = func
+        .syntax()
+        .descendants()
+        .filter_map(ast::Literal::cast)
+        .find(|it| it.syntax().text_range() == fuel.syntax().text_range())?;
+    let new_fuel = ast::make::expr_literal(&fuel_value.to_string());
+    ted::replace(fuel.syntax(), new_fuel.syntax().clone_for_update());
+    Fn::try_from(func).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist_not_applicable_with_verifier, check_assist_with_verifier},
+    };
+
+    /// `fibo(3)` needs a fuel of at least 3.
+    fn fuel_verifier() -> MockVerifier {
+        MockVerifier::new(|item| {
+            let item: String = item.chars().filter(|c| !c.is_whitespace()).collect();
+            let Some((_, rest)) = item.split_once("reveal_with_fuel(fibo,") else {
+                return Vec::new();
+            };
+            let fuel: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+            if fuel.parse::<u64>().map_or(false, |it| it >= 3) {
+                Vec::new()
+            } else {
+                vec!["assert(fibo(3) == 2)".to_owned()]
+            }
+        })
+    }
+
+    #[test]
+    fn minimize_fuel() {
+        check_assist_with_verifier(
+            minimize_reveal_fuel,
+            fuel_verifier(),
+            "
+use vstd::prelude::*;
+#[verifier::opaque]
+spec fn fibo(n: nat) -> nat
+    decreases n,
+{
+    if n <= 1 { n } else { fibo((n - 2) as nat) + fibo((n - 1) as nat) }
+}
+
+proof fn fibo_3() {
+    reveal_with$0_fuel(fibo, 10);
+    assert(fibo(3) == 2);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+#[verifier::opaque]
+spec fn fibo(n: nat) -> nat
+    decreases n,
+{
+    if n <= 1 { n } else { fibo((n - 2) as nat) + fibo((n - 1) as nat) }
+}
+
+proof fn fibo_3() {
+    reveal_with_fuel(fibo, 3);
+    assert(fibo(3) == 2);
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn minimal_fuel() {
+        check_assist_not_applicable_with_verifier(
+            minimize_reveal_fuel,
+            fuel_verifier(),
+            "
+use vstd::prelude::*;
+#[verifier::opaque]
+spec fn fibo(n: nat) -> nat
+    decreases n,
+{
+    if n <= 1 { n } else { fibo((n - 2) as nat) + fibo((n - 1) as nat) }
+}
+
+proof fn fibo_3() {
+    reveal_with$0_fuel(fibo, 3);
+    assert(fibo(3) == 2);
+}
+
+fn main() {}
+",
+        );
+    }
+}
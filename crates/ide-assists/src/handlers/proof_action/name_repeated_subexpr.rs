@@ -0,0 +1,257 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    AssistId, AssistKind,
+};
+use ide_db::{
+    syntax_helpers::node_ext::{enclosing_proof_block, is_in_proof_block},
+    FxHashMap, FxHashSet,
+};
+use syntax::{
+    ast::{self, AstNode, HasName},
+    SyntaxNode, TextRange, T,
+};
+
+/// Proof action: name a subexpression repeated in an assertion
+/// assert(x * y * z <= x * y * z + x * y * z);
+/// into
+/// let ghost tmp = x * y * z;
+/// assert(tmp <= tmp + tmp);
+///
+/// Offered for each subexpression occurring at least three times in the assertion, including its
+/// `by` block, unless it is part of a larger one occurring as often. A ghost variable is equal to
+/// its definition for Verus, so the proof stays as it is, while Z3 sees the term only once.
+/// Subexpressions under a quantifier, or mentioning a variable bound inside the assertion, are
+/// left alone. In proof code, including `proof { .. }` blocks, the variable is not `ghost`.
+pub(crate) fn name_repeated_subexpr(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "assert"
+    let _ = ctx.at_this_token(T![assert])?;
+
+    // retrieve the assertion of interest, which has to be a statement; a `proof { .. }` block
+    // parses as a record expression, whose fields are its statements without their `;`
+    let expr: ast::AssertExpr = ctx.find_node_at_offset()?;
+    if !ast::ExprStmt::can_cast(expr.syntax().parent()?.kind())
+        && enclosing_proof_block(expr.syntax()).is_none()
+    {
+        return None;
+    }
+    let func: ast::Fn = ctx.find_node_at_offset()?;
+
+    let name = fresh_name(&func);
+    let let_token = if is_in_proof_block(expr.syntax()) { "let" } else { "let ghost" };
+    for (term, ranges) in repeated_subexprs(&expr) {
+        // the `;` of the assertion is left in place
+        let stmt_range = expr.syntax().text_range();
+        let mut text = expr.syntax().to_string();
+        for range in ranges.iter().rev() {
+            let range: std::ops::Range<usize> = range.checked_sub(stmt_range.start())?.into();
+            text.replace_range(range, &name);
+        }
+        let result = format!("{let_token} {name} = {term};\n{text}");
+        let Some(result) = ctx.fmt(expr.clone(), result) else { continue }; // pretty-print
+        acc.add(
+            AssistId("name_repeated_subexpr", AssistKind::RefactorExtract),
+            format!("Introduce `{name}` for `{term}`"),
+            expr.syntax().text_range(),
+            |edit| {
+                edit.replace(stmt_range, result);
+            },
+        );
+    }
+    Some(())
+}
+
+/// The subexpressions of `assert` occurring at least three times, along with the ranges of their
+/// occurrences, in the order they first occur.
+fn repeated_subexprs(assert: &ast::AssertExpr) -> Vec<(String, Vec<TextRange>)> {
+    let bound = bound_names(assert.syntax());
+    let mut order: Vec<(String, String)> = vec![];
+    let mut occurrences: FxHashMap<String, Vec<TextRange>> = FxHashMap::default();
+    for expr in assert.syntax().descendants().filter_map(ast::Expr::cast) {
+        if matches!(
+            expr,
+            ast::Expr::PathExpr(_)
+                | ast::Expr::Literal(_)
+                | ast::Expr::ParenExpr(_)
+                | ast::Expr::BlockExpr(_)
+                | ast::Expr::AssertExpr(_)
+        ) || is_under_quantifier(expr.syntax(), assert.syntax())
+            || mentions(expr.syntax(), &bound)
+        {
+            continue;
+        }
+        // a parenthesized occurrence is replaced along with its parentheses
+        let node = match expr.syntax().parent().and_then(ast::ParenExpr::cast) {
+            Some(paren) => paren.syntax().clone(),
+            None => expr.syntax().clone(),
+        };
+        let key: String = expr.syntax().to_string().split_whitespace().collect();
+        if !occurrences.contains_key(&key) {
+            order.push((key.clone(), expr.syntax().to_string()));
+        }
+        occurrences.entry(key).or_default().push(node.text_range());
+    }
+
+    let repeated: Vec<(String, Vec<TextRange>)> = order
+        .into_iter()
+        .filter_map(|(key, term)| {
+            let ranges = occurrences.remove(&key)?;
+            (ranges.len() >= 3).then_some((term, ranges))
+        })
+        .collect();
+    // a subexpression only occurring inside a larger repeated one is named along with it
+    repeated
+        .iter()
+        .filter(|(_, ranges)| {
+            !repeated.iter().any(|(_, larger)| {
+                larger != ranges
+                    && larger.len() == ranges.len()
+                    && ranges.iter().all(|it| larger.iter().any(|l| l.contains_range(*it)))
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+fn is_under_quantifier(node: &SyntaxNode, assert: &SyntaxNode) -> bool {
+    node.ancestors()
+        .take_while(|it| it != assert)
+        .filter_map(ast::ClosureExpr::cast)
+        .any(|it| it.forall_token().is_some() || it.exists_token().is_some())
+}
+
+/// The names of the variables bound inside `node`.
+fn bound_names(node: &SyntaxNode) -> FxHashSet<String> {
+    node.descendants()
+        .filter_map(ast::IdentPat::cast)
+        .filter_map(|it| Some(it.name()?.text().to_string()))
+        .collect()
+}
+
+fn mentions(node: &SyntaxNode, names: &FxHashSet<String>) -> bool {
+    node.descendants().filter_map(ast::NameRef::cast).any(|it| names.contains(it.text().as_str()))
+}
+
+/// A name for the new variable that `func` does not use yet.
+fn fresh_name(func: &ast::Fn) -> String {
+    let used: FxHashSet<String> = func
+        .syntax()
+        .descendants()
+        .filter_map(|it| match ast::Name::cast(it.clone()) {
+            Some(name) => Some(name.text().to_string()),
+            None => Some(ast::NameRef::cast(it)?.text().to_string()),
+        })
+        .collect();
+    std::iter::once("tmp".to_owned())
+        .chain((1..).map(|idx| format!("tmp{idx}")))
+        .find(|it| !used.contains(it))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    #[test]
+    fn name_product() {
+        check_assist(
+            name_repeated_subexpr,
+            "
+use vstd::prelude::*;
+fn foo(x: u64, y: u64, z: u64) {
+    ass$0ert(x * y * z <= x * y * z + x * y * z);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+fn foo(x: u64, y: u64, z: u64) {
+    let ghost tmp = x * y * z;
+    assert(tmp <= tmp + tmp);
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn name_in_proof_block() {
+        check_assist(
+            name_repeated_subexpr,
+            "
+use vstd::prelude::*;
+spec fn f(x: int) -> int;
+
+proof fn foo(a: int, tmp: int) {
+    ass$0ert(f(a + 1) >= 0) by {
+        assume(f(a + 1) > 0);
+        assert(f(a + 1) > 0);
+    };
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+spec fn f(x: int) -> int;
+
+proof fn foo(a: int, tmp: int) {
+    let tmp1 = f(a + 1);
+    assert(tmp1 >= 0) by {
+        assume(tmp1 > 0);
+        assert(tmp1 > 0);
+    };
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn name_in_exec_proof_block() {
+        check_assist(
+            name_repeated_subexpr,
+            "
+use vstd::prelude::*;
+fn foo(x: u64, y: u64) {
+    proof {
+        ass$0ert((x + y) * (x + y) >= (x + y));
+    }
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+fn foo(x: u64, y: u64) {
+    proof {
+        let tmp = x + y;
+        assert(tmp * tmp >= tmp);
+    }
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn under_quantifier() {
+        check_assist_not_applicable(
+            name_repeated_subexpr,
+            "
+use vstd::prelude::*;
+spec fn f(x: int) -> int;
+
+proof fn foo() {
+    ass$0ert(forall|i: int| f(i) + f(i) == 2 * f(i));
+}
+
+fn main() {}
+",
+        );
+    }
+}
@@ -0,0 +1,380 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    AssistId, AssistKind,
+};
+use ide_db::{
+    base_db::FileId, defs::Definition, syntax_helpers::node_ext::is_in_spec_code, FxHashMap,
+    FxHashSet,
+};
+use itertools::Itertools;
+use syntax::{
+    algo::find_node_at_range,
+    ast::{self, AstNode, HasArgList, HasName},
+    Direction, SyntaxKind, SyntaxNode, TextRange, TextSize, T,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Propagation {
+    Assert,
+    Forward,
+}
+
+/// Proof action: propagate the preconditions of a proof function to its callers
+/// proof fn lemma(x: int)
+///     requires
+///         x > 0,
+/// ...
+/// fn caller(y: int) {
+///     lemma(y + 1);
+/// }
+/// into
+/// fn caller(y: int) {
+///     assert(y + 1 > 0);
+///     lemma(y + 1);
+/// }
+///
+/// Triggered on `requires`. Each precondition is instantiated with the arguments of each call of
+/// the function in the workspace, then either asserted before the call, or forwarded to the
+/// `requires` clause of the caller. An instance mentioning local variables of the caller cannot
+/// be forwarded, so it is asserted instead. The instances the caller already states are skipped.
+pub(crate) fn propagate_requires(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "requires"
+    let _ = ctx.at_this_token(T![requires])?;
+    let func: ast::Fn = ctx.find_node_at_offset()?;
+    if func.fn_mode()?.proof_token().is_none() {
+        return None;
+    }
+    let requires = func.requires_clause()?;
+    if requires.exprs().next().is_none() {
+        return None;
+    }
+    let params: Vec<String> = func
+        .param_list()?
+        .params()
+        .map(|param| match param.pat()? {
+            ast::Pat::IdentPat(it) => Some(it.name()?.text().to_string()),
+            _ => None,
+        })
+        .collect::<Option<_>>()?;
+
+    let def = Definition::Function(ctx.sema.to_def(&func)?);
+    let usages = def.usages(&ctx.sema).all();
+    if usages.references.values().all(|it| it.is_empty()) {
+        return None;
+    }
+
+    for (propagation, label) in [
+        (Propagation::Assert, "Assert the preconditions at each call site"),
+        (Propagation::Forward, "Forward the preconditions to the callers"),
+    ] {
+        acc.add(
+            AssistId("propagate_requires", AssistKind::RefactorRewrite),
+            label,
+            requires.syntax().text_range(),
+            |edit| {
+                for (file_id, references) in usages.clone() {
+                    edit.edit_file(file_id);
+                    let edits = call_site_edits(
+                        ctx,
+                        file_id,
+                        &func,
+                        &requires,
+                        &params,
+                        references.into_iter().map(|it| it.range),
+                        propagation,
+                    );
+                    for (offset, text) in edits {
+                        edit.insert(offset, text);
+                    }
+                }
+            },
+        );
+    }
+    Some(())
+}
+
+/// The insertions propagating the preconditions `requires` of `func` to its calls of
+/// `references` in the file `file_id`.
+fn call_site_edits(
+    ctx: &AssistContext<'_>,
+    file_id: FileId,
+    func: &ast::Fn,
+    requires: &ast::RequiresClause,
+    params: &[String],
+    references: impl Iterator<Item = TextRange>,
+    propagation: Propagation,
+) -> Vec<(TextSize, String)> {
+    let source_file = ctx.sema.parse(file_id);
+    let mut asserts: FxHashMap<TextSize, (String, Vec<String>)> = FxHashMap::default();
+    let mut forwarded: FxHashMap<TextSize, (ast::Fn, Vec<String>)> = FxHashMap::default();
+    for range in references {
+        let Some(call) = find_node_at_range::<ast::CallExpr>(source_file.syntax(), range) else {
+            continue;
+        };
+        if !call.expr().map_or(false, |it| it.syntax().text_range().contains_range(range))
+            || is_in_spec_code(call.syntax())
+        {
+            continue;
+        }
+        let Some(caller) = call.syntax().ancestors().find_map(ast::Fn::cast) else { continue };
+        if caller.syntax() == func.syntax() {
+            continue;
+        }
+        let args: Vec<ast::Expr> = call.arg_list().into_iter().flat_map(|it| it.args()).collect();
+        if args.len() != params.len() {
+            continue;
+        }
+        let locals = locals_of(&caller);
+        let forwardable = args.iter().all(|arg| {
+            !arg.syntax()
+                .descendants()
+                .filter_map(ast::NameRef::cast)
+                .any(|it| locals.contains(it.text().as_str()))
+        });
+        let Some(stmt) = statement_of(call.syntax()) else { continue };
+        let caller_text = normalize(&caller.syntax().to_string());
+        for pre in requires.exprs() {
+            let Some(instance) = instantiate(&pre, params, &args) else { continue };
+            if propagation == Propagation::Forward && forwardable {
+                let stated = caller.requires_clause().map_or(false, |it| {
+                    it.exprs().any(|it| normalize(&it.to_string()) == instance)
+                });
+                let entry = forwarded
+                    .entry(caller.syntax().text_range().start())
+                    .or_insert_with(|| (caller.clone(), vec![]));
+                if !stated {
+                    entry.1.push(instance);
+                }
+            } else if !caller_text.contains(&format!("assert({instance});")) {
+                let indent = indent_of(&stmt);
+                let entry =
+                    asserts.entry(stmt.text_range().start()).or_insert_with(|| (indent, vec![]));
+                entry.1.push(instance);
+            }
+        }
+    }
+
+    let mut edits = vec![];
+    for (offset, (indent, instances)) in asserts {
+        let text =
+            instances.into_iter().unique().map(|it| format!("assert({it});\n{indent}")).join("");
+        edits.push((offset, text));
+    }
+    for (caller, instances) in forwarded.into_values() {
+        let instances: Vec<String> = instances.into_iter().unique().collect();
+        if let Some(edit) = forward_edit(&caller, &instances) {
+            edits.push(edit);
+        }
+    }
+    edits
+}
+
+/// The insertion adding `instances` to the `requires` clause of `caller`, creating it if needed.
+fn forward_edit(caller: &ast::Fn, instances: &[String]) -> Option<(TextSize, String)> {
+    if instances.is_empty() {
+        return None;
+    }
+    let indent = indent_of(caller.syntax());
+    let exprs = instances.iter().map(|it| format!("\n{indent}        {it},")).join("");
+    if let Some(requires) = caller.requires_clause() {
+        let last = requires.syntax().last_token()?;
+        let text =
+            if last.kind() == T![,] { exprs } else { format!(",{}", &exprs[..exprs.len() - 1]) };
+        return Some((requires.syntax().text_range().end(), text));
+    }
+    let text = format!("\n{indent}    requires{exprs}");
+    let anchor = caller
+        .ret_type()
+        .map(|it| it.syntax().clone())
+        .or_else(|| caller.param_list().map(|it| it.syntax().clone()))?;
+    Some((anchor.text_range().end(), text))
+}
+
+/// `pre`, with the parameters `params` replaced by the arguments `args`.
+fn instantiate(pre: &ast::Expr, params: &[String], args: &[ast::Expr]) -> Option<String> {
+    let start = pre.syntax().text_range().start();
+    let mut text = pre.syntax().to_string();
+    let substs: Vec<(TextRange, String)> = pre
+        .syntax()
+        .descendants()
+        .filter_map(ast::PathExpr::cast)
+        .filter_map(|it| {
+            let name = it.path()?.as_single_name_ref()?;
+            let idx = params.iter().position(|param| *param == name.text().as_str())?;
+            Some((it.syntax().text_range(), paren(&args[idx])))
+        })
+        .collect();
+    for (range, arg) in substs.into_iter().rev() {
+        let range: std::ops::Range<usize> = range.checked_sub(start)?.into();
+        text.replace_range(range, &arg);
+    }
+    Some(normalize(&text))
+}
+
+/// The text of `expr`, parenthesized unless it is atomic.
+fn paren(expr: &ast::Expr) -> String {
+    match expr {
+        ast::Expr::PathExpr(_)
+        | ast::Expr::Literal(_)
+        | ast::Expr::ParenExpr(_)
+        | ast::Expr::CallExpr(_)
+        | ast::Expr::MethodCallExpr(_)
+        | ast::Expr::FieldExpr(_)
+        | ast::Expr::IndexExpr(_) => normalize(&expr.syntax().to_string()),
+        _ => format!("({})", normalize(&expr.syntax().to_string())),
+    }
+}
+
+/// The names of the variables bound in the body of `func`.
+fn locals_of(func: &ast::Fn) -> FxHashSet<String> {
+    func.body()
+        .into_iter()
+        .flat_map(|it| it.syntax().descendants().filter_map(ast::IdentPat::cast).collect_vec())
+        .filter_map(|it| Some(it.name()?.text().to_string()))
+        .collect()
+}
+
+/// The innermost statement containing `node`.
+fn statement_of(node: &SyntaxNode) -> Option<SyntaxNode> {
+    node.ancestors().find(|it| it.parent().map_or(false, |it| ast::StmtList::can_cast(it.kind())))
+}
+
+/// The indentation of the line `node` starts.
+fn indent_of(node: &SyntaxNode) -> String {
+    node.siblings_with_tokens(Direction::Prev)
+        .nth(1)
+        .filter(|it| it.kind() == SyntaxKind::WHITESPACE)
+        .map(|it| it.to_string().rsplit('\n').next().unwrap_or_default().to_owned())
+        .unwrap_or_default()
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{check_assist_by_label, check_assist_not_applicable};
+
+    #[test]
+    fn assert_at_call_sites() {
+        check_assist_by_label(
+            propagate_requires,
+            "
+use vstd::prelude::*;
+proof fn lemma(x: int)
+    req$0uires
+        x > 0,
+{
+}
+
+proof fn caller(y: int) {
+    let z = y * y + 1;
+    lemma(z);
+}
+
+proof fn other(y: int) {
+    lemma(y + 1);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn lemma(x: int)
+    requires
+        x > 0,
+{
+}
+
+proof fn caller(y: int) {
+    let z = y * y + 1;
+    assert(z > 0);
+    lemma(z);
+}
+
+proof fn other(y: int) {
+    assert((y + 1) > 0);
+    lemma(y + 1);
+}
+
+fn main() {}
+",
+            "Assert the preconditions at each call site",
+        );
+    }
+
+    #[test]
+    fn forward_to_callers() {
+        check_assist_by_label(
+            propagate_requires,
+            "
+use vstd::prelude::*;
+proof fn lemma(x: int, y: int)
+    req$0uires
+        x < y,
+{
+}
+
+proof fn caller(a: int)
+    requires
+        a > 0,
+{
+    lemma(a, 2 * a);
+}
+
+proof fn caller2(b: int) {
+    lemma(b, b + 1);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn lemma(x: int, y: int)
+    requires
+        x < y,
+{
+}
+
+proof fn caller(a: int)
+    requires
+        a > 0,
+        a < (2 * a),
+{
+    lemma(a, 2 * a);
+}
+
+proof fn caller2(b: int)
+    requires
+        b < (b + 1),
+{
+    lemma(b, b + 1);
+}
+
+fn main() {}
+",
+            "Forward the preconditions to the callers",
+        );
+    }
+
+    #[test]
+    fn exec_fn() {
+        check_assist_not_applicable(
+            propagate_requires,
+            "
+use vstd::prelude::*;
+fn foo(x: u64)
+    req$0uires
+        x > 0,
+{
+}
+
+fn main() {
+    foo(1);
+}
+",
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    proof_plumber_api::vst_ext::vst_walk_expr,
+    AssistId, AssistKind,
+};
+use syntax::{
+    ast::{self, vst::*, ArithOp, AstNode},
+    T,
+};
+
+/// Proof action: prove a failing bit-manipulation assertion by `bit_vector`
+/// assert(x & 0xf == x);
+/// into
+/// assert(x & 0xf == x) by (bit_vector)
+///     requires
+///         x < 16,
+/// ;
+///
+/// The `bit_vector` prover does not see the context of the assertion, so the preconditions of the
+/// function that bound the variables of the assertion are passed to it in `requires`.
+/// Verus is run before offering the edit: the assertion has to fail as it is, and hold once
+/// proven by `bit_vector`.
+pub(crate) fn prove_by_bit_vector(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "assert"
+    let _ = ctx.at_this_token(T![assert])?;
+
+    // retrieve the assertion of interest, along with its statement
+    let expr: ast::AssertExpr = ctx.find_node_at_offset()?;
+    let stmt = ast::ExprStmt::cast(expr.syntax().parent()?)?;
+    let func: ast::Fn = ctx.find_node_at_offset()?;
+
+    // lift CST into TOST node
+    let assert: AssertExpr = AssertExpr::try_from(expr.clone()).ok()?;
+    let v_func = Fn::try_from(func.clone()).ok()?;
+
+    let bounds = ctx.requires_bounding(&v_func, &assert.expr);
+    let result = vst_rewriter_prove_by_bit_vector(assert.clone(), bounds)?;
+
+    // verify the original and the rewritten function in a single Verus run
+    let modified_fn = ctx.replace_statement(&v_func, assert.clone(), result.clone())?;
+    let mut verif_results = ctx.try_verus_batch(&[v_func, modified_fn]).into_iter();
+    let (before, after) = (verif_results.next()??, verif_results.next()??);
+    if !before.is_failing(&assert) || after.is_failing(&result) {
+        return None;
+    }
+
+    // pretty-print, along with the semicolon that may end up after `requires`
+    let result = ctx.fmt(stmt.clone(), format!("{result};"))?;
+
+    acc.add(
+        AssistId("prove_by_bit_vector", AssistKind::RefactorRewrite),
+        "Prove assertion by bit_vector",
+        stmt.syntax().text_range(),
+        |edit| {
+            edit.replace(stmt.syntax().text_range(), result);
+        },
+    )
+}
+
+pub(crate) fn vst_rewriter_prove_by_bit_vector(
+    mut assert: AssertExpr,
+    bounds: Vec<Expr>,
+) -> Option<AssertExpr> {
+    // if is already has a proof, return None
+    if assert.by_token || !has_bit_op(&assert.expr) {
+        return None;
+    }
+    assert.by_token = true;
+    let mut prover = Name::new();
+    prover.ident_token = Some("bit_vector".into());
+    assert.name = Some(Box::new(prover));
+    if !bounds.is_empty() {
+        let mut requires = RequiresClause::new();
+        requires.exprs = bounds;
+        assert.requires_clause = Some(Box::new(requires));
+    }
+    Some(assert)
+}
+
+fn has_bit_op(expr: &Expr) -> bool {
+    let mut found = false;
+    vst_walk_expr(expr, &mut |it| {
+        if let Expr::BinExpr(it) = it {
+            found |= matches!(
+                it.op,
+                BinaryOp::ArithOp(
+                    ArithOp::BitAnd
+                        | ArithOp::BitOr
+                        | ArithOp::BitXor
+                        | ArithOp::Shl
+                        | ArithOp::Shr
+                )
+            );
+        }
+    });
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist_not_applicable, check_assist_with_verifier},
+    };
+
+    #[test]
+    fn prove_with_bounds() {
+        check_assist_with_verifier(
+            prove_by_bit_vector,
+            // only `bit_vector` proves the assertion
+            MockVerifier::new(|item| {
+                if item.contains("bit_vector") {
+                    vec![]
+                } else {
+                    vec!["assert(x & 0xf == x)".to_owned()]
+                }
+            }),
+            "
+use vstd::prelude::*;
+proof fn foo(x: u32, y: u32)
+    requires
+        x < 16,
+        y > x,
+{
+    ass$0ert(x & 0xf == x);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn foo(x: u32, y: u32)
+    requires
+        x < 16,
+        y > x,
+{
+    assert(x & 0xf == x) by (bit_vector)
+        requires
+            x < 16,
+    ;
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn verifying_assertion() {
+        check_assist_not_applicable(
+            prove_by_bit_vector,
+            "
+use vstd::prelude::*;
+proof fn foo(x: u32, y: u32) {
+    ass$0ert(x & y == y & x);
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn no_bit_operator() {
+        check_assist_not_applicable(
+            prove_by_bit_vector,
+            "
+use vstd::prelude::*;
+proof fn foo(x: u32) {
+    ass$0ert(x + 1 > x);
+}
+
+fn main() {}
+",
+        );
+    }
+}
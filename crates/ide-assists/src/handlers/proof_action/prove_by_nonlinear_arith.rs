@@ -0,0 +1,162 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    proof_plumber_api::vst_ext::vst_walk_expr,
+    AssistId, AssistKind,
+};
+use syntax::{
+    ast::{self, vst::*, ArithOp, AstNode},
+    T,
+};
+
+/// Proof action: prove a failing nonlinear assertion by `nonlinear_arith`
+/// assert(x * y <= 100);
+/// into
+/// assert(x * y <= 100) by (nonlinear_arith)
+///     requires
+///         x <= 10,
+///         y <= 10,
+/// {
+/// };
+///
+/// Applies to assertions multiplying, dividing or taking the remainder of two non-literal values.
+/// Like for `bit_vector`, the preconditions bounding the variables of the assertion are passed
+/// in `requires`, and Verus is run before offering the edit.
+pub(crate) fn prove_by_nonlinear_arith(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "assert"
+    let _ = ctx.at_this_token(T![assert])?;
+
+    // retrieve the assertion of interest, along with its statement
+    let expr: ast::AssertExpr = ctx.find_node_at_offset()?;
+    let stmt = ast::ExprStmt::cast(expr.syntax().parent()?)?;
+    let func: ast::Fn = ctx.find_node_at_offset()?;
+
+    // lift CST into TOST node
+    let assert: AssertExpr = AssertExpr::try_from(expr.clone()).ok()?;
+    let v_func = Fn::try_from(func.clone()).ok()?;
+
+    let bounds = ctx.requires_bounding(&v_func, &assert.expr);
+    let result = vst_rewriter_prove_by_nonlinear_arith(assert.clone(), bounds)?;
+
+    // verify the original and the rewritten function in a single Verus run
+    let modified_fn = ctx.replace_statement(&v_func, assert.clone(), result.clone())?;
+    let mut verif_results = ctx.try_verus_batch(&[v_func, modified_fn]).into_iter();
+    let (before, after) = (verif_results.next()??, verif_results.next()??);
+    if !before.is_failing(&assert) || after.is_failing(&result) {
+        return None;
+    }
+
+    // pretty-print the whole statement
+    let result = ctx.fmt(stmt.clone(), format!("{result};"))?;
+
+    acc.add(
+        AssistId("prove_by_nonlinear_arith", AssistKind::RefactorRewrite),
+        "Prove assertion by nonlinear_arith",
+        stmt.syntax().text_range(),
+        |edit| {
+            edit.replace(stmt.syntax().text_range(), result);
+        },
+    )
+}
+
+pub(crate) fn vst_rewriter_prove_by_nonlinear_arith(
+    mut assert: AssertExpr,
+    bounds: Vec<Expr>,
+) -> Option<AssertExpr> {
+    // if is already has a proof, return None
+    if assert.by_token || !is_nonlinear(&assert.expr) {
+        return None;
+    }
+    assert.by_token = true;
+    let mut prover = Name::new();
+    prover.ident_token = Some("nonlinear_arith".into());
+    assert.name = Some(Box::new(prover));
+    if !bounds.is_empty() {
+        let mut requires = RequiresClause::new();
+        requires.exprs = bounds;
+        assert.requires_clause = Some(Box::new(requires));
+    }
+    assert.block_expr = Some(Box::new(BlockExpr::new(StmtList::new())));
+    Some(assert)
+}
+
+fn is_nonlinear(expr: &Expr) -> bool {
+    let mut found = false;
+    vst_walk_expr(expr, &mut |it| {
+        if let Expr::BinExpr(it) = it {
+            found |= matches!(it.op, BinaryOp::ArithOp(ArithOp::Mul | ArithOp::Div | ArithOp::Rem))
+                && !matches!(*it.lhs, Expr::Literal(_))
+                && !matches!(*it.rhs, Expr::Literal(_));
+        }
+    });
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist_not_applicable, check_assist_with_verifier},
+    };
+
+    #[test]
+    fn prove_with_bounds() {
+        check_assist_with_verifier(
+            prove_by_nonlinear_arith,
+            // only `nonlinear_arith` proves the assertion
+            MockVerifier::new(|item| {
+                if item.contains("nonlinear_arith") {
+                    vec![]
+                } else {
+                    vec!["assert(x * y <= 100)".to_owned()]
+                }
+            }),
+            "
+use vstd::prelude::*;
+proof fn foo(x: nat, y: nat, z: nat)
+    requires
+        x <= 10,
+        y <= 10,
+        z > x,
+{
+    ass$0ert(x * y <= 100);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn foo(x: nat, y: nat, z: nat)
+    requires
+        x <= 10,
+        y <= 10,
+        z > x,
+{
+    assert(x * y <= 100) by (nonlinear_arith)
+        requires
+            x <= 10,
+            y <= 10,
+    {
+    };
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn linear_assertion() {
+        check_assist_not_applicable(
+            prove_by_nonlinear_arith,
+            "
+use vstd::prelude::*;
+proof fn foo(x: nat) {
+    ass$0ert(x * 2 == x + x);
+}
+
+fn main() {}
+",
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    AssistId, AssistKind,
+};
+use syntax::{
+    ast::{self, vst::*, AstNode},
+    T,
+};
+
+/// Proof action: remove the proof block of an assertion
+/// assert(x == 3) by {
+///     assert(x == 3);
+/// };
+/// into
+/// assert(x == 3);
+///
+/// The inverse of `assert_by`. Offered when the proof block is empty, or when Verus checks that
+/// the assertion holds without it. A prover like `nonlinear_arith` is removed along with it.
+pub(crate) fn remove_assert_by(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "assert"
+    let _ = ctx.at_this_token(T![assert])?;
+
+    // retrieve the assertion of interest
+    let expr: ast::AssertExpr = ctx.find_node_at_offset()?;
+
+    // lift CST into TOST node
+    let assert: AssertExpr = AssertExpr::try_from(expr.clone()).ok()?;
+    let result = vst_rewriter_remove_assert_by(assert.clone())?;
+
+    // a proof that does nothing can go without asking Verus
+    let is_empty = assert
+        .block_expr
+        .as_ref()
+        .map_or(true, |it| it.stmt_list.statements.is_empty() && it.stmt_list.tail_expr.is_none());
+    if !is_empty || assert.name.is_some() || assert.requires_clause.is_some() {
+        let this_fn = ctx.vst_find_node_at_offset::<Fn, ast::Fn>()?;
+        let plain_fn = ctx.replace_statement(&this_fn, assert, result.clone())?;
+        if !ctx.try_verus(&plain_fn)?.is_success {
+            return None;
+        }
+    }
+
+    // pretty-print
+    let result = ctx.fmt(expr.clone(), result.to_string())?;
+
+    acc.add(
+        AssistId("remove_assert_by", AssistKind::RefactorRewrite),
+        "Remove proof block of this assert",
+        expr.syntax().text_range(),
+        |edit| {
+            edit.replace(expr.syntax().text_range(), result);
+        },
+    )
+}
+
+pub(crate) fn vst_rewriter_remove_assert_by(mut assert: AssertExpr) -> Option<AssertExpr> {
+    // without a "by block", report "not applicable" by returning None
+    if !assert.by_token {
+        return None;
+    }
+    assert.by_token = false;
+    assert.name = None;
+    assert.requires_clause = None;
+    assert.block_expr = None;
+    Some(assert)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist, check_assist_not_applicable_with_verifier},
+    };
+
+    #[test]
+    fn remove_empty_block() {
+        check_assist(
+            remove_assert_by,
+            "
+use vstd::prelude::*;
+proof fn f(x: int)
+    requires
+        x == 3,
+{
+    ass$0ert(x == 3) by {};
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn f(x: int)
+    requires
+        x == 3,
+{
+    assert(x == 3);
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn remove_unneeded_block() {
+        check_assist(
+            remove_assert_by,
+            "
+use vstd::prelude::*;
+spec fn pow2(e: nat) -> nat
+    decreases e,
+{
+    if e == 0 { 1 } else { 2 * pow2((e - 1) as nat) }
+}
+
+proof fn lemma_pow2(e: nat)
+    requires
+        e > 3,
+{
+    asse$0rt(pow2(e) == 2 * pow2((e - 1) as nat)) by {
+        assert(pow2(e) == 2 * pow2((e - 1) as nat));
+    };
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+spec fn pow2(e: nat) -> nat
+    decreases e,
+{
+    if e == 0 { 1 } else { 2 * pow2((e - 1) as nat) }
+}
+
+proof fn lemma_pow2(e: nat)
+    requires
+        e > 3,
+{
+    assert(pow2(e) == 2 * pow2((e - 1) as nat));
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn needed_block() {
+        check_assist_not_applicable_with_verifier(
+            remove_assert_by,
+            MockVerifier::needing("lemma_pow2", &["reveal_with_fuel(pow2, 4)"]),
+            "
+use vstd::prelude::*;
+spec fn pow2(e: nat) -> nat
+    decreases e,
+{
+    if e == 0 { 1 } else { 2 * pow2((e - 1) as nat) }
+}
+
+proof fn lemma_pow2() {
+    asse$0rt(pow2(3) == 8) by {
+        reveal_with_fuel(pow2, 4);
+    }
+}
+
+fn main() {}
+",
+        );
+    }
+}
@@ -1,4 +1,5 @@
 // use ide_db::syntax_helpers::node_ext::is_pattern_cond;
+use crate::handlers::proof_action::remove_assert_by_block::vst_rewriter_remove_assert_by;
 use crate::proof_plumber_api::vst_ext::vst_map_expr_visitor;
 use crate::{
     assist_context::{AssistContext, Assists},
@@ -11,7 +12,7 @@ use syntax::{
         vst::{self, *},
         AstNode,
     },
-    T,
+    Direction, TextRange, T,
 };
 
 /// Proof action: remove redundant assertions
@@ -62,12 +63,38 @@ pub(crate) fn vst_rewriter_remove_dead_assertions(
     func: vst::Fn,
     initial_verif_result: VerifResult,
 ) -> Option<vst::Fn> {
+    // Try removing each assertion on its own first, in a single Verus run.
+    // An assertion the proof needs on its own is still needed once others are removed,
+    // so the one-by-one search below skips it.
+    let mut assertions: Vec<vst::Stmt> = vec![];
+    let mut collect = |exp: &mut Expr| {
+        if let Expr::AssertExpr(_) = exp {
+            let s: Stmt = exp.clone().into();
+            if assertions.iter().all(|r| r.to_string().trim() != s.to_string().trim()) {
+                assertions.push(s);
+            }
+        }
+        Ok::<Expr, String>(exp.clone())
+    };
+    let _ = vst_map_expr_visitor(*func.clone().body?, &mut collect).ok()?;
+    let candidates = assertions
+        .iter()
+        .map(|it| rewriter_rm_assertions(&func, &vec![it.clone()]))
+        .collect::<Option<Vec<_>>>()?;
+    let needed: Vec<String> = assertions
+        .iter()
+        .zip(ctx.try_verus_batch(&candidates))
+        .filter(|(_, result)| result.as_ref().map_or(false, |it| !it.is_success))
+        .map(|(assertion, _)| assertion.to_string().trim().to_owned())
+        .collect();
+
     let mut redundant_assertions: Vec<vst::Stmt> = vec![];
     let mut cb = |exp: &mut Expr| {
         match exp {
             Expr::AssertExpr(_) => {
                 let s: Stmt = exp.clone().into();
                 if redundant_assertions.iter().all(|r| r.to_string().trim() != s.to_string().trim())
+                    && !needed.iter().any(|it| it == s.to_string().trim())
                 {
                     redundant_assertions.push(exp.clone().into());
                     let modified_fn = rewriter_rm_assertions(&func, &redundant_assertions)
@@ -137,10 +164,144 @@ fn rewriter_rm_assertions(
     Some(func)
 }
 
+/// Proof action: remove dead proof code in a whole module
+/// `remove_dead_assertions` for each `proof fn` of a module or of a `verus!` block, which also
+/// removes the reveals the proofs do not need, and the empty `by` blocks of assertions.
+/// It is triggered on the `mod` keyword, or on `verus` of `verus! {`.
+/// Like `remove_dead_assertions`, Verus is run once before offering the action, on all the
+/// functions at once, and only the proofs that succeed are cleaned up once it is picked.
+pub(crate) fn remove_dead_proof_code(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let (range, funcs) = proof_fns_in_scope(ctx)?;
+
+    // lift CST into TOST node
+    let v_funcs =
+        funcs.iter().map(|it| vst::Fn::try_from(it.clone()).ok()).collect::<Option<Vec<_>>>()?;
+
+    // only rewrite succeeding proofs
+    let results = ctx.try_verus_batch(&v_funcs);
+    let succeeding: Vec<(ast::Fn, vst::Fn, VerifResult)> = funcs
+        .into_iter()
+        .zip(v_funcs)
+        .zip(results)
+        .filter_map(|((func, v_func), result)| {
+            let result = result?;
+            result.is_success.then_some((func, v_func, result))
+        })
+        .collect();
+    if succeeding.is_empty() {
+        return None;
+    }
+
+    acc.add(
+        AssistId("remove_dead_proof_code", AssistKind::RefactorRewrite),
+        "Remove Dead Proof Code in this Module",
+        range,
+        |edit| {
+            // as in `remove_dead_assertions`, Verus runs many times only once this is picked
+            for (func, v_func, initial_verif_result) in succeeding {
+                let result =
+                    vst_rewriter_remove_dead_proof_code(ctx, v_func.clone(), initial_verif_result)
+                        .expect("vst_rewriter_remove_dead_proof_code");
+                if result.to_string() == v_func.to_string() {
+                    continue;
+                }
+                let result = ctx.fmt(func.clone(), result.to_string()).expect("fmt"); // pretty-print
+                edit.replace(func.syntax().text_range(), result);
+            }
+        },
+    )
+}
+
+pub(crate) fn vst_rewriter_remove_dead_proof_code(
+    ctx: &AssistContext<'_>,
+    func: vst::Fn,
+    initial_verif_result: VerifResult,
+) -> Option<vst::Fn> {
+    let func = vst_rewriter_remove_dead_assertions(ctx, func, initial_verif_result)?;
+
+    // try removing each reveal, keeping the ones the proof fails without
+    let mut reveals: Vec<vst::Stmt> = vec![];
+    let mut collect = |exp: &mut Expr| {
+        if let Expr::BlockExpr(bb) = exp {
+            for s in &bb.stmt_list.statements {
+                let text = s.to_string();
+                if (text.trim().starts_with("reveal(")
+                    || text.trim().starts_with("reveal_with_fuel("))
+                    && reveals.iter().all(|r| r.to_string().trim() != text.trim())
+                {
+                    reveals.push(s.clone());
+                }
+            }
+        }
+        Ok::<Expr, String>(exp.clone())
+    };
+    let _ = vst_map_expr_visitor(*func.clone().body?, &mut collect).ok()?;
+    let mut redundant_reveals: Vec<vst::Stmt> = vec![];
+    for reveal in reveals {
+        redundant_reveals.push(reveal);
+        let modified_fn = rewriter_rm_assertions(&func, &redundant_reveals)?;
+        if !ctx.try_verus(&modified_fn)?.is_success {
+            redundant_reveals.pop();
+        }
+    }
+    let mut func = rewriter_rm_assertions(&func, &redundant_reveals)?;
+
+    // an empty proof block proves nothing
+    let mut cb = |exp: &mut Expr| {
+        if let Expr::AssertExpr(assert) = exp {
+            let is_empty = assert.block_expr.as_ref().map_or(false, |it| {
+                it.stmt_list.statements.is_empty() && it.stmt_list.tail_expr.is_none()
+            });
+            if is_empty && assert.name.is_none() && assert.requires_clause.is_none() {
+                if let Some(plain) = vst_rewriter_remove_assert_by(*assert.clone()) {
+                    return Ok(plain.into());
+                }
+            }
+        }
+        Ok::<Expr, String>(exp.clone())
+    };
+    match vst_map_expr_visitor(*func.clone().body?, &mut cb).ok()? {
+        Expr::BlockExpr(be) => func.body = Some(be),
+        _ => return None,
+    }
+    Some(func)
+}
+
+/// The `proof fn`s in the module whose `mod` keyword, or in the `verus!` block whose `verus`, is
+/// under the cursor, along with the range of the module or block.
+fn proof_fns_in_scope(ctx: &AssistContext<'_>) -> Option<(TextRange, Vec<ast::Fn>)> {
+    let (range, scope) = if ctx.at_this_token(T![mod]).is_some() {
+        let module: ast::Module = ctx.find_node_at_offset()?;
+        (module.syntax().text_range(), module.item_list()?.syntax().clone())
+    } else {
+        let _ = ctx.at_this_token(T![verus])?;
+        // the items of `verus! { .. }` are parsed as items of the enclosing item list, between
+        // the braces of the block
+        let verus_token = ctx.find_token_syntax_at_offset(T![verus])?;
+        let r_curly =
+            verus_token.siblings_with_tokens(Direction::Next).find(|it| it.kind() == T!['}'])?;
+        let range = TextRange::new(verus_token.text_range().start(), r_curly.text_range().end());
+        (range, verus_token.parent()?)
+    };
+    let funcs: Vec<ast::Fn> = scope
+        .descendants()
+        .filter_map(ast::Fn::cast)
+        .filter(|it| range.contains_range(it.syntax().text_range()))
+        .filter(|it| it.fn_mode().map_or(false, |mode| mode.proof_token().is_some()))
+        .collect();
+    if funcs.is_empty() {
+        return None;
+    }
+    Some((range, funcs))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::check_assist;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist, check_assist_with_verifier},
+    };
 
     #[test]
     fn remove_one() {
@@ -205,8 +366,9 @@ fn main() {}
 
     #[test]
     fn preserve_necessary() {
-        check_assist(
+        check_assist_with_verifier(
             remove_dead_assertions,
+            MockVerifier::needing("foo", &["by (bit_vector)"]),
             "
 use vstd::prelude::*;
 proo$0f fn foo(x: u32, y: u32)
@@ -270,8 +432,12 @@ proof fn proof_index(a: u16, offset: u16)
 
     #[test]
     fn remove_autogen_asserts_fibo() {
-        check_assist(
+        check_assist_with_verifier(
             remove_dead_assertions,
+            MockVerifier::needing(
+                "lemma_fibo_is_monotonic",
+                &["assert(fibo(j) == fibo((j - 1) as nat) + fibo((j - 2) as nat))"],
+            ),
             "
 use vstd::prelude::*;
 pub open spec fn fibo(n: nat) -> nat
@@ -341,8 +507,12 @@ fn main() {}
 
     #[test]
     fn remove_autogen_asserts_fibo2() {
-        check_assist(
+        check_assist_with_verifier(
             remove_dead_assertions,
+            MockVerifier::needing(
+                "lemma_fibo_is_monotonic",
+                &["assert(fibo(j) == fibo((j - 1) as nat) + fibo((j - 2) as nat))"],
+            ),
             "
 use vstd::prelude::*;
 pub open spec fn fibo(n: nat) -> nat
@@ -410,8 +580,13 @@ fn main() {}
 
     #[test]
     fn remove_autogen_asserts_fibo3() {
-        check_assist(
+        check_assist_with_verifier(
             remove_dead_assertions,
+            MockVerifier::needing(
+                "lemma_fibo_is_monotonic",
+                &["assert(fibo(i) <= fibo((j - 1) as nat) ==> fibo(i) <= fibo((j - 2) as nat) \
+                   ==> fibo(i) <= fibo(j))"],
+            ),
             "
 use vstd::prelude::*;
 pub open spec fn fibo(n: nat) -> nat
@@ -484,6 +659,102 @@ proof fn lemma_fibo_is_monotonic(i: nat, j: nat)
 }
 
 
+fn main() {}
+",
+        )
+    }
+
+    #[test]
+    fn remove_in_verus_block() {
+        check_assist_with_verifier(
+            remove_dead_proof_code,
+            MockVerifier::needing("bar", &["reveal(f)", "assert(f(x) == x)"]),
+            "
+use vstd::prelude::*;
+ver$0us! {
+#[verifier::opaque]
+spec fn f(x: int) -> int { x }
+
+proof fn foo(x: nat)
+    ensures
+        x >= 0,
+{
+    assert(x >= 0);
+}
+
+proof fn bar(x: int)
+    ensures
+        f(x) == x,
+{
+    reveal(f);
+    assert(f(x) == x) by {};
+}
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+verus! {
+#[verifier::opaque]
+spec fn f(x: int) -> int { x }
+
+proof fn foo(x: nat)
+    ensures
+        x >= 0,
+{
+}
+
+
+proof fn bar(x: int)
+    ensures
+        f(x) == x,
+{
+    reveal(f);
+    assert(f(x) == x);
+}
+
+}
+
+fn main() {}
+",
+        )
+    }
+
+    #[test]
+    fn remove_reveal_in_module() {
+        check_assist(
+            remove_dead_proof_code,
+            "
+use vstd::prelude::*;
+m$0od lemmas {
+    #[verifier::opaque]
+    spec fn f(x: int) -> int { x }
+
+    proof fn foo(x: int)
+        ensures
+            x + 1 > x,
+    {
+        reveal(f);
+    }
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+mod lemmas {
+    #[verifier::opaque]
+    spec fn f(x: int) -> int { x }
+
+    proof fn foo(x: int)
+        ensures
+            x + 1 > x,
+    {
+    }
+
+}
+
 fn main() {}
 ",
         )
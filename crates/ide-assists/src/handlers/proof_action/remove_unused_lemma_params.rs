@@ -0,0 +1,266 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    proof_plumber_api::vst_ext::vst_walk_expr,
+    AssistId, AssistKind,
+};
+use ide_db::{defs::Definition, FxHashSet};
+use itertools::Itertools;
+use syntax::{
+    algo::find_node_at_range,
+    ast::{self, vst, AstNode, HasArgList, HasName},
+    TextRange,
+};
+
+/// Proof action: remove the parameters of a lemma that it does not use
+/// proof fn lemma(x: int, y: int)
+///     ensures
+///         x * x >= 0,
+/// ...
+/// lemma(a, b);
+/// into
+/// proof fn lemma(x: int)
+///     ensures
+///         x * x >= 0,
+/// ...
+/// lemma(a);
+///
+/// Triggered on the signature of a `proof fn`. A parameter is unused when its name occurs
+/// nowhere in the `requires`, `recommends`, `ensures` and `decreases` clauses nor in the body.
+/// The arguments of the calls in the whole workspace are removed along with the parameters.
+pub(crate) fn remove_unused_lemma_params(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on the signature
+    let func: ast::Fn = ctx.find_node_at_offset()?;
+    let param_list = func.param_list()?;
+    if ctx.offset() > param_list.syntax().text_range().end() {
+        return None;
+    }
+    if func.fn_mode()?.proof_token().is_none() || func.body().is_none() || is_trait_impl(&func) {
+        return None;
+    }
+
+    let v_func = vst::Fn::try_from(func.clone()).ok()?;
+    let mentioned = mentioned_names(&v_func);
+    let params: Vec<ast::Param> = param_list.params().collect();
+    let unused: Vec<usize> = params
+        .iter()
+        .positions(|param| match param.pat() {
+            Some(ast::Pat::IdentPat(it)) => {
+                it.name().map_or(false, |name| !mentioned.contains(name.text().as_str()))
+            }
+            Some(ast::Pat::WildcardPat(_)) => true,
+            _ => false,
+        })
+        .collect();
+    if unused.is_empty() {
+        return None;
+    }
+    let names = unused.iter().filter_map(|&idx| params[idx].pat()).join("`, `");
+
+    let self_param = param_list.self_param();
+    let kept_params = self_param
+        .iter()
+        .map(|it| it.to_string())
+        .chain(
+            params
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| !unused.contains(idx))
+                .map(|(_, it)| it.to_string()),
+        )
+        .join(", ");
+    let def = Definition::Function(ctx.sema.to_def(&func)?);
+
+    acc.add(
+        AssistId("remove_unused_lemma_params", AssistKind::RefactorRewrite),
+        format!("Remove unused parameters `{names}`"),
+        param_list.syntax().text_range(),
+        |edit| {
+            edit.replace(param_list.syntax().text_range(), format!("({kept_params})"));
+            for (file_id, references) in def.usages(&ctx.sema).all() {
+                let source_file = ctx.sema.parse(file_id);
+                edit.edit_file(file_id);
+                let arg_lists = references
+                    .into_iter()
+                    .filter_map(|it| call_arg_list(source_file.syntax(), it.range))
+                    .unique_by(|(arg_list, _)| arg_list.syntax().text_range())
+                    .collect_vec();
+                for (arg_list, is_method_call) in arg_lists {
+                    // `Self::lemma(s, ..)` passes the receiver as its first argument
+                    let offset = usize::from(self_param.is_some() && !is_method_call);
+                    let kept_args = arg_list
+                        .args()
+                        .enumerate()
+                        .filter(|(idx, _)| {
+                            idx.checked_sub(offset).map_or(true, |idx| !unused.contains(&idx))
+                        })
+                        .map(|(_, it)| it.to_string())
+                        .join(", ");
+                    edit.replace(arg_list.syntax().text_range(), format!("({kept_args})"));
+                }
+            }
+        },
+    )
+}
+
+/// The names occurring in the clauses and the body of `func`.
+fn mentioned_names(func: &vst::Fn) -> FxHashSet<String> {
+    let mut exprs: Vec<vst::Expr> = vec![];
+    if let Some(it) = &func.requires_clause {
+        exprs.extend(it.exprs.iter().cloned());
+    }
+    if let Some(it) = &func.recommends_clause {
+        exprs.extend(it.exprs.iter().cloned());
+        exprs.extend(it.expr.iter().map(|it| *it.clone()));
+    }
+    if let Some(it) = &func.ensures_clause {
+        exprs.extend(it.exprs.iter().cloned());
+    }
+    if let Some(it) = &func.signature_decreases {
+        exprs.extend(it.decreases_clause.exprs.iter().cloned());
+        exprs.extend(it.expr.iter().map(|it| *it.clone()));
+    }
+    if let Some(body) = &func.body {
+        exprs.push(vst::Expr::BlockExpr(body.clone()));
+    }
+
+    let mut names = FxHashSet::default();
+    for expr in exprs {
+        vst_walk_expr(&expr, &mut |it| match it {
+            vst::Expr::PathExpr(it) => {
+                if let Some(name) = &it.path.segment.name_ref.ident_token {
+                    names.insert(name.to_string());
+                }
+            }
+            // the walk goes through the subexpressions of these
+            vst::Expr::BinExpr(_)
+            | vst::Expr::MethodCallExpr(_)
+            | vst::Expr::FieldExpr(_)
+            | vst::Expr::IndexExpr(_)
+            | vst::Expr::ParenExpr(_)
+            | vst::Expr::PrefixExpr(_)
+            | vst::Expr::CastExpr(_)
+            | vst::Expr::Literal(_) => (),
+            // but not through those of blocks, closures and the like, whose identifiers are all
+            // taken as mentioned
+            _ => names.extend(
+                it.to_string()
+                    .split(|c: char| !c.is_alphanumeric() && c != '_')
+                    .filter(|it| !it.is_empty())
+                    .map(|it| it.to_owned()),
+            ),
+        });
+    }
+    names
+}
+
+/// The argument list of the call at `range`, a reference to the callee, and whether it is a
+/// method call.
+fn call_arg_list(root: &syntax::SyntaxNode, range: TextRange) -> Option<(ast::ArgList, bool)> {
+    if let Some(call) = find_node_at_range::<ast::CallExpr>(root, range) {
+        if call.expr()?.syntax().text_range().contains_range(range) {
+            return Some((call.arg_list()?, false));
+        }
+    }
+    let call = find_node_at_range::<ast::MethodCallExpr>(root, range)?;
+    if !call.name_ref()?.syntax().text_range().contains_range(range) {
+        return None;
+    }
+    Some((call.arg_list()?, true))
+}
+
+fn is_trait_impl(func: &ast::Fn) -> bool {
+    func.syntax()
+        .parent()
+        .and_then(|it| it.parent())
+        .and_then(ast::Impl::cast)
+        .map_or(false, |it| it.trait_().is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    #[test]
+    fn remove_unused_params() {
+        check_assist(
+            remove_unused_lemma_params,
+            "
+use vstd::prelude::*;
+proof fn lem$0ma(x: int, y: int, s: Seq<int>)
+    requires
+        s.len() > 0,
+    ensures
+        x * x >= 0,
+{
+}
+
+proof fn caller(a: int, t: Seq<int>)
+    requires
+        t.len() > 0,
+{
+    lemma(a, a + 1, t);
+    lemma(2 * a, 0, t);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn lemma(x: int, s: Seq<int>)
+    requires
+        s.len() > 0,
+    ensures
+        x * x >= 0,
+{
+}
+
+proof fn caller(a: int, t: Seq<int>)
+    requires
+        t.len() > 0,
+{
+    lemma(a, t);
+    lemma(2 * a, t);
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn used_in_body() {
+        check_assist_not_applicable(
+            remove_unused_lemma_params,
+            "
+use vstd::prelude::*;
+proof fn lem$0ma(x: int, y: int)
+    ensures
+        x * x >= 0,
+{
+    assert forall|i: int| i < y implies i < y + 1 by {}
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn exec_fn() {
+        check_assist_not_applicable(
+            remove_unused_lemma_params,
+            "
+use vstd::prelude::*;
+fn fo$0o(x: u64, y: u64) -> (r: u64)
+    ensures
+        r == x,
+{
+    x
+}
+
+fn main() {}
+",
+        );
+    }
+}
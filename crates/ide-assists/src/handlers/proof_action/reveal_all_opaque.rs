@@ -0,0 +1,262 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    proof_plumber_api::vst_ext::vst_walk_expr,
+    AssistId, AssistKind,
+};
+use syntax::{
+    ast::{self, vst::*, AstNode},
+    T,
+};
+
+/// Proof action: reveal every opaque function called in an assertion
+/// assert(f(x) + g(2) == 3);
+/// into
+/// {
+///     reveal(f);
+///     reveal_with_fuel(g, 2);
+///     assert(f(x) + g(2) == 3);
+/// };
+///
+/// A recursive function called on literals is revealed with as much fuel as the largest of them.
+/// Once the action is picked, Verus is run to leave out the reveals the assertion does not need.
+pub(crate) fn insert_reveal_all(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "assert"
+    let _ = ctx.at_this_token(T![assert])?;
+
+    let assert_expr: ast::AssertExpr = ctx.find_node_at_offset()?;
+    let assert = AssertExpr::try_from(assert_expr.clone()).ok()?;
+    if assert.by_token {
+        return None;
+    }
+    let reveals = opaque_reveals(ctx, &assert);
+    if reveals.is_empty() {
+        return None;
+    }
+
+    acc.add(
+        AssistId("insert_reveal_all", AssistKind::RefactorRewrite),
+        "Reveal all opaque functions of this assertion",
+        assert_expr.syntax().text_range(),
+        |edit| {
+            // Run Verus only when the user explicitly wants it
+            let reveals = needed_reveals(ctx, &assert, &reveals).unwrap_or(reveals);
+            let result = vst_rewriter_insert_reveal_all(&assert, &reveals);
+            // pretty-print
+            let result = ctx.fmt(assert_expr.clone(), result.to_string()).expect("fmt");
+            edit.replace(assert_expr.syntax().text_range(), result);
+        },
+    )
+}
+
+pub(crate) fn vst_rewriter_insert_reveal_all(
+    assert: &AssertExpr,
+    reveals: &[CallExpr],
+) -> BlockExpr {
+    let mut stmts = StmtList::new();
+    stmts.statements = reveals.iter().map(|it| it.clone().into()).collect();
+    stmts.statements.push(assert.clone().into());
+    BlockExpr::new(stmts)
+}
+
+/// `reveal(f)` for each opaque function `f` called in `assert`, or `reveal_with_fuel(f, n)` for a
+/// recursive one called on literals no larger than `n`.
+fn opaque_reveals(ctx: &AssistContext<'_>, assert: &AssertExpr) -> Vec<CallExpr> {
+    let mut opaque: Vec<(Fn, Expr, u64)> = vec![];
+    vst_walk_expr(&assert.expr, &mut |it| {
+        let Expr::CallExpr(call) = it else { return };
+        let Some(func) = ctx.vst_find_fn(&call) else { return };
+        if !ctx.is_opaque(&func) {
+            return;
+        }
+        let depth = call
+            .arg_list
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                Expr::Literal(lit) => lit.literal.parse::<u64>().ok(),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(1);
+        match opaque.iter_mut().find(|(it, _, _)| it.name.to_string() == func.name.to_string()) {
+            Some((_, _, fuel)) => *fuel = (*fuel).max(depth),
+            None => opaque.push((func, *call.expr, depth)),
+        }
+    });
+    opaque
+        .into_iter()
+        .filter_map(|(func, name, fuel)| {
+            let mut args = ArgList::new();
+            args.args.push(name);
+            if func.signature_decreases.is_some() && fuel > 1 {
+                args.args.push(Literal::new(fuel.to_string()).into());
+                ctx.vst_call_expr_from_text("reveal_with_fuel", args)
+            } else {
+                ctx.vst_call_expr_from_text("reveal", args)
+            }
+        })
+        .collect()
+}
+
+/// The reveals among `reveals` that `assert` needs. A reveal is left out when the function still
+/// verifies without it, provided it also verifies without all the reveals left out.
+/// `None` if Verus could not tell, for example when the assertion fails even with all of them or
+/// holds without any of them.
+fn needed_reveals(
+    ctx: &AssistContext<'_>,
+    assert: &AssertExpr,
+    reveals: &[CallExpr],
+) -> Option<Vec<CallExpr>> {
+    let this_fn = ctx.vst_find_node_at_offset::<Fn, ast::Fn>()?;
+    let with_reveals = |reveals: &[CallExpr]| {
+        ctx.replace_statement(
+            &this_fn,
+            assert.clone(),
+            vst_rewriter_insert_reveal_all(assert, reveals),
+        )
+    };
+
+    // all of them, then each of them left out, in a single Verus run
+    let mut candidates = vec![with_reveals(reveals)?];
+    for idx in 0..reveals.len() {
+        let mut others = reveals.to_vec();
+        others.remove(idx);
+        candidates.push(with_reveals(&others)?);
+    }
+    let mut results = ctx.try_verus_batch(&candidates).into_iter();
+    if !results.next()??.is_success {
+        return None;
+    }
+    let needed: Vec<CallExpr> = reveals
+        .iter()
+        .zip(results)
+        .filter(|(_, result)| result.as_ref().map_or(true, |it| !it.is_success))
+        .map(|(reveal, _)| reveal.clone())
+        .collect();
+
+    // reveals unneeded one by one may still be needed together
+    if needed.is_empty() {
+        return None;
+    }
+    if needed.len() < reveals.len() && !ctx.try_verus(&with_reveals(&needed)?)?.is_success {
+        return None;
+    }
+    Some(needed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist_not_applicable, check_assist_with_verifier},
+    };
+
+    #[test]
+    fn reveal_all() {
+        check_assist_with_verifier(
+            insert_reveal_all,
+            MockVerifier::needing(
+                "test_opaque",
+                &["reveal_with_fuel(opaque_fibo, 3)", "reveal(double)"],
+            ),
+            "
+#[verifier::opaque]
+spec fn opaque_fibo(n: nat) -> nat
+  decreases n
+{
+  if n == 0 { 0 } else if n == 1 { 1 }
+  else { opaque_fibo((n - 2) as nat) + opaque_fibo((n - 1) as nat) }
+}
+
+#[verifier::opaque]
+spec fn double(n: nat) -> nat {
+  n + n
+}
+
+proof fn test_opaque(x: nat) {
+  ass$0ert(opaque_fibo(3) + double(x) == 2 + x + x);
+}
+",
+            "
+#[verifier::opaque]
+spec fn opaque_fibo(n: nat) -> nat
+  decreases n
+{
+  if n == 0 { 0 } else if n == 1 { 1 }
+  else { opaque_fibo((n - 2) as nat) + opaque_fibo((n - 1) as nat) }
+}
+
+#[verifier::opaque]
+spec fn double(n: nat) -> nat {
+  n + n
+}
+
+proof fn test_opaque(x: nat) {
+  {
+        reveal_with_fuel(opaque_fibo, 3);
+        reveal(double);
+        assert(opaque_fibo(3) + double(x) == 2 + x + x);
+    };
+}
+",
+        )
+    }
+
+    #[test]
+    fn prune_unneeded_reveals() {
+        check_assist_with_verifier(
+            insert_reveal_all,
+            MockVerifier::needing("test_opaque", &["reveal(double)"]),
+            "
+#[verifier::opaque]
+spec fn double(n: nat) -> nat {
+  n + n
+}
+
+#[verifier::opaque]
+spec fn pos(n: nat) -> bool {
+  n > 0
+}
+
+proof fn test_opaque(x: nat) {
+  ass$0ert(pos(x) ==> double(x) > x);
+}
+",
+            "
+#[verifier::opaque]
+spec fn double(n: nat) -> nat {
+  n + n
+}
+
+#[verifier::opaque]
+spec fn pos(n: nat) -> bool {
+  n > 0
+}
+
+proof fn test_opaque(x: nat) {
+  {
+        reveal(double);
+        assert(pos(x) ==> double(x) > x);
+    };
+}
+",
+        )
+    }
+
+    #[test]
+    fn no_opaque_function() {
+        check_assist_not_applicable(
+            insert_reveal_all,
+            "
+spec fn double(n: nat) -> nat {
+  n + n
+}
+
+proof fn test_opaque(x: nat) {
+  ass$0ert(double(x) == x + x);
+}
+",
+        );
+    }
+}
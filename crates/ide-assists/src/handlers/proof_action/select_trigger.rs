@@ -0,0 +1,222 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    AssistId, AssistKind,
+};
+use ide_db::FxHashSet;
+use itertools::Itertools;
+use syntax::{
+    ast::{self, AstNode, HasName},
+    TextRange,
+};
+
+/// Proof action: pick a trigger for a quantifier
+/// forall|i: int, j: int| 0 <= i < j < s.len() ==> f(s[i], s[j])
+/// into
+/// forall|i: int, j: int| #![trigger f(s[i], s[j])] 0 <= i < j < s.len() ==> f(s[i], s[j])
+///
+/// Offered inside a `forall` or `exists` without a trigger. The candidates are the function
+/// applications and indexing of the body mentioning all of the quantified variables. When there
+/// is none, the pairs of them that mention all of the variables together are offered instead.
+/// Each candidate is a distinct assist, so that the user picks the one Verus should use.
+pub(crate) fn select_trigger(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger inside a quantifier
+    let quantifier: ast::ClosureExpr = ctx.find_node_at_offset()?;
+    if quantifier.forall_token().is_none() && quantifier.exists_token().is_none() {
+        return None;
+    }
+    if has_trigger(&quantifier) {
+        return None;
+    }
+    let body = quantifier.body()?;
+    let vars: FxHashSet<String> = quantifier
+        .param_list()?
+        .params()
+        .filter_map(|it| it.pat())
+        .flat_map(|it| it.syntax().descendants().filter_map(ast::IdentPat::cast).collect_vec())
+        .filter_map(|it| Some(it.name()?.text().to_string()))
+        .collect();
+    if vars.is_empty() {
+        return None;
+    }
+
+    let insert_at = TextRange::empty(body.syntax().text_range().start());
+    for terms in trigger_candidates(&body, &vars) {
+        let terms = terms.iter().map(|it| it.split_whitespace().join(" ")).join(", ");
+        acc.add(
+            AssistId("select_trigger", AssistKind::RefactorRewrite),
+            format!("Use `{terms}` as the trigger"),
+            quantifier.syntax().text_range(),
+            |edit| {
+                edit.replace(insert_at, format!("#![trigger {terms}] "));
+            },
+        );
+    }
+    Some(())
+}
+
+/// Whether `quantifier` already has a trigger, `#[trigger]` as well as `#![trigger ..]`.
+fn has_trigger(quantifier: &ast::ClosureExpr) -> bool {
+    quantifier.syntax().descendants().any(|it| {
+        ast::TriggerAttribute::can_cast(it.kind())
+            || ast::Attr::cast(it)
+                .map_or(false, |it| it.simple_name().as_deref() == Some("trigger"))
+    })
+}
+
+/// The trigger sets for the quantified variables `vars` among the terms of `body`.
+fn trigger_candidates(body: &ast::Expr, vars: &FxHashSet<String>) -> Vec<Vec<String>> {
+    let terms: Vec<(String, FxHashSet<String>)> = body
+        .syntax()
+        .descendants()
+        .filter_map(ast::Expr::cast)
+        .filter(|it| {
+            matches!(
+                it,
+                ast::Expr::CallExpr(_) | ast::Expr::MethodCallExpr(_) | ast::Expr::IndexExpr(_)
+            )
+        })
+        .filter(|it| is_valid_trigger_term(it, body))
+        .map(|it| {
+            let mentioned = it
+                .syntax()
+                .descendants()
+                .filter_map(ast::NameRef::cast)
+                .map(|it| it.text().to_string())
+                .filter(|it| vars.contains(it))
+                .collect();
+            (it.syntax().to_string(), mentioned)
+        })
+        .filter(|(_, mentioned): &(String, FxHashSet<String>)| !mentioned.is_empty())
+        .unique_by(|(term, _)| term.split_whitespace().join(""))
+        .collect();
+
+    let singles: Vec<Vec<String>> = terms
+        .iter()
+        .filter(|(_, mentioned)| mentioned == vars)
+        .map(|(term, _)| vec![term.clone()])
+        .collect();
+    if !singles.is_empty() {
+        return singles;
+    }
+    terms
+        .iter()
+        .tuple_combinations()
+        .filter(|((_, lhs), (_, rhs))| lhs.union(rhs).count() == vars.len())
+        .map(|((lhs, _), (rhs, _))| vec![lhs.clone(), rhs.clone()])
+        .collect()
+}
+
+/// Verus rejects a trigger with a boolean or arithmetic operator, or a variable bound inside the
+/// quantifier.
+fn is_valid_trigger_term(term: &ast::Expr, body: &ast::Expr) -> bool {
+    let under_closure = term
+        .syntax()
+        .ancestors()
+        .take_while(|it| it != body.syntax())
+        .any(|it| ast::ClosureExpr::can_cast(it.kind()));
+    let has_operator = term.syntax().descendants().filter_map(ast::BinExpr::cast).any(|it| {
+        matches!(
+            it.op_kind(),
+            Some(ast::BinaryOp::LogicOp(_) | ast::BinaryOp::CmpOp(_) | ast::BinaryOp::ArithOp(_))
+        )
+    });
+    !under_closure && !has_operator
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{check_assist_by_label, check_assist_not_applicable};
+
+    #[test]
+    fn single_term() {
+        check_assist_by_label(
+            select_trigger,
+            "
+use vstd::prelude::*;
+spec fn f(x: int, y: int) -> bool;
+
+proof fn foo(s: Seq<int>) {
+    assume(forall|i: int, j: int| 0 <= i < j < s.len() ==> f$0(s[i], s[j]));
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+spec fn f(x: int, y: int) -> bool;
+
+proof fn foo(s: Seq<int>) {
+    assume(forall|i: int, j: int| #![trigger f(s[i], s[j])] 0 <= i < j < s.len() ==> f(s[i], s[j]));
+}
+
+fn main() {}
+",
+            "Use `f(s[i], s[j])` as the trigger",
+        );
+    }
+
+    #[test]
+    fn pair_of_terms() {
+        check_assist_by_label(
+            select_trigger,
+            "
+use vstd::prelude::*;
+spec fn f(x: int) -> bool;
+spec fn g(x: int) -> bool;
+
+proof fn foo() {
+    assume(for$0all|i: int, j: int| f(i) && g(j) ==> f(j));
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+spec fn f(x: int) -> bool;
+spec fn g(x: int) -> bool;
+
+proof fn foo() {
+    assume(forall|i: int, j: int| #![trigger f(i), g(j)] f(i) && g(j) ==> f(j));
+}
+
+fn main() {}
+",
+            "Use `f(i), g(j)` as the trigger",
+        );
+    }
+
+    #[test]
+    fn explicit_trigger() {
+        check_assist_not_applicable(
+            select_trigger,
+            "
+use vstd::prelude::*;
+spec fn f(x: int) -> bool;
+
+proof fn foo() {
+    assume(for$0all|i: int| #[trigger] f(i));
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn arithmetic_in_term() {
+        check_assist_not_applicable(
+            select_trigger,
+            "
+use vstd::prelude::*;
+spec fn f(x: int) -> bool;
+
+proof fn foo() {
+    assume(for$0all|i: int| f(i + 1));
+}
+
+fn main() {}
+",
+        );
+    }
+}
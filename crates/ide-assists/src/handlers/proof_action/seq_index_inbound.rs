@@ -2,7 +2,11 @@ use crate::{
     assist_context::{AssistContext, Assists},
     AssistId, AssistKind,
 };
-use syntax::ast::{self, vst::*, AstNode, LogicOp};
+use itertools::Itertools;
+use syntax::{
+    ast::{self, vst::*, AstNode, HasArgList, LogicOp},
+    T,
+};
 
 pub(crate) fn seq_index_inbound(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
     // if the function name is not inside an assertForallExpr, return None
@@ -83,11 +87,146 @@ pub(crate) fn vst_rewriter_seq_index_inbound(
     Some(assert_forall)
 }
 
+/// Proof action: state the bounds of the sequence indexing in a failing assertion
+/// assert(s.subrange(1, 3)[k] > 0);
+/// into
+/// assert(s.subrange(1, 3).len() == 3 - 1);
+/// assert(0 <= k < s.subrange(1, 3).len());
+/// assert(s.subrange(1, 3)[k] == s[1 + k]);
+/// assert(s.subrange(1, 3)[k] > 0);
+///
+/// Each indexing site of the assertion gets a bounds assertion, inner sites first, so that
+/// `s[i][j]` needs `i` in bounds of `s` before `j` is in bounds of `s[i]`. When the indexed
+/// sequence is built by `subrange`, `push` or `update`, the instances of the `Seq` axioms about
+/// its length and its elements come along. Sites under a quantifier are left alone, as their
+/// variables are not bound before the assertion.
+pub(crate) fn seq_index_bounds(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "assert"
+    let _ = ctx.at_this_token(T![assert])?;
+
+    // retrieve the assertion of interest, along with its statement
+    let expr: ast::AssertExpr = ctx.find_node_at_offset()?;
+    let stmt = ast::ExprStmt::cast(expr.syntax().parent()?)?;
+    let pred = expr.expr()?;
+
+    let facts: Vec<String> = index_sites(&pred)
+        .iter()
+        .flat_map(|it| index_facts(it).unwrap_or_default())
+        .unique()
+        .collect();
+    if facts.is_empty() {
+        return None;
+    }
+
+    // only worth it when the assertion fails
+    let assert = AssertExpr::try_from(expr.clone()).ok()?;
+    let this_fn = ctx.vst_find_node_at_offset::<Fn, ast::Fn>()?;
+    if !ctx.try_verus(&this_fn)?.is_failing(&AssertExpr::new(*assert.expr)) {
+        return None;
+    }
+
+    let result = format!("{}\n{stmt}", facts.join("\n"));
+    let result = ctx.fmt(stmt.clone(), result)?; // pretty-print
+    acc.add(
+        AssistId("seq_index_bounds", AssistKind::RefactorRewrite),
+        "Insert bounds assertions for sequence indexing",
+        expr.syntax().text_range(),
+        |edit| {
+            edit.replace(stmt.syntax().text_range(), result);
+        },
+    )
+}
+
+/// The indexing sites of `pred` out of quantifiers, inner ones first.
+fn index_sites(pred: &ast::Expr) -> Vec<ast::IndexExpr> {
+    let mut sites: Vec<ast::IndexExpr> = pred
+        .syntax()
+        .descendants()
+        .filter_map(ast::IndexExpr::cast)
+        .filter(|it| {
+            !it.syntax()
+                .ancestors()
+                .take_while(|it| it != pred.syntax())
+                .filter_map(ast::ClosureExpr::cast)
+                .any(|it| it.forall_token().is_some() || it.exists_token().is_some())
+        })
+        .collect();
+    sites.sort_by_key(|it| {
+        let range = it.syntax().text_range();
+        (range.end(), std::cmp::Reverse(range.start()))
+    });
+    sites
+}
+
+/// The assertions stating that `site` is in bounds, along with the `Seq` axioms about the
+/// sequence it indexes.
+fn index_facts(site: &ast::IndexExpr) -> Option<Vec<String>> {
+    let base = site.base()?;
+    let idx = text_of(&site.index()?);
+    let seq = text_of(&base);
+    let elem = format!("{seq}[{idx}]");
+    let mut facts = vec![];
+    let mut elem_facts = vec![];
+    if let ast::Expr::MethodCallExpr(call) = &base {
+        let receiver = text_of(&call.receiver()?);
+        let args: Vec<ast::Expr> = call.arg_list()?.args().collect();
+        match (call.name_ref()?.text().as_str(), args.as_slice()) {
+            ("subrange", [start, end]) => {
+                facts.push(format!("assert({seq}.len() == {} - {});", paren(end), paren(start)));
+                elem_facts.push(format!(
+                    "assert({elem} == {receiver}[{} + {}]);",
+                    paren(start),
+                    paren(&site.index()?)
+                ));
+            }
+            ("push", [value]) => {
+                let value = text_of(value);
+                facts.push(format!("assert({seq}.len() == {receiver}.len() + 1);"));
+                elem_facts.push(format!(
+                    "assert({idx} < {receiver}.len() ==> {elem} == {receiver}[{idx}]);"
+                ));
+                elem_facts
+                    .push(format!("assert({idx} == {receiver}.len() ==> {elem} == {value});"));
+            }
+            ("update", [at, value]) => {
+                let (at, value) = (text_of(at), text_of(value));
+                facts.push(format!("assert({seq}.len() == {receiver}.len());"));
+                elem_facts.push(format!("assert({idx} == {at} ==> {elem} == {value});"));
+                elem_facts.push(format!("assert({idx} != {at} ==> {elem} == {receiver}[{idx}]);"));
+            }
+            _ => (),
+        }
+    }
+    facts.push(format!("assert(0 <= {idx} < {seq}.len());"));
+    facts.extend(elem_facts);
+    Some(facts)
+}
+
+fn text_of(expr: &ast::Expr) -> String {
+    expr.syntax().to_string().split_whitespace().join(" ")
+}
+
+/// The text of `expr`, parenthesized unless it is atomic.
+fn paren(expr: &ast::Expr) -> String {
+    match expr {
+        ast::Expr::PathExpr(_)
+        | ast::Expr::Literal(_)
+        | ast::Expr::ParenExpr(_)
+        | ast::Expr::CallExpr(_)
+        | ast::Expr::MethodCallExpr(_)
+        | ast::Expr::IndexExpr(_) => text_of(expr),
+        _ => format!("({})", text_of(expr)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::tests::check_assist;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist, check_assist_not_applicable, check_assist_with_verifier},
+    };
 
     #[test]
     fn test_seq_index_inbound1() {
@@ -125,6 +264,105 @@ proof fn test_seq_in_bound() {
     assert forall|i: int| 0 <= i && i < s2.len() implies s2[i] < 40 by {}
     assert forall|i: int| s2[i] < 40 by{};
 }
+",
+        )
+    }
+    #[test]
+    fn nested_index() {
+        check_assist_with_verifier(
+            seq_index_bounds,
+            MockVerifier::failing(&["assert(s[i][j] > 0)"]),
+            "
+use vstd::prelude::*;
+proof fn foo(s: Seq<Seq<int>>, i: int, j: int) {
+    ass$0ert(s[i][j] > 0);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn foo(s: Seq<Seq<int>>, i: int, j: int) {
+    assert(0 <= i < s.len());
+    assert(0 <= j < s[i].len());
+    assert(s[i][j] > 0);
+}
+
+fn main() {}
+",
+        )
+    }
+
+    #[test]
+    fn subrange_index() {
+        check_assist_with_verifier(
+            seq_index_bounds,
+            MockVerifier::failing(&["assert(s.subrange(1, 3)[k] == s[k + 1])"]),
+            "
+use vstd::prelude::*;
+proof fn foo(s: Seq<int>, k: int) {
+    ass$0ert(s.subrange(1, 3)[k] == s[k + 1]);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn foo(s: Seq<int>, k: int) {
+    assert(s.subrange(1, 3).len() == 3 - 1);
+    assert(0 <= k < s.subrange(1, 3).len());
+    assert(s.subrange(1, 3)[k] == s[1 + k]);
+    assert(0 <= k + 1 < s.len());
+    assert(s.subrange(1, 3)[k] == s[k + 1]);
+}
+
+fn main() {}
+",
+        )
+    }
+
+    #[test]
+    fn update_index() {
+        check_assist_with_verifier(
+            seq_index_bounds,
+            MockVerifier::failing(&["assert(s.update(i, 7)[j] >= 0)"]),
+            "
+use vstd::prelude::*;
+proof fn foo(s: Seq<int>, i: int, j: int) {
+    ass$0ert(s.update(i, 7)[j] >= 0);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn foo(s: Seq<int>, i: int, j: int) {
+    assert(s.update(i, 7).len() == s.len());
+    assert(0 <= j < s.update(i, 7).len());
+    assert(j == i ==> s.update(i, 7)[j] == 7);
+    assert(j != i ==> s.update(i, 7)[j] == s[j]);
+    assert(s.update(i, 7)[j] >= 0);
+}
+
+fn main() {}
+",
+        )
+    }
+
+    #[test]
+    fn verified_index() {
+        check_assist_not_applicable(
+            seq_index_bounds,
+            "
+use vstd::prelude::*;
+proof fn foo(s: Seq<int>, i: int)
+    requires
+        0 <= i < s.len(),
+{
+    ass$0ert(s[i] == s[i]);
+}
+
+fn main() {}
 ",
         )
     }
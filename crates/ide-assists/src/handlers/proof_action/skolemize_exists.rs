@@ -0,0 +1,279 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    proof_plumber_api::vst_ext::vst_subst_expr,
+    AssistId, AssistKind,
+};
+use ide_db::{
+    syntax_helpers::node_ext::{
+        enclosing_proof_block, is_in_proof_block, quantifier_without_triggers,
+    },
+    FxHashMap,
+};
+use itertools::Itertools;
+use syntax::{
+    ast::{self, vst::*, AstNode, HasName},
+    SyntaxToken, TextSize,
+};
+
+/// Proof action: choose a witness of an `exists` from the `requires` clause
+/// requires exists|i: int| 0 <= i < s.len() && s[i] == 0,
+/// ...
+/// assert(s.len() > 0);
+/// into (with the cursor on the assertion)
+/// let i_witness = choose|i: int| 0 <= i < s.len() && s[i] == 0;
+/// assert(0 <= i_witness < s.len() && s[i_witness] == 0);
+/// assert(s.len() > 0);
+///
+/// Offered anywhere in the body of the function, once for each `exists` of its `requires` clause.
+/// The statements are inserted before the statement under the cursor. The witness is named after
+/// the quantified variable, and is `ghost` outside of proof code. In a `proof { .. }` block, they
+/// are inserted in the block.
+pub(crate) fn skolemize_exists(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // the cursor has to be in a block of the function
+    let stmt_list: ast::StmtList = ctx.find_node_at_offset()?;
+    let func = stmt_list.syntax().ancestors().find_map(ast::Fn::cast)?;
+    let offset = ctx.offset();
+    let proof_block =
+        ctx.token_at_offset().right_biased().and_then(|it| enclosing_proof_block(&it.parent()?));
+    let insert_at = match &proof_block {
+        Some(proof) => proof_block_stmt_start(proof, offset)?,
+        None => stmt_list
+            .syntax()
+            .children()
+            .find(|it| it.text_range().contains(offset))
+            .map_or(offset, |it| it.text_range().start()),
+    };
+    let let_token = if proof_block.is_some() || is_in_proof_block(stmt_list.syntax()) {
+        "let"
+    } else {
+        "let ghost"
+    };
+
+    let exists = func.requires_clause()?.exprs().filter_map(|it| match it {
+        ast::Expr::ClosureExpr(closure) if closure.exists_token().is_some() => Some(closure),
+        _ => None,
+    });
+    for closure in exists {
+        let Some((witnesses, stmts)) = choose_witness(ctx, &closure, let_token) else { continue };
+        let Some(result) = insert_into(ctx, &stmt_list, insert_at, &stmts) else { continue };
+        acc.add(
+            AssistId("skolemize_exists", AssistKind::RefactorRewrite),
+            format!("Choose a witness `{}`", witnesses.join(", ")),
+            stmt_list.syntax().text_range(),
+            |edit| {
+                edit.replace(stmt_list.syntax().text_range(), result);
+            },
+        );
+    }
+    Some(())
+}
+
+/// The predicate of the quantifier `exists`, asserted on the witnesses named `witnesses`.
+pub(crate) fn vst_rewriter_skolemize_exists(
+    ctx: &AssistContext<'_>,
+    exists: &ClosureExpr,
+    witnesses: &[String],
+) -> Option<AssertExpr> {
+    let params = &exists.param_list.as_ref()?.params;
+    let mut subst = FxHashMap::default();
+    for (param, witness) in params.iter().zip(witnesses) {
+        let Some(Pat::IdentPat(pat)) = param.pat.as_deref() else { return None };
+        subst.insert(pat.name.to_string().trim().to_owned(), ctx.vst_expr_from_text(witness)?);
+    }
+    let pred = vst_subst_expr(*exists.body.clone(), &subst).ok()?;
+    Some(AssertExpr::new(pred))
+}
+
+/// The names of the witnesses of `exists`, along with the statements choosing them and asserting
+/// its predicate on them.
+fn choose_witness(
+    ctx: &AssistContext<'_>,
+    exists: &ast::ClosureExpr,
+    let_token: &str,
+) -> Option<(Vec<String>, String)> {
+    let param_list = exists.param_list()?;
+    let witnesses: Vec<String> = param_list
+        .params()
+        .map(|param| match param.pat()? {
+            ast::Pat::IdentPat(pat) => Some(format!("{}_witness", pat.name()?)),
+            _ => None,
+        })
+        .collect::<Option<_>>()?;
+    if witnesses.is_empty() {
+        return None;
+    }
+
+    // triggers are kept in `choose`, but not in the assertion
+    let v_exists = ClosureExpr::try_from(quantifier_without_triggers(exists)).ok()?;
+    let assert = vst_rewriter_skolemize_exists(ctx, &v_exists, &witnesses)?;
+    let pattern = match witnesses.as_slice() {
+        [witness] => witness.clone(),
+        _ => format!("({})", witnesses.iter().join(", ")),
+    };
+    let choose = format!("choose{} {}", param_list.syntax(), exists.body()?.syntax());
+    Some((witnesses, format!("{let_token} {pattern} = {choose};\n{assert};\n")))
+}
+
+/// The start of the statement at `offset` in the `proof { .. }` block of the token `proof`, or
+/// `offset` between two statements. The block parses as a record expression, so its statements
+/// are told apart by the `;` between them.
+fn proof_block_stmt_start(proof: &SyntaxToken, offset: TextSize) -> Option<TextSize> {
+    let fields =
+        proof.parent_ancestors().find_map(ast::RecordExpr::cast)?.record_expr_field_list()?;
+    let mut start = None;
+    for element in fields.syntax().children_with_tokens() {
+        if element.text_range().start() > offset {
+            break;
+        }
+        if matches!(element.to_string().trim(), "{" | ";") {
+            start = None;
+        } else if start.is_none() && !element.kind().is_trivia() {
+            start = Some(element.text_range().start());
+        }
+    }
+    Some(start.unwrap_or(offset))
+}
+
+/// The text of `stmt_list`, pretty-printed, with `stmts` inserted at `offset`.
+fn insert_into(
+    ctx: &AssistContext<'_>,
+    stmt_list: &ast::StmtList,
+    offset: TextSize,
+    stmts: &str,
+) -> Option<String> {
+    let range = stmt_list.syntax().text_range();
+    let mut text = stmt_list.syntax().to_string();
+    text.insert_str(offset.checked_sub(range.start())?.into(), stmts);
+    ctx.fmt(stmt_list.clone(), text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    #[test]
+    fn choose_in_proof_fn() {
+        check_assist(
+            skolemize_exists,
+            "
+use vstd::prelude::*;
+proof fn foo(s: Seq<int>)
+    requires
+        s.len() < 10,
+        exists|i: int| 0 <= i < s.len() && #[trigger] s[i] == 0,
+{
+    $0assert(s.len() > 0);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn foo(s: Seq<int>)
+    requires
+        s.len() < 10,
+        exists|i: int| 0 <= i < s.len() && #[trigger] s[i] == 0,
+{
+    let i_witness = choose|i: int| 0 <= i < s.len() && #[trigger] s[i] == 0;
+    assert(0 <= i_witness < s.len() && s[i_witness] == 0);
+    assert(s.len() > 0);
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn choose_pair_in_exec_fn() {
+        check_assist(
+            skolemize_exists,
+            "
+use vstd::prelude::*;
+spec fn f(x: int, y: int) -> bool;
+
+fn bar(n: u64)
+    requires
+        exists|x: int, y: int| f(x, y) && x < y,
+{
+    let m = n / 2;
+    $0let k = m + 1;
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+spec fn f(x: int, y: int) -> bool;
+
+fn bar(n: u64)
+    requires
+        exists|x: int, y: int| f(x, y) && x < y,
+{
+    let m = n / 2;
+    let ghost (x_witness, y_witness) = choose|x: int, y: int| f(x, y) && x < y;
+    assert(f(x_witness, y_witness) && x_witness < y_witness);
+    let k = m + 1;
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn choose_in_proof_block() {
+        check_assist(
+            skolemize_exists,
+            "
+use vstd::prelude::*;
+fn baz(v: Vec<u64>)
+    requires
+        exists|i: int| 0 <= i < v.len() && #[trigger] v[i] > 0,
+{
+    let n = v.len();
+    proof {
+        $0assert(n > 0);
+    }
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+fn baz(v: Vec<u64>)
+    requires
+        exists|i: int| 0 <= i < v.len() && #[trigger] v[i] > 0,
+{
+    let n = v.len();
+    proof {
+        let i_witness = choose|i: int| 0 <= i < v.len() && #[trigger] v[i] > 0;
+        assert(0 <= i_witness < v.len() && v[i_witness] > 0);
+        assert(n > 0);
+    }
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn no_exists() {
+        check_assist_not_applicable(
+            skolemize_exists,
+            "
+use vstd::prelude::*;
+proof fn foo(s: Seq<int>)
+    requires
+        forall|i: int| 0 <= i < s.len() ==> #[trigger] s[i] == 0,
+{
+    $0assert(s.len() >= 0);
+}
+
+fn main() {}
+",
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    AssistId, AssistKind,
+};
+use itertools::Itertools;
+use syntax::{
+    ast::{self, vst::*, AstNode, LogicOp},
+    T,
+};
+
+/// Proof action: split a conjunctive assertion
+/// assert(A && B && C);
+/// into
+/// assert(A);
+/// assert(B);
+/// assert(C);
+///
+/// `&&&` chains are split alike. Once the action is picked, Verus is run on the split assertions,
+/// and the ones that fail are marked with a comment.
+pub(crate) fn split_conjunctive_assert(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "assert"
+    let _ = ctx.at_this_token(T![assert])?;
+
+    // retrieve the assertion of interest, along with its statement
+    let expr: ast::AssertExpr = ctx.find_node_at_offset()?;
+    let stmt = ast::ExprStmt::cast(expr.syntax().parent()?)?;
+
+    // lift CST into TOST node
+    let assert: AssertExpr = AssertExpr::try_from(expr.clone()).ok()?;
+    let split_asserts = vst_rewriter_split_conjunctive_assert(assert.clone())?;
+
+    acc.add(
+        AssistId("split_conjunctive_assert", AssistKind::RefactorRewrite),
+        "Split conjunction into separate assertions",
+        stmt.syntax().text_range(),
+        |edit| {
+            // Run Verus only when the user explicitly wants it
+            let failing = failing_asserts(ctx, &assert, &split_asserts)
+                .unwrap_or_else(|| vec![false; split_asserts.len()]);
+            let result = split_asserts
+                .iter()
+                .zip(failing)
+                .map(|(it, failing)| {
+                    let comment = if failing { " // assertion failed" } else { "" };
+                    format!("{it};{comment}")
+                })
+                .join("\n");
+            let result = ctx.fmt(stmt.clone(), result).expect("fmt"); // pretty-print
+            edit.replace(stmt.syntax().text_range(), result);
+        },
+    )
+}
+
+pub(crate) fn vst_rewriter_split_conjunctive_assert(assert: AssertExpr) -> Option<Vec<AssertExpr>> {
+    // an assertion with a proof is left alone
+    if assert.by_token {
+        return None;
+    }
+    let mut conjuncts = vec![];
+    collect_conjuncts(*assert.expr, &mut conjuncts);
+    if conjuncts.len() < 2 {
+        return None;
+    }
+    Some(conjuncts.into_iter().map(AssertExpr::new).collect())
+}
+
+fn collect_conjuncts(expr: Expr, acc: &mut Vec<Expr>) {
+    match expr {
+        Expr::BinExpr(be) if be.op == BinaryOp::LogicOp(LogicOp::And) => {
+            collect_conjuncts(*be.lhs, acc);
+            collect_conjuncts(*be.rhs, acc);
+        }
+        Expr::ParenExpr(pe) => collect_conjuncts(*pe.expr, acc),
+        _ => acc.push(expr),
+    }
+}
+
+/// Whether each of `split_asserts` fails once they replace `assert` in the current function.
+fn failing_asserts(
+    ctx: &AssistContext<'_>,
+    assert: &AssertExpr,
+    split_asserts: &[AssertExpr],
+) -> Option<Vec<bool>> {
+    let this_fn = ctx.vst_find_node_at_offset::<Fn, ast::Fn>()?;
+    let mut stmts = StmtList::new();
+    stmts.statements = split_asserts.iter().map(|it| it.clone().into()).collect();
+    let split_fn = ctx.replace_statement(&this_fn, assert.clone(), BlockExpr::new(stmts))?;
+    let verif_result = ctx.try_verus(&split_fn)?;
+    Some(split_asserts.iter().map(|it| verif_result.is_failing(it)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist, check_assist_not_applicable, check_assist_with_verifier},
+    };
+
+    #[test]
+    fn split_conjunction() {
+        check_assist(
+            split_conjunctive_assert,
+            "
+use vstd::prelude::*;
+proof fn foo(a: int, b: int)
+    requires
+        a > 10,
+        b > 0,
+{
+    ass$0ert(a > 10 && (b > 0 && a + b > 10));
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn foo(a: int, b: int)
+    requires
+        a > 10,
+        b > 0,
+{
+    assert(a > 10);
+    assert(b > 0);
+    assert(a + b > 10);
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn mark_failing_conjuncts() {
+        check_assist_with_verifier(
+            split_conjunctive_assert,
+            MockVerifier::failing(&["assert(b > 0)", "assert(a + b > 10)"]),
+            "
+use vstd::prelude::*;
+proof fn foo(a: int, b: int)
+    requires
+        a > 10,
+{
+    ass$0ert(a > 10 &&& b > 0 &&& a + b > 10);
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+proof fn foo(a: int, b: int)
+    requires
+        a > 10,
+{
+    assert(a > 10);
+    assert(b > 0); // assertion failed
+    assert(a + b > 10); // assertion failed
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn not_a_conjunction() {
+        check_assist_not_applicable(
+            split_conjunctive_assert,
+            "
+use vstd::prelude::*;
+proof fn foo(a: int, b: int) {
+    ass$0ert(a > 10 || b > 0);
+}
+
+fn main() {}
+",
+        );
+    }
+}
@@ -0,0 +1,272 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    proof_plumber_api::vst_ext::vst_parenthesize,
+    AssistId, AssistKind,
+};
+use ide_db::FxHashSet;
+use itertools::Itertools;
+use syntax::{
+    ast::{self, vst::*, AstNode, HasName},
+    T,
+};
+
+/// Proof action: weaken a failing postcondition
+/// fn foo(x: int) -> (r: int)
+///     ensures
+///         r == 0,
+/// {
+///     if x > 10 { 0 } else { x }
+/// }
+/// into
+/// fn foo(x: int) -> (r: int)
+///     ensures
+///         x > 10 ==> r == 0,
+/// {
+///     if x > 10 { 0 } else { x }
+/// }
+///
+/// The weakenings of a postcondition are: dropping one of its conjuncts, turning an `==` among
+/// them into `<=` or `>=`, and making it conditional on the condition of an `if` of the body, or
+/// on its negation, when it only mentions parameters.
+/// All of them are checked in a single Verus run, and each that makes the function verify is
+/// offered.
+pub(crate) fn weaken_ensures(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "ensures"
+    let _ = ctx.at_this_token(T![ensures])?;
+    let func: ast::Fn = ctx.find_node_at_offset()?;
+    let ensures: ast::EnsuresClause = func.ensures_clause()?;
+
+    // lift CST into TOST node
+    let v_func = Fn::try_from(func.clone()).ok()?;
+    let conditions = branch_conditions(&func)?;
+
+    let mut candidates: Vec<(ast::Expr, Expr, Fn)> = vec![];
+    for (idx, post) in ensures.exprs().enumerate() {
+        let v_post = v_func.ensures_clause.as_ref()?.exprs.get(idx)?.clone();
+        for weaker in vst_rewriter_weaken_ensures(v_post, &conditions) {
+            let mut candidate = v_func.clone();
+            candidate.ensures_clause.as_mut()?.exprs[idx] = weaker.clone();
+            candidates.push((post.clone(), weaker, candidate));
+        }
+    }
+    if candidates.is_empty() {
+        return None;
+    }
+
+    // the function as it is, then each candidate, in a single Verus run
+    let mut probes = vec![v_func];
+    probes.extend(candidates.iter().map(|(_, _, it)| it.clone()));
+    let mut results = ctx.try_verus_batch(&probes).into_iter();
+    if results.next()??.is_success {
+        return None;
+    }
+
+    let fn_range = func.syntax().text_range();
+    for ((post, weaker, _), result) in candidates.into_iter().zip(results) {
+        if !result.map_or(false, |it| it.is_success) {
+            continue;
+        }
+        let post_range: std::ops::Range<usize> =
+            post.syntax().text_range().checked_sub(fn_range.start())?.into();
+        let mut fn_text = func.syntax().to_string();
+        fn_text.replace_range(post_range, &weaker.to_string());
+        let Some(result) = ctx.fmt(func.clone(), fn_text) else { continue }; // pretty-print
+        acc.add(
+            AssistId("weaken_ensures", AssistKind::RefactorRewrite),
+            format!("Weaken postcondition to `{}`", weaker.to_string().trim()),
+            ensures.syntax().text_range(),
+            |edit| {
+                edit.replace(fn_range, result);
+            },
+        );
+    }
+    Some(())
+}
+
+/// The weakenings of the postcondition `post`, given the conditions of the branches of the body.
+pub(crate) fn vst_rewriter_weaken_ensures(post: Expr, conditions: &[Expr]) -> Vec<Expr> {
+    let mut conjuncts = vec![];
+    collect_conjuncts(post.clone(), &mut conjuncts);
+    let conjoin = |exprs: Vec<Expr>| {
+        exprs
+            .into_iter()
+            .reduce(|lhs, rhs| BinExpr::new(lhs, BinaryOp::LogicOp(ast::LogicOp::And), rhs).into())
+    };
+
+    let mut weaker: Vec<Expr> = vec![];
+    for (idx, conjunct) in conjuncts.iter().enumerate() {
+        // drop the conjunct
+        if conjuncts.len() > 1 {
+            let mut rest = conjuncts.clone();
+            rest.remove(idx);
+            weaker.extend(conjoin(rest));
+        }
+        // relax the equality
+        let Expr::BinExpr(eq) = conjunct else { continue };
+        if eq.op != BinaryOp::CmpOp(ast::CmpOp::Eq { negated: false }) {
+            continue;
+        }
+        for ordering in [ast::Ordering::Less, ast::Ordering::Greater] {
+            let mut relaxed = eq.clone();
+            relaxed.op = BinaryOp::CmpOp(ast::CmpOp::Ord { ordering, strict: false });
+            let mut relaxed_all = conjuncts.clone();
+            relaxed_all[idx] = Expr::BinExpr(relaxed);
+            weaker.extend(conjoin(relaxed_all));
+        }
+    }
+    // hold in some of the branches only
+    for condition in conditions {
+        let negated = PrefixExpr::new(UnaryOp::Not, vst_parenthesize(condition.clone())).into();
+        for hyp in [condition.clone(), negated] {
+            let imply = BinExpr::new(hyp, BinaryOp::LogicOp(ast::LogicOp::Imply), post.clone());
+            weaker.push(imply.into());
+        }
+    }
+    weaker.into_iter().unique_by(|it| it.to_string()).collect()
+}
+
+fn collect_conjuncts(expr: Expr, acc: &mut Vec<Expr>) {
+    match expr {
+        Expr::BinExpr(be) if be.op == BinaryOp::LogicOp(ast::LogicOp::And) => {
+            collect_conjuncts(*be.lhs, acc);
+            collect_conjuncts(*be.rhs, acc);
+        }
+        _ => acc.push(expr),
+    }
+}
+
+/// The conditions of the `if`s of the body of `func` that only mention its parameters.
+fn branch_conditions(func: &ast::Fn) -> Option<Vec<Expr>> {
+    let params: FxHashSet<String> = func
+        .param_list()?
+        .params()
+        .filter_map(|param| match param.pat()? {
+            ast::Pat::IdentPat(it) => Some(it.name()?.text().to_string()),
+            _ => None,
+        })
+        .collect();
+    let conditions = func
+        .body()?
+        .syntax()
+        .descendants()
+        .filter_map(ast::IfExpr::cast)
+        .filter_map(|it| it.condition())
+        .filter(|it| {
+            let mentioned: FxHashSet<String> = it
+                .syntax()
+                .descendants()
+                .filter_map(ast::NameRef::cast)
+                .map(|it| it.text().to_string())
+                .collect();
+            !ast::LetExpr::can_cast(it.syntax().kind())
+                && !mentioned.is_empty()
+                && mentioned.is_subset(&params)
+        })
+        .filter_map(|it| Expr::try_from(it).ok())
+        .unique_by(|it| it.to_string())
+        .collect();
+    Some(conditions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist_not_applicable, check_assist_with_verifier},
+    };
+
+    #[test]
+    fn drop_conjunct() {
+        check_assist_with_verifier(
+            weaken_ensures,
+            MockVerifier::failing(&["r > 0"]),
+            "
+use vstd::prelude::*;
+fn foo(x: u64) -> (r: u64)
+    ens$0ures
+        r == x && r > 0,
+{
+    x
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+fn foo(x: u64) -> (r: u64)
+    ensures
+        r == x,
+{
+    x
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn condition_of_branch() {
+        check_assist_with_verifier(
+            weaken_ensures,
+            // the postcondition only holds in the `then` branch
+            MockVerifier::new(|item| {
+                let item: String = item.chars().filter(|c| !c.is_whitespace()).collect();
+                if item.contains("x>10==>r==0") {
+                    vec![]
+                } else {
+                    vec!["r == 0".to_owned()]
+                }
+            }),
+            "
+use vstd::prelude::*;
+fn foo(x: int) -> (r: int)
+    ens$0ures
+        r == 0,
+{
+    if x > 10 {
+        0
+    } else {
+        x
+    }
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+fn foo(x: int) -> (r: int)
+    ensures
+        x > 10 ==> r == 0,
+{
+    if x > 10 {
+        0
+    } else {
+        x
+    }
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn verified_postcondition() {
+        check_assist_not_applicable(
+            weaken_ensures,
+            "
+use vstd::prelude::*;
+fn foo(x: u64) -> (r: u64)
+    ens$0ures
+        r == x,
+{
+    x
+}
+
+fn main() {}
+",
+        );
+    }
+}
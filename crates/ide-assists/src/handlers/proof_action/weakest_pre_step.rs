@@ -1,9 +1,14 @@
 use crate::proof_plumber_api::vst_ext::*;
 use crate::{AssistContext, Assists};
-use ide_db::assists::{AssistId, AssistKind};
+use hir::{HirDisplay, PathResolution};
+use ide_db::{
+    assists::{AssistId, AssistKind},
+    FxHashSet,
+};
+use itertools::Itertools;
 use syntax::{
-    ast::{self, vst::*},
-    AstNode, T,
+    ast::{self, vst::*, HasLoopBody},
+    AstNode, SyntaxNode, T,
 };
 
 /*
@@ -290,10 +295,224 @@ pub(crate) fn vst_rewriter_wp_move_assertion(
     return Some(new_stmt_list);
 }
 
+/// Variant of `wp_move_assertion` that moves an assertion at the start of a loop body to just
+/// before the loop
+/// while i < n
+///     invariant
+///         i <= n,
+/// {
+///     assert(i + 1 <= n);
+///     i = i + 1;
+/// }
+/// gets, right before the loop,
+/// assert(forall|i: u64| #![auto] i <= n && i < n ==> i + 1 <= n);
+///
+/// The variables the loop assigns to hold any value allowed by the invariants and the condition
+/// of the loop, so they are quantified over. The original assertion is kept.
+/// Once the action is picked, Verus is run and the moved assertion is marked with a comment if it
+/// fails.
+pub(crate) fn wp_hoist_assertion(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on assert keyword
+    let _ = ctx.at_this_token(T![assert])?;
+    let expr = ctx.find_node_at_offset::<ast::AssertExpr>()?;
+    let stmt = ast::ExprStmt::cast(expr.syntax().parent()?)?;
+
+    // only at the start of the loop body does the assertion see the state of an iteration as is
+    let body_list = ast::StmtList::cast(stmt.syntax().parent()?)?;
+    if body_list.statements().next()?.syntax() != stmt.syntax() {
+        return None;
+    }
+    let loop_expr = body_list.syntax().parent()?.parent()?;
+    let (condition, loop_clauses, body) = if let Some(it) = ast::WhileExpr::cast(loop_expr.clone())
+    {
+        let body = it.loop_body()?;
+        let it = WhileExpr::try_from(it).ok()?;
+        (Some(*it.condition), it.loop_clauses, body)
+    } else {
+        let it = ast::LoopExpr::cast(loop_expr.clone())?;
+        let body = it.loop_body()?;
+        let it = LoopExpr::try_from(it).ok()?;
+        (None, it.loop_clauses, body)
+    };
+    if body.stmt_list()? != body_list {
+        return None;
+    }
+
+    // the loop is either a statement or the tail of the enclosing block
+    let anchor = match loop_expr.parent()? {
+        it if ast::ExprStmt::can_cast(it.kind()) => it,
+        _ => loop_expr.clone(),
+    };
+    let outer = ast::StmtList::cast(anchor.parent()?)?;
+
+    let assertion = AssertExpr::try_from(expr.clone()).ok()?;
+    let binders = loop_binders(ctx, &loop_expr, &expr)?;
+    let hoisted =
+        vst_rewriter_wp_hoist_assertion(ctx, &assertion, condition, &loop_clauses, &binders)?;
+
+    acc.add(
+        AssistId("hoist_assertion_above_loop", AssistKind::RefactorRewrite),
+        "Move assertion above the loop",
+        expr.syntax().text_range(),
+        |edit| {
+            // Run Verus only when the user explicitly wants it
+            let failing = hoisted_fails(ctx, &outer, &anchor, &hoisted).unwrap_or(false);
+            let comment = if failing { " // assertion failed" } else { "" };
+            let range = outer.syntax().text_range();
+            let offset: usize = (anchor.text_range().start() - range.start()).into();
+            let mut text = outer.syntax().to_string();
+            text.insert_str(offset, &format!("{hoisted};{comment}\n"));
+            let result = ctx.fmt(outer.clone(), text).expect("fmt"); // pretty-print
+            edit.replace(range, result);
+        },
+    )
+}
+
+// use only VST in the rewriter
+pub(crate) fn vst_rewriter_wp_hoist_assertion(
+    ctx: &AssistContext<'_>,
+    assertion: &AssertExpr,
+    condition: Option<Expr>,
+    loop_clauses: &[LoopClause],
+    binders: &[(String, String)],
+) -> Option<AssertExpr> {
+    // what is known at the start of an iteration: the invariants and the condition
+    let mut known: Vec<Expr> = vec![];
+    for clause in loop_clauses {
+        match clause {
+            LoopClause::InvariantClause(it) => known.extend(it.exprs.iter().cloned()),
+            LoopClause::InvariantExceptBreakClause(it) => known.extend(it.exprs.iter().cloned()),
+            _ => (),
+        }
+    }
+    known.extend(condition);
+    let known: Vec<Expr> = known
+        .into_iter()
+        .map(|e| {
+            // `||` and `==>` bind looser than the `&&` joining them
+            let is_loose = matches!(&e, Expr::BinExpr(be)
+                if matches!(be.op, BinaryOp::LogicOp(op) if op != ast::LogicOp::And));
+            if is_loose {
+                ParenExpr::new(e).into()
+            } else {
+                e
+            }
+        })
+        .collect();
+
+    let pred: Expr = match ctx.reduce_exprs(known) {
+        Some(known) => {
+            BinExpr::new(known, BinaryOp::LogicOp(ast::LogicOp::Imply), *assertion.expr.clone())
+                .into()
+        }
+        None => *assertion.expr.clone(),
+    };
+    if binders.is_empty() {
+        return Some(AssertExpr::new(pred));
+    }
+    let binders = binders.iter().map(|(name, ty)| format!("{name}: {ty}")).join(", ");
+    let forall = ctx.vst_expr_from_text(&format!("forall|{binders}| #![auto] {pred}"))?;
+    Some(AssertExpr::new(forall))
+}
+
+/// The variables mutated in `loop_expr` that its clauses, its condition or `assertion` mention,
+/// along with their types, in order of mutation.
+fn loop_binders(
+    ctx: &AssistContext<'_>,
+    loop_expr: &SyntaxNode,
+    assertion: &ast::AssertExpr,
+) -> Option<Vec<(String, String)>> {
+    let mut mentioned: Vec<SyntaxNode> =
+        loop_expr.children().filter(|it| !ast::BlockExpr::can_cast(it.kind())).collect();
+    mentioned.push(assertion.syntax().clone());
+    let mentioned: FxHashSet<String> = mentioned
+        .iter()
+        .flat_map(|it| it.descendants().filter_map(ast::NameRef::cast))
+        .map(|it| it.text().to_string())
+        .collect();
+
+    let mut binders: Vec<(String, String)> = vec![];
+    for path in loop_expr.descendants().filter_map(|it| mutated_path(ctx, it)) {
+        let name = path.syntax().to_string();
+        if !mentioned.contains(&name) || binders.iter().any(|(it, _)| *it == name) {
+            continue;
+        }
+        let Some(PathResolution::Local(local)) = ctx.sema.resolve_path(&path) else { continue };
+        let pat = local.primary_source(ctx.db()).into_ident_pat()?;
+        let declared = pat.syntax().parent().and_then(|it| {
+            if let Some(param) = ast::Param::cast(it.clone()) {
+                param.ty()
+            } else {
+                ast::LetStmt::cast(it)?.ty()
+            }
+        });
+        let ty = match declared {
+            Some(ty) => ty.to_string(),
+            None => {
+                let ty = local.ty(ctx.db());
+                if ty.contains_unknown() {
+                    return None;
+                }
+                ty.display(ctx.db()).to_string()
+            }
+        };
+        binders.push((name, ty));
+    }
+    Some(binders)
+}
+
+/// The path of the variable `node` mutates: the left-hand side of an assignment, a `&mut`
+/// borrow, or the receiver of a method taking `&mut self`.
+fn mutated_path(ctx: &AssistContext<'_>, node: SyntaxNode) -> Option<ast::Path> {
+    let place = if let Some(assign) = ast::BinExpr::cast(node.clone()) {
+        if !matches!(assign.op_kind(), Some(ast::BinaryOp::Assignment { .. })) {
+            return None;
+        }
+        assign.lhs()?
+    } else if let Some(borrow) = ast::RefExpr::cast(node.clone()) {
+        borrow.mut_token()?;
+        borrow.expr()?
+    } else {
+        let call = ast::MethodCallExpr::cast(node)?;
+        let self_param = ctx.sema.resolve_method_call(&call)?.self_param(ctx.db())?;
+        if self_param.access(ctx.db()) != hir::Access::Exclusive {
+            return None;
+        }
+        call.receiver()?
+    };
+    match place {
+        ast::Expr::PathExpr(it) => it.path(),
+        _ => None,
+    }
+}
+
+/// Whether `hoisted` fails once put before `anchor`, in the body of the current function.
+/// `None` if the loop is in a nested block.
+fn hoisted_fails(
+    ctx: &AssistContext<'_>,
+    outer: &ast::StmtList,
+    anchor: &SyntaxNode,
+    hoisted: &AssertExpr,
+) -> Option<bool> {
+    let func = outer.syntax().ancestors().find_map(ast::Fn::cast)?;
+    if func.body()?.stmt_list()? != *outer {
+        return None;
+    }
+    let mut this_fn = ctx.vst_find_node_at_offset::<Fn, ast::Fn>()?;
+    let stmts = &mut this_fn.body.as_mut()?.stmt_list.statements;
+    let index = outer.statements().position(|it| it.syntax() == anchor).unwrap_or(stmts.len());
+    stmts.insert(index, hoisted.clone().into());
+    let verif_result = ctx.try_verus(&this_fn)?;
+    Some(verif_result.is_failing(hoisted))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::check_assist;
+    use crate::{
+        proof_plumber_api::verifier::MockVerifier,
+        tests::{check_assist, check_assist_not_applicable, check_assist_with_verifier},
+    };
 
     // TEST: let-binding
     #[test]
@@ -659,6 +878,152 @@ proof fn lemma_fibo_is_monotonic(i: nat, j: nat)
     assert(fibo(i) <= fibo(j));
 }
 
+"#,
+        );
+    }
+
+    // TEST: hoist out of a while loop
+    #[test]
+    fn wp_hoist_while() {
+        check_assist(
+            wp_hoist_assertion,
+            r#"
+fn foo(n: u64)
+{
+    let mut i: u64 = 0;
+    while i < n
+        invariant
+            i <= n,
+    {
+        ass$0ert(i + 1 <= n);
+        i = i + 1;
+    }
+}
+"#,
+            r#"
+fn foo(n: u64)
+{
+    let mut i: u64 = 0;
+    assert(forall|i: u64| #![auto] i <= n && i < n ==> i + 1 <= n);
+    while i < n
+        invariant
+            i <= n,
+    {
+        assert(i + 1 <= n);
+        i = i + 1;
+    }
+}
+
+"#,
+        );
+    }
+
+    // TEST: hoist out of a while loop mutating through a `&mut` borrow
+    #[test]
+    fn wp_hoist_mut_borrow() {
+        check_assist(
+            wp_hoist_assertion,
+            r#"
+fn bump(x: &mut u64)
+{
+}
+
+fn foo(n: u64)
+{
+    let mut i: u64 = 0;
+    while i < n
+        invariant
+            i <= n,
+    {
+        ass$0ert(i + 1 <= n);
+        bump(&mut i);
+    }
+}
+"#,
+            r#"
+fn bump(x: &mut u64)
+{
+}
+
+fn foo(n: u64)
+{
+    let mut i: u64 = 0;
+    assert(forall|i: u64| #![auto] i <= n && i < n ==> i + 1 <= n);
+    while i < n
+        invariant
+            i <= n,
+    {
+        assert(i + 1 <= n);
+        bump(&mut i);
+    }
+}
+
+"#,
+        );
+    }
+
+    // TEST: hoist out of a while loop, failing
+    #[test]
+    fn wp_hoist_failing() {
+        check_assist_with_verifier(
+            wp_hoist_assertion,
+            MockVerifier::failing(&["forall|i: u64, j: u64|"]),
+            r#"
+fn foo(n: u64)
+{
+    let mut i: u64 = 0;
+    let mut j: u64 = 0;
+    while i < n
+        invariant
+            j <= i,
+            i <= n,
+    {
+        ass$0ert(j + 1 < n);
+        i = i + 1;
+        j = j + 1;
+    };
+    assert(j <= n);
+}
+"#,
+            r#"
+fn foo(n: u64)
+{
+    let mut i: u64 = 0;
+    let mut j: u64 = 0;
+    assert(forall|i: u64, j: u64| #![auto] j <= i && i <= n && i < n ==> j + 1 < n); // assertion failed
+    while i < n
+        invariant
+            j <= i,
+            i <= n,
+    {
+        assert(j + 1 < n);
+        i = i + 1;
+        j = j + 1;
+    };
+    assert(j <= n);
+}
+
+"#,
+        );
+    }
+
+    // TEST: hoist, not at the start of the loop body
+    #[test]
+    fn wp_hoist_after_assignment() {
+        check_assist_not_applicable(
+            wp_hoist_assertion,
+            r#"
+fn foo(n: u64)
+{
+    let mut i: u64 = 0;
+    while i < n
+        invariant
+            i <= n,
+    {
+        i = i + 1;
+        ass$0ert(i <= n);
+    }
+}
 "#,
         );
     }
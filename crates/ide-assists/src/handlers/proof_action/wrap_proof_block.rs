@@ -0,0 +1,366 @@
+use crate::{
+    assist_context::{AssistContext, Assists},
+    AssistId, AssistKind,
+};
+use hir::{ModuleDef, PathResolution};
+use ide_db::syntax_helpers::node_ext::{is_in_proof_block, is_in_spec_code};
+use syntax::{
+    ast::{self, AstNode, HasName},
+    Direction, Edition, SourceFile, SyntaxKind, SyntaxToken, TextRange, TextSize, T,
+};
+
+/// Proof action: wrap the selected ghost statements of an exec function into a `proof` block
+/// let ghost z = x;
+/// assert(z > 0);
+/// into
+/// proof {
+///     let z = x;
+///     assert(z > 0);
+/// }
+///
+/// The statements are assertions, assumptions, calls to proof functions and ghost variables, the
+/// latter losing their `ghost`. The action is offered once the function with the `proof` block
+/// parses and verifies.
+pub(crate) fn wrap_in_proof_block(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on a selection of statements
+    if ctx.has_empty_selection() {
+        return None;
+    }
+    let selection = ctx.selection_trimmed();
+    let func: ast::Fn = ctx.find_node_at_offset()?;
+    let stmt_list = ctx.covering_element().ancestors().find_map(ast::StmtList::cast)?;
+    if !is_exec(&func)
+        || is_in_proof_block(stmt_list.syntax())
+        || is_in_spec_code(stmt_list.syntax())
+    {
+        return None;
+    }
+    let stmts: Vec<ast::Stmt> = stmt_list
+        .statements()
+        .filter(|it| it.syntax().text_range().intersect(selection).is_some())
+        .collect();
+    if stmts.is_empty()
+        || stmts.iter().any(|it| !selection.contains_range(it.syntax().text_range()))
+        || !stmts.iter().all(|it| is_ghost_stmt(ctx, it))
+    {
+        return None;
+    }
+    let range = TextRange::new(
+        stmts.first()?.syntax().text_range().start(),
+        stmts.last()?.syntax().text_range().end(),
+    );
+
+    // the statements as they go in the block, without `ghost`
+    let fn_range = func.syntax().text_range();
+    let mut inner = func.syntax().to_string()[range.checked_sub(fn_range.start())?].to_owned();
+    for stmt in stmts.iter().rev() {
+        let ast::Stmt::LetStmt(let_stmt) = stmt else { continue };
+        let Some(ghost) = let_stmt.ghost_token() else { continue };
+        let ghost_range: std::ops::Range<usize> =
+            token_with_trailing_space(&ghost).checked_sub(range.start())?.into();
+        inner.replace_range(ghost_range, "");
+    }
+    if !parses_as_statements(&inner) {
+        return None;
+    }
+    let indent = indent_of(stmts.first()?.syntax());
+    let result = format!("proof {{\n{indent}    {}\n{indent}}}", inner.replace('\n', "\n    "));
+
+    // only offered when the function still verifies
+    let mut fn_text = func.syntax().to_string();
+    fn_text.replace_range::<std::ops::Range<usize>>(
+        range.checked_sub(fn_range.start())?.into(),
+        &result,
+    );
+    if !ctx.try_verus_text(func.name()?.text().as_str(), &fn_text)?.is_success {
+        return None;
+    }
+
+    acc.add(
+        AssistId("wrap_in_proof_block", AssistKind::RefactorRewrite),
+        "Wrap into proof block",
+        range,
+        |edit| {
+            edit.replace(range, result);
+        },
+    )
+}
+
+/// Proof action: unwrap a `proof` block of an exec function into the enclosing body
+/// proof {
+///     let z = x;
+///     assert(z > 0);
+/// }
+/// into
+/// let ghost z = x;
+/// assert(z > 0);
+///
+/// Only offered when all of its statements are allowed in exec code: assertions, assumptions
+/// and variables, which become ghost variables.
+pub(crate) fn unwrap_proof_block(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    // trigger on "proof"
+    let proof_token = ctx.token_at_offset().find(|it| it.text() == "proof")?;
+    if !proof_token.text_range().contains_range(ctx.selection_trimmed()) {
+        return None;
+    }
+    let func: ast::Fn = ctx.find_node_at_offset()?;
+    if !is_exec(&func) || is_in_spec_code(&proof_token.parent()?) {
+        return None;
+    }
+
+    // `proof` is not a block for the parser, so its braces are matched by hand
+    let l_curly = non_trivia_after(&proof_token)?;
+    if l_curly.kind() != T!['{'] {
+        return None;
+    }
+    let r_curly = matching_r_curly(&l_curly)?;
+    let block_range = TextRange::new(proof_token.text_range().start(), r_curly.text_range().end());
+    let inner_range = TextRange::new(l_curly.text_range().end(), r_curly.text_range().start());
+    if !func.syntax().text_range().contains_range(block_range) {
+        return None;
+    }
+    let fn_start = func.syntax().text_range().start();
+    let inner = &func.syntax().to_string()[inner_range.checked_sub(fn_start)?];
+    let inner = exec_statements(inner)?;
+
+    // the statements lose the indentation of the block
+    let result = inner
+        .trim()
+        .lines()
+        .map(|it| it.strip_prefix("    ").unwrap_or(it))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    acc.add(
+        AssistId("unwrap_proof_block", AssistKind::RefactorRewrite),
+        "Unwrap proof block",
+        block_range,
+        |edit| {
+            edit.replace(block_range, result);
+        },
+    )
+}
+
+fn is_exec(func: &ast::Fn) -> bool {
+    func.fn_mode().map_or(true, |it| it.exec_token().is_some())
+}
+
+/// Whether `stmt` can go in a `proof` block: an assertion, an assumption, a call to a proof
+/// function or a ghost variable.
+fn is_ghost_stmt(ctx: &AssistContext<'_>, stmt: &ast::Stmt) -> bool {
+    let expr = match stmt {
+        ast::Stmt::LetStmt(it) => return it.ghost_token().is_some(),
+        ast::Stmt::ExprStmt(it) => it.expr(),
+        ast::Stmt::Item(_) => None,
+    };
+    match expr {
+        Some(
+            ast::Expr::AssertExpr(_) | ast::Expr::AssertForallExpr(_) | ast::Expr::AssumeExpr(_),
+        ) => true,
+        Some(ast::Expr::CallExpr(call)) => {
+            let Some(ast::Expr::PathExpr(callee)) = call.expr() else { return false };
+            let Some(PathResolution::Def(ModuleDef::Function(callee))) =
+                callee.path().and_then(|it| ctx.sema.resolve_path(&it))
+            else {
+                return false;
+            };
+            ctx.sema.source(callee).map_or(false, |it| {
+                it.value.fn_mode().map_or(false, |mode| mode.proof_token().is_some())
+            })
+        }
+        _ => false,
+    }
+}
+
+/// The statements of the text `inner` of a `proof` block, rewritten for exec code, if they are
+/// allowed there. Its variables become ghost variables.
+fn exec_statements(inner: &str) -> Option<String> {
+    let prefix = "fn f() {";
+    let parse = SourceFile::parse(&format!("{prefix}{inner}}}"), Edition::CURRENT);
+    if !parse.errors().is_empty() {
+        return None;
+    }
+    let body = parse.tree().syntax().descendants().find_map(ast::StmtList::cast)?;
+    if body.tail_expr().is_some() {
+        return None;
+    }
+    let mut result = inner.to_owned();
+    let mut lets = vec![];
+    for stmt in body.statements() {
+        match &stmt {
+            ast::Stmt::LetStmt(it)
+                if it.ghost_token().is_none() && it.tracked_token().is_none() =>
+            {
+                lets.push(it.let_token()?.text_range().end())
+            }
+            ast::Stmt::ExprStmt(it)
+                if matches!(
+                    it.expr(),
+                    Some(
+                        ast::Expr::AssertExpr(_)
+                            | ast::Expr::AssertForallExpr(_)
+                            | ast::Expr::AssumeExpr(_)
+                    )
+                ) => {}
+            _ => return None,
+        }
+    }
+    for offset in lets.into_iter().rev() {
+        let offset: usize = (offset - TextSize::of(prefix)).into();
+        result.insert_str(offset, " ghost");
+    }
+    Some(result)
+}
+
+fn parses_as_statements(text: &str) -> bool {
+    SourceFile::parse(&format!("fn f() {{\n{text}\n}}"), Edition::CURRENT).errors().is_empty()
+}
+
+fn non_trivia_after(token: &SyntaxToken) -> Option<SyntaxToken> {
+    std::iter::successors(token.next_token(), |it| it.next_token())
+        .find(|it| !it.kind().is_trivia())
+}
+
+fn matching_r_curly(l_curly: &SyntaxToken) -> Option<SyntaxToken> {
+    let mut depth = 0usize;
+    for token in std::iter::successors(Some(l_curly.clone()), |it| it.next_token()) {
+        match token.kind() {
+            T!['{'] => depth += 1,
+            T!['}'] => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(token);
+                }
+            }
+            _ => (),
+        }
+    }
+    None
+}
+
+fn token_with_trailing_space(token: &SyntaxToken) -> TextRange {
+    let end = match token.next_token() {
+        Some(it) if it.kind() == SyntaxKind::WHITESPACE => it.text_range().end(),
+        _ => token.text_range().end(),
+    };
+    TextRange::new(token.text_range().start(), end)
+}
+
+/// The indentation of the line `node` starts.
+fn indent_of(node: &syntax::SyntaxNode) -> String {
+    node.siblings_with_tokens(Direction::Prev)
+        .nth(1)
+        .filter(|it| it.kind() == SyntaxKind::WHITESPACE)
+        .map(|it| it.to_string().rsplit('\n').next().unwrap_or_default().to_owned())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    #[test]
+    fn wrap_ghost_statements() {
+        check_assist(
+            wrap_in_proof_block,
+            "
+use vstd::prelude::*;
+fn foo(x: u64)
+    requires
+        x > 0,
+{
+    let y = x + 1;
+    $0let ghost z = x;
+    assert(z > 0);$0
+    let w = y;
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+fn foo(x: u64)
+    requires
+        x > 0,
+{
+    let y = x + 1;
+    proof {
+        let z = x;
+        assert(z > 0);
+    }
+    let w = y;
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn wrap_exec_statement() {
+        check_assist_not_applicable(
+            wrap_in_proof_block,
+            "
+use vstd::prelude::*;
+fn foo(x: u64)
+    requires
+        x > 0,
+{
+    $0let y = x - 1;
+    assert(y < x);$0
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn unwrap_block() {
+        check_assist(
+            unwrap_proof_block,
+            "
+use vstd::prelude::*;
+fn foo(x: u64) {
+    let y = x;
+    pro$0of {
+        let z = x;
+        assert(z == y);
+    }
+}
+
+fn main() {}
+",
+            "
+use vstd::prelude::*;
+fn foo(x: u64) {
+    let y = x;
+    let ghost z = x;
+    assert(z == y);
+}
+
+fn main() {}
+",
+        );
+    }
+
+    #[test]
+    fn unwrap_lemma_call() {
+        check_assist_not_applicable(
+            unwrap_proof_block,
+            "
+use vstd::prelude::*;
+proof fn lemma(x: u64) {}
+
+fn foo(x: u64) {
+    pro$0of {
+        lemma(x);
+    }
+}
+
+fn main() {}
+",
+        );
+    }
+}
@@ -74,9 +74,9 @@ pub(crate) mod tests;
 pub mod utils;
 
 use hir::Semantics;
-use ide_db::{base_db::FileRange, RootDatabase};
+use ide_db::{base_db::FileRange, FxHashSet, RootDatabase};
 use proof_plumber_api::verus_error::VerusError;
-use syntax::TextRange;
+use syntax::{ast, AstNode, TextRange};
 
 pub(crate) use crate::assist_context::{AssistContext, Assists};
 
@@ -120,8 +120,64 @@ pub fn assists_with_verus_error(
     acc.finish()
 }
 
+/// Return the proof actions fixing the Verus failures reported in the given range, as quick
+/// fixes.
+///
+/// Unlike [`assists_with_verus_error`], each action is run where it triggers rather than at
+/// `range`: a postcondition fails at the end of the body, but its fix triggers on `ensures`.
+pub fn verus_quickfixes(
+    db: &RootDatabase,
+    config: &AssistConfig,
+    range: FileRange,
+    verus_errors: Vec<VerusError>,
+) -> Vec<Assist> {
+    let include_fixes = match &config.allowed {
+        Some(it) => it.iter().any(|&it| it == AssistKind::None || it == AssistKind::QuickFix),
+        None => true,
+    };
+    if !include_fixes {
+        return Vec::new();
+    }
+    // The actions are refactorings on their own, don't let `allowed` filter them out.
+    let config = AssistConfig { allowed: None, ..config.clone() };
+    let source_file = Semantics::new(db).parse(range.file_id);
+
+    let mut seen = FxHashSet::default();
+    let mut res = Vec::new();
+    for verus_error in &verus_errors {
+        let Some(handler) = handlers::verus_error_fix(verus_error) else { continue };
+        let (site, trigger) = match verus_error {
+            VerusError::Pre(pre) => (pre.callsite, pre.callsite),
+            VerusError::Post(post) => {
+                let ensures = source_file
+                    .syntax()
+                    .covering_element(post.failing_post)
+                    .ancestors()
+                    .find_map(ast::EnsuresClause::cast)
+                    .and_then(|it| it.ensures_token());
+                let Some(ensures) = ensures else { continue };
+                (post.func_body, ensures.text_range())
+            }
+            VerusError::Assert(_) => continue,
+        };
+        if site.intersect(range.range).is_none() || !seen.insert(trigger) {
+            continue;
+        }
+
+        let frange = FileRange { file_id: range.file_id, range: trigger };
+        let ctx = AssistContext::new(Semantics::new(db), &config, frange, verus_errors.clone());
+        let mut acc = Assists::new(&ctx, AssistResolveStrategy::All);
+        handler(&mut acc, &ctx);
+        res.extend(acc.finish().into_iter().map(|mut assist| {
+            assist.id.1 = AssistKind::QuickFix;
+            assist
+        }));
+    }
+    res
+}
+
 pub(crate) mod handlers {
-    use crate::{AssistContext, Assists};
+    use crate::{AssistContext, Assists, VerusError};
 
     pub(crate) type Handler = fn(&mut Assists, &AssistContext<'_>) -> Option<()>;
 
@@ -248,6 +304,20 @@ pub(crate) mod handlers {
     // verus
     mod proof_action;
 
+    /// The proof action fixing the given Verus failure, if any.
+    pub(crate) fn verus_error_fix(verus_error: &VerusError) -> Option<Handler> {
+        let handler: Handler = match verus_error {
+            VerusError::Pre(_) => proof_action::insert_failing_precondition::intro_failing_requires,
+            VerusError::Post(_) => {
+                proof_action::insert_failing_postcondition::intro_failing_ensures
+            }
+            // FIXME: "possible arithmetic underflow/overflow" failures are not recorded as a
+            // `VerusError` yet, and there is no proof action addressing them.
+            VerusError::Assert(_) => return None,
+        };
+        cfg!(feature = "proof-action").then_some(handler)
+    }
+
     pub(crate) fn all() -> &'static [Handler] {
         &[
             // These are alphabetic for the foolish consistency
@@ -408,13 +478,19 @@ pub(crate) mod handlers {
             #[cfg(feature="proof-action")]
             proof_action::insert_assert_by_block::assert_by,
             #[cfg(feature="proof-action")]
+            proof_action::remove_assert_by_block::remove_assert_by,
+            #[cfg(feature="proof-action")]
             proof_action::insert_failing_postcondition::intro_failing_ensures,
             #[cfg(feature="proof-action")]
             proof_action::insert_failing_precondition::intro_failing_requires,
             //#[cfg(feature="proof-action")]
             //proof_action::intro_matching_assertions::intro_match,
             #[cfg(feature="proof-action")]
+            proof_action::case_split_on_bool::case_split_on_bool,
+            #[cfg(feature="proof-action")]
             proof_action::weakest_pre_step::wp_move_assertion,
+            #[cfg(feature="proof-action")]
+            proof_action::weakest_pre_step::wp_hoist_assertion,
             //#[cfg(feature="proof-action")]
             //proof_action::apply_induction::apply_induction,
             //#[cfg(feature="proof-action")]
@@ -426,14 +502,66 @@ pub(crate) mod handlers {
             #[cfg(feature="proof-action")]
             proof_action::reveal_opaque_above::insert_reveal,
             #[cfg(feature="proof-action")]
+            proof_action::reveal_all_opaque::insert_reveal_all,
+            #[cfg(feature="proof-action")]
             proof_action::convert_imply_to_if::imply_to_if,
             #[cfg(feature="proof-action")]
             proof_action::split_imply_ensures::split_imply_ensures,
             #[cfg(feature="proof-action")]
+            proof_action::split_conjunctive_assert::split_conjunctive_assert,
+            #[cfg(feature="proof-action")]
             proof_action::intro_forall::intro_forall,
             #[cfg(feature="proof-action")]
             proof_action::intro_forall_implies::intro_forall_implies,
             #[cfg(feature="proof-action")]
+            proof_action::intro_loop_invariants::intro_loop_invariants,
+            #[cfg(feature="proof-action")]
+            proof_action::intro_decreases::intro_decreases,
+            #[cfg(feature="proof-action")]
+            proof_action::prove_by_bit_vector::prove_by_bit_vector,
+            #[cfg(feature="proof-action")]
+            proof_action::prove_by_nonlinear_arith::prove_by_nonlinear_arith,
+            #[cfg(feature="proof-action")]
+            proof_action::extract_lemma::extract_lemma,
+            #[cfg(feature="proof-action")]
+            proof_action::lift_assert_to_requires::lift_assert_to_requires,
+            #[cfg(feature="proof-action")]
+            proof_action::weaken_ensures::weaken_ensures,
+            #[cfg(feature="proof-action")]
+            proof_action::name_repeated_subexpr::name_repeated_subexpr,
+            #[cfg(feature="proof-action")]
+            proof_action::intro_assert_forall_ensures::intro_assert_forall_ensures,
+            #[cfg(feature="proof-action")]
+            proof_action::minimize_assume::minimize_assume,
+            #[cfg(feature="proof-action")]
+            proof_action::remove_redundant_assertion::remove_dead_proof_code,
+            #[cfg(feature="proof-action")]
+            proof_action::minimize_fuel::minimize_reveal_fuel,
+            #[cfg(feature="proof-action")]
+            proof_action::convert_fn_mode::convert_to_proof_fn,
+            #[cfg(feature="proof-action")]
+            proof_action::convert_fn_mode::convert_to_spec_fn,
+            #[cfg(feature="proof-action")]
+            proof_action::select_trigger::select_trigger,
+            #[cfg(feature="proof-action")]
+            proof_action::seq_index_inbound::seq_index_bounds,
+            #[cfg(feature="proof-action")]
+            proof_action::wrap_proof_block::wrap_in_proof_block,
+            #[cfg(feature="proof-action")]
+            proof_action::wrap_proof_block::unwrap_proof_block,
+            #[cfg(feature="proof-action")]
+            proof_action::intro_calc::intro_calc,
+            #[cfg(feature="proof-action")]
+            proof_action::propagate_requires::propagate_requires,
+            #[cfg(feature="proof-action")]
+            proof_action::remove_unused_lemma_params::remove_unused_lemma_params,
+            #[cfg(feature="proof-action")]
+            proof_action::inline_spec_fn_call::inline_spec_fn_call,
+            #[cfg(feature="proof-action")]
+            proof_action::instantiate_forall::instantiate_forall,
+            #[cfg(feature="proof-action")]
+            proof_action::skolemize_exists::skolemize_exists,
+            #[cfg(feature="proof-action")]
             proof_action::intro_assume_false::by_assume_false,
             #[cfg(feature="proof-action")]
             proof_action::split_smaller_or_equal_to::split_smaller_or_equal_to,
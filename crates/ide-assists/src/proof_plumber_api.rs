@@ -38,6 +38,8 @@ pub mod proof_action_context;
 pub mod run_fmt;
 pub mod run_verus;
 pub mod semantic_info;
+pub mod verifier;
 pub mod verus_error;
+pub mod verus_pool;
 pub mod vst_ext;
 pub mod vst_from_text;
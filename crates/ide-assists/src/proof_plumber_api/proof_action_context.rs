@@ -4,9 +4,10 @@
 #![allow(dead_code)]
 
 use crate::{proof_plumber_api::verus_error::*, AssistContext};
+use ide_db::FxHashSet;
 use syntax::{
     ast::{self, vst},
-    AstNode, SyntaxKind,
+    AstNode, SyntaxKind, SyntaxNode,
 };
 
 impl<'a> AssistContext<'a> {
@@ -140,6 +141,33 @@ impl<'a> AssistContext<'a> {
         replaced_stmts
     }
 
+    /// The preconditions of `func` that only mention variables of `expr`. Provers that do not see
+    /// the context of an assertion, like `bit_vector`, need these to know the range of the
+    /// variables.
+    pub fn requires_bounding(&self, func: &vst::Fn, expr: &vst::Expr) -> Vec<vst::Expr> {
+        let names = |node: &SyntaxNode| -> FxHashSet<String> {
+            node.descendants()
+                .filter_map(ast::NameRef::cast)
+                .map(|it| it.text().to_string())
+                .collect()
+        };
+        let (Some(expr), Some(requires)) = (expr.cst(), &func.requires_clause) else {
+            return vec![];
+        };
+        let vars = names(expr.syntax());
+        requires
+            .exprs
+            .iter()
+            .filter(|it| {
+                it.cst().map_or(false, |it| {
+                    let used = names(it.syntax());
+                    !used.is_empty() && used.is_subset(&vars)
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
     /// helper routine to reduce a list of predicate into &&-ed predicate
     pub fn reduce_exprs(&self, es: Vec<vst::Expr>) -> Option<vst::Expr> {
         es.into_iter().reduce(|acc, e| {
@@ -1,7 +1,13 @@
 //! Run Verus and return the verification result
 
-use crate::AssistContext;
-use std::{fs::File, io::Write, process::Command, time::Instant};
+use crate::{
+    proof_plumber_api::{
+        verifier::{Verifier, VerusOutput},
+        verus_pool,
+    },
+    AssistContext,
+};
+use std::{ops::RangeInclusive, sync::mpsc};
 use syntax::ast::{self, vst, HasModuleItem, HasName};
 
 impl<'a> AssistContext<'a> {
@@ -21,9 +27,105 @@ impl<'a> AssistContext<'a> {
         &self,
         vst_fn: &vst::Fn, // only replace this function and run
     ) -> Option<VerifResult> {
+        self.spawn_verus(vst_fn)?.wait()
+    }
+
+    /// Starts the run of [`try_verus`](Self::try_verus) on the Verus worker pool, so that several
+    /// runs can proceed at once.
+    pub(crate) fn spawn_verus(&self, vst_fn: &vst::Fn) -> Option<PendingVerif> {
+        let name = vst_fn.name.to_string();
+        let text_string = self.scratch_file_text(Some((name.trim(), &vst_fn.to_string())))?;
+        let verifier = self.verifier.clone();
+        let receiver = verus_pool::spawn(move || verify(&*verifier, &text_string));
+        Some(PendingVerif { function: name, receiver })
+    }
+
+    /// Like [`try_verus`](Self::try_verus), with the function named `name` replaced by `fn_text`,
+    /// for the rewrites that cannot be lifted into a `vst::Fn`, such as the ones with a `proof`
+    /// block.
+    pub(crate) fn try_verus_text(&self, name: &str, fn_text: &str) -> Option<VerifResult> {
+        let text_string = self.scratch_file_text(Some((name, fn_text)))?;
+        let verifier = self.verifier.clone();
+        let receiver = verus_pool::spawn(move || verify(&*verifier, &text_string));
+        PendingVerif { function: name.to_owned(), receiver }.wait()
+    }
+
+    /// Verifies each of `candidates`, alternative versions of the same function, in a single
+    /// Verus run. The candidates are added under fresh names next to the unmodified function, and
+    /// the errors Verus reports are mapped back to the candidate they point into. Errors outside of
+    /// the candidates are ignored.
+    ///
+    /// The results are in the order of `candidates`, each with the time of the whole run. If a
+    /// candidate does not compile, Verus reports nothing for the others, so each candidate is run
+    /// on its own instead. Calls a candidate makes to the function itself still reach the original
+    /// one, so this is not suited to recursive candidates.
+    pub(crate) fn try_verus_batch(&self, candidates: &[vst::Fn]) -> Vec<Option<VerifResult>> {
+        let span = tracing::info_span!(
+            "try_verus_batch",
+            candidates = candidates.len(),
+            elapsed_ms = tracing::field::Empty,
+        )
+        .entered();
+        let one_by_one = || {
+            let pending: Vec<_> = candidates.iter().map(|it| self.spawn_verus(it)).collect();
+            pending.into_iter().map(|it| it?.wait()).collect::<Vec<_>>()
+        };
+        if candidates.len() < 2 {
+            return one_by_one();
+        }
+        let Some(mut text) = self.scratch_file_text(None) else {
+            return candidates.iter().map(|_| None).collect();
+        };
+        // The lines of `text` holding each candidate, 1-based like the ones Verus reports.
+        let mut lines: Vec<RangeInclusive<usize>> = Vec::with_capacity(candidates.len());
+        for (idx, candidate) in candidates.iter().enumerate() {
+            let mut candidate = candidate.clone();
+            let name = format!("{}_batch_candidate_{idx}", candidate.name.to_string().trim());
+            candidate.name.ident_token = Some(name.into());
+            text += "\nverus!{\n";
+            let start = text.matches('\n').count() + 1;
+            text += &candidate.to_string();
+            lines.push(start..=text.matches('\n').count() + 1);
+            text += "\n}\n";
+        }
+
+        let verifier = self.verifier.clone();
+        let run = verus_pool::spawn(move || verify(&*verifier, &text)).recv().ok().flatten();
+        let Some(output) = run else {
+            return candidates.iter().map(|_| None).collect();
+        };
+        span.record("elapsed_ms", output.elapsed.as_millis() as u64);
+        let elapsed = output.elapsed;
+        let stdout = output.stdout;
+        if !output.success && stdout.contains("verification results:: verified: 0 errors: 0") {
+            return one_by_one();
+        }
+
+        let mut stderrs = vec![String::new(); candidates.len()];
+        let mut failed = vec![false; candidates.len()];
+        for diagnostic in split_diagnostics(&output.stderr) {
+            let Some(line) = primary_line(diagnostic) else { continue };
+            let Some(idx) = lines.iter().position(|it| it.contains(&line)) else { continue };
+            failed[idx] |= diagnostic.starts_with("error");
+            stderrs[idx].push_str(diagnostic);
+        }
+        stderrs
+            .into_iter()
+            .zip(failed)
+            .map(|(stderr, failed)| {
+                Some(if failed {
+                    VerifResult::mk_failure(stdout.clone(), stderr, elapsed.as_secs())
+                } else {
+                    VerifResult::mk_success(elapsed.as_secs())
+                })
+            })
+            .collect()
+    }
+
+    /// The text of the current file to run Verus on, with the text of `replaced` in place of the
+    /// function of its name.
+    fn scratch_file_text(&self, replaced: Option<(&str, &str)>) -> Option<String> {
         let source_file = &self.source_file;
-        let verus_exec_path = std::env::var("VERUS_BINARY_PATH")
-            .expect("please set VERUS_BINARY_PATH environment variable");
         let mut text_string = String::new();
         // in VST, we should also be able to "print" and verify
         // display for VST should be correct modulo whitespace
@@ -31,8 +133,11 @@ impl<'a> AssistContext<'a> {
             match it {
                 ast::Item::Fn(f) => {
                     text_string += "\nverus!{\n";
-                    if f.name()?.to_string().trim() == vst_fn.name.to_string().trim() {
-                        text_string += &vst_fn.to_string();
+                    let replaced = replaced.filter(|(replaced_name, _)| {
+                        f.name().map_or(false, |name| name.to_string().trim() == *replaced_name)
+                    });
+                    if let Some((_, fn_text)) = replaced {
+                        text_string += fn_text;
                     } else {
                         // review: f.cst.to_string?
                         text_string += &f.to_string();
@@ -62,77 +167,74 @@ impl<'a> AssistContext<'a> {
             }
         }
         //dbg!(&text_string);
+        Some(text_string)
+    }
+}
 
-        // let verify_func_flag = "--verify-function";
-        // let verify_root_flag = "--verify-root"; // TODO: figure out the surrounding module of `token`
-        // let func_name = vst_fn.name.to_string();
-
-        // REIVEW: instead of writing to a file in the tmp directory, consider using `memfd_create` for an anonymous file
-        // refer to `man memfd_create` or `dev/shm`
-        // REVIEW: Is this true? In linux, set env TMPDIR to set the tmp directory. Otherwise, it fails
-        let tmp_dir = tempfile::TempDir::new().ok()?;
-        let file_path = tmp_dir.path().join("verus_proof_action_scratch_file.rs");
-        //dbg!(&file_path);
-        let display = file_path.display();
-
-        // Open a file in write-only mode, returns `io::Result<File>`
-        let mut file = match File::create(&file_path) {
-            Err(why) => {
-                dbg!("couldn't create {}: {}", display, why);
-                return None;
-            }
-            Ok(file) => file,
-        };
+/// Runs `verifier` on `text`, logging why Verus could not be run, if it could not.
+fn verify(verifier: &dyn Verifier, text: &str) -> Option<VerusOutput> {
+    verifier.verify(text).map_err(|err| tracing::warn!("{err}")).ok()
+}
 
-        // Write the modified verus program to `file`, returns `io::Result<()>`
-        match file.write_all(text_string.as_bytes()) {
-            Err(why) => {
-                dbg!("couldn't write to {}: {}", display, why);
-                return None;
-            }
-            Ok(_) => (),//dbg!("successfully wrote to {}", display),
-        };
+/// A run of [`AssistContext::spawn_verus`] in progress.
+pub(crate) struct PendingVerif {
+    function: String,
+    receiver: mpsc::Receiver<Option<VerusOutput>>,
+}
 
-        let now = Instant::now();
-        let output = Command::new(verus_exec_path)
-            .arg(file_path)
-            .arg("--multiple-errors")
-            .arg("10") // we want many errors as proof-action reads this. By default, Verus gives a couple of errors as a human reads those.
-            .output();
-        let elapsed = now.elapsed().as_secs();
+impl PendingVerif {
+    /// Waits for Verus to finish, see [`AssistContext::try_verus`].
+    pub(crate) fn wait(self) -> Option<VerifResult> {
+        let span = tracing::info_span!(
+            "try_verus",
+            function = %self.function,
+            elapsed_ms = tracing::field::Empty,
+            success = tracing::field::Empty,
+        )
+        .entered();
+        let output = self.receiver.recv().ok()??;
+        span.record("elapsed_ms", output.elapsed.as_millis() as u64);
+        let elapsed = output.elapsed.as_secs();
 
-        let output = output.ok()?;
+        span.record("success", output.success);
         // dbg!(&output);
-        if output.status.success() {
+        if output.success {
             return Some(VerifResult::mk_success(elapsed));
-        } else {
-            // disambiguate verification failure     VS    compile error etc
-            match std::str::from_utf8(&output.stdout) {
-                Ok(out) => {
-                    //dbg!(out);
-                    if out.contains("verification results:: verified: 0 errors: 0") {
-                        // failure from other errors. (e.g. compile error)
-                        return None;
-                    } else {
-                        // verification failure
-                        match std::str::from_utf8(&output.stderr) {
-                            Ok(err_msg) => {
-                                return Some(VerifResult::mk_failure(
-                                    out.into(),
-                                    err_msg.into(),
-                                    elapsed,
-                                ));
-                            }
-                            Err(_) => return None,
-                        }
-                    }
-                }
-                Err(_) => return None,
-            }
         }
+        // disambiguate verification failure     VS    compile error etc
+        if output.stdout.contains("verification results:: verified: 0 errors: 0") {
+            // failure from other errors. (e.g. compile error)
+            return None;
+        }
+        // verification failure
+        Some(VerifResult::mk_failure(output.stdout, output.stderr, elapsed))
     }
 }
 
+/// Splits the human readable errors of Verus into diagnostics, each starting with an unindented
+/// `error`, `warning` or `note` line.
+fn split_diagnostics(stderr: &str) -> Vec<&str> {
+    let mut res = Vec::new();
+    let (mut start, mut offset) = (0, 0);
+    for line in stderr.split_inclusive('\n') {
+        if offset != 0 && line.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            res.push(&stderr[start..offset]);
+            start = offset;
+        }
+        offset += line.len();
+    }
+    res.push(&stderr[start..]);
+    res
+}
+
+/// The line of the first location of `diagnostic`, given as `--> file:line:column`.
+fn primary_line(diagnostic: &str) -> Option<usize> {
+    let location = diagnostic.lines().find_map(|it| it.trim_start().strip_prefix("--> "))?;
+    let mut parts = location.rsplitn(3, ':');
+    let _column = parts.next()?;
+    parts.next()?.parse().ok()
+}
+
 #[derive(Debug)]
 pub(crate) struct VerifResult {
     pub(crate) is_success: bool,
@@ -159,3 +261,33 @@ impl VerifResult {
         self.stderr.contains(&assertion.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{primary_line, split_diagnostics};
+
+    #[test]
+    fn diagnostics_map_to_their_first_location() {
+        let stderr = "\
+error: assertion failed
+  --> /tmp/scratch.rs:12:9
+   |
+12 |         assert(x > 0);
+   |                ^^^^^ assertion failed
+
+note: automatically chose triggers for this expression:
+  --> /tmp/scratch.rs:30:5
+   |
+30 |     forall|i: int| f(i)
+   |     ^^^^^^^^^^^^^^^^^^^
+
+error: aborting due to 1 previous error
+";
+        let diagnostics = split_diagnostics(stderr);
+        assert_eq!(diagnostics.len(), 3);
+        assert!(diagnostics[0].starts_with("error: assertion failed"));
+        assert!(diagnostics[1].starts_with("note:"));
+        let lines: Vec<_> = diagnostics.iter().map(|&it| primary_line(it)).collect();
+        assert_eq!(lines, [Some(12), Some(30), None]);
+    }
+}
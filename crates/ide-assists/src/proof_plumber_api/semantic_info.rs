@@ -112,7 +112,7 @@ impl<'a> AssistContext<'a> {
             let v_item: ast::generated::vst_nodes::Item = match item.try_into() {
                 Ok(ii) => ii,
                 Err(err_msg) => {
-                    dbg!("into_vst failed: {}", err_msg);
+                    tracing::debug!("into_vst failed: {err_msg}");
                     continue;
                 }
             };
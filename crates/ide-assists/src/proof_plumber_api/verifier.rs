@@ -0,0 +1,215 @@
+//! The verifier proof actions check their rewrites with.
+//!
+//! Proof actions go through [`Verifier`] rather than starting Verus themselves, so that their
+//! tests can answer in its place with a `MockVerifier`. With the `verus-integration` feature, the
+//! tests run the Verus binary instead, on the same fixtures.
+
+use std::{
+    fs::File,
+    io::Write,
+    process::Command,
+    time::{Duration, Instant},
+};
+
+/// What Verus reported on a file.
+#[derive(Debug, Clone)]
+pub(crate) struct VerusOutput {
+    pub(crate) success: bool,
+    pub(crate) stdout: String,
+    pub(crate) stderr: String,
+    pub(crate) elapsed: Duration,
+}
+
+pub(crate) trait Verifier: Send + Sync {
+    /// Runs Verus on a file holding `text`. An error if Verus could not be started.
+    fn verify(&self, text: &str) -> Result<VerusOutput, String>;
+}
+
+/// The Verus binary at `binary_path`, the `verus.binaryPath` setting, falling back to
+/// `VERUS_BINARY_PATH`, then to `verus` in the `PATH`, like the Verus runs on save.
+pub(crate) struct VerusBinary {
+    pub(crate) binary_path: Option<String>,
+}
+
+impl Verifier for VerusBinary {
+    fn verify(&self, text: &str) -> Result<VerusOutput, String> {
+        let verus_exec_path = match (&self.binary_path, std::env::var("VERUS_BINARY_PATH")) {
+            (Some(path), _) => path.clone(),
+            (None, Ok(path)) => path,
+            (None, Err(_)) => "verus".to_owned(),
+        };
+
+        // REIVEW: instead of writing to a file in the tmp directory, consider using `memfd_create` for an anonymous file
+        // refer to `man memfd_create` or `dev/shm`
+        // REVIEW: Is this true? In linux, set env TMPDIR to set the tmp directory. Otherwise, it fails
+        let tmp_dir = tempfile::TempDir::new()
+            .map_err(|why| format!("couldn't create a temporary directory: {why}"))?;
+        let file_path = tmp_dir.path().join("verus_proof_action_scratch_file.rs");
+        let display = file_path.display();
+
+        // Open a file in write-only mode, returns `io::Result<File>`
+        let mut file =
+            File::create(&file_path).map_err(|why| format!("couldn't create {display}: {why}"))?;
+
+        // Write the modified verus program to `file`, returns `io::Result<()>`
+        file.write_all(text.as_bytes())
+            .map_err(|why| format!("couldn't write to {display}: {why}"))?;
+
+        let now = Instant::now();
+        let output = Command::new(&verus_exec_path)
+            .arg(&file_path)
+            .arg("--multiple-errors")
+            .arg("10") // we want many errors as proof-action reads this. By default, Verus gives a couple of errors as a human reads those.
+            .output()
+            .map_err(|why| {
+                format!(
+                    "couldn't run Verus at `{verus_exec_path}` ({why}), \
+                     set verus.binaryPath or VERUS_BINARY_PATH"
+                )
+            })?;
+        Ok(VerusOutput {
+            success: output.status.success(),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            elapsed: now.elapsed(),
+        })
+    }
+}
+
+/// Answers in place of Verus, as told by a test. Each item of the file verifies unless the
+/// closure gives snippets of it, each of which is then reported as failing. Compile errors are
+/// not modeled, and every run takes no time.
+#[cfg(test)]
+pub(crate) struct MockVerifier {
+    failures: Box<dyn Fn(&str) -> Vec<String> + Send + Sync>,
+}
+
+#[cfg(test)]
+impl MockVerifier {
+    pub(crate) fn new(failures: impl Fn(&str) -> Vec<String> + Send + Sync + 'static) -> Self {
+        MockVerifier { failures: Box::new(failures) }
+    }
+
+    /// Everything verifies.
+    pub(crate) fn verified() -> Self {
+        MockVerifier::new(|_| Vec::new())
+    }
+
+    /// Any of `snippets` fails wherever it appears.
+    pub(crate) fn failing(snippets: &[&str]) -> Self {
+        let snippets: Vec<String> = snippets.iter().map(|it| it.to_string()).collect();
+        MockVerifier::new(move |item| {
+            snippets.iter().filter(|it| find(item, it).is_some()).cloned().collect()
+        })
+    }
+
+    /// The function named `function` fails unless it has all of `needed`, like a proof relying
+    /// on these assertions.
+    pub(crate) fn needing(function: &str, needed: &[&str]) -> Self {
+        let header = format!("fn {function}");
+        let needed: Vec<String> = needed.iter().map(|it| it.to_string()).collect();
+        MockVerifier::new(move |item| {
+            // `fn foo` also starts `fn foo_batch_candidate_0` and `fn foobar`.
+            let declares =
+                ["(", "<"].iter().any(|it| find(item, &format!("{header}{it}")).is_some());
+            if declares && needed.iter().any(|it| find(item, it).is_none()) {
+                vec![header.clone()]
+            } else {
+                Vec::new()
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+impl Verifier for MockVerifier {
+    fn verify(&self, text: &str) -> Result<VerusOutput, String> {
+        let (mut verified, mut errors) = (0, 0);
+        let mut stderr = String::new();
+        for (first_line, item) in scratch_items(text) {
+            let failures = (self.failures)(item);
+            if failures.is_empty() {
+                verified += 1;
+                continue;
+            }
+            errors += 1;
+            // Laid out like the errors of Verus, as proof actions read them.
+            for snippet in failures {
+                let Some((start, end)) = find(item, &snippet) else { continue };
+                let line_start = item[..start].rfind('\n').map_or(0, |it| it + 1);
+                let line_end = item[end..].find('\n').map_or(item.len(), |it| end + it);
+                let line = first_line + item[..start].matches('\n').count();
+                stderr += &format!("error: assertion failed\n  --> scratch.rs:{line}:1\n   |\n");
+                for (idx, source) in item[line_start..line_end].lines().enumerate() {
+                    stderr += &format!("{:<3}| {source}\n", line + idx);
+                }
+                stderr += "\n";
+            }
+        }
+        Ok(VerusOutput {
+            success: errors == 0,
+            stdout: format!("verification results:: verified: {verified} errors: {errors}\n"),
+            stderr,
+            elapsed: Duration::ZERO,
+        })
+    }
+}
+
+/// The items of a scratch file, each wrapped in its own `verus!`, along with the 1-based line
+/// each of them starts at.
+#[cfg(test)]
+fn scratch_items(text: &str) -> Vec<(usize, &str)> {
+    const OPEN: &str = "verus!{\n";
+    let mut res = Vec::new();
+    let mut offset = 0;
+    while let Some(start) = text[offset..].find(OPEN) {
+        let start = offset + start + OPEN.len();
+        let Some(len) = text[start..].find("\n}\n") else { break };
+        res.push((text[..start].matches('\n').count() + 1, &text[start..start + len]));
+        offset = start + len;
+    }
+    res
+}
+
+/// The byte range of `snippet` in `item`, ignoring whitespace since the items printed from a VST
+/// are not laid out like the fixtures.
+#[cfg(test)]
+fn find(item: &str, snippet: &str) -> Option<(usize, usize)> {
+    let chars: Vec<(usize, char)> =
+        item.char_indices().filter(|(_, c)| !c.is_whitespace()).collect();
+    let snippet: Vec<char> = snippet.chars().filter(|c| !c.is_whitespace()).collect();
+    if snippet.is_empty() {
+        return None;
+    }
+    let start = chars
+        .windows(snippet.len())
+        .position(|window| window.iter().map(|(_, c)| *c).eq(snippet.iter().copied()))?;
+    let (last, c) = chars[start + snippet.len() - 1];
+    Some((chars[start].0, last + c.len_utf8()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MockVerifier, Verifier};
+
+    #[test]
+    fn mock_reports_failures_at_their_line() {
+        let text =
+            "use vstd::prelude::*;\n\nverus!{\nproof fn foo() {\n    assert(1 == 2);\n}\n}\n";
+        let output = MockVerifier::failing(&["assert(1==2)"]).verify(text).unwrap();
+        assert!(!output.success);
+        assert_eq!(output.stdout, "verification results:: verified: 0 errors: 1\n");
+        assert!(output.stderr.contains("--> scratch.rs:5:1"));
+        assert!(output.stderr.contains("5  |     assert(1 == 2);"));
+    }
+
+    #[test]
+    fn mock_needing_matches_whole_names() {
+        let text = "verus!{\nproof fn foo_batch_candidate_0() {\n}\n}\n";
+        let output = MockVerifier::needing("foo", &["assert(x)"]).verify(text).unwrap();
+        assert!(output.success);
+        let text = "verus!{\nproof fn foo<T>() {\n}\n}\n";
+        let output = MockVerifier::needing("foo", &["assert(x)"]).verify(text).unwrap();
+        assert!(!output.success);
+    }
+}
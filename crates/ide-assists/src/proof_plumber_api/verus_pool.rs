@@ -0,0 +1,33 @@
+//! A bounded pool of threads running the Verus processes of proof actions.
+//!
+//! Proof actions are computed on the server's worker threads, which a Verus run would otherwise
+//! keep busy until the solver is done. Running Verus here instead lets several proof actions
+//! verify their candidates side by side, while bounding how many Verus processes compete with
+//! each other and with the verification of the saved file.
+
+use std::sync::{mpsc, OnceLock};
+
+use stdx::thread::{Pool, ThreadIntent};
+
+fn pool() -> &'static Pool {
+    static POOL: OnceLock<Pool> = OnceLock::new();
+    POOL.get_or_init(|| {
+        // Each Verus process keeps a solver busy, leave room for the rest of the server.
+        let threads = std::thread::available_parallelism().map_or(1, |it| (it.get() / 2).max(1));
+        Pool::new(threads)
+    })
+}
+
+/// Runs `job` on the pool. Its result is delivered through the returned receiver, which
+/// disconnects without a value if `job` panics.
+pub(crate) fn spawn<T, F>(job: F) -> mpsc::Receiver<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (sender, receiver) = mpsc::sync_channel(1);
+    pool().spawn(ThreadIntent::Worker, move || {
+        let _ = sender.send(job());
+    });
+    receiver
+}
@@ -3,6 +3,7 @@
 //! Visitor patterns available to map an expression recursively
 //!
 //! Referenced syntax_helpers::node_ext
+use ide_db::FxHashMap;
 use syntax::ast::vst;
 
 /// Preorder walk all the expression's child expressions.
@@ -22,6 +23,8 @@ where
     let exp: vst::Expr = exp.into();
     let res = match exp {
         vst::Expr::Literal(_) | vst::Expr::PathExpr(_) => cb(&mut exp.clone())?,
+        // binders are left to `cb`, which maps inside them as it needs
+        vst::Expr::ClosureExpr(_) | vst::Expr::MatchExpr(_) => cb(&mut exp.clone())?,
         vst::Expr::BinExpr(mut e) => {
             let new_lhs = vst_map_expr_visitor(*e.lhs.clone(), cb)?;
             let new_rhs = vst_map_expr_visitor(*e.rhs.clone(), cb)?;
@@ -68,6 +71,50 @@ where
             }
             vst::Expr::IfExpr(e)
         }
+        // `cb` may replace a call as a whole, otherwise its arguments are mapped
+        vst::Expr::CallExpr(_) => match cb(&mut exp.clone())? {
+            vst::Expr::CallExpr(mut e) => {
+                e.arg_list.args = e
+                    .arg_list
+                    .args
+                    .into_iter()
+                    .map(|arg| vst_map_expr_visitor(arg, cb))
+                    .collect::<Result<Vec<vst::Expr>, String>>()?;
+                vst::Expr::CallExpr(e)
+            }
+            other => other,
+        },
+        vst::Expr::MethodCallExpr(mut e) => {
+            e.receiver = Box::new(vst_map_expr_visitor(*e.receiver, cb)?);
+            e.arg_list.args = e
+                .arg_list
+                .args
+                .into_iter()
+                .map(|arg| vst_map_expr_visitor(arg, cb))
+                .collect::<Result<Vec<vst::Expr>, String>>()?;
+            vst::Expr::MethodCallExpr(e)
+        }
+        vst::Expr::ParenExpr(mut e) => {
+            e.expr = Box::new(vst_map_expr_visitor(*e.expr, cb)?);
+            vst::Expr::ParenExpr(e)
+        }
+        vst::Expr::PrefixExpr(mut e) => {
+            e.expr = Box::new(vst_map_expr_visitor(*e.expr, cb)?);
+            vst::Expr::PrefixExpr(e)
+        }
+        vst::Expr::CastExpr(mut e) => {
+            e.expr = Box::new(vst_map_expr_visitor(*e.expr, cb)?);
+            vst::Expr::CastExpr(e)
+        }
+        vst::Expr::FieldExpr(mut e) => {
+            e.expr = Box::new(vst_map_expr_visitor(*e.expr, cb)?);
+            vst::Expr::FieldExpr(e)
+        }
+        vst::Expr::IndexExpr(mut e) => {
+            e.base = Box::new(vst_map_expr_visitor(*e.base, cb)?);
+            e.index = Box::new(vst_map_expr_visitor(*e.index, cb)?);
+            vst::Expr::IndexExpr(e)
+        }
         vst::Expr::AssertExpr(_) => {
             let assert_exp = cb(&mut exp.clone())?;
             match assert_exp {
@@ -102,20 +149,116 @@ where
                         })
                         .collect();
                     e.stmt_list.statements = new_stmts?;
+                    if let Some(tail) = e.stmt_list.tail_expr.take() {
+                        e.stmt_list.tail_expr = Some(Box::new(vst_map_expr_visitor(*tail, cb)?));
+                    }
                     vst::Expr::BlockExpr(e)
                 }
                 _ => panic!(),
             }
         }
-        _ => {
-            //dgb!("note(warning): base case map expr");
-            //dgb!(&exp.to_string());
-            exp.clone()
-        }
+        // the other expressions are handed to `cb` as a whole
+        _ => cb(&mut exp.clone())?,
     };
     Ok(res)
 }
 
+/// Substitute the variables of an expression by the expressions `subst` maps their names to.
+/// For example, instantiate the body of a function with the arguments at a callsite.
+/// Substituted expressions are put in parentheses where needed to keep their precedence.
+/// Variables bound by quantifiers, closures and match arms shadow the substituted ones. Fails on
+/// an expression mentioning a substituted variable that the substitution does not go through.
+pub fn vst_subst_expr<EE>(
+    exp: EE,
+    subst: &FxHashMap<String, vst::Expr>,
+) -> Result<vst::Expr, String>
+where
+    EE: Into<vst::Expr>,
+{
+    vst_map_expr_visitor(exp, &mut |e| match e {
+        vst::Expr::PathExpr(path) => match subst.get(path.to_string().trim()) {
+            Some(it) => Ok(vst_parenthesize(it.clone())),
+            None => Ok(e.clone()),
+        },
+        vst::Expr::ClosureExpr(closure) => {
+            let mut closure = closure.clone();
+            let bound: Vec<String> = closure
+                .param_list
+                .iter()
+                .flat_map(|it| &it.params)
+                .filter_map(|it| it.pat.as_deref())
+                .flat_map(vst_names)
+                .collect();
+            closure.body = Box::new(vst_subst_expr(*closure.body.clone(), &unbind(subst, &bound))?);
+            Ok(vst::Expr::ClosureExpr(closure))
+        }
+        vst::Expr::MatchExpr(match_expr) => {
+            let mut match_expr = match_expr.clone();
+            match_expr.expr = Box::new(vst_subst_expr(*match_expr.expr.clone(), subst)?);
+            for arm in &mut match_expr.match_arm_list.arms {
+                // the condition of a guard is not part of the VST
+                if arm.guard.is_some() {
+                    return Err("cannot substitute in a match guard".to_string());
+                }
+                let bound = arm.pat.as_deref().map_or_else(Vec::new, vst_names);
+                arm.expr = Box::new(vst_subst_expr(*arm.expr.clone(), &unbind(subst, &bound))?);
+            }
+            Ok(vst::Expr::MatchExpr(match_expr))
+        }
+        // the visitor maps the expression statements and the tail of a block, but not its `let`s
+        vst::Expr::BlockExpr(block)
+            if block.stmt_list.statements.iter().any(|it| {
+                !matches!(it, vst::Stmt::ExprStmt(_)) && mentions(&it.to_string(), subst)
+            }) =>
+        {
+            Err(format!("cannot substitute in `{e}`"))
+        }
+        vst::Expr::Literal(_)
+        | vst::Expr::CallExpr(_)
+        | vst::Expr::AssertExpr(_)
+        | vst::Expr::BlockExpr(_) => Ok(e.clone()),
+        // the visitor does not go through the other expressions
+        _ if mentions(&e.to_string(), subst) => Err(format!("cannot substitute in `{e}`")),
+        _ => Ok(e.clone()),
+    })
+}
+
+/// The identifiers of `pat`, which include the names it binds.
+fn vst_names(pat: &vst::Pat) -> Vec<String> {
+    identifiers(&pat.to_string()).map(|it| it.to_owned()).collect()
+}
+
+/// `subst`, without the variables shadowed by `bound`.
+fn unbind(subst: &FxHashMap<String, vst::Expr>, bound: &[String]) -> FxHashMap<String, vst::Expr> {
+    subst
+        .iter()
+        .filter(|(name, _)| !bound.contains(name))
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect()
+}
+
+fn mentions(text: &str, subst: &FxHashMap<String, vst::Expr>) -> bool {
+    identifiers(text).any(|it| subst.contains_key(it))
+}
+
+fn identifiers(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '_').filter(|it| !it.is_empty())
+}
+
+/// Put an expression in parentheses, unless it binds tighter than any operator
+pub fn vst_parenthesize(exp: vst::Expr) -> vst::Expr {
+    match exp {
+        vst::Expr::Literal(_)
+        | vst::Expr::PathExpr(_)
+        | vst::Expr::ParenExpr(_)
+        | vst::Expr::CallExpr(_)
+        | vst::Expr::MethodCallExpr(_)
+        | vst::Expr::FieldExpr(_)
+        | vst::Expr::IndexExpr(_) => exp,
+        _ => vst::ParenExpr::new(exp).into(),
+    }
+}
+
 /// Preorder walk all the expression's child expressions
 pub fn vst_preorder_expr(exp: &vst::Expr, cb: &mut dyn FnMut(vst::Expr) -> bool) {
     cb(exp.clone());
@@ -135,9 +278,31 @@ pub fn vst_preorder_expr(exp: &vst::Expr, cb: &mut dyn FnMut(vst::Expr) -> bool)
                 vst_preorder_expr(&arg, cb);
             }
         }
+        vst::Expr::MethodCallExpr(e) => {
+            vst_preorder_expr(&e.receiver, cb);
+            for arg in &e.arg_list.args {
+                vst_preorder_expr(&arg, cb);
+            }
+        }
+        vst::Expr::FieldExpr(e) => {
+            vst_preorder_expr(&e.expr, cb);
+        }
+        vst::Expr::IndexExpr(e) => {
+            vst_preorder_expr(&e.base, cb);
+            vst_preorder_expr(&e.index, cb);
+        }
         vst::Expr::AssertExpr(e) => {
             vst_preorder_expr(&e.expr, cb);
         }
+        vst::Expr::ParenExpr(e) => {
+            vst_preorder_expr(&e.expr, cb);
+        }
+        vst::Expr::PrefixExpr(e) => {
+            vst_preorder_expr(&e.expr, cb);
+        }
+        vst::Expr::CastExpr(e) => {
+            vst_preorder_expr(&e.expr, cb);
+        }
         _ => {
             //dgb!("note(warning): base case vst_preorder_expr");
             //dgb!(&exp.to_string());
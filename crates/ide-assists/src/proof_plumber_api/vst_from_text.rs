@@ -29,7 +29,7 @@ impl<'a> AssistContext<'a> {
     /// Generate NameRef from text
     pub fn vst_nameref_from_text(&self, s: &str) -> Option<vst::NameRef> {
         let mut name = vst::NameRef::new();
-        name.ident_token = Some(String::from(s));
+        name.ident_token = Some(s.into());
         Some(name)
     }
 
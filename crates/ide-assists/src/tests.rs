@@ -3,6 +3,8 @@
 #![allow(unused_imports)]
 mod generated;
 
+use std::sync::Arc;
+
 use expect_test::expect;
 use hir::Semantics;
 use ide_db::{
@@ -17,7 +19,10 @@ use test_fixture::WithFixture;
 use test_utils::{assert_eq_text, extract_offset};
 
 use crate::{
-    assists, handlers::Handler, Assist, AssistConfig, AssistContext, AssistKind,
+    assists,
+    handlers::Handler,
+    proof_plumber_api::{verifier::MockVerifier, verus_error::mk_post_failure},
+    verus_quickfixes, Assist, AssistConfig, AssistContext, AssistId, AssistKind,
     AssistResolveStrategy, Assists, SingleResolve, VerusError,
 };
 
@@ -35,6 +40,7 @@ pub(crate) const TEST_CONFIG: AssistConfig = AssistConfig {
     prefer_prelude: true,
     assist_emit_must_use: false,
     term_search_fuel: 400,
+    verus_binary_path: None,
 };
 
 pub(crate) const TEST_CONFIG_IMPORT_ONE: AssistConfig = AssistConfig {
@@ -51,6 +57,7 @@ pub(crate) const TEST_CONFIG_IMPORT_ONE: AssistConfig = AssistConfig {
     prefer_prelude: true,
     assist_emit_must_use: false,
     term_search_fuel: 400,
+    verus_binary_path: None,
 };
 
 pub(crate) const TEST_CONFIG_NO_SNIPPET_CAP: AssistConfig = AssistConfig {
@@ -67,6 +74,7 @@ pub(crate) const TEST_CONFIG_NO_SNIPPET_CAP: AssistConfig = AssistConfig {
     prefer_prelude: true,
     assist_emit_must_use: false,
     term_search_fuel: 400,
+    verus_binary_path: None,
 };
 
 pub(crate) fn with_single_file(text: &str) -> (RootDatabase, FileId) {
@@ -79,6 +87,27 @@ pub(crate) fn check_assist(assist: Handler, ra_fixture_before: &str, ra_fixture_
     check(assist, ra_fixture_before, ExpectedResult::After(&ra_fixture_after), None);
 }
 
+/// Like [`check_assist`], with `verifier` answering in place of Verus. The `verus-integration`
+/// feature runs Verus instead.
+#[track_caller]
+pub(crate) fn check_assist_with_verifier(
+    assist: Handler,
+    verifier: MockVerifier,
+    ra_fixture_before: &str,
+    ra_fixture_after: &str,
+) {
+    let ra_fixture_after = trim_indent(ra_fixture_after);
+    check_with_config(
+        TEST_CONFIG,
+        assist,
+        ra_fixture_before,
+        ExpectedResult::After(&ra_fixture_after),
+        None,
+        vec![],
+        verifier,
+    );
+}
+
 #[track_caller]
 pub(crate) fn check_assist_with_verus_error(
     assist: Handler,
@@ -110,6 +139,7 @@ pub(crate) fn check_assist_no_snippet_cap(
         ExpectedResult::After(&ra_fixture_after),
         None,
         vec![],
+        MockVerifier::verified(),
     );
 }
 
@@ -127,6 +157,7 @@ pub(crate) fn check_assist_import_one(
         ExpectedResult::After(&ra_fixture_after),
         None,
         vec![],
+        MockVerifier::verified(),
     );
 }
 
@@ -155,6 +186,24 @@ pub(crate) fn check_assist_not_applicable(assist: Handler, ra_fixture: &str) {
     check(assist, ra_fixture, ExpectedResult::NotApplicable, None);
 }
 
+/// Like [`check_assist_not_applicable`], with `verifier` answering in place of Verus.
+#[track_caller]
+pub(crate) fn check_assist_not_applicable_with_verifier(
+    assist: Handler,
+    verifier: MockVerifier,
+    ra_fixture: &str,
+) {
+    check_with_config(
+        TEST_CONFIG,
+        assist,
+        ra_fixture,
+        ExpectedResult::NotApplicable,
+        None,
+        vec![],
+        verifier,
+    );
+}
+
 #[track_caller]
 pub(crate) fn check_assist_not_applicable_by_label(assist: Handler, ra_fixture: &str, label: &str) {
     check(assist, ra_fixture, ExpectedResult::NotApplicable, Some(label));
@@ -169,6 +218,7 @@ pub(crate) fn check_assist_not_applicable_for_import_one(assist: Handler, ra_fix
         ExpectedResult::NotApplicable,
         None,
         vec![],
+        MockVerifier::verified(),
     );
 }
 
@@ -228,7 +278,15 @@ enum ExpectedResult<'a> {
 
 #[track_caller]
 fn check(handler: Handler, before: &str, expected: ExpectedResult<'_>, assist_label: Option<&str>) {
-    check_with_config(TEST_CONFIG, handler, before, expected, assist_label, vec![]);
+    check_with_config(
+        TEST_CONFIG,
+        handler,
+        before,
+        expected,
+        assist_label,
+        vec![],
+        MockVerifier::verified(),
+    );
 }
 
 #[track_caller]
@@ -239,7 +297,15 @@ fn check_with_verus_error(
     assist_label: Option<&str>,
     verus_errors: Vec<VerusError>,
 ) {
-    check_with_config(TEST_CONFIG, handler, before, expected, assist_label, verus_errors);
+    check_with_config(
+        TEST_CONFIG,
+        handler,
+        before,
+        expected,
+        assist_label,
+        verus_errors,
+        MockVerifier::verified(),
+    );
 }
 
 #[track_caller]
@@ -250,6 +316,7 @@ fn check_with_config(
     expected: ExpectedResult<'_>,
     assist_label: Option<&str>,
     verus_errors: Vec<VerusError>,
+    verifier: MockVerifier,
 ) {
     let (mut db, file_with_caret_id, range_or_offset) = RootDatabase::with_range_or_offset(before);
     db.enable_proc_attr_macros();
@@ -258,7 +325,10 @@ fn check_with_config(
     let frange = FileRange { file_id: file_with_caret_id, range: range_or_offset.into() };
 
     let sema = Semantics::new(&db);
-    let ctx = AssistContext::new(sema, &config, frange, verus_errors);
+    let mut ctx = AssistContext::new(sema, &config, frange, verus_errors);
+    if !cfg!(feature = "verus-integration") {
+        ctx.verifier = Arc::new(verifier);
+    }
     let resolve = match expected {
         ExpectedResult::Unresolved => AssistResolveStrategy::None,
         _ => AssistResolveStrategy::All,
@@ -450,6 +520,37 @@ pub fn test_some_range(a: int) -> bool {
     }
 }
 
+#[test]
+#[cfg(feature = "proof-action")]
+fn verus_quickfix_for_failing_postcondition() {
+    // The postcondition fails at the end of the body, while its fix triggers on `ensures`.
+    let (db, position) = RootDatabase::with_position(
+        r#"
+proof fn my_proof_fun(x: int, y: int)
+    requires
+        x < 100,
+        y < 100,
+    ensures
+        x + y < 200,
+        x + y < 100,
+{
+    assert(x + y < 600);
+$0}
+"#,
+    );
+    let frange = FileRange { file_id: position.file_id, range: TextRange::empty(position.offset) };
+    let verus_errors = vec![mk_post_failure(126, 137, 139, 167)];
+
+    let fixes = verus_quickfixes(&db, &TEST_CONFIG, frange, verus_errors.clone());
+    assert_eq!(labels(&fixes), "Insert failing ensures clauses to the end\n");
+    assert_eq!(fixes[0].id, AssistId("intro_failing_ensures", AssistKind::QuickFix));
+    assert!(fixes[0].source_change.is_some());
+
+    let mut cfg = TEST_CONFIG;
+    cfg.allowed = Some(vec![AssistKind::RefactorRewrite]);
+    assert!(verus_quickfixes(&db, &cfg, frange, verus_errors).is_empty());
+}
+
 #[test]
 fn various_resolve_strategies() {
     let (db, frange) = RootDatabase::with_range(
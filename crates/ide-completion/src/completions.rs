@@ -21,6 +21,7 @@ pub(crate) mod snippet;
 pub(crate) mod r#type;
 pub(crate) mod use_;
 pub(crate) mod vis;
+pub(crate) mod vstd_method;
 
 use std::iter;
 
@@ -36,7 +37,7 @@ use crate::{
     item::Builder,
     render::{
         const_::render_const,
-        function::{render_fn, render_method},
+        function::{is_ghost_fn, render_fn, render_method},
         literal::{render_struct_literal, render_variant_lit},
         macro_::render_macro,
         pattern::{render_struct_pat, render_variant_pat},
@@ -302,6 +303,10 @@ impl Completions {
         if !ctx.check_stability(Some(&func.attrs(ctx.db))) {
             return;
         }
+        // verus: exec functions cannot be called from proof code
+        if ctx.in_proof_block && !is_ghost_fn(ctx.db, func) {
+            return;
+        }
         let is_private_editable = match ctx.is_visible(&func) {
             Visible::Yes => false,
             Visible::Editable => true,
@@ -760,6 +765,7 @@ pub(super) fn complete_name_ref(
             flyimport::import_on_the_fly_dot(acc, ctx, dot_access);
             dot::complete_dot(acc, ctx, dot_access);
             postfix::complete_postfix(acc, ctx, dot_access);
+            vstd_method::complete_vstd_methods(acc, ctx, dot_access);
         }
         NameRefKind::Keyword(item) => {
             keyword::complete_for_and_where(acc, ctx, item);
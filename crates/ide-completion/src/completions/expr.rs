@@ -1,10 +1,11 @@
 //! Completion of names from the current scope in expression position.
 
 use hir::{ImportPathConfig, ScopeDef};
+use ide_db::syntax_helpers::node_ext::is_in_verus_macro;
 use syntax::ast;
 
 use crate::{
-    completions::record::add_default_update,
+    completions::{keyword::complete_loop_clauses, record::add_default_update},
     context::{BreakableKind, PathCompletionCtx, PathExprCtx, Qualified},
     CompletionContext, Completions,
 };
@@ -24,6 +25,7 @@ pub(crate) fn complete_expr_path(
         in_block_expr,
         in_breakable,
         after_if_expr,
+        ref after_loop_header,
         in_condition,
         incomplete_let,
         ref ref_expr_parent,
@@ -327,6 +329,20 @@ pub(crate) fn complete_expr_path(
                             },
                         );
                     }
+
+                    // verus: proof constructs only make sense inside `verus!`
+                    if ctx.token.parent().map_or(false, |it| is_in_verus_macro(&it)) {
+                        add_keyword("assert", "assert($0)");
+                        add_keyword("assume", "assume($0)");
+                        add_keyword("forall", "forall|$1| $0");
+                        add_keyword("exists", "exists|$1| $0");
+                        if in_block_expr {
+                            add_keyword("proof", "proof {\n    $0\n}");
+                        }
+                        if let Some(loop_expr) = after_loop_header {
+                            complete_loop_clauses(&mut add_keyword, loop_expr);
+                        }
+                    }
                 }
             }
         }
@@ -12,7 +12,10 @@ use crate::{
         CompletionContext, DotAccess, PathCompletionCtx, PathKind, PatternContext, Qualified,
         TypeLocation,
     },
-    render::{render_resolution_with_import, render_resolution_with_import_pat, RenderContext},
+    render::{
+        function::is_ghost_fn, render_resolution_with_import, render_resolution_with_import_pat,
+        RenderContext,
+    },
     Completions,
 };
 
@@ -227,6 +230,10 @@ fn import_on_the_fly(
             ) => mac.is_fn_like(ctx.db),
             (PathKind::Item { .. }, ..) => false,
 
+            // verus: exec functions cannot be called from proof code
+            (PathKind::Expr { .. }, ItemInNs::Values(ModuleDef::Function(func))) => {
+                !ctx.in_proof_block || is_ghost_fn(ctx.db, func)
+            }
             (PathKind::Expr { .. }, ItemInNs::Types(_) | ItemInNs::Values(_)) => true,
 
             (PathKind::Pat { .. }, ItemInNs::Types(_)) => true,
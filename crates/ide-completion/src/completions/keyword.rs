@@ -1,6 +1,7 @@
-//! Completes `where` and `for` keywords.
+//! Completes `where` and `for` keywords, and the verus clauses of function signatures and loops.
 
-use syntax::ast::{self, Item};
+use ide_db::syntax_helpers::node_ext::is_in_verus_macro;
+use syntax::ast::{self, AstNode, Item};
 
 use crate::{CompletionContext, Completions};
 
@@ -18,18 +19,70 @@ pub(crate) fn complete_for_and_where(
             }
             add_keyword("where", "where $0");
         }
-        Item::Enum(_)
-        | Item::Fn(_)
-        | Item::Struct(_)
-        | Item::Trait(_)
-        | Item::TypeAlias(_)
-        | Item::Union(_) => {
+        Item::Fn(it) => {
+            add_keyword("where", "where $0");
+            if is_in_verus_macro(it.syntax()) {
+                complete_fn_clauses(&mut add_keyword, it);
+            }
+        }
+        Item::Enum(_) | Item::Struct(_) | Item::Trait(_) | Item::TypeAlias(_) | Item::Union(_) => {
             add_keyword("where", "where $0");
         }
         _ => (),
     }
 }
 
+/// Offers the specification clauses that may still follow the signature of `func`, respecting
+/// the `requires`, `recommends`, `ensures`, `decreases` order verus expects.
+fn complete_fn_clauses(add_keyword: &mut impl FnMut(&'static str, &'static str), func: &ast::Fn) {
+    let present = [
+        func.requires_clause().is_some(),
+        func.recommends_clause().is_some(),
+        func.ensures_clause().is_some(),
+        func.signature_decreases().is_some(),
+    ];
+    let clauses = [
+        ("requires", "requires\n    $0"),
+        ("recommends", "recommends\n    $0"),
+        ("ensures", "ensures\n    $0"),
+        ("decreases", "decreases $0"),
+    ];
+    let first_missing = present.iter().rposition(|&it| it).map_or(0, |it| it + 1);
+    for &(kw, snippet) in &clauses[first_missing..] {
+        add_keyword(kw, snippet);
+    }
+}
+
+/// Offers the clauses that may still follow the header of `loop_expr`, a `while` or a `loop`,
+/// respecting the `invariant_except_break`, `invariant`, `ensures`, `decreases` order verus
+/// expects.
+pub(crate) fn complete_loop_clauses(
+    add_keyword: &mut impl FnMut(&'static str, &'static str),
+    loop_expr: &ast::Expr,
+) {
+    let clauses: Vec<ast::LoopClause> = match loop_expr {
+        ast::Expr::WhileExpr(it) => it.loop_clauses().collect(),
+        ast::Expr::LoopExpr(it) => it.loop_clauses().collect(),
+        _ => return,
+    };
+    let present = [
+        clauses.iter().any(|it| matches!(it, ast::LoopClause::InvariantExceptBreakClause(_))),
+        clauses.iter().any(|it| matches!(it, ast::LoopClause::InvariantClause(_))),
+        clauses.iter().any(|it| matches!(it, ast::LoopClause::EnsuresClause(_))),
+        clauses.iter().any(|it| matches!(it, ast::LoopClause::DecreasesClause(_))),
+    ];
+    let snippets = [
+        ("invariant_except_break", "invariant_except_break\n    $0"),
+        ("invariant", "invariant\n    $0"),
+        ("ensures", "ensures\n    $0"),
+        ("decreases", "decreases $0"),
+    ];
+    let first_missing = present.iter().rposition(|&it| it).map_or(0, |it| it + 1);
+    for &(kw, snippet) in &snippets[first_missing..] {
+        add_keyword(kw, snippet);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use expect_test::{expect, Expect};
@@ -52,6 +105,105 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_verus_proof_keywords_edit() {
+        check_edit(
+            "assert",
+            r#"
+verus! {
+fn quux() { $0 }
+}
+"#,
+            r#"
+verus! {
+fn quux() { assert($0) }
+}
+"#,
+        );
+        check_edit(
+            "forall",
+            r#"
+verus! {
+spec fn quux() -> bool { $0 }
+}
+"#,
+            r#"
+verus! {
+spec fn quux() -> bool { forall|$1| $0 }
+}
+"#,
+        );
+        check_edit(
+            "requires",
+            r#"
+verus! {
+fn quux(x: u32) $0
+}
+"#,
+            r#"
+verus! {
+fn quux(x: u32) requires
+    $0
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_verus_loop_clauses_edit() {
+        check_edit(
+            "invariant",
+            r#"
+verus! {
+fn quux(n: u32) {
+    let mut i = 0;
+    while i < n $0
+    {
+        i = i + 1;
+    }
+}
+}
+"#,
+            r#"
+verus! {
+fn quux(n: u32) {
+    let mut i = 0;
+    while i < n invariant
+    $0
+    {
+        i = i + 1;
+    }
+}
+}
+"#,
+        );
+        check_edit(
+            "decreases",
+            r#"
+verus! {
+fn quux(n: u32) {
+    let mut i = 0;
+    loop $0
+    {
+        i = i + 1;
+    }
+}
+}
+"#,
+            r#"
+verus! {
+fn quux(n: u32) {
+    let mut i = 0;
+    loop decreases $0
+    {
+        i = i + 1;
+    }
+}
+}
+"#,
+        );
+    }
+
     #[test]
     fn test_keywords_after_unsafe_in_block_expr() {
         check(
@@ -2,10 +2,11 @@
 
 mod format_like;
 
-use hir::{ImportPathConfig, ItemInNs};
+use hir::{ImportPathConfig, ItemInNs, ModuleDef, PathResolution};
 use ide_db::{
     documentation::{Documentation, HasDocs},
     imports::insert_use::ImportScope,
+    syntax_helpers::node_ext::is_in_verus_macro,
     ty_filter::TryEnum,
     SnippetCap,
 };
@@ -169,6 +170,10 @@ pub(crate) fn complete_postfix(
     };
     postfix_snippet("unsafe", "unsafe {}", &unsafe_completion_string).add_to(acc, ctx.db);
 
+    if is_in_verus_macro(dot_receiver.syntax()) {
+        add_verus_postfix_completions(acc, ctx, &postfix_snippet, dot_access, &receiver_text);
+    }
+
     // The rest of the postfix completions create an expression that moves an argument,
     // so it's better to consider references now to avoid breaking the compilation
 
@@ -260,6 +265,42 @@ pub(crate) fn complete_postfix(
     }
 }
 
+/// Postfix completions for common proof idioms, e.g. `v.view` => `v@`.
+fn add_verus_postfix_completions(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    postfix_snippet: &impl Fn(&str, &str, &str) -> Builder,
+    dot_access: &DotAccess,
+    receiver_text: &str,
+) {
+    let (Some(receiver), Some(receiver_ty)) = (&dot_access.receiver, &dot_access.receiver_ty)
+    else {
+        return;
+    };
+    let semi = if dot_access.ctx.in_block_expr { ";" } else { "" };
+
+    postfix_snippet("view", "expr@", &format!("{receiver_text}@")).add_to(acc, ctx.db);
+
+    if receiver_ty.original.is_bool() || receiver_ty.original.is_unknown() {
+        postfix_snippet("assert", "assert(expr)", &format!("assert({receiver_text}){semi}"))
+            .add_to(acc, ctx.db);
+        postfix_snippet("choose", "choose|x| expr", &format!("choose|${{1:x}}| {receiver_text}"))
+            .add_to(acc, ctx.db);
+    }
+
+    let is_fn = match receiver {
+        ast::Expr::PathExpr(it) => it
+            .path()
+            .and_then(|path| ctx.sema.resolve_path(&path))
+            .map_or(false, |it| matches!(it, PathResolution::Def(ModuleDef::Function(_)))),
+        _ => false,
+    };
+    if is_fn {
+        postfix_snippet("reveal", "reveal(f)", &format!("reveal({receiver_text}){semi}"))
+            .add_to(acc, ctx.db);
+    }
+}
+
 fn get_receiver_text(receiver: &ast::Expr, receiver_is_ambiguous_float_literal: bool) -> String {
     let mut text = if receiver_is_ambiguous_float_literal {
         let text = receiver.syntax().text();
@@ -851,4 +892,61 @@ fn test() {
             expect![[r#""#]],
         );
     }
+
+    #[test]
+    fn verus_postfix_completions() {
+        check_edit(
+            "assert",
+            r#"
+verus! {
+fn main(x: u32) {
+    (x > 0).$0
+}
+}
+"#,
+            r#"
+verus! {
+fn main(x: u32) {
+    assert((x > 0));
+}
+}
+"#,
+        );
+        check_edit(
+            "view",
+            r#"
+verus! {
+fn main(v: u32) {
+    let s = v.$0
+}
+}
+"#,
+            r#"
+verus! {
+fn main(v: u32) {
+    let s = v@
+}
+}
+"#,
+        );
+        check_edit(
+            "reveal",
+            r#"
+verus! {
+spec fn f() -> bool { true }
+proof fn main() {
+    f.$0
+}
+}
+"#,
+            r#"
+verus! {
+spec fn f() -> bool { true }
+proof fn main() {
+    reveal(f);
+}
+}
+"#,
+        );
+    }
 }
@@ -1,6 +1,9 @@
 //! This file provides snippet completions, like `pd` => `eprintln!(...)`.
 
-use ide_db::{documentation::Documentation, imports::insert_use::ImportScope, SnippetCap};
+use ide_db::{
+    documentation::Documentation, imports::insert_use::ImportScope,
+    syntax_helpers::node_ext::is_in_verus_macro, SnippetCap,
+};
 
 use crate::{
     context::{ItemListKind, PathCompletionCtx, PathExprCtx, Qualified},
@@ -45,6 +48,12 @@ macro_rules! $1 {
 }",
         );
         item.add_to(acc, ctx.db);
+
+        if in_verus_macro(ctx) {
+            let mut item = snippet(ctx, cap, "assert by", "assert($1) by {\n    $0\n}");
+            item.lookup_by("assertby");
+            item.add_to(acc, ctx.db);
+        }
     }
 }
 
@@ -115,6 +124,58 @@ macro_rules! $1 {
         );
         item.add_to(acc, ctx.db);
     }
+
+    if !matches!(kind, ItemListKind::ExternBlock) && in_verus_macro(ctx) {
+        let mut item = snippet(
+            ctx,
+            cap,
+            "pfn (Proof function)",
+            "\
+proof fn ${1:name}($2)
+    requires
+        ${3:true},
+    ensures
+        ${4:true},
+    decreases
+        ${5:0},
+{
+    $0
+}",
+        );
+        item.lookup_by("pfn");
+        item.add_to(acc, ctx.db);
+
+        let mut item = snippet(
+            ctx,
+            cap,
+            "sfn (Spec function)",
+            "\
+spec fn ${1:name}($2) -> ${3:bool} {
+    $0
+}",
+        );
+        item.lookup_by("sfn");
+        item.add_to(acc, ctx.db);
+
+        let mut item = snippet(
+            ctx,
+            cap,
+            "lemma (Lemma)",
+            "\
+proof fn lemma_${1:name}()
+    ensures
+        forall|${2:i: int}| $3,
+{
+    $0
+}",
+        );
+        item.lookup_by("lemma");
+        item.add_to(acc, ctx.db);
+    }
+}
+
+fn in_verus_macro(ctx: &CompletionContext<'_>) -> bool {
+    ctx.token.parent().map_or(false, |it| is_in_verus_macro(&it))
 }
 
 fn snippet(ctx: &CompletionContext<'_>, cap: SnippetCap, label: &str, snippet: &str) -> Builder {
@@ -152,7 +213,7 @@ fn add_custom_completions(
 #[cfg(test)]
 mod tests {
     use crate::{
-        tests::{check_edit_with_config, TEST_CONFIG},
+        tests::{check_edit, check_edit_with_config, TEST_CONFIG},
         CompletionConfig, Snippet,
     };
 
@@ -180,6 +241,47 @@ fn main() { $0 }
 use core::ops::ControlFlow;
 
 fn main() { ControlFlow::Break(()) }
+"#,
+        );
+    }
+
+    #[test]
+    fn proof_item_snippets() {
+        check_edit(
+            "lemma",
+            r#"
+verus! {
+$0
+}
+"#,
+            r#"
+verus! {
+proof fn lemma_${1:name}()
+    ensures
+        forall|${2:i: int}| $3,
+{
+    $0
+}
+}
+"#,
+        );
+        check_edit(
+            "assertby",
+            r#"
+verus! {
+proof fn f() {
+    $0
+}
+}
+"#,
+            r#"
+verus! {
+proof fn f() {
+    assert($1) by {
+    $0
+}
+}
+}
 "#,
         );
     }
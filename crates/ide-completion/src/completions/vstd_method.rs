@@ -0,0 +1,359 @@
+//! Completes spec methods of the vstd collections (`Seq`, `Set`, `Map` and `Multiset`) from a
+//! curated index.
+//!
+//! Inference does not model most of the spec-level code yet (views, `int`/`nat` arithmetic, vstd
+//! not being resolvable), which leaves receivers like `v@` without a type and the regular method
+//! completion without anything to offer. The index below covers the most commonly used methods.
+
+use hir::{HasSource, PathResolution, Semantics};
+use ide_db::{syntax_helpers::node_ext::is_in_verus_macro, RootDatabase, SymbolKind};
+use itertools::Itertools;
+use stdx::format_to;
+use syntax::{ast, AstNode};
+
+use crate::{
+    context::{DotAccess, DotAccessKind},
+    CompletionContext, CompletionItem, CompletionItemKind, Completions,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Collection {
+    Seq,
+    Set,
+    Map,
+    Multiset,
+}
+
+impl Collection {
+    const ALL: [Collection; 4] =
+        [Collection::Seq, Collection::Set, Collection::Map, Collection::Multiset];
+
+    fn from_name(name: &str) -> Option<Collection> {
+        let it = match name {
+            "Seq" => Collection::Seq,
+            "Set" => Collection::Set,
+            "Map" => Collection::Map,
+            "Multiset" => Collection::Multiset,
+            _ => return None,
+        };
+        Some(it)
+    }
+
+    /// The collection an exec type is viewed as through `@`.
+    fn from_view_of(name: &str) -> Option<Collection> {
+        let it = match name {
+            "Vec" | "VecDeque" | "String" | "str" => Collection::Seq,
+            "HashSet" => Collection::Set,
+            "HashMap" => Collection::Map,
+            _ => return None,
+        };
+        Some(it)
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Collection::Seq => "Seq",
+            Collection::Set => "Set",
+            Collection::Map => "Map",
+            Collection::Multiset => "Multiset",
+        }
+    }
+
+    fn methods(self) -> &'static [SpecMethod] {
+        match self {
+            Collection::Seq => SEQ_METHODS,
+            Collection::Set => SET_METHODS,
+            Collection::Map => MAP_METHODS,
+            Collection::Multiset => MULTISET_METHODS,
+        }
+    }
+}
+
+struct SpecMethod {
+    name: &'static str,
+    params: &'static [(&'static str, &'static str)],
+    ret: &'static str,
+}
+
+const fn m(
+    name: &'static str,
+    params: &'static [(&'static str, &'static str)],
+    ret: &'static str,
+) -> SpecMethod {
+    SpecMethod { name, params, ret }
+}
+
+const SEQ_METHODS: &[SpecMethod] = &[
+    m("len", &[], "nat"),
+    m("index", &[("i", "int")], "A"),
+    m("push", &[("a", "A")], "Seq<A>"),
+    m("update", &[("i", "int"), ("a", "A")], "Seq<A>"),
+    m("subrange", &[("start_inclusive", "int"), ("end_exclusive", "int")], "Seq<A>"),
+    m("add", &[("rhs", "Seq<A>")], "Seq<A>"),
+    m("first", &[], "A"),
+    m("last", &[], "A"),
+    m("drop_last", &[], "Seq<A>"),
+    m("take", &[("n", "int")], "Seq<A>"),
+    m("skip", &[("n", "int")], "Seq<A>"),
+    m("contains", &[("needle", "A")], "bool"),
+    m("index_of", &[("needle", "A")], "int"),
+    m("no_duplicates", &[], "bool"),
+    m("to_set", &[], "Set<A>"),
+    m("to_multiset", &[], "Multiset<A>"),
+    m("filter", &[("pred", "spec_fn(A) -> bool")], "Seq<A>"),
+    m("map", &[("f", "spec_fn(int, A) -> B")], "Seq<B>"),
+    m("map_values", &[("f", "spec_fn(A) -> B")], "Seq<B>"),
+];
+
+const SET_METHODS: &[SpecMethod] = &[
+    m("len", &[], "nat"),
+    m("contains", &[("a", "A")], "bool"),
+    m("insert", &[("a", "A")], "Set<A>"),
+    m("remove", &[("a", "A")], "Set<A>"),
+    m("union", &[("s2", "Set<A>")], "Set<A>"),
+    m("intersect", &[("s2", "Set<A>")], "Set<A>"),
+    m("difference", &[("s2", "Set<A>")], "Set<A>"),
+    m("subset_of", &[("s2", "Set<A>")], "bool"),
+    m("disjoint", &[("s2", "Set<A>")], "bool"),
+    m("finite", &[], "bool"),
+    m("is_empty", &[], "bool"),
+    m("choose", &[], "A"),
+    m("filter", &[("f", "spec_fn(A) -> bool")], "Set<A>"),
+    m("map", &[("f", "spec_fn(A) -> B")], "Set<B>"),
+];
+
+const MAP_METHODS: &[SpecMethod] = &[
+    m("len", &[], "nat"),
+    m("dom", &[], "Set<K>"),
+    m("values", &[], "Set<V>"),
+    m("index", &[("key", "K")], "V"),
+    m("contains_key", &[("k", "K")], "bool"),
+    m("contains_value", &[("v", "V")], "bool"),
+    m("insert", &[("key", "K"), ("value", "V")], "Map<K, V>"),
+    m("remove", &[("key", "K")], "Map<K, V>"),
+    m("restrict", &[("keys", "Set<K>")], "Map<K, V>"),
+    m("remove_keys", &[("keys", "Set<K>")], "Map<K, V>"),
+    m("union_prefer_right", &[("m2", "Map<K, V>")], "Map<K, V>"),
+    m("submap_of", &[("m2", "Map<K, V>")], "bool"),
+];
+
+const MULTISET_METHODS: &[SpecMethod] = &[
+    m("len", &[], "nat"),
+    m("count", &[("value", "V")], "nat"),
+    m("contains", &[("v", "V")], "bool"),
+    m("insert", &[("v", "V")], "Multiset<V>"),
+    m("remove", &[("v", "V")], "Multiset<V>"),
+    m("add", &[("m2", "Multiset<V>")], "Multiset<V>"),
+    m("sub", &[("m2", "Multiset<V>")], "Multiset<V>"),
+    m("subset_of", &[("m2", "Multiset<V>")], "bool"),
+    m("dom", &[], "Set<V>"),
+];
+
+pub(crate) fn complete_vstd_methods(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    dot_access: &DotAccess,
+) {
+    let Some(receiver) = &dot_access.receiver else { return };
+    // Receivers with a known type are served by the regular method completion.
+    if dot_access.receiver_ty.as_ref().map_or(false, |it| !it.original.contains_unknown()) {
+        return;
+    }
+    if !is_in_verus_macro(receiver.syntax()) {
+        return;
+    }
+
+    let has_parens = matches!(dot_access.kind, DotAccessKind::Method { has_parens: true });
+    match receiver_collection(&ctx.sema, receiver) {
+        Some(collection) => {
+            for method in collection.methods() {
+                add_method(acc, ctx, method, has_parens, None);
+            }
+        }
+        // Nothing is known about the view, offer the methods of all collections once.
+        None if matches!(receiver, ast::Expr::ViewExpr(_)) => {
+            let methods = Collection::ALL
+                .iter()
+                .flat_map(|&collection| collection.methods().iter().map(move |it| (collection, it)))
+                .into_group_map_by(|(_, it)| it.name);
+            for (_, group) in methods.into_iter().sorted_by_key(|(name, _)| *name) {
+                let owners = group.iter().map(|(collection, _)| collection.name()).join(", ");
+                add_method(acc, ctx, group[0].1, has_parens, Some(owners));
+            }
+        }
+        None => (),
+    }
+}
+
+fn add_method(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    method: &SpecMethod,
+    has_parens: bool,
+    owners: Option<String>,
+) {
+    let params = method.params.iter().map(|(name, ty)| format!(", {name}: {ty}")).join("");
+    let mut detail = format!("spec fn(self{params}) -> {}", method.ret);
+    if let Some(owners) = owners {
+        format_to!(detail, " ({owners})");
+    }
+
+    let label = if method.params.is_empty() {
+        format!("{}()", method.name)
+    } else {
+        format!("{}(…)", method.name)
+    };
+    let mut item = CompletionItem::new(
+        CompletionItemKind::SymbolKind(SymbolKind::Method),
+        ctx.source_range(),
+        label,
+    );
+    item.detail(detail).lookup_by(method.name);
+    match ctx.config.snippet_cap {
+        Some(cap) if !has_parens => {
+            let args = method
+                .params
+                .iter()
+                .enumerate()
+                .map(|(idx, (name, _))| format!("${{{}:{name}}}", idx + 1))
+                .join(", ");
+            item.insert_snippet(cap, format!("{}({args})$0", method.name));
+        }
+        _ => {
+            item.insert_text(method.name);
+        }
+    }
+    item.add_to(acc, ctx.db);
+}
+
+/// Figures out which collection `receiver` is from its syntax: the type annotation of the local
+/// or field it refers to, or the exec type a view (`v@`) is taken of.
+fn receiver_collection(
+    sema: &Semantics<'_, RootDatabase>,
+    receiver: &ast::Expr,
+) -> Option<Collection> {
+    match receiver {
+        ast::Expr::ViewExpr(view) => {
+            let inner = view.expr()?;
+            let adt = sema.type_of_expr(&inner)?.original.strip_references().as_adt();
+            match adt {
+                Some(adt) => Collection::from_view_of(adt.name(sema.db).as_str()?),
+                None => Collection::from_view_of(&declared_type_name(sema, &inner)?),
+            }
+        }
+        ast::Expr::ParenExpr(it) => receiver_collection(sema, &it.expr()?),
+        _ => Collection::from_name(&declared_type_name(sema, receiver)?),
+    }
+}
+
+/// The name of the outermost type in the annotation of the local or field `expr` refers to.
+fn declared_type_name(sema: &Semantics<'_, RootDatabase>, expr: &ast::Expr) -> Option<String> {
+    let ty = match expr {
+        ast::Expr::PathExpr(path) => match sema.resolve_path(&path.path()?)? {
+            PathResolution::Local(local) => {
+                let pat = local.primary_source(sema.db).into_ident_pat()?;
+                let binder = pat.syntax().parent()?;
+                ast::Param::cast(binder.clone())
+                    .and_then(|it| it.ty())
+                    .or_else(|| ast::LetStmt::cast(binder)?.ty())?
+            }
+            _ => return None,
+        },
+        ast::Expr::FieldExpr(field) => {
+            let field = sema.resolve_field(field)?.left()?;
+            match field.source(sema.db)?.value {
+                hir::FieldSource::Named(it) => it.ty()?,
+                hir::FieldSource::Pos(it) => it.ty()?,
+            }
+        }
+        _ => return None,
+    };
+    match ty {
+        ast::Type::PathType(it) => Some(it.path()?.segment()?.name_ref()?.text().to_string()),
+        ast::Type::RefType(it) => match it.ty()? {
+            ast::Type::PathType(it) => Some(it.path()?.segment()?.name_ref()?.text().to_string()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::tests::{check_edit, completion_list_no_kw};
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list_no_kw(ra_fixture);
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn completes_methods_of_annotated_collection() {
+        check(
+            r#"
+verus! {
+spec fn f(s: Set<int>) -> bool {
+    s.$0
+}
+}
+"#,
+            expect![[r#"
+                me choose()      spec fn(self) -> A
+                me contains(…)   spec fn(self, a: A) -> bool
+                me difference(…) spec fn(self, s2: Set<A>) -> Set<A>
+                me disjoint(…)   spec fn(self, s2: Set<A>) -> bool
+                me filter(…)     spec fn(self, f: spec_fn(A) -> bool) -> Set<A>
+                me finite()      spec fn(self) -> bool
+                me insert(…)     spec fn(self, a: A) -> Set<A>
+                me intersect(…)  spec fn(self, s2: Set<A>) -> Set<A>
+                me is_empty()    spec fn(self) -> bool
+                me len()         spec fn(self) -> nat
+                me map(…)        spec fn(self, f: spec_fn(A) -> B) -> Set<B>
+                me remove(…)     spec fn(self, a: A) -> Set<A>
+                me subset_of(…)  spec fn(self, s2: Set<A>) -> bool
+                me union(…)      spec fn(self, s2: Set<A>) -> Set<A>
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_seq_methods_of_vec_view() {
+        check_edit(
+            "subrange",
+            r#"
+struct Vec<T>(T);
+verus! {
+fn f(v: Vec<u8>)
+    requires
+        v@.$0
+{
+}
+}
+"#,
+            r#"
+struct Vec<T>(T);
+verus! {
+fn f(v: Vec<u8>)
+    requires
+        v@.subrange(${1:start_inclusive}, ${2:end_exclusive})$0
+{
+}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_curated_methods_outside_verus() {
+        check(
+            r#"
+fn f(s: Set<int>) {
+    s.$0
+}
+"#,
+            expect![[r#""#]],
+        );
+    }
+}
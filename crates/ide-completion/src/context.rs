@@ -13,6 +13,7 @@ use ide_db::{
     base_db::{FilePosition, SourceDatabase},
     famous_defs::FamousDefs,
     helpers::is_editable_crate,
+    syntax_helpers::node_ext::is_in_proof_block,
     FxHashMap, FxHashSet, RootDatabase,
 };
 use syntax::{
@@ -136,6 +137,9 @@ pub(crate) struct PathExprCtx {
     pub(crate) in_block_expr: bool,
     pub(crate) in_breakable: BreakableKind,
     pub(crate) after_if_expr: bool,
+    /// verus: the `while` or `loop` expression right before this one, if its body is missing, as
+    /// when typing the clauses of its header
+    pub(crate) after_loop_header: Option<ast::Expr>,
     /// Whether this expression is the direct condition of an if or while expression
     pub(crate) in_condition: bool,
     pub(crate) incomplete_let: bool,
@@ -455,6 +459,8 @@ pub(crate) struct CompletionContext<'a> {
     ///
     /// Here depth will be 2
     pub(crate) depth_from_crate_root: usize,
+    /// Whether the cursor is in proof code, where only `spec` and `proof` functions may be called.
+    pub(crate) in_proof_block: bool,
 }
 
 impl CompletionContext<'_> {
@@ -731,6 +737,8 @@ impl<'a> CompletionContext<'a> {
 
         let depth_from_crate_root = iter::successors(module.parent(db), |m| m.parent(db)).count();
 
+        let in_proof_block = token.parent().map_or(false, |it| is_in_proof_block(&it));
+
         let ctx = CompletionContext {
             sema,
             scope,
@@ -747,6 +755,7 @@ impl<'a> CompletionContext<'a> {
             qualifier_ctx,
             locals,
             depth_from_crate_root,
+            in_proof_block,
         };
         Some((ctx, analysis))
     }
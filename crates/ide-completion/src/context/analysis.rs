@@ -735,19 +735,24 @@ fn classify_name_ref(
             None
         }
     };
-    let after_if_expr = |node: SyntaxNode| {
-        let prev_expr = (|| {
-            let node = match node.parent().and_then(ast::ExprStmt::cast) {
-                Some(stmt) => stmt.syntax().clone(),
-                None => node,
-            };
-            let prev_sibling = non_trivia_sibling(node.into(), Direction::Prev)?.into_node()?;
+    let prev_expr = |node: SyntaxNode| {
+        let node = match node.parent().and_then(ast::ExprStmt::cast) {
+            Some(stmt) => stmt.syntax().clone(),
+            None => node,
+        };
+        let prev_sibling = non_trivia_sibling(node.into(), Direction::Prev)?.into_node()?;
 
-            ast::ExprStmt::cast(prev_sibling.clone())
-                .and_then(|it| it.expr())
-                .or_else(|| ast::Expr::cast(prev_sibling))
-        })();
-        matches!(prev_expr, Some(ast::Expr::IfExpr(_)))
+        ast::ExprStmt::cast(prev_sibling.clone())
+            .and_then(|it| it.expr())
+            .or_else(|| ast::Expr::cast(prev_sibling))
+    };
+    let after_if_expr = |node: SyntaxNode| matches!(prev_expr(node), Some(ast::Expr::IfExpr(_)));
+    let after_loop_header = |node: SyntaxNode| {
+        prev_expr(node).filter(|it| match it {
+            ast::Expr::WhileExpr(it) => it.loop_body().is_none(),
+            ast::Expr::LoopExpr(it) => it.loop_body().is_none(),
+            _ => false,
+        })
     };
 
     // We do not want to generate path completions when we are sandwiched between an item decl signature and its body.
@@ -996,6 +1001,7 @@ fn classify_name_ref(
         let in_block_expr = is_in_block(it);
         let in_loop_body = is_in_breakable(it);
         let after_if_expr = after_if_expr(it.clone());
+        let after_loop_header = after_loop_header(it.clone());
         let ref_expr_parent =
             path.as_single_name_ref().and_then(|_| it.parent()).and_then(ast::RefExpr::cast);
         let (innermost_ret_ty, self_param) = {
@@ -1065,6 +1071,7 @@ fn classify_name_ref(
                 in_block_expr,
                 in_breakable: in_loop_body,
                 after_if_expr,
+                after_loop_header,
                 in_condition,
                 ref_expr_parent,
                 is_func_update,
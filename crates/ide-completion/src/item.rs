@@ -168,6 +168,8 @@ pub struct CompletionRelevance {
     pub is_definite: bool,
     /// This is set for items that are function (associated or method)
     pub function: Option<CompletionRelevanceFn>,
+    /// This is set for `proof fn`s (lemmas) completed in proof code.
+    pub is_lemma: bool,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -252,6 +254,7 @@ impl CompletionRelevance {
             is_definite,
             is_item_from_notable_trait,
             function,
+            is_lemma,
         } = self;
 
         // lower rank private things
@@ -296,6 +299,9 @@ impl CompletionRelevance {
         if is_definite {
             score += 10;
         }
+        if is_lemma {
+            score += 15;
+        }
 
         score += function
             .map(|asf| {
@@ -731,6 +731,7 @@ mod tests {
                 ),
                 (relevance.is_op_method, "op_method"),
                 (relevance.requires_import, "requires_import"),
+                (relevance.is_lemma, "lemma"),
             ]
             .into_iter()
             .filter_map(|(cond, desc)| if cond { Some(desc) } else { None })
@@ -740,6 +741,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn prefers_lemmas_in_proof_code() {
+        check_relevance_for_kinds(
+            r#"
+proof fn lemma_pos(x: u64)
+    ensures
+        x >= 0,
+{
+}
+spec fn double(x: u64) -> u64 { x }
+fn exec_fn() {}
+
+fn main() {
+    assert(true) by { $0 }
+}
+"#,
+            &[CompletionItemKind::SymbolKind(SymbolKind::Function)],
+            expect![[r#"
+                fn lemma_pos(…) [lemma]
+                fn double(…) []
+            "#]],
+        );
+
+        let completions = do_completion(
+            r#"
+proof fn lemma_pos(x: u64)
+    ensures
+        x >= 0,
+{
+}
+proof fn main() { $0 }
+"#,
+            CompletionItemKind::SymbolKind(SymbolKind::Function),
+        );
+        let lemma = completions.iter().find(|it| it.label == "lemma_pos(…)").unwrap();
+        assert_eq!(lemma.detail.as_deref(), Some("fn(u64) ensures x >= 0"));
+    }
+
     #[test]
     fn set_struct_type_completion_info() {
         check_relevance(
@@ -1254,6 +1293,7 @@ fn main() { let _: m::Spam = S$0 }
                             postfix_match: None,
                             is_definite: false,
                             function: None,
+                            is_lemma: false,
                         },
                         trigger_call_info: true,
                     },
@@ -1282,6 +1322,7 @@ fn main() { let _: m::Spam = S$0 }
                             postfix_match: None,
                             is_definite: false,
                             function: None,
+                            is_lemma: false,
                         },
                         trigger_call_info: true,
                     },
@@ -1362,6 +1403,7 @@ fn foo() { A { the$0 } }
                             postfix_match: None,
                             is_definite: false,
                             function: None,
+                            is_lemma: false,
                         },
                     },
                 ]
@@ -1419,6 +1461,7 @@ impl S {
                                     return_type: Other,
                                 },
                             ),
+                            is_lemma: false,
                         },
                     },
                     CompletionItem {
@@ -1546,6 +1589,7 @@ fn foo(s: S) { s.$0 }
                                     return_type: Other,
                                 },
                             ),
+                            is_lemma: false,
                         },
                     },
                 ]
@@ -1756,6 +1800,7 @@ fn f() -> i32 {
                             postfix_match: None,
                             is_definite: false,
                             function: None,
+                            is_lemma: false,
                         },
                     },
                 ]
@@ -2480,6 +2525,7 @@ fn foo(f: Foo) { let _: &u32 = f.b$0 }
                                     return_type: Other,
                                 },
                             ),
+                            is_lemma: false,
                         },
                         ref_match: "&@107",
                     },
@@ -2556,6 +2602,7 @@ fn foo() {
                             postfix_match: None,
                             is_definite: false,
                             function: None,
+                            is_lemma: false,
                         },
                     },
                 ]
@@ -2612,6 +2659,7 @@ fn main() {
                                     return_type: Other,
                                 },
                             ),
+                            is_lemma: false,
                         },
                         ref_match: "&@92",
                     },
@@ -2978,6 +3026,7 @@ fn main() {
                             postfix_match: None,
                             is_definite: false,
                             function: None,
+                            is_lemma: false,
                         },
                     },
                     CompletionItem {
@@ -3003,6 +3052,7 @@ fn main() {
                             postfix_match: None,
                             is_definite: false,
                             function: None,
+                            is_lemma: false,
                         },
                     },
                 ]
@@ -1,6 +1,6 @@
 //! Renderer for function calls.
 
-use hir::{db::HirDatabase, AsAssocItem, HirDisplay};
+use hir::{db::HirDatabase, AsAssocItem, HasSource, HirDisplay};
 use ide_db::{SnippetCap, SymbolKind};
 use itertools::Itertools;
 use stdx::{format_to, to_lower_snake_case};
@@ -86,6 +86,8 @@ fn render(
     let is_item_from_notable_trait =
         trait_.map_or(false, |trait_| completion.is_doc_notable_trait(trait_));
 
+    let lemma_ensures = if completion.in_proof_block { proof_fn_ensures(db, func) } else { None };
+
     let (has_dot_receiver, has_call_parens, cap) = match func_kind {
         FuncKind::Function(&PathCompletionCtx {
             kind: PathKind::Expr { .. },
@@ -123,6 +125,7 @@ fn render(
         function,
         is_op_method,
         is_item_from_notable_trait,
+        is_lemma: lemma_ensures.is_some(),
         ..ctx.completion_relevance()
     });
 
@@ -140,11 +143,14 @@ fn render(
         _ => (),
     }
 
-    let detail = if ctx.completion.config.full_function_signatures {
+    let mut detail = if ctx.completion.config.full_function_signatures {
         detail_full(db, func)
     } else {
         detail(db, func)
     };
+    if let Some(ensures) = lemma_ensures.filter(|it| !it.is_empty()) {
+        format_to!(detail, " ensures {ensures}");
+    }
     item.set_documentation(ctx.docs(func))
         .set_deprecated(ctx.is_deprecated(func) || ctx.is_deprecated_assoc_item(func))
         .detail(detail)
@@ -171,6 +177,20 @@ fn render(
     item
 }
 
+/// Whether `func` is a `spec` or `proof` function, and hence callable from proof code.
+pub(crate) fn is_ghost_fn(db: &dyn HirDatabase, func: hir::Function) -> bool {
+    let mode = func.source(db).and_then(|it| it.value.fn_mode());
+    mode.map_or(false, |it| it.exec_token().is_none())
+}
+
+/// For a `proof fn`, renders the expressions of its `ensures` clause on a single line.
+fn proof_fn_ensures(db: &dyn HirDatabase, func: hir::Function) -> Option<String> {
+    let func = func.source(db)?.value;
+    func.fn_mode()?.proof_token()?;
+    let ensures = func.ensures_clause().into_iter().flat_map(|it| it.exprs());
+    Some(ensures.map(|it| it.syntax().text().to_string()).join(", "))
+}
+
 fn compute_return_type_match(
     db: &dyn HirDatabase,
     ctx: &RenderContext<'_>,
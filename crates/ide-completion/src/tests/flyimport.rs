@@ -1586,3 +1586,87 @@ pub struct FooStruct;
         "#]],
     );
 }
+
+#[test]
+fn flyimport_lemma_outside_verus_block() {
+    check_edit(
+        "lemma_mul_pos",
+        r#"
+//- /lib.rs crate:dep
+verus! {
+pub mod arith {
+    pub proof fn lemma_mul_pos() {}
+}
+}
+//- /main.rs crate:main deps:dep
+verus! {
+proof fn f() {
+    lemma_mul$0
+}
+}
+"#,
+        r#"
+use dep::arith::lemma_mul_pos;
+
+verus! {
+proof fn f() {
+    lemma_mul_pos()$0
+}
+}
+"#,
+    );
+}
+
+#[test]
+fn flyimport_lemma_next_to_imports_in_verus_block() {
+    check_edit(
+        "lemma_mul_pos",
+        r#"
+//- /lib.rs crate:dep
+verus! {
+pub mod arith {
+    pub proof fn lemma_add_pos() {}
+    pub proof fn lemma_mul_pos() {}
+}
+}
+//- /main.rs crate:main deps:dep
+verus! {
+use dep::arith::lemma_add_pos;
+
+proof fn f() {
+    lemma_mul$0
+}
+}
+"#,
+        r#"
+verus! {
+use dep::arith::{lemma_add_pos, lemma_mul_pos};
+
+proof fn f() {
+    lemma_mul_pos()$0
+}
+}
+"#,
+    );
+}
+
+#[test]
+fn flyimport_no_exec_fns_in_proof_code() {
+    check(
+        r#"
+//- /lib.rs crate:dep
+verus! {
+pub mod arith {
+    pub fn mul_exec() {}
+}
+}
+//- /main.rs crate:main deps:dep
+verus! {
+proof fn f() {
+    mul_exe$0
+}
+}
+"#,
+        expect![[""]],
+    );
+}
@@ -138,6 +138,54 @@ fn completes_where() {
     );
 }
 
+#[test]
+fn completes_verus_clauses() {
+    check(
+        r"fn func() $0",
+        expect![[r#"
+        kw where
+    "#]],
+    );
+    check(
+        r"
+verus! {
+fn func() $0
+}
+",
+        expect![[r#"
+            kw decreases
+            kw ensures
+            kw recommends
+            kw requires
+            kw where
+        "#]],
+    );
+    check(
+        r"
+verus! {
+fn func() requires true $0
+}
+",
+        expect![[r#"
+            kw decreases
+            kw ensures
+            kw recommends
+            kw where
+        "#]],
+    );
+    check(
+        r"
+verus! {
+fn func() ensures true $0
+}
+",
+        expect![[r#"
+            kw decreases
+            kw where
+        "#]],
+    );
+}
+
 #[test]
 fn before_record_field() {
     check(
@@ -89,6 +89,9 @@ impl RootDatabase {
             crate::symbol_index::LibrarySymbolsQuery
             crate::symbol_index::LocalRootsQuery
             crate::symbol_index::LibraryRootsQuery
+            // LemmaIndexDatabase
+            crate::lemma_index::ModuleLemmasQuery
+            crate::lemma_index::LibraryLemmasQuery
             // HirDatabase
             hir::db::AdtDatumQuery
             hir::db::AdtVarianceQuery
@@ -3,15 +3,20 @@
 use std::collections::VecDeque;
 
 use base_db::{FileId, SourceDatabaseExt};
-use hir::{Crate, DescendPreference, ItemInNs, ModuleDef, Name, Semantics};
+use hir::{
+    Crate, DescendPreference, InFile, ItemInNs, ModuleDef, ModuleSource, Name, PathResolution,
+    Semantics,
+};
 use syntax::{
     ast::{self, make},
-    AstToken, SyntaxKind, SyntaxToken, TokenAtOffset,
+    AstNode, AstToken, SyntaxKind, SyntaxToken, TokenAtOffset,
 };
 
 use crate::{
     defs::{Definition, IdentClass},
-    generated, RootDatabase,
+    generated,
+    search::{FileReferenceNode, SearchScope},
+    RootDatabase,
 };
 
 pub fn item_name(db: &RootDatabase, item: ItemInNs) -> Option<Name> {
@@ -22,6 +27,87 @@ pub fn item_name(db: &RootDatabase, item: ItemInNs) -> Option<Name> {
     }
 }
 
+/// Resolves `path` to a Verus `broadcast group` declaration.
+///
+/// Broadcast groups are not lowered into the item scope, so `broadcast use` lists and group
+/// members naming them are resolved by looking the group up in the syntax of the module the path
+/// points into.
+pub fn resolve_broadcast_group(
+    sema: &Semantics<'_, RootDatabase>,
+    path: &ast::Path,
+) -> Option<InFile<ast::BroadcastGroup>> {
+    let name = path.segment()?.name_ref()?;
+    let module = match path.qualifier() {
+        Some(qualifier) => match sema.resolve_path(&qualifier)? {
+            PathResolution::Def(ModuleDef::Module(it)) => it,
+            _ => return None,
+        },
+        None => sema.scope(path.syntax())?.module(),
+    };
+    let source = module.definition_source(sema.db);
+    let items = match &source.value {
+        ModuleSource::SourceFile(it) => it.syntax().clone(),
+        ModuleSource::Module(it) => it.item_list()?.syntax().clone(),
+        ModuleSource::BlockExpr(it) => it.stmt_list()?.syntax().clone(),
+    };
+    let group = items.children().filter_map(ast::BroadcastGroup::cast).find(|group| {
+        group
+            .broadcast_group_identifier()
+            .and_then(|it| it.ident_token())
+            .map_or(false, |it| it.text() == name.text())
+    })?;
+    Some(source.with_value(group))
+}
+
+/// Resolves the function specified by a Verus `assume_specification [path] (..)` item.
+pub fn assume_specification_target(
+    sema: &Semantics<'_, RootDatabase>,
+    stub: &ast::Fn,
+) -> Option<hir::Function> {
+    match sema.resolve_path(&stub.assume_specification_path()?)? {
+        PathResolution::Def(ModuleDef::Function(it)) => Some(it),
+        _ => None,
+    }
+}
+
+/// Finds the Verus `assume_specification` items giving `func` a contract, typically for functions
+/// from crates that are not verified, like `std`.
+///
+/// The stubs have no name of their own, so they are found by looking for the references to `func`
+/// from their bracketed paths, in the files of local crates that have any stubs at all.
+pub fn assume_specifications(
+    sema: &Semantics<'_, RootDatabase>,
+    func: hir::Function,
+) -> Vec<InFile<ast::Fn>> {
+    let db = sema.db;
+    let files: Vec<FileId> = Crate::all(db)
+        .into_iter()
+        .filter(|krate| krate.origin(db).is_local())
+        .flat_map(|krate| {
+            db.source_root(db.file_source_root(krate.root_file(db))).iter().collect::<Vec<_>>()
+        })
+        .filter(|&file_id| db.file_text(file_id).contains("assume_specification"))
+        .collect();
+    if files.is_empty() {
+        return Vec::new();
+    }
+    let scope = SearchScope::files(&files);
+    Definition::Function(func)
+        .usages(sema)
+        .in_scope(&scope)
+        .all()
+        .into_iter()
+        .flat_map(|(file_id, refs)| refs.into_iter().map(move |it| (file_id, it)))
+        .filter_map(|(file_id, reference)| {
+            let FileReferenceNode::NameRef(name_ref) = reference.name else { return None };
+            let stub = name_ref.syntax().ancestors().find_map(ast::Fn::cast)?;
+            let path = stub.assume_specification_path()?;
+            let in_path = path.syntax().text_range().contains_range(name_ref.syntax().text_range());
+            in_path.then(|| InFile::new(file_id.into(), stub))
+        })
+        .collect()
+}
+
 /// Picks the token with the highest rank returned by the passed in function.
 pub fn pick_best_token(
     tokens: TokenAtOffset<SyntaxToken>,
@@ -0,0 +1,364 @@
+//! This module indexes the lemmas of the workspace and its dependencies, vstd included, by the
+//! shape of what they ensure.
+//!
+//! Each `ensures` clause of a `proof fn` is reduced to an [`EnsuresSkeleton`]: the symbol at the
+//! head of the clause, the relation it states and the sorts of the arguments of the head. For
+//! example, `s.len() <= t.len()` with `s: Seq<int>` becomes `len(Seq) <=`. Assists looking for a
+//! lemma proving an assertion, and completion ranking lemmas, look up the skeleton of their goal.
+//!
+//! Like the symbol index, there is an index for each module of the workspace and one for each
+//! library, so that an edit only invalidates the index of the module it is in.
+
+use std::fmt;
+
+use base_db::{salsa, SourceDatabaseExt, SourceRootId, Upcast};
+use hir::{db::HirDatabase, AssocItem, Crate, Function, HasSource, Module, ModuleDef};
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+use syntax::{
+    ast::{self, HasName},
+    SmolStr,
+};
+use triomphe::Arc;
+
+use crate::{symbol_index::SymbolsDatabase, RootDatabase};
+
+/// The shape of an `ensures` clause, or of an assertion to prove.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnsuresSkeleton {
+    /// The function, method or operator at the head of the clause, `None` for a variable.
+    pub head: Option<SmolStr>,
+    /// The relation the clause states, as `==` or `<=`, or `bool` for a predicate.
+    pub relation: SmolStr,
+    /// The sorts of the arguments of the head, the receiver of a method first. `None` where the
+    /// sort is not known.
+    pub arg_sorts: Vec<Option<SmolStr>>,
+}
+
+impl EnsuresSkeleton {
+    /// The skeleton of `expr`, where `sort_of` gives the sorts of the variables.
+    /// Quantifiers and the hypotheses of implications are looked through.
+    pub fn from_expr(
+        expr: &ast::Expr,
+        sort_of: &dyn Fn(&str) -> Option<SmolStr>,
+    ) -> Option<EnsuresSkeleton> {
+        match strip_parens(expr) {
+            ast::Expr::ClosureExpr(it) if it.forall_token().is_some() => {
+                EnsuresSkeleton::from_expr(&it.body()?, sort_of)
+            }
+            ast::Expr::BinExpr(it) => match it.op_kind()? {
+                ast::BinaryOp::LogicOp(ast::LogicOp::Imply) => {
+                    EnsuresSkeleton::from_expr(&it.rhs()?, sort_of)
+                }
+                op @ ast::BinaryOp::CmpOp(_) => {
+                    // `x == f(y)` is indexed like `f(y) == x`
+                    let (lhs, rhs) = (it.lhs()?, it.rhs()?);
+                    let (head, arg_sorts) = match head_of(&lhs, sort_of) {
+                        (None, _) => head_of(&rhs, sort_of),
+                        it => it,
+                    };
+                    Some(EnsuresSkeleton {
+                        head,
+                        relation: SmolStr::new(op.to_string()),
+                        arg_sorts,
+                    })
+                }
+                _ => {
+                    let (head, arg_sorts) = head_of(&ast::Expr::BinExpr(it), sort_of);
+                    Some(EnsuresSkeleton { head, relation: SmolStr::new_static("bool"), arg_sorts })
+                }
+            },
+            expr => {
+                let (head, arg_sorts) = head_of(&expr, sort_of);
+                Some(EnsuresSkeleton { head, relation: SmolStr::new_static("bool"), arg_sorts })
+            }
+        }
+    }
+
+    /// Whether a lemma ensuring `self` may prove a goal of skeleton `goal`.
+    /// An unknown sort matches any sort.
+    pub fn matches(&self, goal: &EnsuresSkeleton) -> bool {
+        self.head == goal.head
+            && self.relation == goal.relation
+            && self.arg_sorts.len() == goal.arg_sorts.len()
+            && self.arg_sorts.iter().zip(&goal.arg_sorts).all(|it| match it {
+                (Some(lemma), Some(goal)) => lemma == goal,
+                _ => true,
+            })
+    }
+}
+
+impl fmt::Display for EnsuresSkeleton {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sorts = self.arg_sorts.iter().map(|it| it.as_deref().unwrap_or("_")).join(", ");
+        match &self.head {
+            Some(head) => write!(f, "{head}({sorts}) {}", self.relation),
+            None => write!(f, "_ {}", self.relation),
+        }
+    }
+}
+
+/// The lemmas of a module or of a library, by the skeletons of their `ensures` clauses.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct LemmaIndex {
+    lemmas: FxHashMap<(Option<SmolStr>, SmolStr), Vec<(EnsuresSkeleton, Function)>>,
+}
+
+impl LemmaIndex {
+    fn insert(&mut self, skeleton: EnsuresSkeleton, lemma: Function) {
+        let key = (skeleton.head.clone(), skeleton.relation.clone());
+        self.lemmas.entry(key).or_default().push((skeleton, lemma));
+    }
+
+    /// The lemmas with an `ensures` clause matching `goal`.
+    pub fn lemmas_for<'a>(
+        &'a self,
+        goal: &'a EnsuresSkeleton,
+    ) -> impl Iterator<Item = Function> + 'a {
+        self.lemmas
+            .get(&(goal.head.clone(), goal.relation.clone()))
+            .into_iter()
+            .flatten()
+            .filter(|(skeleton, _)| skeleton.matches(goal))
+            .map(|(_, lemma)| *lemma)
+            .unique()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lemmas.values().map(|it| it.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lemmas.is_empty()
+    }
+}
+
+#[salsa::query_group(LemmaIndexDatabaseStorage)]
+pub trait LemmaIndexDatabase: SymbolsDatabase {
+    /// The lemma index for a given module. These modules should only be in source roots that
+    /// are inside local_roots.
+    fn module_lemmas(&self, module: Module) -> Arc<LemmaIndex>;
+
+    /// The lemma index for a given source root within library_roots.
+    fn library_lemmas(&self, source_root_id: SourceRootId) -> Arc<LemmaIndex>;
+}
+
+fn module_lemmas(db: &dyn LemmaIndexDatabase, module: Module) -> Arc<LemmaIndex> {
+    let _p = tracing::info_span!("module_lemmas").entered();
+
+    let mut index = LemmaIndex::default();
+    collect_module(db, module, &mut index);
+    Arc::new(index)
+}
+
+fn library_lemmas(db: &dyn LemmaIndexDatabase, source_root_id: SourceRootId) -> Arc<LemmaIndex> {
+    let _p = tracing::info_span!("library_lemmas").entered();
+
+    let mut index = LemmaIndex::default();
+    db.source_root_crates(source_root_id)
+        .iter()
+        .flat_map(|&krate| Crate::from(krate).modules(db.upcast()))
+        .for_each(|module| collect_module(db, module, &mut index));
+    Arc::new(index)
+}
+
+/// The lemmas of the workspace and of its dependencies with an `ensures` clause matching `goal`,
+/// those of the workspace first.
+pub fn lemmas_for(db: &RootDatabase, goal: &EnsuresSkeleton) -> Vec<Function> {
+    let _p = tracing::info_span!("lemmas_for", goal = %goal).entered();
+
+    let mut res = vec![];
+    for &root in db.local_roots().iter() {
+        for &krate in db.source_root_crates(root).iter() {
+            for module in Crate::from(krate).modules(db) {
+                res.extend(db.module_lemmas(module).lemmas_for(goal));
+            }
+        }
+    }
+    for &root in db.library_roots().iter() {
+        res.extend(db.library_lemmas(root).lemmas_for(goal));
+    }
+    res
+}
+
+fn collect_module(db: &dyn LemmaIndexDatabase, module: Module, index: &mut LemmaIndex) {
+    let db: &dyn HirDatabase = db.upcast();
+    let functions = module
+        .declarations(db)
+        .into_iter()
+        .filter_map(|it| match it {
+            ModuleDef::Function(it) => Some(it),
+            _ => None,
+        })
+        .chain(module.impl_defs(db).into_iter().flat_map(|it| it.items(db)).filter_map(
+            |it| match it {
+                AssocItem::Function(it) => Some(it),
+                _ => None,
+            },
+        ));
+    for lemma in functions {
+        let Some(source) = lemma.source(db) else { continue };
+        let func = source.value;
+        if func.fn_mode().and_then(|it| it.proof_token()).is_none() {
+            continue;
+        }
+        let Some(ensures) = func.ensures_clause() else { continue };
+        let sorts: FxHashMap<String, SmolStr> = func
+            .param_list()
+            .into_iter()
+            .flat_map(|it| it.params())
+            .filter_map(|param| {
+                let ast::Pat::IdentPat(pat) = param.pat()? else { return None };
+                Some((pat.name()?.text().to_string(), sort_of_type(&param.ty()?)?))
+            })
+            .collect();
+        let sort_of = |name: &str| sorts.get(name).cloned();
+        for expr in ensures.exprs().flat_map(|it| conjuncts(&it)) {
+            if let Some(skeleton) = EnsuresSkeleton::from_expr(&expr, &sort_of) {
+                index.insert(skeleton, lemma);
+            }
+        }
+    }
+}
+
+/// The symbol at the head of `expr` and the sorts of its arguments.
+fn head_of(
+    expr: &ast::Expr,
+    sort_of: &dyn Fn(&str) -> Option<SmolStr>,
+) -> (Option<SmolStr>, Vec<Option<SmolStr>>) {
+    let sorts = |args: Vec<Option<ast::Expr>>| -> Vec<Option<SmolStr>> {
+        args.iter().map(|it| it.as_ref().and_then(|it| sort_of_expr(it, sort_of))).collect()
+    };
+    match strip_parens(expr) {
+        ast::Expr::CallExpr(it) => {
+            let head = match it.expr() {
+                Some(ast::Expr::PathExpr(callee)) => callee
+                    .path()
+                    .and_then(|it| it.segment())
+                    .and_then(|it| it.name_ref())
+                    .map(|it| SmolStr::new(it.text())),
+                _ => None,
+            };
+            let args = it.arg_list().into_iter().flat_map(|it| it.args()).map(Some).collect();
+            (head, sorts(args))
+        }
+        ast::Expr::MethodCallExpr(it) => {
+            let head = it.name_ref().map(|it| SmolStr::new(it.text()));
+            let args = std::iter::once(it.receiver())
+                .chain(it.arg_list().into_iter().flat_map(|it| it.args()).map(Some))
+                .collect();
+            (head, sorts(args))
+        }
+        ast::Expr::IndexExpr(it) => {
+            (Some(SmolStr::new_static("[]")), sorts(vec![it.base(), it.index()]))
+        }
+        ast::Expr::BinExpr(it) => {
+            let head = it.op_kind().map(|it| SmolStr::new(it.to_string()));
+            (head, sorts(vec![it.lhs(), it.rhs()]))
+        }
+        ast::Expr::PrefixExpr(it) => {
+            let head = it.op_kind().map(|it| SmolStr::new(it.to_string()));
+            (head, sorts(vec![it.expr()]))
+        }
+        _ => (None, vec![]),
+    }
+}
+
+fn sort_of_expr(expr: &ast::Expr, sort_of: &dyn Fn(&str) -> Option<SmolStr>) -> Option<SmolStr> {
+    match strip_parens(expr) {
+        ast::Expr::PathExpr(it) => sort_of(it.path()?.as_single_name_ref()?.text().as_str()),
+        ast::Expr::Literal(it) => match it.kind() {
+            ast::LiteralKind::Bool(_) => Some(SmolStr::new_static("bool")),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The sort of a type, its name without generic arguments: `Seq` for `&Seq<int>`.
+pub fn sort_of_type(ty: &ast::Type) -> Option<SmolStr> {
+    match ty {
+        ast::Type::PathType(it) => Some(SmolStr::new(it.path()?.segment()?.name_ref()?.text())),
+        ast::Type::RefType(it) => sort_of_type(&it.ty()?),
+        _ => None,
+    }
+}
+
+fn conjuncts(expr: &ast::Expr) -> Vec<ast::Expr> {
+    match strip_parens(expr) {
+        ast::Expr::BinExpr(it)
+            if it.op_kind() == Some(ast::BinaryOp::LogicOp(ast::LogicOp::And)) =>
+        {
+            it.lhs().into_iter().chain(it.rhs()).flat_map(|it| conjuncts(&it)).collect()
+        }
+        _ => vec![expr.clone()],
+    }
+}
+
+fn strip_parens(expr: &ast::Expr) -> ast::Expr {
+    match expr {
+        ast::Expr::ParenExpr(it) => it.expr().map_or_else(|| expr.clone(), |it| strip_parens(&it)),
+        _ => expr.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syntax::{AstNode, Edition, SourceFile};
+    use test_fixture::WithFixture;
+
+    use super::*;
+
+    fn goal(text: &str, sort_of: &dyn Fn(&str) -> Option<SmolStr>) -> EnsuresSkeleton {
+        let parse = SourceFile::parse(&format!("fn f() {{ {text} }}"), Edition::CURRENT);
+        let expr = parse.tree().syntax().descendants().find_map(ast::StmtList::cast).unwrap();
+        EnsuresSkeleton::from_expr(&expr.tail_expr().unwrap(), sort_of).unwrap()
+    }
+
+    #[test]
+    fn test_lemma_index_lookup() {
+        let (db, _) = RootDatabase::with_single_file(
+            r#"
+proof fn lemma_len_le(s: Seq<int>, t: Seq<int>)
+    requires
+        s.is_prefix_of(t),
+    ensures
+        s.len() <= t.len(),
+{
+}
+
+proof fn lemma_mul_pos(x: int, y: int)
+    ensures
+        0 < x && 0 < y ==> 0 < x * y,
+        x * y == y * x,
+{
+}
+
+spec fn len_of(s: Seq<int>) -> nat;
+
+fn exec_len(s: Seq<int>, t: Seq<int>)
+    ensures
+        s.len() <= t.len(),
+{
+}
+"#,
+        );
+        let module = Crate::from(db.test_crate()).root_module();
+        let index = db.module_lemmas(module);
+        assert_eq!(index.len(), 3);
+
+        let name = |lemma: Function| lemma.name(&db).display(&db).to_string();
+        let seq = |_: &str| Some(SmolStr::new_static("Seq"));
+        let len = goal("a.len() <= b.len()", &seq);
+        assert_eq!(len.to_string(), "len(Seq) <=");
+        assert_eq!(index.lemmas_for(&len).map(name).collect::<Vec<_>>(), ["lemma_len_le"]);
+
+        let int = |_: &str| Some(SmolStr::new_static("int"));
+        let mul = goal("forall|a: int| a > 0 ==> 0 < a * b", &int);
+        assert_eq!(mul.to_string(), "*(int, int) <");
+        assert_eq!(index.lemmas_for(&mul).map(name).collect::<Vec<_>>(), ["lemma_mul_pos"]);
+
+        let mismatch = goal("a.len() <= b.len()", &int);
+        assert_eq!(index.lemmas_for(&mismatch).count(), 0);
+    }
+}
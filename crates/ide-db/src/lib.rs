@@ -14,6 +14,7 @@ pub mod famous_defs;
 pub mod helpers;
 pub mod items_locator;
 pub mod label;
+pub mod lemma_index;
 pub mod path_transform;
 pub mod prime_caches;
 pub mod rename;
@@ -76,7 +77,8 @@ pub type FxIndexMap<K, V> =
     hir::db::HirDatabaseStorage,
     hir::db::InternDatabaseStorage,
     LineIndexDatabaseStorage,
-    symbol_index::SymbolsDatabaseStorage
+    symbol_index::SymbolsDatabaseStorage,
+    lemma_index::LemmaIndexDatabaseStorage
 )]
 pub struct RootDatabase {
     // We use `ManuallyDrop` here because every codegen unit that contains a
@@ -296,6 +298,10 @@ impl RootDatabase {
             // symbol_index::LocalRootsQuery
             // symbol_index::LibraryRootsQuery
 
+            // LemmaIndexDatabase
+            lemma_index::ModuleLemmasQuery
+            lemma_index::LibraryLemmasQuery
+
             // LineIndexDatabase
             crate::LineIndexQuery
 
@@ -37,7 +37,7 @@ use crate::{
     defs::Definition,
     search::{FileReference, FileReferenceNode},
     source_change::{FileSystemEdit, SourceChange},
-    syntax_helpers::node_ext::expr_as_name_ref,
+    syntax_helpers::node_ext::{expr_as_name_ref, is_bound_by_quantifier},
     traits::convert_to_def_in_trait,
     RootDatabase,
 };
@@ -343,6 +343,15 @@ fn rename_reference(
         cov_mark::hit!(rename_underscore_multiple);
         bail!("Cannot rename reference to `_` as it is being referenced multiple times");
     }
+    if let Definition::Local(_) = def {
+        // Usages inside Verus quantifiers binding `new_name` would be captured by the quantifier.
+        let captured = usages.iter().flat_map(|(_, refs)| refs).any(|it| {
+            it.name.as_name_ref().map_or(false, |it| is_bound_by_quantifier(it.syntax(), new_name))
+        });
+        if captured {
+            bail!("Cannot rename to `{new_name}`: a quantifier already binds that name at a usage");
+        }
+    }
     let mut source_change = SourceChange::default();
     source_change.extend(usages.iter().map(|(&file_id, references)| {
         (file_id, source_edit_from_references(references, def, new_name))
@@ -20,6 +20,7 @@ use triomphe::Arc;
 
 use crate::{
     defs::{Definition, NameClass, NameRefClass},
+    syntax_helpers::node_ext::is_in_spec_code,
     traits::{as_trait_assoc_def, convert_to_def_in_trait},
     RootDatabase,
 };
@@ -126,13 +127,21 @@ impl FileReferenceNode {
 
 bitflags::bitflags! {
     #[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Debug)]
-    pub struct ReferenceCategory: u8 {
+    pub struct ReferenceCategory: u16 {
         // FIXME: Add this variant and delete the `retain_adt_literal_usages` function.
         // const CREATE = 1 << 0;
         const WRITE = 1 << 0;
         const READ = 1 << 1;
         const IMPORT = 1 << 2;
         const TEST = 1 << 3;
+        // The verus context of a reference to a spec function.
+        const REQUIRES = 1 << 4;
+        const ENSURES = 1 << 5;
+        const INVARIANT = 1 << 6;
+        const ASSERT = 1 << 7;
+        const TRIGGER = 1 << 8;
+        const REVEAL = 1 << 9;
+        const EXEC_CALL = 1 << 10;
     }
 }
 
@@ -884,6 +893,12 @@ impl ReferenceCategory {
             result |= ReferenceCategory::TEST;
         }
 
+        if let Definition::Function(func) = def {
+            if func.is_spec(sema.db) {
+                result |= spec_context(r);
+            }
+        }
+
         // Only Locals and Fields have accesses for now.
         if !matches!(def, Definition::Local(_) | Definition::Field(_)) {
             if is_name_ref_in_import(r) {
@@ -916,6 +931,63 @@ impl ReferenceCategory {
     }
 }
 
+/// Where a reference to a spec function is used, the innermost context wins: a trigger inside an
+/// `ensures` clause is a `TRIGGER`.
+fn spec_context(name_ref: &ast::NameRef) -> ReferenceCategory {
+    for node in name_ref.syntax().ancestors() {
+        let category = match node.kind() {
+            SyntaxKind::TRIGGER_ATTRIBUTE => ReferenceCategory::TRIGGER,
+            SyntaxKind::REQUIRES_CLAUSE => ReferenceCategory::REQUIRES,
+            SyntaxKind::ENSURES_CLAUSE => ReferenceCategory::ENSURES,
+            SyntaxKind::INVARIANT_CLAUSE | SyntaxKind::INVARIANT_EXCEPT_BREAK_CLAUSE => {
+                ReferenceCategory::INVARIANT
+            }
+            SyntaxKind::ASSERT_EXPR | SyntaxKind::ASSERT_FORALL_EXPR => ReferenceCategory::ASSERT,
+            SyntaxKind::CALL_EXPR if is_reveal(&node) => ReferenceCategory::REVEAL,
+            _ => continue,
+        };
+        return category;
+    }
+    if is_exec_call(name_ref) {
+        ReferenceCategory::EXEC_CALL
+    } else {
+        ReferenceCategory::empty()
+    }
+}
+
+/// `reveal(f)` and `reveal_with_fuel(f, n)` make the body of `f` visible to the prover.
+fn is_reveal(node: &syntax::SyntaxNode) -> bool {
+    let callee = ast::CallExpr::cast(node.clone()).and_then(|it| it.expr());
+    let Some(ast::Expr::PathExpr(path)) = callee else { return false };
+    path.path()
+        .and_then(|it| it.segment())
+        .and_then(|it| it.name_ref())
+        .map_or(false, |it| matches!(it.text().as_str(), "reveal" | "reveal_with_fuel"))
+}
+
+fn is_exec_call(name_ref: &ast::NameRef) -> bool {
+    let is_callee = match name_ref.syntax().parent().and_then(ast::MethodCallExpr::cast) {
+        Some(_) => true,
+        None => name_ref
+            .syntax()
+            .ancestors()
+            .find_map(ast::PathExpr::cast)
+            .and_then(|path| {
+                let call = ast::CallExpr::cast(path.syntax().parent()?)?;
+                call.expr().filter(|it| it.syntax() == path.syntax())
+            })
+            .is_some(),
+    };
+    if !is_callee || is_in_spec_code(name_ref.syntax()) {
+        return false;
+    }
+    let func = name_ref.syntax().ancestors().find_map(ast::Fn::cast);
+    func.map_or(false, |func| match func.fn_mode() {
+        Some(mode) => mode.exec_token().is_some(),
+        None => true,
+    })
+}
+
 fn is_name_ref_in_import(name_ref: &ast::NameRef) -> bool {
     name_ref
         .syntax()
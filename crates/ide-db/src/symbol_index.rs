@@ -35,7 +35,7 @@ use fst::{raw::IndexedValue, Automaton, Streamer};
 use hir::{
     db::HirDatabase,
     import_map::{AssocSearchMode, SearchMode},
-    symbols::{FileSymbol, SymbolCollector},
+    symbols::{FileSymbol, SymbolCollector, VerusDirectiveSymbol, VerusSymbolCategory},
     Crate, Module,
 };
 use rayon::prelude::*;
@@ -52,6 +52,7 @@ pub struct Query {
     assoc_mode: AssocSearchMode,
     case_sensitive: bool,
     only_types: bool,
+    verus_category: Option<VerusSymbolCategory>,
     libs: bool,
 }
 
@@ -62,6 +63,7 @@ impl Query {
             query,
             lowercased,
             only_types: false,
+            verus_category: None,
             libs: false,
             mode: SearchMode::Fuzzy,
             assoc_mode: AssocSearchMode::Include,
@@ -73,6 +75,11 @@ impl Query {
         self.only_types = true;
     }
 
+    /// Restricts the results to functions of the given Verus category.
+    pub fn verus_category(&mut self, category: VerusSymbolCategory) {
+        self.verus_category = Some(category);
+    }
+
     pub fn libs(&mut self) {
         self.libs = true;
     }
@@ -136,16 +143,18 @@ fn library_symbols(db: &dyn SymbolsDatabase, source_root_id: SourceRootId) -> Ar
         // the module or crate indices for those in salsa unless we need to.
         .for_each(|module| symbol_collector.collect(module));
 
-    let mut symbols = symbol_collector.finish();
+    let (mut symbols, directives) = symbol_collector.finish_with_directives();
     symbols.shrink_to_fit();
-    Arc::new(SymbolIndex::new(symbols))
+    Arc::new(SymbolIndex::new(symbols, directives))
 }
 
 fn module_symbols(db: &dyn SymbolsDatabase, module: Module) -> Arc<SymbolIndex> {
     let _p = tracing::info_span!("module_symbols").entered();
 
-    let symbols = SymbolCollector::collect_module(db.upcast(), module);
-    Arc::new(SymbolIndex::new(symbols))
+    let mut symbol_collector = SymbolCollector::new(db.upcast());
+    symbol_collector.collect(module);
+    let (symbols, directives) = symbol_collector.finish_with_directives();
+    Arc::new(SymbolIndex::new(symbols, directives))
 }
 
 pub fn crate_symbols(db: &dyn SymbolsDatabase, krate: Crate) -> Box<[Arc<SymbolIndex>]> {
@@ -189,6 +198,9 @@ impl<DB> std::ops::Deref for Snap<DB> {
 // That is, `#` switches from "types" to all symbols, `*` switches from the current
 // workspace to dependencies.
 //
+// A leading `#spec`, `#proof` (or `#lemma`) or `#broadcast` restricts the search to
+// Verus functions of that mode, e.g. `#lemma seq_len` only lists proof functions.
+//
 // Note that filtering does not currently work in VSCode due to the editor never
 // sending the special symbols to the language server. Instead, you can configure
 // the filtering via the `rust-analyzer.workspace.symbol.search.scope` and
@@ -203,7 +215,25 @@ impl<DB> std::ops::Deref for Snap<DB> {
 pub fn world_symbols(db: &RootDatabase, query: Query) -> Vec<FileSymbol> {
     let _p = tracing::info_span!("world_symbols", query = ?query.query).entered();
 
-    let indices: Vec<_> = if query.libs {
+    let indices = world_indices(db, &query);
+    let mut res = vec![];
+    query.search(&indices, |f| res.push(f.clone()));
+    res
+}
+
+/// The `broadcast group` and `global` directives matching `query`, which [`world_symbols`] leaves
+/// out as they aren't definitions.
+pub fn world_verus_directives(db: &RootDatabase, query: Query) -> Vec<VerusDirectiveSymbol> {
+    let _p = tracing::info_span!("world_verus_directives", query = ?query.query).entered();
+
+    let indices = world_indices(db, &query);
+    let mut res = vec![];
+    query.search_verus_directives(&indices, |it| res.push(it.clone()));
+    res
+}
+
+fn world_indices(db: &RootDatabase, query: &Query) -> Vec<Arc<SymbolIndex>> {
+    if query.libs {
         db.library_roots()
             .par_iter()
             .map_with(Snap::new(db), |snap, &root| snap.library_symbols(root))
@@ -219,17 +249,16 @@ pub fn world_symbols(db: &RootDatabase, query: Query) -> Vec<FileSymbol> {
             .map_with(Snap::new(db), |snap, krate| snap.crate_symbols(krate.into()))
             .collect();
         indices.iter().flat_map(|indices| indices.iter().cloned()).collect()
-    };
-
-    let mut res = vec![];
-    query.search(&indices, |f| res.push(f.clone()));
-    res
+    }
 }
 
 #[derive(Default)]
 pub struct SymbolIndex {
     symbols: Vec<FileSymbol>,
     map: fst::Map<Vec<u8>>,
+    /// The `broadcast group` and `global` directives, which are few enough to be searched through
+    /// without a map.
+    directives: Vec<VerusDirectiveSymbol>,
 }
 
 impl fmt::Debug for SymbolIndex {
@@ -240,7 +269,7 @@ impl fmt::Debug for SymbolIndex {
 
 impl PartialEq for SymbolIndex {
     fn eq(&self, other: &SymbolIndex) -> bool {
-        self.symbols == other.symbols
+        self.symbols == other.symbols && self.directives == other.directives
     }
 }
 
@@ -248,12 +277,13 @@ impl Eq for SymbolIndex {}
 
 impl Hash for SymbolIndex {
     fn hash<H: Hasher>(&self, hasher: &mut H) {
-        self.symbols.hash(hasher)
+        self.symbols.hash(hasher);
+        self.directives.hash(hasher)
     }
 }
 
 impl SymbolIndex {
-    fn new(mut symbols: Vec<FileSymbol>) -> SymbolIndex {
+    fn new(mut symbols: Vec<FileSymbol>, directives: Vec<VerusDirectiveSymbol>) -> SymbolIndex {
         fn cmp(lhs: &FileSymbol, rhs: &FileSymbol) -> Ordering {
             let lhs_chars = lhs.name.chars().map(|c| c.to_ascii_lowercase());
             let rhs_chars = rhs.name.chars().map(|c| c.to_ascii_lowercase());
@@ -290,7 +320,7 @@ impl SymbolIndex {
             buf
         })
         .unwrap();
-        SymbolIndex { symbols, map }
+        SymbolIndex { symbols, map, directives }
     }
 
     pub fn len(&self) -> usize {
@@ -298,7 +328,9 @@ impl SymbolIndex {
     }
 
     pub fn memory_size(&self) -> usize {
-        self.map.as_fst().size() + self.symbols.len() * mem::size_of::<FileSymbol>()
+        self.map.as_fst().size()
+            + self.symbols.len() * mem::size_of::<FileSymbol>()
+            + self.directives.len() * mem::size_of::<VerusDirectiveSymbol>()
     }
 
     fn range_to_map_value(start: usize, end: usize) -> u64 {
@@ -376,6 +408,19 @@ impl Query {
                     if non_type_for_type_only_query || !self.matches_assoc_mode(symbol.is_assoc) {
                         continue;
                     }
+                    if let Some(category) = self.verus_category {
+                        let in_category = match (category, symbol.verus_category) {
+                            // Broadcast lemmas are lemmas too.
+                            (
+                                VerusSymbolCategory::Proof,
+                                Some(VerusSymbolCategory::BroadcastProof),
+                            ) => true,
+                            (_, it) => it == Some(category),
+                        };
+                        if !in_category {
+                            continue;
+                        }
+                    }
                     // Hide symbols that start with `__` unless the query starts with `__`
                     if ignore_underscore_prefixed && symbol.name.starts_with("__") {
                         continue;
@@ -388,6 +433,24 @@ impl Query {
         }
     }
 
+    pub(crate) fn search_verus_directives<'sym>(
+        &self,
+        indices: &'sym [Arc<SymbolIndex>],
+        mut cb: impl FnMut(&'sym VerusDirectiveSymbol),
+    ) {
+        if self.only_types || !self.matches_assoc_mode(false) {
+            return;
+        }
+        for directive in indices.iter().flat_map(|it| &it.directives) {
+            if self.verus_category.map_or(false, |it| it != directive.category) {
+                continue;
+            }
+            if self.mode.check(&self.query, self.case_sensitive, &directive.name) {
+                cb(directive);
+            }
+        }
+    }
+
     fn matches_assoc_mode(&self, is_trait_assoc_item: bool) -> bool {
         !matches!(
             (is_trait_assoc_item, self.assoc_mode),
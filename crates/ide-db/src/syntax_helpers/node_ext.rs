@@ -2,8 +2,9 @@
 use itertools::Itertools;
 use parser::T;
 use syntax::{
-    ast::{self, HasLoopBody, MacroCall, PathSegmentKind, VisibilityKind},
-    AstNode, AstToken, Preorder, RustLanguage, WalkEvent,
+    ast::{self, HasLoopBody, HasName, MacroCall, PathSegmentKind, VisibilityKind},
+    ted, AstNode, AstToken, Direction, Preorder, RustLanguage, SyntaxKind, SyntaxNode, SyntaxToken,
+    WalkEvent,
 };
 
 pub fn expr_as_name_ref(expr: &ast::Expr) -> Option<ast::NameRef> {
@@ -480,3 +481,204 @@ pub fn macro_call_for_string_token(string: &ast::String) -> Option<MacroCall> {
     let macro_call = string.syntax().parent_ancestors().find_map(ast::MacroCall::cast)?;
     Some(macro_call)
 }
+
+/// Whether `node` is part of a `verus! { ... }` block.
+///
+/// The parser drops the `verus! {` and `}` tokens into the surrounding item list instead of
+/// wrapping the items into a node, so this looks for an unclosed `verus! {` among the preceding
+/// siblings of `node` or one of its ancestors.
+pub fn is_in_verus_macro(node: &SyntaxNode) -> bool {
+    node.ancestors().any(|node| {
+        let mut prev_tokens = node
+            .siblings_with_tokens(Direction::Prev)
+            .skip(1)
+            .filter_map(|it| it.into_token())
+            .filter(|it| !it.kind().is_trivia());
+        while let Some(token) = prev_tokens.next() {
+            match token.kind() {
+                T!['}'] => return false,
+                T!['{'] => {
+                    let is_verus_open = prev_tokens.next().map_or(false, |it| it.kind() == T![!])
+                        && prev_tokens.next().map_or(false, |it| it.kind() == T![verus]);
+                    if is_verus_open {
+                        return true;
+                    }
+                }
+                _ => (),
+            }
+        }
+        false
+    })
+}
+
+/// The `proof` token of the `proof { .. }` block of an exec function that `node` is in.
+///
+/// The parser has no node for these blocks, so this looks for an unclosed `{` preceded by `proof`
+/// among the tokens of the function body before `node`.
+pub fn enclosing_proof_block(node: &SyntaxNode) -> Option<SyntaxToken> {
+    let body = node.ancestors().find_map(ast::Fn::cast)?.body()?;
+    let body_start = body.syntax().text_range().start();
+    let mut depth = 0usize;
+    let tokens = std::iter::successors(node.first_token()?.prev_token(), |it| it.prev_token())
+        .take_while(|it| it.text_range().start() >= body_start);
+    for token in tokens {
+        match token.kind() {
+            T!['}'] => depth += 1,
+            T!['{'] if depth > 0 => depth -= 1,
+            T!['{'] => {
+                let prev = std::iter::successors(token.prev_token(), |it| it.prev_token())
+                    .find(|it| !it.kind().is_trivia());
+                if let Some(prev) = prev.filter(|it| it.text() == "proof") {
+                    return Some(prev);
+                }
+            }
+            _ => (),
+        }
+    }
+    None
+}
+
+/// Whether `node` is part of proof code: the body of a `proof fn`, a `proof { .. }` block or the
+/// `by` block of an assertion.
+pub fn is_in_proof_block(node: &SyntaxNode) -> bool {
+    if enclosing_proof_block(node).is_some() {
+        return true;
+    }
+    for ancestor in node.ancestors() {
+        if let Some(func) = ast::Fn::cast(ancestor.clone()) {
+            return func.fn_mode().map_or(false, |mode| mode.proof_token().is_some());
+        }
+        let by_block = if let Some(assert) = ast::AssertExpr::cast(ancestor.clone()) {
+            assert
+                .by_token()
+                .and_then(|_| ancestor.children().filter_map(ast::BlockExpr::cast).last())
+        } else {
+            ast::AssertForallExpr::cast(ancestor).and_then(|it| it.block_expr())
+        };
+        if by_block.map_or(false, |it| it.syntax().text_range().contains_range(node.text_range())) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Whether `node` is part of spec code: a contract or loop clause, the condition of an assertion
+/// or assumption, a quantifier or the body of a `spec fn`.
+pub fn is_in_spec_code(node: &SyntaxNode) -> bool {
+    for ancestor in node.ancestors() {
+        if matches!(
+            ancestor.kind(),
+            SyntaxKind::REQUIRES_CLAUSE
+                | SyntaxKind::ENSURES_CLAUSE
+                | SyntaxKind::RECOMMENDS_CLAUSE
+                | SyntaxKind::DECREASES_CLAUSE
+                | SyntaxKind::SIGNATURE_DECREASES
+                | SyntaxKind::INVARIANT_CLAUSE
+                | SyntaxKind::INVARIANT_EXCEPT_BREAK_CLAUSE
+                | SyntaxKind::ASSUME_EXPR
+        ) {
+            return true;
+        }
+        if let Some(func) = ast::Fn::cast(ancestor.clone()) {
+            return func.fn_mode().map_or(false, |mode| {
+                mode.spec_token().is_some() || mode.mode_spec_checked().is_some()
+            });
+        }
+        if let Some(closure) = ast::ClosureExpr::cast(ancestor.clone()) {
+            if closure.forall_token().is_some() || closure.exists_token().is_some() {
+                return true;
+            }
+        }
+        let condition = ast::AssertExpr::cast(ancestor).and_then(|it| it.expr());
+        if condition.map_or(false, |it| it.syntax().text_range().contains_range(node.text_range()))
+        {
+            return true;
+        }
+    }
+    false
+}
+
+/// Whether `name` is bound by a Verus quantifier (`forall|..|` or `exists|..|`) that encloses
+/// `node`.
+pub fn is_bound_by_quantifier(node: &SyntaxNode, name: &str) -> bool {
+    node.ancestors()
+        .filter_map(ast::ClosureExpr::cast)
+        .filter(|it| it.forall_token().is_some() || it.exists_token().is_some())
+        .filter_map(|it| it.param_list())
+        .filter(|it| !it.syntax().text_range().contains_range(node.text_range()))
+        .flat_map(|it| it.params())
+        .filter_map(|param| match param.pat()? {
+            ast::Pat::IdentPat(it) => it.name(),
+            _ => None,
+        })
+        .any(|it| it.text() == name)
+}
+
+/// A copy of the quantifier `closure` without its triggers, `#[trigger]` as well as
+/// `#![trigger ..]`, as they are rejected once its body is taken out of it.
+pub fn quantifier_without_triggers(closure: &ast::ClosureExpr) -> ast::ClosureExpr {
+    let closure = closure.clone_for_update();
+    let triggers: Vec<ast::Attr> = closure
+        .syntax()
+        .descendants()
+        .filter_map(ast::Attr::cast)
+        .filter(|attr| attr.simple_name().as_deref() == Some("trigger"))
+        .collect();
+    triggers.iter().for_each(|attr| ted::remove(attr.syntax()));
+    closure
+}
+
+#[cfg(test)]
+mod tests {
+    use syntax::{Edition, SourceFile};
+
+    use super::*;
+
+    fn node_at(text: &str, needle: &str) -> SyntaxNode {
+        let file = SourceFile::parse(text, Edition::CURRENT).tree();
+        let offset = text.find(needle).unwrap();
+        file.syntax()
+            .token_at_offset((offset as u32).into())
+            .right_biased()
+            .unwrap()
+            .parent()
+            .unwrap()
+    }
+
+    #[test]
+    fn proof_block_of_exec_fn() {
+        let text = "
+fn foo(x: u64) {
+    let y = x;
+    proof {
+        lemma(y);
+        assert(y == x) by { lemma2(x); }
+    }
+    bar(y);
+}
+";
+        assert!(is_in_proof_block(&node_at(text, "lemma(y)")));
+        assert!(is_in_proof_block(&node_at(text, "lemma2")));
+        assert!(!is_in_proof_block(&node_at(text, "bar")));
+        assert!(!is_in_proof_block(&node_at(text, "let y")));
+    }
+
+    #[test]
+    fn proof_fn_and_by_block() {
+        let text = "
+proof fn lemma(x: int) {
+    assert(x == x);
+}
+
+fn foo(x: u64) {
+    assert(x == x) by { reveal(f); }
+    if x > 0 {
+        bar(x);
+    }
+}
+";
+        assert!(is_in_proof_block(&node_at(text, "assert(x == x);")));
+        assert!(is_in_proof_block(&node_at(text, "reveal")));
+        assert!(!is_in_proof_block(&node_at(text, "bar")));
+    }
+}
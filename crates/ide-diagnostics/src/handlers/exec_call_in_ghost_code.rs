@@ -0,0 +1,79 @@
+use crate::{Diagnostic, DiagnosticCode, DiagnosticsContext, Severity};
+
+// Diagnostic: exec-call-in-ghost-code
+//
+// This diagnostic is triggered if spec or proof code calls an exec function, which Verus rejects.
+pub(crate) fn exec_call_in_ghost_code(
+    ctx: &DiagnosticsContext<'_>,
+    d: &hir::ExecCallInGhostCode,
+) -> Diagnostic {
+    let mode = match d.mode {
+        hir::Mode::Spec => "spec",
+        hir::Mode::Proof | hir::Mode::Exec => "proof",
+    };
+    Diagnostic::new_with_syntax_node_ptr(
+        ctx,
+        DiagnosticCode::Ra("exec-call-in-ghost-code", Severity::Error),
+        format!(
+            "cannot call exec function `{}` from {mode} code",
+            d.callee.name(ctx.sema.db).display(ctx.sema.db)
+        ),
+        d.expr.map(|it| it.into()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::check_diagnostics;
+
+    #[test]
+    fn exec_call_in_spec_and_proof_code() {
+        check_diagnostics(
+            r#"
+fn exec_fn() -> u32 { 0 }
+spec fn spec_fn() -> u32 { 0 }
+#[verifier::when_used_as_spec(spec_fn)]
+fn exec_with_spec() -> u32 { 0 }
+
+spec fn f() -> u32 {
+    exec_fn() + spec_fn() + exec_with_spec()
+  //^^^^^^^^^ error: cannot call exec function `exec_fn` from spec code
+}
+
+proof fn g() {
+    let _x = exec_fn();
+           //^^^^^^^^^ error: cannot call exec function `exec_fn` from proof code
+}
+
+fn h() {
+    exec_fn();
+    assert(exec_fn() == 0);
+         //^^^^^^^^^ error: cannot call exec function `exec_fn` from spec code
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn library_exec_call_in_requires() {
+        check_diagnostics(
+            r#"
+//- /main.rs crate:main deps:std
+use std::vec::Vec;
+
+fn f(v: &Vec<u32>)
+    requires
+        v.len() > 0,
+{
+}
+//- /std.rs crate:std
+pub mod vec {
+    pub struct Vec<T>(T);
+    impl<T> Vec<T> {
+        pub fn len(&self) -> usize { 0 }
+    }
+}
+"#,
+        );
+    }
+}
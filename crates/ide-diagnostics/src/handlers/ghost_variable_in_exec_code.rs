@@ -0,0 +1,78 @@
+use crate::{Diagnostic, DiagnosticCode, DiagnosticsContext, Severity};
+
+// Diagnostic: ghost-variable-in-exec-code
+//
+// This diagnostic is triggered if exec code uses a `ghost` or `tracked` variable, whose value
+// is erased at runtime.
+pub(crate) fn ghost_variable_in_exec_code(
+    ctx: &DiagnosticsContext<'_>,
+    d: &hir::GhostVariableInExecCode,
+) -> Diagnostic {
+    Diagnostic::new_with_syntax_node_ptr(
+        ctx,
+        DiagnosticCode::Ra("ghost-variable-in-exec-code", Severity::Error),
+        format!(
+            "cannot use ghost variable `{}` in exec code",
+            d.local.name(ctx.sema.db).display(ctx.sema.db)
+        ),
+        d.expr.map(|it| it.into()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::check_diagnostics;
+
+    #[test]
+    fn ghost_variables_in_exec_code() {
+        check_diagnostics(
+            r#"
+//- /main.rs crate:main deps:builtin
+use builtin::{Ghost, Tracked};
+
+fn f(tracked t: u32) -> u32 {
+    let ghost g = 1;
+    let ghost h = g;
+    let _w = Ghost(g);
+    assert(g == h);
+    let _x = g;
+           //^ error: cannot use ghost variable `g` in exec code
+    t
+  //^ error: cannot use ghost variable `t` in exec code
+}
+//- /builtin.rs crate:builtin
+pub struct Ghost<T>(pub T);
+pub struct Tracked<T>(pub T);
+"#,
+        );
+    }
+
+    #[test]
+    fn user_defined_ghost_is_not_a_wrapper() {
+        check_diagnostics(
+            r#"
+struct Ghost<T>(T);
+
+fn f() {
+    let ghost g = 1;
+    let _w = Ghost(g);
+                 //^ error: cannot use ghost variable `g` in exec code
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn ghost_variables_in_proof_block() {
+        check_diagnostics(
+            r#"
+proof fn lemma(_x: u32) {}
+
+fn f() {
+    let ghost g = 1;
+    proof { lemma(g) }
+}
+"#,
+        );
+    }
+}
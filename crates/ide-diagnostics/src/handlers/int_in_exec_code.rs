@@ -0,0 +1,196 @@
+use ide_db::{
+    base_db::{FileId, FileRange},
+    source_change::SourceChange,
+    syntax_helpers::node_ext::{is_in_proof_block, is_in_spec_code},
+};
+use syntax::{ast, match_ast, AstNode, SyntaxNode};
+use text_edit::TextEdit;
+
+use crate::{fix, Diagnostic, DiagnosticCode, Severity};
+
+// Diagnostic: int-in-exec-code
+//
+// This diagnostic is triggered if the ghost types `int` or `nat` are used by exec code: outside of
+// `Ghost<..>` and `Tracked<..>`, ghost variables, specifications and proof code.
+pub(crate) fn int_in_exec_code(
+    acc: &mut Vec<Diagnostic>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let ty = ast::PathType::cast(node.clone())?;
+    let name = ty.path()?.segment()?.name_ref()?;
+    let name = name.text();
+    let fixed_width = match name.as_str() {
+        "int" => "i64",
+        "nat" => "u64",
+        _ => return None,
+    };
+    if !is_in_exec_code(ty.syntax()) {
+        return None;
+    }
+
+    let range = ty.syntax().text_range();
+    let mut fixes = vec![fix(
+        "change_to_fixed_width_type",
+        &format!("Change to `{fixed_width}`"),
+        SourceChange::from_text_edit(file_id, TextEdit::replace(range, fixed_width.to_owned())),
+        range,
+    )];
+    // Only the callers of a function can provide or receive a `Ghost` value, an initializer or a
+    // cast would have to be rewritten as well.
+    let is_signature = ty.syntax().parent().map_or(false, |it| {
+        ast::Param::can_cast(it.kind()) || ast::RetType::can_cast(it.kind())
+    });
+    if is_signature {
+        fixes.push(fix(
+            "wrap_in_ghost",
+            "Wrap in `Ghost<..>`",
+            SourceChange::from_text_edit(file_id, TextEdit::replace(range, format!("Ghost<{ty}>"))),
+            range,
+        ));
+    }
+    acc.push(
+        Diagnostic::new(
+            DiagnosticCode::Ra("int-in-exec-code", Severity::Error),
+            format!("`{name}` is a ghost type and can only be used in ghost code"),
+            FileRange { file_id, range },
+        )
+        .with_fixes(Some(fixes)),
+    );
+    Some(())
+}
+
+/// Whether `ty` is used by the exec code of an exec function.
+fn is_in_exec_code(ty: &SyntaxNode) -> bool {
+    if is_in_spec_code(ty) || is_in_proof_block(ty) {
+        return false;
+    }
+    for ancestor in ty.ancestors().skip(1) {
+        match_ast! {
+            match ancestor {
+                ast::GenericArgList(it) => {
+                    if is_ghost_wrapper(&it) {
+                        return false;
+                    }
+                },
+                ast::LetStmt(it) => {
+                    if it.ghost_token().is_some() || it.tracked_token().is_some() {
+                        return false;
+                    }
+                },
+                ast::Param(it) => {
+                    if it.tracked_token().is_some() {
+                        return false;
+                    }
+                },
+                ast::RetType(it) => {
+                    if it.tracked_token().is_some() {
+                        return false;
+                    }
+                },
+                // The proof block of an `assert(..) by { .. }`.
+                ast::AssertExpr(_) => return false,
+                ast::AssertForallExpr(_) => return false,
+                ast::Fn(it) => return it.fn_mode().map_or(true, |it| it.exec_token().is_some()),
+                _ => (),
+            }
+        }
+    }
+    false
+}
+
+/// `Ghost<int>` and `Tracked<nat>`.
+fn is_ghost_wrapper(args: &ast::GenericArgList) -> bool {
+    let segment = args.syntax().parent().and_then(ast::PathSegment::cast);
+    segment
+        .and_then(|it| it.name_ref())
+        .map_or(false, |it| matches!(it.text().as_str(), "Ghost" | "Tracked"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_diagnostics, check_fixes, check_has_single_fix};
+
+    #[test]
+    fn ghost_types_in_exec_code() {
+        check_diagnostics(
+            r#"
+struct Ghost<T>(T);
+
+fn exec_fn(a: int, _b: Ghost<nat>, tracked _c: int) -> nat
+            //^^^ 💡 error: `int` is a ghost type and can only be used in ghost code
+                                                     //^^^ 💡 error: `nat` is a ghost type and can only be used in ghost code
+    requires
+        a > 0,
+    ensures
+        0 <= a as int,
+{
+    let x: int = 0;
+         //^^^ 💡 error: `int` is a ghost type and can only be used in ghost code
+    let ghost _y: nat = 0;
+    assert(x as nat >= 0);
+    let _z = 1u64 as int;
+                   //^^^ 💡 error: `int` is a ghost type and can only be used in ghost code
+    0
+}
+
+proof fn lemma(a: int) -> nat {
+    let _x: int = a;
+    0
+}
+
+spec fn f(a: nat) -> int {
+    a as int
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn change_to_fixed_width_or_wrap_in_ghost() {
+        check_fixes(
+            r#"
+fn f(_x: na$0t) {}
+"#,
+            vec![
+                r#"
+fn f(_x: u64) {}
+"#,
+                r#"
+fn f(_x: Ghost<nat>) {}
+"#,
+            ],
+        );
+    }
+
+    #[test]
+    fn no_ghost_wrapper_for_initialized_let() {
+        check_has_single_fix(
+            r#"
+fn f() {
+    let _x: i$0nt = 0;
+}
+"#,
+            r#"
+fn f() {
+    let _x: i64 = 0;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn ghost_types_in_proof_block() {
+        check_diagnostics(
+            r#"
+proof fn lemma(_x: int) {}
+
+fn exec_fn(a: u64) {
+    proof { lemma(a as int) }
+    let _b = a as int;
+                //^^^ 💡 error: `int` is a ghost type and can only be used in ghost code
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use hir::{PathResolution, Semantics};
+use ide_db::{
+    base_db::{FileId, FileRange},
+    RootDatabase,
+};
+use itertools::Itertools;
+use syntax::{ast, AstNode, SyntaxNode};
+
+use crate::{Diagnostic, DiagnosticCode, Severity};
+
+// Diagnostic: missing-trigger
+//
+// This diagnostic is triggered if a `forall` or `exists` without an explicit trigger has no
+// function application, field access or indexing over all of its variables. Verus either fails
+// to infer a trigger for such a quantifier or falls back to arithmetic ones.
+pub(crate) fn missing_trigger(
+    sema: &Semantics<'_, RootDatabase>,
+    acc: &mut Vec<Diagnostic>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let quantifier = ast::ClosureExpr::cast(node.clone())?;
+    if quantifier.forall_token().is_none() && quantifier.exists_token().is_none() {
+        return None;
+    }
+    let body = quantifier.body()?;
+    if body.syntax().descendants().any(|it| ast::TriggerAttribute::can_cast(it.kind())) {
+        return None;
+    }
+    let param_list = quantifier.param_list()?;
+    let binders = param_list
+        .params()
+        .filter_map(|it| it.pat())
+        .flat_map(|it| it.syntax().descendants().filter_map(ast::IdentPat::cast))
+        .filter_map(|it| sema.to_def(&it))
+        .collect::<Vec<_>>();
+    if binders.is_empty() {
+        return None;
+    }
+
+    let mut candidates = Vec::new();
+    for term in body.syntax().descendants().filter_map(ast::Expr::cast) {
+        if !matches!(
+            term,
+            ast::Expr::CallExpr(_)
+                | ast::Expr::MethodCallExpr(_)
+                | ast::Expr::IndexExpr(_)
+                | ast::Expr::FieldExpr(_)
+        ) {
+            continue;
+        }
+        let locals = locals_in(sema, &term);
+        let covered = binders.iter().filter(|it| locals.contains(it)).count();
+        if covered == binders.len() {
+            return None;
+        }
+        if covered > 0 {
+            candidates.push(term);
+        }
+    }
+
+    let message = if candidates.is_empty() {
+        "quantifier has no function application over its variables to use as a trigger".to_owned()
+    } else {
+        format!(
+            "quantifier has no trigger over all of its variables; candidate trigger terms: {}",
+            candidates
+                .iter()
+                .map(|it| format!(
+                    "`{}`",
+                    it.syntax().text().to_string().split_whitespace().join(" ")
+                ))
+                .join(", ")
+        )
+    };
+    acc.push(Diagnostic::new(
+        DiagnosticCode::Ra("missing-trigger", Severity::Warning),
+        message,
+        FileRange { file_id, range: param_list.syntax().text_range() },
+    ));
+    Some(())
+}
+
+fn locals_in(sema: &Semantics<'_, RootDatabase>, term: &ast::Expr) -> Vec<hir::Local> {
+    term.syntax()
+        .descendants()
+        .filter_map(ast::PathExpr::cast)
+        .filter_map(|it| match sema.resolve_path(&it.path()?)? {
+            PathResolution::Local(local) => Some(local),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::check_diagnostics;
+
+    #[test]
+    fn quantifiers_without_triggers() {
+        check_diagnostics(
+            r#"
+struct int;
+spec fn f(i: int) -> bool { true }
+spec fn g(i: int, j: int) -> bool { true }
+
+proof fn test() {
+    assert(forall|i: int| f(i));
+    assert(forall|i: int, j: int| g(i, j));
+    assert(forall|i: int, j: int| #![trigger f(i), f(j)] f(i) && f(j));
+    assert(forall|i: int| i == i);
+               //^^^^^^^^ warning: quantifier has no function application over its variables to use as a trigger
+    assert(exists|i: int, j: int| f(i) && f(j));
+               //^^^^^^^^^^^^^^^^ warning: quantifier has no trigger over all of its variables; candidate trigger terms: `f(i)`, `f(j)`
+}
+"#,
+        );
+    }
+}
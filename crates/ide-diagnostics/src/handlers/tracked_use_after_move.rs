@@ -0,0 +1,108 @@
+use crate::{Diagnostic, DiagnosticCode, DiagnosticsContext, Severity};
+
+// Diagnostic: tracked-use-after-move
+//
+// This diagnostic is triggered if a `tracked` variable is used after its value was moved, e.g.
+// after passing it by value to a `tracked` parameter of a lemma.
+pub(crate) fn tracked_use_after_move(
+    ctx: &DiagnosticsContext<'_>,
+    d: &hir::TrackedUseAfterMove,
+) -> Diagnostic {
+    Diagnostic::new_with_syntax_node_ptr(
+        ctx,
+        DiagnosticCode::Ra("tracked-use-after-move", Severity::Error),
+        format!(
+            "use of moved tracked variable `{}`",
+            d.local.name(ctx.sema.db).display(ctx.sema.db)
+        ),
+        d.expr.map(|it| it.into()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::check_diagnostics;
+
+    #[test]
+    fn use_after_move() {
+        check_diagnostics(
+            r#"
+struct Token;
+proof fn consume(tracked t: Token) {}
+proof fn inspect(t: Token) {}
+
+proof fn test(tracked t: Token, tracked u: Token) {
+    inspect(t);
+    consume(t);
+    inspect(t);
+    consume(t);
+          //^ error: use of moved tracked variable `t`
+    let tracked _v = u;
+    assert(u == u);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn moved_in_branch_or_previous_iteration() {
+        check_diagnostics(
+            r#"
+struct Token;
+proof fn consume(tracked t: Token) {}
+
+proof fn branch(tracked t: Token, b: bool) {
+    if b {
+        consume(t);
+    } else {
+        let tracked _u = &t;
+    }
+    consume(t);
+          //^ error: use of moved tracked variable `t`
+}
+
+proof fn iteration(tracked t: Token) {
+    loop {
+        consume(t);
+              //^ error: use of moved tracked variable `t`
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn diverging_paths_and_tracked_self() {
+        check_diagnostics(
+            r#"
+struct Token;
+impl Token {
+    proof fn consume(tracked self) {}
+    proof fn inspect(self) {}
+}
+
+proof fn early_return(tracked t: Token, b: bool) {
+    if b {
+        t.consume();
+        return;
+    }
+    t.consume();
+}
+
+proof fn leave_loop(tracked t: Token) {
+    loop {
+        t.consume();
+        break;
+    }
+}
+
+proof fn receiver(tracked t: Token) {
+    t.inspect();
+    t.consume();
+    t.consume();
+  //^ error: use of moved tracked variable `t`
+}
+"#,
+        );
+    }
+}
@@ -1,9 +1,11 @@
 use either::Either;
 use hir::{db::ExpandDatabase, ClosureStyle, HirDisplay, HirFileIdExt, InFile, Type};
-use ide_db::{famous_defs::FamousDefs, source_change::SourceChange};
+use ide_db::{
+    famous_defs::FamousDefs, source_change::SourceChange, syntax_helpers::node_ext::is_in_spec_code,
+};
 use syntax::{
     ast::{self, BlockExpr, ExprStmt},
-    AstNode, AstPtr,
+    AstNode, AstPtr, SyntaxKind,
 };
 use text_edit::TextEdit;
 
@@ -13,7 +15,21 @@ use crate::{adjusted_display_range, fix, Assist, Diagnostic, DiagnosticCode, Dia
 //
 // This diagnostic is triggered when the type of an expression or pattern does not match
 // the expected type.
-pub(crate) fn type_mismatch(ctx: &DiagnosticsContext<'_>, d: &hir::TypeMismatch) -> Diagnostic {
+pub(crate) fn type_mismatch(
+    ctx: &DiagnosticsContext<'_>,
+    d: &hir::TypeMismatch,
+) -> Option<Diagnostic> {
+    // Spec code compares and computes on mathematical integers, so Verus accepts integer literals
+    // and operands of any integer type there.
+    if is_integer(ctx, &d.expected) && is_integer(ctx, &d.actual) {
+        let root = ctx.sema.parse_or_expand(d.expr_or_pat.file_id);
+        let node = d.expr_or_pat.value.syntax_node_ptr().to_node(&root);
+        let is_operand_or_literal = node.kind() == SyntaxKind::LITERAL
+            || node.parent().map_or(false, |it| it.kind() == SyntaxKind::BIN_EXPR);
+        if is_operand_or_literal && is_in_spec_code(&node) {
+            return None;
+        }
+    }
     let display_range = adjusted_display_range(ctx, d.expr_or_pat, &|node| {
         let Either::Left(expr) = node else { return None };
         let salient_token_range = match expr {
@@ -45,7 +61,18 @@ pub(crate) fn type_mismatch(ctx: &DiagnosticsContext<'_>, d: &hir::TypeMismatch)
     if diag.fixes.is_none() {
         diag.experimental = true;
     }
-    diag
+    Some(diag)
+}
+
+/// Whether `ty` is a machine integer or the `int` or `nat` of builtin, which `vstd` reexports.
+fn is_integer(ctx: &DiagnosticsContext<'_>, ty: &Type) -> bool {
+    let db = ctx.sema.db;
+    ty.is_int_or_uint()
+        || ty.as_adt().map_or(false, |it| {
+            let krate = it.module(db).krate().display_name(db);
+            matches!(it.name(db).as_str(), Some("int" | "nat"))
+                && matches!(krate.as_ref().map(|it| it.canonical_name()), Some("builtin" | "vstd"))
+        })
 }
 
 fn fixes(ctx: &DiagnosticsContext<'_>, d: &hir::TypeMismatch) -> Option<Vec<Assist>> {
@@ -745,6 +772,44 @@ fn f() {
         0
     }
 }
+"#,
+        );
+    }
+
+    #[test]
+    fn integer_operands_in_spec_code() {
+        check_diagnostics(
+            r#"
+//- /main.rs crate:main deps:builtin
+use builtin::int;
+
+spec fn f(x: u32, y: int) -> bool {
+    x == y
+}
+
+fn g(x: u32, y: int) -> bool
+    requires
+        x == y,
+{
+    x == y
+       //^ error: expected u32, found int
+}
+//- /builtin.rs crate:builtin
+pub struct int;
+"#,
+        );
+    }
+
+    #[test]
+    fn user_defined_int_in_spec_code() {
+        check_diagnostics(
+            r#"
+struct int;
+
+spec fn f(x: u32, y: int) -> bool {
+    x == y
+       //^ error: expected u32, found int
+}
 "#,
         );
     }
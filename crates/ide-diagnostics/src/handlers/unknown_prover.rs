@@ -0,0 +1,114 @@
+use ide_db::{
+    base_db::{FileId, FileRange},
+    source_change::SourceChange,
+};
+use itertools::Itertools;
+use syntax::{
+    ast::{self, HasName},
+    AstNode, SyntaxNode,
+};
+use text_edit::TextEdit;
+
+use crate::{fix, Diagnostic, DiagnosticCode, Severity};
+
+/// The provers `assert(..) by (prover)` can be discharged with.
+const PROVERS: &[&str] = &["bit_vector", "nonlinear_arith", "compute", "compute_only"];
+
+// Diagnostic: unknown-prover
+//
+// This diagnostic is triggered if `assert(..) by (..)` names a prover Verus doesn't know.
+pub(crate) fn unknown_prover(
+    acc: &mut Vec<Diagnostic>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let assert = ast::AssertExpr::cast(node.clone())?;
+    let name = assert.name()?;
+    let prover = name.text();
+    if PROVERS.contains(&prover.as_str()) {
+        return None;
+    }
+
+    let range = name.syntax().text_range();
+    let fixes = closest_prover(prover.as_str()).map(|suggestion| {
+        vec![fix(
+            "replace_with_known_prover",
+            &format!("Replace with `{suggestion}`"),
+            SourceChange::from_text_edit(file_id, TextEdit::replace(range, suggestion.to_owned())),
+            range,
+        )]
+    });
+    acc.push(
+        Diagnostic::new(
+            DiagnosticCode::Ra("unknown-prover", Severity::Error),
+            format!(
+                "unknown prover `{prover}`, expected one of {}",
+                PROVERS.iter().map(|it| format!("`{it}`")).join(", ")
+            ),
+            FileRange { file_id, range },
+        )
+        .with_fixes(fixes),
+    );
+    Some(())
+}
+
+/// The known prover closest to `name`, if it looks like a typo of it.
+fn closest_prover(name: &str) -> Option<&'static str> {
+    PROVERS
+        .iter()
+        .map(|&it| (edit_distance(name, it), it))
+        .filter(|&(distance, _)| distance <= 3)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, it)| it)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur = vec![i + 1];
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != cb);
+            cur.push(substitution.min(prev[j + 1] + 1).min(cur[j] + 1));
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_diagnostics, check_fix};
+
+    #[test]
+    fn unknown_provers() {
+        check_diagnostics(
+            r#"
+proof fn test(x: u32) {
+    assert(x & 0 == 0) by (bit_vector);
+    assert(x * x >= 0) by (nonlinear_arith);
+    assert(x & 0 == 0) by (bitvector);
+                         //^^^^^^^^^ 💡 error: unknown prover `bitvector`, expected one of `bit_vector`, `nonlinear_arith`, `compute`, `compute_only`
+    assert(x == x) by (magic);
+                     //^^^^^ error: unknown prover `magic`, expected one of `bit_vector`, `nonlinear_arith`, `compute`, `compute_only`
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn replace_with_closest_prover() {
+        check_fix(
+            r#"
+proof fn test(x: u32) {
+    assert(x * x >= 0) by (nonlinear_arth$0);
+}
+"#,
+            r#"
+proof fn test(x: u32) {
+    assert(x * x >= 0) by (nonlinear_arith);
+}
+"#,
+        );
+    }
+}
@@ -119,6 +119,49 @@ fn main() {
         );
     }
 
+    #[test]
+    fn ghost_variables() {
+        check_diagnostics(
+            r#"
+struct Ghost<T>(T);
+struct Tracked<T>(T);
+
+fn spec_only(x: u32, y: u32) -> (r: u32)
+    requires x > 0,
+    ensures r == y,
+{
+    y
+}
+
+proof fn lemma(Ghost(g): Ghost<u32>, Tracked(t): Tracked<u32>)
+                                           //^ 💡 warn: unused variable
+    requires g > 0,
+{
+    let ghost unused = 1;
+            //^^^^^^ 💡 warn: unused variable
+    let tracked used = 2;
+    assert(used == used);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn ghost_variables_in_proof_block() {
+        check_diagnostics(
+            r#"
+proof fn lemma(_x: u32) {}
+
+fn f(x: u32) {
+    let ghost g = x;
+    let ghost unused = 1;
+            //^^^^^^ 💡 warn: unused variable
+    proof { lemma(g) }
+}
+"#,
+        );
+    }
+
     #[test]
     fn unused_self() {
         check_diagnostics(
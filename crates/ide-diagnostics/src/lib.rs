@@ -27,7 +27,9 @@
 
 mod handlers {
     pub(crate) mod break_outside_of_loop;
+    pub(crate) mod exec_call_in_ghost_code;
     pub(crate) mod expected_function;
+    pub(crate) mod ghost_variable_in_exec_code;
     pub(crate) mod inactive_code;
     pub(crate) mod incoherent_impl;
     pub(crate) mod incorrect_case;
@@ -47,6 +49,7 @@ mod handlers {
     pub(crate) mod remove_trailing_return;
     pub(crate) mod remove_unnecessary_else;
     pub(crate) mod replace_filter_map_next_with_find_map;
+    pub(crate) mod tracked_use_after_move;
     pub(crate) mod trait_impl_incorrect_safety;
     pub(crate) mod trait_impl_missing_assoc_item;
     pub(crate) mod trait_impl_orphan;
@@ -69,7 +72,10 @@ mod handlers {
 
     // The handlers below are unusual, the implement the diagnostics as well.
     pub(crate) mod field_shorthand;
+    pub(crate) mod int_in_exec_code;
     pub(crate) mod json_is_not_rust;
+    pub(crate) mod missing_trigger;
+    pub(crate) mod unknown_prover;
     pub(crate) mod unlinked_file;
     pub(crate) mod useless_braces;
 }
@@ -318,6 +324,9 @@ pub fn diagnostics(
         handlers::useless_braces::useless_braces(&mut res, file_id, &node);
         handlers::field_shorthand::field_shorthand(&mut res, file_id, &node);
         handlers::json_is_not_rust::json_in_items(&sema, &mut res, file_id, &node, config);
+        handlers::missing_trigger::missing_trigger(&sema, &mut res, file_id, &node);
+        handlers::unknown_prover::unknown_prover(&mut res, file_id, &node);
+        handlers::int_in_exec_code::int_in_exec_code(&mut res, file_id, &node);
     }
 
     let module = sema.file_to_module_def(file_id);
@@ -332,7 +341,9 @@ pub fn diagnostics(
 
     for diag in diags {
         let d = match diag {
+            AnyDiagnostic::ExecCallInGhostCode(d) => handlers::exec_call_in_ghost_code::exec_call_in_ghost_code(&ctx, &d),
             AnyDiagnostic::ExpectedFunction(d) => handlers::expected_function::expected_function(&ctx, &d),
+            AnyDiagnostic::GhostVariableInExecCode(d) => handlers::ghost_variable_in_exec_code::ghost_variable_in_exec_code(&ctx, &d),
             AnyDiagnostic::InactiveCode(d) => match handlers::inactive_code::inactive_code(&ctx, &d) {
                 Some(it) => it,
                 None => continue,
@@ -371,12 +382,16 @@ pub fn diagnostics(
             AnyDiagnostic::PrivateAssocItem(d) => handlers::private_assoc_item::private_assoc_item(&ctx, &d),
             AnyDiagnostic::PrivateField(d) => handlers::private_field::private_field(&ctx, &d),
             AnyDiagnostic::ReplaceFilterMapNextWithFindMap(d) => handlers::replace_filter_map_next_with_find_map::replace_filter_map_next_with_find_map(&ctx, &d),
+            AnyDiagnostic::TrackedUseAfterMove(d) => handlers::tracked_use_after_move::tracked_use_after_move(&ctx, &d),
             AnyDiagnostic::TraitImplIncorrectSafety(d) => handlers::trait_impl_incorrect_safety::trait_impl_incorrect_safety(&ctx, &d),
             AnyDiagnostic::TraitImplMissingAssocItems(d) => handlers::trait_impl_missing_assoc_item::trait_impl_missing_assoc_item(&ctx, &d),
             AnyDiagnostic::TraitImplRedundantAssocItems(d) => handlers::trait_impl_redundant_assoc_item::trait_impl_redundant_assoc_item(&ctx, &d),
             AnyDiagnostic::TraitImplOrphan(d) => handlers::trait_impl_orphan::trait_impl_orphan(&ctx, &d),
             AnyDiagnostic::TypedHole(d) => handlers::typed_hole::typed_hole(&ctx, &d),
-            AnyDiagnostic::TypeMismatch(d) => handlers::type_mismatch::type_mismatch(&ctx, &d),
+            AnyDiagnostic::TypeMismatch(d) => match handlers::type_mismatch::type_mismatch(&ctx, &d) {
+                Some(it) => it,
+                None => continue,
+            },
             AnyDiagnostic::UndeclaredLabel(d) => handlers::undeclared_label::undeclared_label(&ctx, &d),
             AnyDiagnostic::UnimplementedBuiltinMacro(d) => handlers::unimplemented_builtin_macro::unimplemented_builtin_macro(&ctx, &d),
             AnyDiagnostic::UnreachableLabel(d) => handlers::unreachable_label::unreachable_label(&ctx, &d),
@@ -18,6 +18,10 @@
 // command in the module `foo` with a pattern of `Bar`, then code in the parent module that refers
 // to `foo::Bar` will match.
 //
+// Verus expressions such as `assert(..)`, `==>` and quantifiers can be matched too, including
+// within `requires`/`ensures` conditions. Variables bound by a `forall`/`exists` in the search
+// pattern are matched by name, e.g. `assert($a ==> $b) ==>> assert(!$b ==> !$a)`.
+//
 // Paths in the replacement template will be rendered appropriately for the context in which the
 // replacement occurs. For example if our replacement template is `foo::Bar` and we match some
 // code in the `foo` module, we'll insert just `Bar`.
@@ -125,16 +125,24 @@ impl Resolver<'_, '_> {
                 // Self cannot be resolved like other paths.
                 return Ok(());
             }
+            if is_quantifier_binding(&path) {
+                // Variables bound by a quantifier in the pattern only exist within the pattern, so
+                // they're matched by name.
+                cov_mark::hit!(quantifier_bound_path);
+                return Ok(());
+            }
             // Check if this is an appropriate place in the path to resolve. If the path is
             // something like `a::B::<i32>::c` then we want to resolve `a::B`. If the path contains
             // a placeholder. e.g. `a::$b::c` then we want to resolve `a`.
             if !path_contains_type_arguments(path.qualifier())
                 && !self.path_contains_placeholder(&path)
             {
-                let resolution = self
-                    .resolution_scope
-                    .resolve_path(&path)
-                    .ok_or_else(|| error!("Failed to resolve path `{}`", node.text()))?;
+                let resolution = match self.resolution_scope.resolve_path(&path) {
+                    Some(resolution) => resolution,
+                    // Without vstd in scope, Verus' mathematical types are matched by name.
+                    None if is_verus_math_type(&path) => return Ok(()),
+                    None => return Err(error!("Failed to resolve path `{}`", node.text())),
+                };
                 if self.ok_to_use_path_resolution(&resolution) {
                     resolved_paths.insert(node, ResolvedPath { resolution, depth });
                     return Ok(());
@@ -306,3 +314,36 @@ fn path_contains_type_arguments(path: Option<ast::Path>) -> bool {
     }
     false
 }
+
+/// Returns whether `path` names a variable bound by an enclosing `forall` or `exists` quantifier.
+fn is_quantifier_binding(path: &ast::Path) -> bool {
+    use syntax::ast::{AstNode, HasName};
+    if path.qualifier().is_some() {
+        return false;
+    }
+    let Some(name_ref) = path.segment().and_then(|segment| segment.name_ref()) else {
+        return false;
+    };
+    path.syntax()
+        .ancestors()
+        .filter_map(ast::ClosureExpr::cast)
+        .filter(|closure| closure.forall_token().is_some() || closure.exists_token().is_some())
+        .filter_map(|closure| closure.param_list())
+        .flat_map(|params| params.params())
+        .any(|param| match param.pat() {
+            Some(ast::Pat::IdentPat(pat)) => {
+                pat.name().map_or(false, |name| name.text() == name_ref.text())
+            }
+            _ => false,
+        })
+}
+
+fn is_verus_math_type(path: &ast::Path) -> bool {
+    use syntax::ast::AstNode;
+    path.qualifier().is_none()
+        && matches!(path.syntax().parent().map(|it| it.kind()), Some(SyntaxKind::PATH_TYPE))
+        && path
+            .segment()
+            .and_then(|segment| segment.name_ref())
+            .map_or(false, |name_ref| matches!(name_ref.text().as_str(), "int" | "nat"))
+}
@@ -1396,3 +1396,35 @@ fn replace_autoref_mut() {
         "#]],
     );
 }
+
+#[test]
+fn replace_verus_assert() {
+    assert_ssr_transform(
+        "assert($a ==> $b) ==>> assert(!$b ==> !$a)",
+        r#"
+        proof fn f(x: int, y: bool) {
+            assert(x > 0 ==> y);
+        }
+        "#,
+        expect![[r#"
+            proof fn f(x: int, y: bool) {
+                assert(!y ==> !(x > 0));
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn match_verus_requires_condition() {
+    assert_matches("$a ==> $b", "fn f(x: bool, y: bool) requires x ==> y, { }", &["x ==> y"]);
+}
+
+#[test]
+fn match_verus_quantifier() {
+    cov_mark::check!(quantifier_bound_path);
+    assert_matches(
+        "forall|i: int| 0 <= i ==> $p",
+        "spec fn f(s: Seq<int>) -> bool { forall|i: int| 0 <= i ==> s[i] > 0 }",
+        &["forall|i: int| 0 <= i ==> s[i] > 0"],
+    );
+}
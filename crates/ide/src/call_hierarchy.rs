@@ -9,9 +9,11 @@ use ide_db::{
     search::FileReference,
     FxIndexMap, RootDatabase,
 };
-use syntax::{ast, AstNode, SyntaxKind::IDENT, TextRange};
+use syntax::{ast, match_ast, AstNode, SyntaxKind::IDENT, SyntaxNode, TextRange};
 
-use crate::{goto_definition, FilePosition, NavigationTarget, RangeInfo, TryToNav};
+use crate::{
+    goto_definition, navigation_target::ToNav, FilePosition, NavigationTarget, RangeInfo, TryToNav,
+};
 
 #[derive(Debug, Clone)]
 pub struct CallItem {
@@ -55,10 +57,24 @@ pub(crate) fn incoming_calls(
         let references =
             references.iter().filter_map(|FileReference { name, .. }| name.as_name_ref());
         for name in references {
-            // This target is the containing function
+            // This target is the containing function, or the broadcast group or module that
+            // broadcasts the lemma.
             let nav = sema.ancestors_with_macros(name.syntax().clone()).find_map(|node| {
-                let def = ast::Fn::cast(node).and_then(|fn_| sema.to_def(&fn_))?;
-                def.try_to_nav(sema.db)
+                match_ast! {
+                    match node {
+                        ast::Fn(it) => sema.to_def(&it)?.try_to_nav(sema.db),
+                        ast::BroadcastGroup(it) => {
+                            NavigationTarget::from_broadcast_group(sema, &it)
+                        },
+                        ast::BroadcastUseList(it) => {
+                            if it.syntax().ancestors().any(|it| ast::Fn::can_cast(it.kind())) {
+                                return None;
+                            }
+                            Some(sema.scope(it.syntax())?.module().to_nav(sema.db))
+                        },
+                        _ => None,
+                    }
+                }
             });
             if let Some(nav) = nav {
                 calls.add(nav.call_site, sema.original_range(name.syntax()).range);
@@ -95,25 +111,34 @@ pub(crate) fn outgoing_calls(
             _ => None,
         })
         .flatten()
-        .filter_map(ast::CallableExpr::cast)
-        .filter_map(|call_node| {
-            let (nav_target, range) = match call_node {
-                ast::CallableExpr::Call(call) => {
+        .filter_map(|node| {
+            let (nav_target, range) = match ast::CallableExpr::cast(node.clone()) {
+                Some(ast::CallableExpr::Call(call)) => {
                     let expr = call.expr()?;
-                    let callable = sema.type_of_expr(&expr)?.original.as_callable(db)?;
-                    match callable.kind() {
-                        hir::CallableKind::Function(it) => it.try_to_nav(db),
-                        hir::CallableKind::TupleEnumVariant(it) => it.try_to_nav(db),
-                        hir::CallableKind::TupleStruct(it) => it.try_to_nav(db),
-                        _ => None,
+                    match sema.type_of_expr(&expr) {
+                        Some(ty) => match ty.original.as_callable(db)?.kind() {
+                            hir::CallableKind::Function(it) => it.try_to_nav(db),
+                            hir::CallableKind::TupleEnumVariant(it) => it.try_to_nav(db),
+                            hir::CallableKind::TupleStruct(it) => it.try_to_nav(db),
+                            _ => None,
+                        },
+                        // Calls inside Verus constructs that aren't lowered into the body, like
+                        // `assert forall .. by { .. }`, are resolved by their path.
+                        None => resolve_function_path(&sema, expr.syntax())?.try_to_nav(db),
                     }
                     .zip(Some(expr.syntax().text_range()))
                 }
-                ast::CallableExpr::MethodCall(expr) => {
+                Some(ast::CallableExpr::MethodCall(expr)) => {
                     let range = expr.name_ref()?.syntax().text_range();
                     let function = sema.resolve_method_call(&expr)?;
                     function.try_to_nav(db).zip(Some(range))
                 }
+                // `broadcast use lemma;` inside of a proof block.
+                None => {
+                    node.parent().and_then(ast::BroadcastUseList::cast)?;
+                    let function = resolve_function_path(&sema, &node)?;
+                    function.try_to_nav(db).zip(Some(node.text_range()))
+                }
             }?;
             Some(nav_target.into_iter().zip(iter::repeat(range)))
         })
@@ -123,6 +148,20 @@ pub(crate) fn outgoing_calls(
     Some(calls.into_items())
 }
 
+fn resolve_function_path(
+    sema: &Semantics<'_, RootDatabase>,
+    node: &SyntaxNode,
+) -> Option<hir::Function> {
+    let path = match ast::PathExpr::cast(node.clone()) {
+        Some(path_expr) => path_expr.path()?,
+        None => ast::Path::cast(node.clone())?,
+    };
+    match sema.resolve_path(&path)? {
+        hir::PathResolution::Def(hir::ModuleDef::Function(it)) => Some(it),
+        _ => None,
+    }
+}
+
 #[derive(Default)]
 struct CallLocations {
     funcs: FxIndexMap<NavigationTarget, Vec<TextRange>>,
@@ -485,4 +524,55 @@ fn caller() {
             expect![[]],
         );
     }
+
+    #[test]
+    fn test_proof_call_hierarchy_assert_by() {
+        check_hierarchy(
+            r#"
+proof fn lemma_a() {}
+proof fn lemma$0_b() {
+    assert(true) by {
+        lemma_a();
+    }
+}
+proof fn lemma_c() {
+    lemma_b();
+}
+"#,
+            expect![["lemma_b Function FileId(0) 22..91 31..38"]],
+            expect![["lemma_c Function FileId(0) 92..129 101..108 : [117..124]"]],
+            expect![["lemma_a Function FileId(0) 0..21 9..16 : [73..80]"]],
+        );
+    }
+
+    #[test]
+    fn test_proof_call_hierarchy_broadcast() {
+        check_hierarchy(
+            r#"
+broadcast proof fn lemma$0_a() {}
+broadcast group group_a {
+    lemma_a,
+}
+proof fn user() {
+    broadcast use lemma_a;
+}
+"#,
+            expect![["lemma_a Function FileId(0) 0..31 19..26"]],
+            expect![[r#"
+                group_a Module FileId(0) 32..72 48..55 : [62..69]
+                user Function FileId(0) 73..119 82..86 : [109..116]"#]],
+            expect![[]],
+        );
+        check_hierarchy(
+            r#"
+broadcast proof fn lemma_a() {}
+proof fn us$0er() {
+    broadcast use lemma_a;
+}
+"#,
+            expect![["user Function FileId(0) 32..78 41..45"]],
+            expect![[]],
+            expect![["lemma_a Function FileId(0) 0..31 19..26 : [68..75]"]],
+        );
+    }
 }
@@ -56,6 +56,12 @@ fn try_extend_selection(
         TUPLE_TYPE,
         TUPLE_PAT,
         WHERE_CLAUSE,
+        REQUIRES_CLAUSE,
+        RECOMMENDS_CLAUSE,
+        ENSURES_CLAUSE,
+        DECREASES_CLAUSE,
+        INVARIANT_CLAUSE,
+        INVARIANT_EXCEPT_BREAK_CLAUSE,
     ];
 
     if range.is_empty() {
@@ -114,9 +120,37 @@ fn try_extend_selection(
         }
     }
 
+    if let Some(range) = extend_clause_to_fn_signature(&node) {
+        return Some(range);
+    }
+
     node.parent().map(|it| it.text_range())
 }
 
+/// Extends a Verus specification clause of a function to the whole signature, from the first
+/// keyword up to (but excluding) the body.
+fn extend_clause_to_fn_signature(node: &SyntaxNode) -> Option<TextRange> {
+    if !matches!(
+        node.kind(),
+        REQUIRES_CLAUSE | RECOMMENDS_CLAUSE | ENSURES_CLAUSE | SIGNATURE_DECREASES
+    ) {
+        return None;
+    }
+    let func = node.parent().and_then(ast::Fn::cast)?;
+    let mut elements = func
+        .syntax()
+        .children_with_tokens()
+        .filter(|it| !it.kind().is_trivia() && it.kind() != ATTR);
+    let start = elements.next()?.text_range().start();
+    let end = elements
+        .take_while(|it| it.kind() != BLOCK_EXPR && it.kind() != T![;])
+        .last()?
+        .text_range()
+        .end();
+    let range = TextRange::new(start, end);
+    (range != node.text_range()).then_some(range)
+}
+
 fn extend_tokens_from_range(
     sema: &Semantics<'_, RootDatabase>,
     macro_call: ast::MacroCall,
@@ -682,4 +716,26 @@ fn main() { let (
             ],
         );
     }
+
+    #[test]
+    fn test_extend_selection_verus_clauses() {
+        do_check(
+            r#"
+fn foo(x: u64)
+    requires
+        x > 0 && $0x < 10,
+        x != 5,
+{
+}"#,
+            &[
+                "x",
+                "x < 10",
+                "x > 0 && x < 10",
+                "x > 0 && x < 10,",
+                "requires\n        x > 0 && x < 10,\n        x != 5,",
+                "fn foo(x: u64)\n    requires\n        x > 0 && x < 10,\n        x != 5,",
+                "fn foo(x: u64)\n    requires\n        x > 0 && x < 10,\n        x != 5,\n{\n}",
+            ],
+        );
+    }
 }
@@ -20,6 +20,10 @@ pub struct StructureNode {
 pub enum StructureNodeKind {
     SymbolKind(SymbolKind),
     Region,
+    /// A Verus `broadcast group`.
+    BroadcastGroup,
+    /// A Verus specification clause such as `requires`, `ensures` or `invariant`.
+    Clause,
 }
 
 // Feature: File Structure
@@ -127,10 +131,39 @@ fn structure_node(node: &SyntaxNode) -> Option<StructureNode> {
         })
     }
 
+    fn clause(node: &SyntaxNode) -> Option<StructureNode> {
+        // `assert(..) by(..) requires ..` is part of a proof, not of the item's contract.
+        if node.parent().map_or(false, |it| ast::AssertExpr::can_cast(it.kind())) {
+            return None;
+        }
+        let keyword = node.first_token()?;
+        let mut detail = String::new();
+        for expr in node.children().filter_map(ast::Expr::cast) {
+            if !detail.is_empty() {
+                detail.push_str(", ");
+            }
+            collapse_ws(expr.syntax(), &mut detail);
+        }
+        Some(StructureNode {
+            parent: None,
+            label: keyword.text().to_owned(),
+            navigation_range: keyword.text_range(),
+            node_range: node.text_range(),
+            kind: StructureNodeKind::Clause,
+            detail: (!detail.is_empty()).then_some(detail),
+            deprecated: false,
+        })
+    }
+
     match_ast! {
         match node {
             ast::Fn(it) => {
-                let mut detail = String::from("fn");
+                let mut detail = String::new();
+                if let Some(fn_mode) = it.fn_mode() {
+                    collapse_ws(fn_mode.syntax(), &mut detail);
+                    detail.push(' ');
+                }
+                detail.push_str("fn");
                 if let Some(type_param_list) = it.generic_param_list() {
                     collapse_ws(type_param_list.syntax(), &mut detail);
                 }
@@ -188,6 +221,24 @@ fn structure_node(node: &SyntaxNode) -> Option<StructureNode> {
                 Some(node)
             },
             ast::Macro(it) => decl(it, StructureNodeKind::SymbolKind(SymbolKind::Macro)),
+            ast::BroadcastGroup(it) => {
+                let name = it.broadcast_group_identifier()?.ident_token()?;
+                Some(StructureNode {
+                    parent: None,
+                    label: name.text().to_owned(),
+                    navigation_range: name.text_range(),
+                    node_range: it.syntax().text_range(),
+                    kind: StructureNodeKind::BroadcastGroup,
+                    detail: None,
+                    deprecated: false,
+                })
+            },
+            ast::RequiresClause(_) => clause(node),
+            ast::RecommendsClause(_) => clause(node),
+            ast::EnsuresClause(_) => clause(node),
+            ast::DecreasesClause(_) => clause(node),
+            ast::InvariantClause(_) => clause(node),
+            ast::InvariantExceptBreakClause(_) => clause(node),
             _ => None,
         }
     }
@@ -635,4 +686,130 @@ fn g() {}
             "#]],
         );
     }
+
+    #[test]
+    fn test_file_structure_verus() {
+        check(
+            r#"
+verus! {
+spec fn double(x: int) -> int {
+    x * 2
+}
+
+proof fn lemma_double(x: int)
+    requires
+        x > 0,
+    ensures
+        double(x) > x,
+{
+}
+
+fn count(n: u64) {
+    let mut i = 0;
+    while i < n
+        invariant
+            i <= n,
+    {
+        i = i + 1;
+    }
+}
+
+broadcast group group_double {
+    lemma_double,
+}
+}
+"#,
+            expect![[r#"
+                [
+                    StructureNode {
+                        parent: None,
+                        label: "double",
+                        navigation_range: 18..24,
+                        node_range: 10..53,
+                        kind: SymbolKind(
+                            Function,
+                        ),
+                        detail: Some(
+                            "spec fn(x: int) -> int",
+                        ),
+                        deprecated: false,
+                    },
+                    StructureNode {
+                        parent: None,
+                        label: "lemma_double",
+                        navigation_range: 64..76,
+                        node_range: 55..151,
+                        kind: SymbolKind(
+                            Function,
+                        ),
+                        detail: Some(
+                            "proof fn(x: int)",
+                        ),
+                        deprecated: false,
+                    },
+                    StructureNode {
+                        parent: Some(
+                            1,
+                        ),
+                        label: "requires",
+                        navigation_range: 89..97,
+                        node_range: 89..112,
+                        kind: Clause,
+                        detail: Some(
+                            "x > 0",
+                        ),
+                        deprecated: false,
+                    },
+                    StructureNode {
+                        parent: Some(
+                            1,
+                        ),
+                        label: "ensures",
+                        navigation_range: 117..124,
+                        node_range: 117..147,
+                        kind: Clause,
+                        detail: Some(
+                            "double(x) > x",
+                        ),
+                        deprecated: false,
+                    },
+                    StructureNode {
+                        parent: None,
+                        label: "count",
+                        navigation_range: 156..161,
+                        node_range: 153..277,
+                        kind: SymbolKind(
+                            Function,
+                        ),
+                        detail: Some(
+                            "fn(n: u64)",
+                        ),
+                        deprecated: false,
+                    },
+                    StructureNode {
+                        parent: Some(
+                            4,
+                        ),
+                        label: "invariant",
+                        navigation_range: 215..224,
+                        node_range: 215..244,
+                        kind: Clause,
+                        detail: Some(
+                            "i <= n",
+                        ),
+                        deprecated: false,
+                    },
+                    StructureNode {
+                        parent: None,
+                        label: "group_double",
+                        navigation_range: 295..307,
+                        node_range: 279..329,
+                        kind: BroadcastGroup,
+                        detail: None,
+                        deprecated: false,
+                    },
+                ]
+            "#]],
+        );
+    }
 }
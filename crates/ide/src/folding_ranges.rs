@@ -3,7 +3,7 @@ use syntax::{
     ast::{self, AstNode, AstToken},
     match_ast, Direction, NodeOrToken, SourceFile,
     SyntaxKind::{self, *},
-    TextRange, TextSize,
+    SyntaxNode, SyntaxToken, TextRange, TextSize, T,
 };
 
 use std::hash::Hash;
@@ -25,6 +25,7 @@ pub enum FoldKind {
     WhereClause,
     ReturnType,
     MatchArm,
+    VerusClause,
 }
 
 #[derive(Debug)]
@@ -36,7 +37,7 @@ pub struct Fold {
 // Feature: Folding
 //
 // Defines folding regions for curly braced blocks, runs of consecutive use, mod, const or static
-// items, and `region` / `endregion` comment markers.
+// items, `region` / `endregion` comment markers, Verus specification clauses and `verus!` bodies.
 pub(crate) fn folding_ranges(file: &SourceFile) -> Vec<Fold> {
     let mut res = vec![];
     let mut visited_comments = FxHashSet::default();
@@ -63,6 +64,12 @@ pub(crate) fn folding_ranges(file: &SourceFile) -> Vec<Fold> {
 
         match element {
             NodeOrToken::Token(token) => {
+                if token.kind() == T![verus] {
+                    if let Some(range) = fold_range_for_verus_macro(&token) {
+                        res.push(Fold { range, kind: FoldKind::Block })
+                    }
+                    continue;
+                }
                 // Fold groups of comments
                 if let Some(comment) = ast::Comment::cast(token) {
                     if visited_comments.contains(&comment) {
@@ -86,6 +93,10 @@ pub(crate) fn folding_ranges(file: &SourceFile) -> Vec<Fold> {
                 }
             }
             NodeOrToken::Node(node) => {
+                if let Some(range) = fold_range_for_verus_clause(&node) {
+                    res.push(Fold { range, kind: FoldKind::VerusClause });
+                    continue;
+                }
                 match_ast! {
                     match node {
                         ast::Module(module) => {
@@ -270,6 +281,44 @@ fn fold_range_for_where_clause(where_clause: ast::WhereClause) -> Option<TextRan
     None
 }
 
+/// Folds the conditions of a multiline `requires`, `ensures`, `invariant`, ... clause, keeping the
+/// keyword visible.
+fn fold_range_for_verus_clause(node: &SyntaxNode) -> Option<TextRange> {
+    if !matches!(
+        node.kind(),
+        REQUIRES_CLAUSE
+            | RECOMMENDS_CLAUSE
+            | ENSURES_CLAUSE
+            | DECREASES_CLAUSE
+            | INVARIANT_CLAUSE
+            | INVARIANT_EXCEPT_BREAK_CLAUSE
+    ) || !node.text().contains_char('\n')
+    {
+        return None;
+    }
+    let keyword = node.first_token()?;
+    Some(TextRange::new(keyword.text_range().end(), node.text_range().end()))
+}
+
+/// Folds the body of a `verus! { ... }` block. The parser leaves the braces as loose tokens next to
+/// the items, so the matching `}` is the next closing brace among the siblings.
+fn fold_range_for_verus_macro(verus: &SyntaxToken) -> Option<TextRange> {
+    let mut siblings = verus
+        .siblings_with_tokens(Direction::Next)
+        .skip(1)
+        .filter_map(NodeOrToken::into_token)
+        .filter(|it| !it.kind().is_trivia());
+    if siblings.next()?.kind() != T![!] {
+        return None;
+    }
+    let l_curly = siblings.next().filter(|it| it.kind() == T!['{'])?;
+    let r_curly = siblings.find(|it| it.kind() == T!['}'])?;
+    let range = TextRange::new(l_curly.text_range().start(), r_curly.text_range().end());
+    let parent = verus.parent()?;
+    let text = parent.text().slice(range - parent.text_range().start());
+    text.contains_char('\n').then_some(range)
+}
+
 fn fold_range_for_multiline_match_arm(match_arm: ast::MatchArm) -> Option<TextRange> {
     if fold_kind(match_arm.expr()?.syntax().kind()).is_some() {
         None
@@ -316,6 +365,7 @@ mod tests {
                 FoldKind::WhereClause => "whereclause",
                 FoldKind::ReturnType => "returntype",
                 FoldKind::MatchArm => "matcharm",
+                FoldKind::VerusClause => "verusclause",
             };
             assert_eq!(kind, &attr.unwrap());
         }
@@ -623,4 +673,37 @@ fn bar() -> (bool, bool) { (true, true) }
 "#,
         )
     }
+
+    #[test]
+    fn fold_verus_clauses_and_body() {
+        check(
+            r#"
+verus! <fold block>{
+fn f(x: u64) -> u64
+    requires<fold verusclause>
+        x > 0,
+        x < 10,</fold>
+    ensures<fold verusclause>
+        x > 0,
+        x < 10,</fold>
+<fold block>{
+    let mut i = 0;
+    while i < x
+        invariant<fold verusclause>
+            i <= x,
+            x < 10,</fold>
+    <fold block>{
+        i = i + 1;
+    }</fold>
+    assert(i == x) by <fold block>{
+        assume(false);
+    }</fold>
+    x
+}</fold>
+
+fn g() requires true, {}
+}</fold>
+"#,
+        );
+    }
 }
@@ -8,11 +8,14 @@ use hir::{AsAssocItem, AssocItem, DescendPreference, MacroFileIdExt, ModuleDef,
 use ide_db::{
     base_db::{AnchoredPath, FileId, FileLoader},
     defs::{Definition, IdentClass},
-    helpers::pick_best_token,
+    helpers::{
+        assume_specification_target, assume_specifications, pick_best_token,
+        resolve_broadcast_group,
+    },
     RootDatabase,
 };
 use itertools::Itertools;
-use syntax::{ast, AstNode, AstToken, SyntaxKind::*, SyntaxToken, TextRange, T};
+use syntax::{ast, AstNode, AstToken, SyntaxKind::*, SyntaxNode, SyntaxToken, TextRange, T};
 
 // Feature: Go to Definition
 //
@@ -85,8 +88,16 @@ pub(crate) fn goto_definition(
                     return Some(vec![x]);
                 }
             }
+
+            if token.kind() == T![assume_specification] {
+                let stub = ast::Fn::cast(parent)?;
+                return Some(def_to_nav(db, assume_specification_target(sema, &stub)?.into()));
+            }
+            let Some(class) = IdentClass::classify_node(sema, &parent) else {
+                return try_lookup_broadcast_group(sema, &parent).map(|x| vec![x]);
+            };
             Some(
-                IdentClass::classify_node(sema, &parent)?
+                class
                     .definitions()
                     .into_iter()
                     .flat_map(|def| {
@@ -98,8 +109,15 @@ pub(crate) fn goto_definition(
                                 .flatten()
                                 .collect();
                         }
-                        try_filter_trait_item_definition(sema, &def)
-                            .unwrap_or_else(|| def_to_nav(sema.db, def))
+                        let mut navs = try_filter_trait_item_definition(sema, &def)
+                            .unwrap_or_else(|| def_to_nav(sema.db, def));
+                        match def {
+                            Definition::Function(func) if ast::Name::can_cast(parent.kind()) => {
+                                navs.extend(assume_specification_navs(sema, func))
+                            }
+                            _ => (),
+                        }
+                        navs
                     })
                     .collect(),
             )
@@ -111,6 +129,49 @@ pub(crate) fn goto_definition(
     Some(RangeInfo::new(original_token.text_range(), navs))
 }
 
+/// Broadcast groups are not part of the item scope, so names referring to them in
+/// `broadcast use` lists and group members are looked up separately.
+fn try_lookup_broadcast_group(
+    sema: &Semantics<'_, RootDatabase>,
+    name_ref: &SyntaxNode,
+) -> Option<NavigationTarget> {
+    let path = ast::NameRef::cast(name_ref.clone())?
+        .syntax()
+        .parent()
+        .and_then(ast::PathSegment::cast)?
+        .parent_path();
+    if path.parent_path().is_some() {
+        return None;
+    }
+    let group = resolve_broadcast_group(sema, &path)?;
+    let file_id = group.file_id.file_id()?;
+    let name = group.value.broadcast_group_identifier()?.ident_token()?;
+    Some(NavigationTarget {
+        file_id,
+        full_range: group.value.syntax().text_range(),
+        name: name.text().into(),
+        alias: None,
+        focus_range: Some(name.text_range()),
+        kind: None,
+        container_name: None,
+        description: None,
+        docs: None,
+    })
+}
+
+/// At the definition of a function, also offers the `assume_specification` items that give it a
+/// contract.
+fn assume_specification_navs(
+    sema: &Semantics<'_, RootDatabase>,
+    func: hir::Function,
+) -> Vec<NavigationTarget> {
+    assume_specifications(sema, func)
+        .iter()
+        .filter_map(|stub| NavigationTarget::from_assume_specification(sema, &stub.value))
+        .flatten()
+        .collect()
+}
+
 fn try_lookup_include_path(
     sema: &Semantics<'_, RootDatabase>,
     token: ast::String,
@@ -2313,4 +2374,102 @@ pub mod prelude {
         "#,
         );
     }
+
+    #[test]
+    fn goto_def_for_broadcast_use() {
+        check(
+            r#"
+proof fn lemma_a() {}
+
+broadcast group group_a {
+              //^^^^^^^
+    lemma_a,
+}
+
+broadcast use group_a$0;
+"#,
+        );
+        check(
+            r#"
+mod m {
+    proof fn lemma_a() {}
+
+    pub broadcast group group_m {
+                      //^^^^^^^
+        lemma_a,
+    }
+}
+
+broadcast group outer {
+    m::group_m$0,
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn goto_def_for_assume_specification() {
+        check(
+            r#"
+//- /lib.rs crate:std
+pub mod mem {
+    pub fn swap<T>(a: &mut T, b: &mut T) {}
+         //^^^^
+}
+//- /main.rs crate:main deps:std
+pub assume_specification$0<T> [std::mem::swap::<T>] (a: &mut T, b: &mut T);
+"#,
+        );
+        check(
+            r#"
+//- /lib.rs crate:std
+pub mod mem {
+    pub fn swap$0<T>(a: &mut T, b: &mut T) {}
+         //^^^^
+}
+//- /main.rs crate:main deps:std
+pub assume_specification<T> [std::mem::swap::<T>] (a: &mut T, b: &mut T);
+                           //^^^^^^^^^^^^^^^^^^^
+"#,
+        );
+    }
+
+    #[test]
+    fn goto_def_in_trigger() {
+        check(
+            r#"
+spec fn f(x: int) -> bool { true }
+      //^
+proof fn p() {
+    assert(forall|x: int| #![trigger f$0(x)] f(x));
+}
+"#,
+        );
+        check(
+            r#"
+spec fn f(x: int) -> bool { true }
+proof fn p() {
+    assert(forall|x: int| #![trigger f(x$0)] f(x));
+                //^
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn goto_def_in_via_clause() {
+        check(
+            r#"
+spec fn add0_recommends(a: nat) {}
+      //^^^^^^^^^^^^^^^
+spec fn add0(a: nat) -> nat
+    recommends
+        a > 0,
+    via add0_rec$0ommends
+{
+    a
+}
+"#,
+        );
+    }
 }
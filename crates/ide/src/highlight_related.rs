@@ -48,6 +48,8 @@ pub struct HighlightRelatedConfig {
 // . if on a `return` or `fn` keyword, `?` character or `->` return type arrow, highlights all exit points for that context
 // . if on a `break`, `loop`, `while` or `for` token, highlights all break points for that loop or block context
 // . if on a `move` or `|` token that belongs to a closure, highlights all captures of the closure.
+// . if on the `ensures` keyword of a function, highlights all exit points it constrains
+// . if on an `invariant` or `invariant_except_break` keyword, highlights the back-edges of the loop
 //
 // Note: `?`, `|` and `->` do not currently trigger this behavior in the VSCode editor.
 pub(crate) fn highlight_related(
@@ -72,6 +74,15 @@ pub(crate) fn highlight_related(
             highlight_exit_points(sema, token)
         }
         T![fn] | T![return] | T![->] if config.exit_points => highlight_exit_points(sema, token),
+        T![ensures]
+            if config.exit_points
+                && token.parent().and_then(|it| it.parent()).and_then(ast::Fn::cast).is_some() =>
+        {
+            highlight_exit_points(sema, token)
+        }
+        T![invariant] | T![invariant_except_break] if config.break_points => {
+            highlight_back_edges(token)
+        }
         T![await] | T![async] if config.yield_points => highlight_yield_points(token),
         T![for] if config.break_points && token.parent().and_then(ast::ForExpr::cast).is_some() => {
             highlight_break_points(token)
@@ -336,7 +347,16 @@ fn highlight_exit_points(
     for anc in token.parent_ancestors() {
         return match_ast! {
             match anc {
-                ast::Fn(fn_) => hl(sema, [fn_.fn_token().map(|it| it.text_range()), None], fn_.body().map(ast::Expr::BlockExpr)),
+                ast::Fn(fn_) => hl(
+                    sema,
+                    [
+                        fn_.fn_token().map(|it| it.text_range()),
+                        fn_.ensures_clause()
+                            .and_then(|it| it.ensures_token())
+                            .map(|it| it.text_range()),
+                    ],
+                    fn_.body().map(ast::Expr::BlockExpr)
+                ),
                 ast::ClosureExpr(closure) => hl(
                     sema,
                     closure.param_list().map_or([None; 2], |p| [p.l_paren_token().map(|it| it.text_range()), p.r_paren_token().map(|it| it.text_range())]),
@@ -361,6 +381,39 @@ fn highlight_exit_points(
     None
 }
 
+/// Highlights the places where a loop starts its next iteration, i.e. where its invariants must
+/// hold again: the `continue`s targeting it and the end of its body.
+fn highlight_back_edges(token: SyntaxToken) -> Option<Vec<HighlightedRange>> {
+    let loop_ = token.parent()?.parent()?;
+    let (label, body) = match_ast! {
+        match loop_ {
+            ast::LoopExpr(it) => (it.label(), it.loop_body()),
+            ast::WhileExpr(it) => (it.label(), it.loop_body()),
+            ast::ForExpr(it) => (it.label(), it.loop_body()),
+            _ => return None,
+        }
+    };
+    let body = body?.stmt_list()?;
+    let mut highlights =
+        vec![HighlightedRange { category: ReferenceCategory::empty(), range: token.text_range() }];
+    for_each_break_and_continue_expr(label, Some(body.clone()), &mut |expr| {
+        if let ast::Expr::ContinueExpr(continue_) = expr {
+            let range = cover_range(
+                continue_.continue_token().map(|it| it.text_range()),
+                continue_.lifetime().map(|it| it.syntax().text_range()),
+            );
+            highlights.extend(
+                range.map(|range| HighlightedRange { category: ReferenceCategory::empty(), range }),
+            );
+        }
+    });
+    highlights.extend(body.r_curly_token().map(|it| HighlightedRange {
+        category: ReferenceCategory::empty(),
+        range: it.text_range(),
+    }));
+    Some(highlights)
+}
+
 fn highlight_break_points(token: SyntaxToken) -> Option<Vec<HighlightedRange>> {
     fn hl(
         cursor_token_kind: SyntaxKind,
@@ -993,6 +1046,68 @@ fn never() -> ! { loop {} }
         );
     }
 
+    #[test]
+    fn test_hl_ensures_exit_points() {
+        check(
+            r#"
+  fn foo(b: bool) -> (r: u32)
+//^^
+    ensures$0
+ // ^^^^^^^
+        r > 0,
+{
+    if b {
+        return 1;
+     // ^^^^^^
+    }
+    2
+ // ^
+}
+"#,
+        );
+        check(
+            r#"
+  fn foo(b: bool) -> (r: u32)
+//^^
+    ensures
+ // ^^^^^^^
+        r > 0,
+{
+    return$0 1;
+ // ^^^^^^
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_hl_invariant_back_edges() {
+        check(
+            r#"
+fn foo(n: u32) {
+    let mut i = 0;
+    'outer: while i < n
+        invariant$0
+     // ^^^^^^^^^
+            i <= n,
+    {
+        i += 1;
+        if i == 2 {
+            continue;
+         // ^^^^^^^^
+        }
+        loop {
+            continue;
+            continue 'outer;
+         // ^^^^^^^^^^^^^^^
+        }
+    }
+ // ^
+}
+"#,
+        );
+    }
+
     #[test]
     fn test_hl_break_loop() {
         check(
@@ -248,6 +248,8 @@ fn hover_simple(
                     acc
                 })
         })
+        // try broadcast groups, which are not part of the item scope
+        .or_else(|| descended().find_map(|token| render::broadcast_group(sema, token)))
         // try keywords
         .or_else(|| descended().find_map(|token| render::keyword(sema, config, token)))
         // try _ hovers
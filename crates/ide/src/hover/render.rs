@@ -12,12 +12,17 @@ use ide_db::{
     documentation::HasDocs,
     famous_defs::FamousDefs,
     generated::lints::{CLIPPY_LINTS, DEFAULT_LINTS, FEATURES},
+    helpers::{assume_specifications, resolve_broadcast_group},
     syntax_helpers::insert_whitespace_into_node,
     RootDatabase,
 };
 use itertools::Itertools;
 use stdx::format_to;
-use syntax::{algo, ast, match_ast, AstNode, AstToken, Direction, SyntaxToken, T};
+use syntax::{
+    algo,
+    ast::{self, HasVisibility},
+    match_ast, AstNode, AstToken, Direction, SyntaxToken, T,
+};
 
 use crate::{
     doc_links::{remove_links, rewrite_links},
@@ -242,6 +247,41 @@ pub(super) fn underscore(
     None
 }
 
+pub(super) fn broadcast_group(
+    sema: &Semantics<'_, RootDatabase>,
+    token: &SyntaxToken,
+) -> Option<HoverResult> {
+    let parent = token.parent()?;
+    let group = if let Some(name_ref) = ast::NameRef::cast(parent.clone()) {
+        let path = name_ref.syntax().parent().and_then(ast::PathSegment::cast)?.parent_path();
+        if path.parent_path().is_some() {
+            return None;
+        }
+        resolve_broadcast_group(sema, &path)?.value
+    } else {
+        ast::BroadcastGroupIdentifier::cast(parent)?
+            .syntax()
+            .parent()
+            .and_then(ast::BroadcastGroup::cast)?
+    };
+
+    let mut desc = String::new();
+    if let Some(vis) = group.visibility() {
+        format_to!(desc, "{vis} ");
+    }
+    format_to!(desc, "broadcast group {}", group.broadcast_group_identifier()?.syntax());
+    let members =
+        group.broadcast_group_list().into_iter().flat_map(|it| it.broadcast_group_members());
+    desc.push_str(" {");
+    for member in members {
+        if let Some(path) = member.path() {
+            format_to!(desc, "\n    {path},");
+        }
+    }
+    desc.push_str("\n}");
+    Some(HoverResult { markup: markup(None, desc, None), actions: Vec::new() })
+}
+
 pub(super) fn keyword(
     sema: &Semantics<'_, RootDatabase>,
     config: &HoverConfig,
@@ -430,7 +470,30 @@ pub(super) fn definition(
             }
             label
         }
-        Definition::Function(fn_) => fn_.display_with_container_bounds(db, true).to_string(),
+        Definition::Function(fn_) => {
+            let mut label = fn_.display_with_container_bounds(db, true).to_string();
+            if let Some(mode) = fn_.source(db).and_then(|it| fn_mode_label(&it.value)) {
+                if let Some(idx) = label.find("fn ") {
+                    label.insert_str(idx, &format!("{mode} "));
+                }
+            }
+            let contract = render_fn_contract(db, fn_).or_else(|| render_assumed_contract(db, fn_));
+            if let Some(contract) = contract {
+                label.push('\n');
+                label.push_str(&contract);
+            }
+            label
+        }
+        Definition::Local(local) => {
+            let label = def.label(db);
+            match local_mode_label(db, local) {
+                Some(mode) => match label.strip_prefix("let ") {
+                    Some(rest) => format!("let {mode} {rest}"),
+                    None => format!("{mode} {label}"),
+                },
+                None => label,
+            }
+        }
         _ => def.label(db),
     };
     let docs = def.docs(db, famous_defs);
@@ -756,6 +819,97 @@ fn closure_ty(
     Some(res)
 }
 
+/// Renders the verus mode of a function (`spec`, `proof`, `exec`), preceded by its `open`/`closed`
+/// publish modifier. Functions without an explicit mode are `exec` and are left unannotated.
+fn fn_mode_label(func: &ast::Fn) -> Option<String> {
+    let mode = func.fn_mode()?;
+    let mode = if mode.mode_spec_checked().is_some() {
+        "spec(checked)"
+    } else if mode.spec_token().is_some() {
+        "spec"
+    } else if mode.proof_token().is_some() {
+        "proof"
+    } else {
+        "exec"
+    };
+    Some(match func.publish() {
+        Some(publish) => format!("{} {mode}", publish.syntax().text()),
+        None => mode.to_owned(),
+    })
+}
+
+/// Renders the `ghost`/`tracked` mode of a binding introduced by a `let` statement or a parameter.
+fn local_mode_label(db: &RootDatabase, local: hir::Local) -> Option<&'static str> {
+    let source = local.primary_source(db);
+    let ident_pat = source.as_ident_pat()?;
+    let binder = ident_pat.syntax().ancestors().find(|it| !ast::Pat::can_cast(it.kind()))?;
+    match_ast! {
+        match binder {
+            ast::LetStmt(it) => {
+                if it.tracked_token().is_some() {
+                    Some("tracked")
+                } else if it.ghost_token().is_some() {
+                    Some("ghost")
+                } else {
+                    None
+                }
+            },
+            ast::Param(it) => it.tracked_token().map(|_| "tracked"),
+            _ => None,
+        }
+    }
+}
+
+/// Renders the `requires`, `ensures` and `decreases` clauses of a verus function, one clause
+/// expression per line, so that the contract can be read without jumping to the definition.
+fn render_fn_contract(db: &RootDatabase, fn_: hir::Function) -> Option<String> {
+    let source = fn_.source(db)?;
+    render_contract(&source.value, source.file_id.is_macro())
+}
+
+/// Functions without a contract of their own, typically from `std`, can be given one by an
+/// `assume_specification` item.
+fn render_assumed_contract(db: &RootDatabase, fn_: hir::Function) -> Option<String> {
+    let sema = Semantics::new(db);
+    let stub = assume_specifications(&sema, fn_).into_iter().next()?;
+    let contract = render_contract(&stub.value, stub.file_id.is_macro())?;
+    Some(format!("// assumed by `assume_specification`\n{contract}"))
+}
+
+fn render_contract(func: &ast::Fn, is_macro: bool) -> Option<String> {
+    let render_expr = |expr: ast::Expr| {
+        let node = expr.syntax().clone();
+        let node = if is_macro { insert_whitespace_into_node::insert_ws_into(node) } else { node };
+        node.to_string()
+    };
+
+    let mut clauses: Vec<(&str, Vec<ast::Expr>)> = Vec::new();
+    if let Some(requires) = func.requires_clause() {
+        clauses.push(("requires", requires.exprs().collect()));
+    }
+    if let Some(ensures) = func.ensures_clause() {
+        clauses.push(("ensures", ensures.exprs().collect()));
+    }
+    if let Some(decreases) = func.signature_decreases().and_then(|it| it.decreases_clause()) {
+        clauses.push(("decreases", decreases.exprs().collect()));
+    }
+    if clauses.is_empty() {
+        return None;
+    }
+
+    let mut buf = String::new();
+    for (keyword, exprs) in clauses {
+        if !buf.is_empty() {
+            buf.push('\n');
+        }
+        buf.push_str(keyword);
+        for expr in exprs {
+            format_to!(buf, "\n    {},", render_expr(expr));
+        }
+    }
+    Some(buf)
+}
+
 fn definition_mod_path(db: &RootDatabase, def: &Definition) -> Option<String> {
     if matches!(def, Definition::GenericParam(_) | Definition::Local(_) | Definition::Label(_)) {
         return None;
@@ -8471,3 +8471,161 @@ fn main(a$0: T) {}
         "#]],
     );
 }
+
+#[test]
+fn hover_shows_fn_contract() {
+    check(
+        r#"
+proof fn lemma_le(x: u64, y: u64)
+    requires
+        x <= y,
+        y < 100,
+    ensures
+        x < 100,
+    decreases y
+{
+}
+
+proof fn main() { lemma$0_le(1, 2); }
+"#,
+        expect![[r#"
+            *lemma_le*
+
+            ```rust
+            test
+            ```
+
+            ```rust
+            proof fn lemma_le(x: u64, y: u64)
+            requires
+                x <= y,
+                y < 100,
+            ensures
+                x < 100,
+            decreases
+                y,
+            ```
+        "#]],
+    );
+}
+
+#[test]
+fn hover_shows_assumed_contract() {
+    check(
+        r#"
+//- /lib.rs crate:std
+pub mod mem {
+    pub fn swap<T>(a: &mut T, b: &mut T) {}
+}
+//- /main.rs crate:main deps:std
+pub assume_specification<T> [std::mem::swap::<T>] (a: &mut T, b: &mut T)
+    ensures
+        *a == *old(b),
+        *b == *old(a);
+
+fn main() {
+    let (mut x, mut y) = (1, 2);
+    std::mem::sw$0ap(&mut x, &mut y);
+}
+"#,
+        expect![[r#"
+            *swap*
+
+            ```rust
+            std::mem
+            ```
+
+            ```rust
+            pub fn swap<T>(a: &mut T, b: &mut T)
+            // assumed by `assume_specification`
+            ensures
+                *a == *old(b),
+                *b == *old(a),
+            ```
+        "#]],
+    );
+}
+
+#[test]
+fn hover_shows_fn_mode() {
+    check(
+        r#"
+pub open spec fn double(x: u64) -> u64 { x + x }
+
+proof fn main() { let y = dou$0ble(1); }
+"#,
+        expect![[r#"
+            *double*
+
+            ```rust
+            test
+            ```
+
+            ```rust
+            pub open spec fn double(x: u64) -> u64
+            ```
+        "#]],
+    );
+}
+
+#[test]
+fn hover_shows_binding_mode() {
+    check(
+        r#"
+fn main(tracked t: u64) {
+    let ghost g: u64 = 0;
+    let x = g$0;
+}
+"#,
+        expect![[r#"
+            *g*
+
+            ```rust
+            // size = 8, align = 8
+            let ghost g: u64
+            ```
+        "#]],
+    );
+    check(
+        r#"
+fn main(tracked t: u64) {
+    let x = t$0;
+}
+"#,
+        expect![[r#"
+            *t*
+
+            ```rust
+            // size = 8, align = 8
+            tracked t: u64
+            ```
+        "#]],
+    );
+}
+
+#[test]
+fn hover_broadcast_group() {
+    check(
+        r#"
+proof fn lemma_a() {}
+proof fn lemma_b() {}
+
+pub broadcast group group_ab {
+    lemma_a,
+    lemma_b,
+}
+
+broadcast use group_a$0b;
+"#,
+        expect![[r#"
+            *group_ab*
+
+            ```rust
+            pub broadcast group group_ab {
+                lemma_a,
+                lemma_b,
+            }
+            ```
+        "#]],
+    );
+}
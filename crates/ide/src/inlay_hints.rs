@@ -29,6 +29,7 @@ mod closure_captures;
 mod closure_ret;
 mod discriminant;
 mod fn_lifetime_fn;
+mod ghost_binding;
 mod implicit_drop;
 mod implicit_static;
 mod param_name;
@@ -53,6 +54,7 @@ pub struct InlayHintsConfig {
     pub hide_named_constructor_hints: bool,
     pub hide_closure_initialization_hints: bool,
     pub range_exclusive_hints: bool,
+    pub ghost_binding_hints: bool,
     pub closure_style: ClosureStyle,
     pub max_length: Option<usize>,
     pub closing_brace_hints_min_lines: Option<usize>,
@@ -125,6 +127,7 @@ pub enum InlayKind {
     ClosureCapture,
     Discriminant,
     GenericParamList,
+    GhostBinding,
     Lifetime,
     Parameter,
     Type,
@@ -566,6 +569,7 @@ fn hints(
                 match it {
                     ast::Pat::IdentPat(it) => {
                         bind_pat::hints(hints, famous_defs, config, file_id, &it);
+                        ghost_binding::hints(hints, config, &it);
                     }
                     ast::Pat::RangePat(it) => {
                         range_exclusive::hints(hints, config, it);
@@ -669,6 +673,7 @@ mod tests {
         fields_to_resolve: InlayFieldsToResolve::empty(),
         implicit_drop_hints: false,
         range_exclusive_hints: false,
+        ghost_binding_hints: false,
     };
     pub(super) const TEST_CONFIG: InlayHintsConfig = InlayHintsConfig {
         type_hints: true,
@@ -9,12 +9,13 @@ use ide_db::{base_db::FileId, famous_defs::FamousDefs, RootDatabase};
 use itertools::Itertools;
 use syntax::{
     ast::{self, AstNode, HasName},
-    match_ast,
+    match_ast, SyntaxNode,
 };
+use text_edit::TextEdit;
 
 use crate::{
     inlay_hints::{closure_has_block_body, label_of_ty, ty_to_text_edit},
-    InlayHint, InlayHintPosition, InlayHintsConfig, InlayKind,
+    InlayHint, InlayHintLabel, InlayHintPosition, InlayHintsConfig, InlayKind,
 };
 
 pub(super) fn hints(
@@ -58,7 +59,12 @@ pub(super) fn hints(
     let desc_pat = descended.as_ref().unwrap_or(pat);
     let ty = sema.type_of_binding_in_pat(desc_pat)?;
 
-    if ty.is_unknown() {
+    // verus: spec arithmetic on fixed-width integers is performed on mathematical integers, which
+    // inference only models for `int` and `nat` operands. Render those bindings as `int` and
+    // never show `{unknown}` in spec code.
+    let in_spec = is_in_spec_context(pat.syntax());
+    let spec_ty = in_spec.then(|| spec_arith_ty(&parent, &ty)).flatten();
+    if spec_ty.is_none() && (ty.is_unknown() || (in_spec && ty.contains_unknown())) {
         return None;
     }
 
@@ -66,7 +72,10 @@ pub(super) fn hints(
         return None;
     }
 
-    let mut label = label_of_ty(famous_defs, config, &ty)?;
+    let mut label = match spec_ty {
+        Some(spec_ty) => InlayHintLabel::from(spec_ty),
+        None => label_of_ty(famous_defs, config, &ty)?,
+    };
 
     if config.hide_named_constructor_hints
         && is_named_constructor(sema, pat, &label.to_string()).is_some()
@@ -74,7 +83,13 @@ pub(super) fn hints(
         return None;
     }
 
-    let text_edit = if let Some(colon_token) = &type_ascriptable {
+    let text_edit = if let (Some(spec_ty), Some(colon_token)) = (spec_ty, &type_ascriptable) {
+        let (offset, prefix) = match colon_token {
+            Some(colon_token) => (colon_token.text_range().end(), ""),
+            None => (pat.syntax().text_range().end(), ": "),
+        };
+        Some(TextEdit::insert(offset, format!("{prefix}{spec_ty}")))
+    } else if let Some(colon_token) = &type_ascriptable {
         ty_to_text_edit(
             sema,
             desc_pat.syntax(),
@@ -114,6 +129,63 @@ pub(super) fn hints(
     Some(())
 }
 
+/// Whether `node` is part of a specification: a function contract, a loop invariant, the
+/// condition of an `assert`/`assume`, or the body of a `spec` function.
+fn is_in_spec_context(node: &SyntaxNode) -> bool {
+    for ancestor in node.ancestors() {
+        let in_spec = match_ast! {
+            match ancestor {
+                ast::RequiresClause(_) => true,
+                ast::EnsuresClause(_) => true,
+                ast::RecommendsClause(_) => true,
+                ast::InvariantClause(_) => true,
+                ast::DecreasesClause(_) => true,
+                ast::AssertForallExpr(_) => true,
+                ast::AssertExpr(it) => {
+                    let cond = it.expr().map(|cond| cond.syntax().text_range());
+                    cond.map_or(false, |cond| cond.contains_range(node.text_range()))
+                },
+                ast::AssumeExpr(_) => true,
+                ast::Fn(it) => {
+                    let Some(mode) = it.fn_mode() else { return false };
+                    return mode.spec_token().is_some() || mode.mode_spec_checked().is_some();
+                },
+                _ => false,
+            }
+        };
+        if in_spec {
+            return true;
+        }
+    }
+    false
+}
+
+/// The spec type of a `let` binding initialized with fixed-width integer arithmetic, which Verus
+/// always evaluates as `int` in specifications. Bitwise operators and shifts keep their type.
+fn spec_arith_ty(binder: &SyntaxNode, ty: &hir::Type) -> Option<&'static str> {
+    let mut init = ast::LetStmt::cast(binder.clone())?.initializer()?;
+    while let ast::Expr::ParenExpr(it) = init {
+        init = it.expr()?;
+    }
+    match init {
+        ast::Expr::BinExpr(it)
+            if matches!(
+                it.op_kind(),
+                Some(ast::BinaryOp::ArithOp(
+                    ast::ArithOp::Add
+                        | ast::ArithOp::Sub
+                        | ast::ArithOp::Mul
+                        | ast::ArithOp::Div
+                        | ast::ArithOp::Rem
+                ))
+            ) && (ty.is_unknown() || ty.is_int_or_uint()) =>
+        {
+            Some("int")
+        }
+        _ => None,
+    }
+}
+
 fn is_named_constructor(
     sema: &Semantics<'_, RootDatabase>,
     pat: &ast::IdentPat,
@@ -1146,4 +1218,49 @@ async fn main() {
 }"#,
         );
     }
+
+    #[test]
+    fn type_hints_spec_arithmetic() {
+        check_types(
+            r#"
+spec fn f(x: u64) -> bool {
+    let a = x + 1;
+      //^ int
+    let b = x;
+      //^ u64
+    let c = x << 1;
+      //^ u64
+    let d = x & 1;
+      //^ u64
+    a > b
+}
+
+fn g(x: u64)
+    ensures
+        forall|i: u64| i < ({ let c = (x * 2); c }),
+                                //^ int
+{
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn type_hints_spec_arithmetic_edit() {
+        check_edit(
+            InlayHintsConfig { type_hints: true, ..DISABLED_CONFIG },
+            r#"
+spec fn f(x: u64) -> u64 {
+    let a = x + 1;
+    x
+}
+"#,
+            expect![[r#"
+                spec fn f(x: u64) -> u64 {
+                    let a: int = x + 1;
+                    x
+                }
+            "#]],
+        );
+    }
 }
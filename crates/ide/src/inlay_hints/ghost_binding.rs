@@ -0,0 +1,133 @@
+//! Implementation of "ghost binding" inlay hints, marking bindings whose values are erased at
+//! runtime:
+//! ```ignore
+//! let ghost /* ghost */x = 1;
+//! let Tracked(/* tracked */perm) = perm;
+//! spec fn f(/* spec */x: int) -> int { x }
+//! ```
+use syntax::{
+    ast::{self, HasName},
+    match_ast, AstNode,
+};
+
+use crate::{InlayHint, InlayHintLabel, InlayHintPosition, InlayHintsConfig, InlayKind};
+
+pub(super) fn hints(
+    acc: &mut Vec<InlayHint>,
+    config: &InlayHintsConfig,
+    pat: &ast::IdentPat,
+) -> Option<()> {
+    if !config.ghost_binding_hints {
+        return None;
+    }
+    let name = pat.name()?;
+    let mode = erased_mode(pat)?;
+    acc.push(InlayHint {
+        range: name.syntax().text_range(),
+        position: InlayHintPosition::Before,
+        pad_left: false,
+        pad_right: true,
+        kind: InlayKind::GhostBinding,
+        label: InlayHintLabel::from(mode),
+        text_edit: None,
+    });
+    Some(())
+}
+
+/// Returns the mode of the binding introduced by `pat` if its value only exists in proof code.
+fn erased_mode(pat: &ast::IdentPat) -> Option<&'static str> {
+    let mut binder = pat.syntax().parent()?;
+    while let Some(parent_pat) = ast::Pat::cast(binder.clone()) {
+        // `Ghost(x)` / `Tracked(x)` unwraps the wrapped value into a proof-only binding.
+        if let ast::Pat::TupleStructPat(it) = &parent_pat {
+            match it.path().and_then(|it| it.segment())?.to_string().as_str() {
+                "Ghost" => return Some("ghost"),
+                "Tracked" => return Some("tracked"),
+                _ => (),
+            }
+        }
+        binder = binder.parent()?;
+    }
+    match_ast! {
+        match binder {
+            ast::LetStmt(it) => {
+                if it.tracked_token().is_some() {
+                    Some("tracked")
+                } else if it.ghost_token().is_some() {
+                    Some("ghost")
+                } else {
+                    None
+                }
+            },
+            ast::Param(it) => {
+                if it.tracked_token().is_some() {
+                    return Some("tracked");
+                }
+                let func = it.syntax().ancestors().find_map(ast::Fn::cast)?;
+                let mode = func.fn_mode()?;
+                if mode.spec_token().is_some() || mode.mode_spec_checked().is_some() {
+                    Some("spec")
+                } else if mode.proof_token().is_some() {
+                    Some("ghost")
+                } else {
+                    None
+                }
+            },
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        inlay_hints::tests::{check_with_config, DISABLED_CONFIG},
+        InlayHintsConfig,
+    };
+
+    #[test]
+    fn ghost_and_tracked_lets() {
+        check_with_config(
+            InlayHintsConfig { ghost_binding_hints: true, ..DISABLED_CONFIG },
+            r#"
+fn main() {
+    let ghost g = 1;
+            //^ghost
+    let tracked t = 2;
+              //^tracked
+    let e = 3;
+}"#,
+        );
+    }
+
+    #[test]
+    fn ghost_and_tracked_unwraps() {
+        check_with_config(
+            InlayHintsConfig { ghost_binding_hints: true, ..DISABLED_CONFIG },
+            r#"
+struct Ghost<T>(T);
+struct Tracked<T>(T);
+fn main(Ghost(g): Ghost<u32>, Tracked(t): Tracked<u32>, e: u32) {
+            //^ghost
+                                    //^tracked
+    let (Ghost(a), b) = (Ghost(1), 2);
+             //^ghost
+}"#,
+        );
+    }
+
+    #[test]
+    fn spec_and_proof_params() {
+        check_with_config(
+            InlayHintsConfig { ghost_binding_hints: true, ..DISABLED_CONFIG },
+            r#"
+spec fn double(x: u32) -> u32 { x }
+             //^spec
+proof fn lemma(x: u32, tracked y: u32) {}
+             //^ghost
+                             //^tracked
+fn exec(x: u32) {}
+"#,
+        );
+    }
+}
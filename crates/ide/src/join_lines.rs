@@ -4,7 +4,10 @@ use itertools::Itertools;
 use syntax::{
     ast::{self, AstNode, AstToken, IsString},
     NodeOrToken, SourceFile, SyntaxElement,
-    SyntaxKind::{self, USE_TREE, WHITESPACE},
+    SyntaxKind::{
+        self, ASSERT_EXPR, ASSERT_FORALL_EXPR, DECREASES_CLAUSE, ENSURES_CLAUSE, INVARIANT_CLAUSE,
+        INVARIANT_EXCEPT_BREAK_CLAUSE, RECOMMENDS_CLAUSE, REQUIRES_CLAUSE, USE_TREE, WHITESPACE,
+    },
     SyntaxToken, TextRange, TextSize, T,
 };
 
@@ -125,6 +128,12 @@ fn remove_newline(
         _ => return,
     };
 
+    // Commas in `requires`/`ensures`/... lists separate conditions, so they are never dropped.
+    if prev.kind() == T![,] && token.parent().map_or(false, |it| is_clause_list(it.kind())) {
+        edit.replace(token.text_range(), " ".to_owned());
+        return;
+    }
+
     if config.remove_trailing_comma && prev.kind() == T![,] {
         match next.kind() {
             T![')'] | T![']'] => {
@@ -211,6 +220,19 @@ fn join_single_expr_block(edit: &mut TextEditBuilder, token: &SyntaxToken) -> Op
     if !block_expr.is_standalone() {
         return None;
     }
+    // `assert(..) by { .. }` needs its proof block.
+    if matches!(block_expr.syntax().parent()?.kind(), ASSERT_EXPR | ASSERT_FORALL_EXPR) {
+        return None;
+    }
+    // `{ &&& a }` is only valid as a block, unwrapping it would drop the bullet.
+    let stmt_list = block_expr.stmt_list()?;
+    let first = stmt_list
+        .syntax()
+        .children_with_tokens()
+        .find(|it| !matches!(it.kind(), WHITESPACE | T!['{']))?;
+    if matches!(first.kind(), T![&&&] | T![|||]) {
+        return None;
+    }
     let expr = extract_trivial_expression(&block_expr)?;
 
     let block_range = block_expr.syntax().text_range();
@@ -270,6 +292,18 @@ fn join_assignments(
     Some(())
 }
 
+fn is_clause_list(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        REQUIRES_CLAUSE
+            | RECOMMENDS_CLAUSE
+            | ENSURES_CLAUSE
+            | DECREASES_CLAUSE
+            | INVARIANT_CLAUSE
+            | INVARIANT_EXCEPT_BREAK_CLAUSE
+    )
+}
+
 fn as_if_expr(element: &SyntaxElement) -> Option<ast::IfExpr> {
     let mut node = element.as_node()?.clone();
     if let Some(stmt) = ast::ExprStmt::cast(node.clone()) {
@@ -832,6 +866,95 @@ fn main() {
         )
     }
 
+    #[test]
+    fn join_lines_clause_list_keeps_commas() {
+        check_join_lines(
+            r"
+fn foo(x: u32)
+    requires$0
+        x > 0,
+        x < 10,
+{
+}
+",
+            r"
+fn foo(x: u32)
+    requires$0 x > 0,
+        x < 10,
+{
+}
+",
+        );
+        check_join_lines(
+            r"
+fn foo(x: u32)
+    requires
+        x > 0,$0
+        x < 10,
+{
+}
+",
+            r"
+fn foo(x: u32)
+    requires
+        x > 0,$0 x < 10,
+{
+}
+",
+        );
+    }
+
+    #[test]
+    fn join_lines_bullets() {
+        check_join_lines(
+            r"
+spec fn foo(x: int) -> bool {
+    &&& x > 0$0
+    &&& x < 10
+}
+",
+            r"
+spec fn foo(x: int) -> bool {
+    &&& x > 0$0 &&& x < 10
+}
+",
+        );
+        check_join_lines(
+            r"
+spec fn foo(x: int) -> bool {
+    $0{
+        &&& x > 0
+    }
+}
+",
+            r"
+spec fn foo(x: int) -> bool {
+    $0{ &&& x > 0
+    }
+}
+",
+        );
+    }
+
+    #[test]
+    fn join_lines_keeps_assert_by_block() {
+        check_join_lines(
+            r"
+proof fn foo() {
+    assert(true) by $0{
+        lemma()
+    }
+}
+",
+            r"
+proof fn foo() {
+    assert(true) by $0{ lemma()
+    }
+}
+",
+        );
+    }
+
     #[test]
     fn join_lines_mandatory_blocks_block() {
         check_join_lines(
@@ -40,8 +40,11 @@ mod matching_brace;
 mod moniker;
 mod move_item;
 mod parent_module;
+mod proof_graph;
+mod proof_obligations;
 mod references;
 mod rename;
+mod reverification;
 mod runnables;
 mod shuffle_crate_graph;
 mod signature_help;
@@ -57,6 +60,7 @@ mod view_hir;
 mod view_item_tree;
 mod view_memory_layout;
 mod view_mir;
+mod view_vst;
 
 use std::panic::UnwindSafe;
 
@@ -101,9 +105,12 @@ pub use crate::{
     },
     move_item::Direction,
     navigation_target::{NavigationTarget, TryToNav, UpmappingResult},
+    proof_graph::{ProofEdge, ProofEdgeKind, ProofGraph, ProofNode, ProofStatus},
+    proof_obligations::{ProofObligation, ProofObligationKind},
     references::ReferenceSearchResult,
     rename::RenameError,
-    runnables::{Runnable, RunnableKind, TestId},
+    reverification::Reverification,
+    runnables::{Runnable, RunnableKind, TestId, VerifyTarget},
     signature_help::SignatureHelp,
     static_index::{StaticIndex, StaticIndexedFile, TokenId, TokenStaticData},
     syntax_highlighting::{
@@ -112,7 +119,7 @@ pub use crate::{
     },
     test_explorer::{TestItem, TestItemKind},
 };
-pub use hir::Semantics;
+pub use hir::{symbols::VerusSymbolCategory, Semantics};
 pub use ide_assists::{
     Assist, AssistConfig, AssistId, AssistKind, AssistResolveStrategy, SingleResolve,
 };
@@ -342,6 +349,33 @@ impl Analysis {
         self.with_db(|db| view_hir::view_hir(db, position))
     }
 
+    pub fn view_vst(&self, position: FilePosition) -> Cancellable<String> {
+        self.with_db(|db| view_vst::view_vst(db, position))
+    }
+
+    /// Returns the tree of proof obligations of the function at `position`.
+    pub fn proof_obligations(
+        &self,
+        position: FilePosition,
+    ) -> Cancellable<Option<ProofObligation>> {
+        self.with_db(|db| proof_obligations::proof_obligations(db, position))
+    }
+
+    /// Lists the functions to verify again now that `file_id` no longer has the text `old_text`
+    /// it had at the last Verus run.
+    pub fn reverifications(
+        &self,
+        file_id: FileId,
+        old_text: &str,
+    ) -> Cancellable<Vec<Reverification>> {
+        self.with_db(|db| reverification::reverifications(db, file_id, old_text))
+    }
+
+    /// Returns the proof dependency graph of the workspace, with the status of each node unknown.
+    pub fn proof_graph(&self) -> Cancellable<ProofGraph> {
+        self.with_db(proof_graph::proof_graph)
+    }
+
     pub fn view_mir(&self, position: FilePosition) -> Cancellable<String> {
         self.with_db(|db| view_mir::view_mir(db, position))
     }
@@ -456,9 +490,11 @@ impl Analysis {
     /// Fuzzy searches for a symbol.
     pub fn symbol_search(&self, query: Query, limit: usize) -> Cancellable<Vec<NavigationTarget>> {
         self.with_db(|db| {
+            let directives = symbol_index::world_verus_directives(db, query.clone());
             symbol_index::world_symbols(db, query)
                 .into_iter() // xx: should we make this a par iter?
                 .filter_map(|s| s.try_to_nav(db))
+                .chain(directives.into_iter().filter_map(|it| it.try_to_nav(db)))
                 .take(limit)
                 .map(UpmappingResult::call_site)
                 .collect::<Vec<_>>()
@@ -506,6 +542,12 @@ impl Analysis {
         self.with_db(|db| references::find_all_refs(&Semantics::new(db), position, search_scope))
     }
 
+    /// Whether the item at point is used from ghost code that rustc does not see, such as
+    /// `broadcast group`s, `via` clauses and triggers.
+    pub fn is_referenced_from_ghost_code(&self, position: FilePosition) -> Cancellable<bool> {
+        self.with_db(|db| references::is_referenced_from_ghost_code(&Semantics::new(db), position))
+    }
+
     /// Returns a short text describing element at position.
     pub fn hover(
         &self,
@@ -727,6 +769,16 @@ impl Analysis {
         })
     }
 
+    /// Returns the proof actions fixing the Verus failures reported in `frange`, as quick fixes.
+    pub fn verus_quickfixes(
+        &self,
+        assist_config: &AssistConfig,
+        frange: FileRange,
+        verus_errors: Vec<VerusError>,
+    ) -> Cancellable<Vec<Assist>> {
+        self.with_db(|db| ide_assists::verus_quickfixes(db, assist_config, frange, verus_errors))
+    }
+
     /// Returns the edit required to rename reference at the position to the new
     /// name.
     pub fn rename(
@@ -37,9 +37,13 @@ pub enum SymbolInformationKind {
     EnumMember,
     Field,
     Function,
+    /// A Verus `proof fn`.
+    Lemma,
     Macro,
     Method,
     Module,
+    /// A Verus `broadcast group`.
+    Namespace,
     Parameter,
     SelfParameter,
     StaticMethod,
@@ -64,9 +68,11 @@ impl From<SymbolInformationKind> for MonikerDescriptorKind {
             SymbolInformationKind::EnumMember => Self::Type,
             SymbolInformationKind::Field => Self::Term,
             SymbolInformationKind::Function => Self::Method,
+            SymbolInformationKind::Lemma => Self::Method,
             SymbolInformationKind::Macro => Self::Macro,
             SymbolInformationKind::Method => Self::Method,
             SymbolInformationKind::Module => Self::Namespace,
+            SymbolInformationKind::Namespace => Self::Namespace,
             SymbolInformationKind::Parameter => Self::Parameter,
             SymbolInformationKind::SelfParameter => Self::Parameter,
             SymbolInformationKind::StaticMethod => Self::Method,
@@ -180,6 +186,7 @@ pub(crate) fn def_to_kind(db: &RootDatabase, def: Definition) -> SymbolInformati
         },
         Definition::Field(..) | Definition::TupleField(..) => Field,
         Definition::Module(..) => Module,
+        Definition::Function(it) if it.is_proof(db) => Lemma,
         Definition::Function(it) => {
             if it.as_assoc_item(db).is_some() {
                 if it.has_self_param(db) {
@@ -248,14 +255,7 @@ pub(crate) fn def_to_moniker(
     }
 
     let module = def.module(db)?;
-    let krate = module.krate();
-    let mut description = vec![];
-    description.extend(module.path_to_root(db).into_iter().filter_map(|x| {
-        Some(MonikerDescriptor {
-            name: x.name(db)?.display(db).to_string(),
-            desc: def_to_kind(db, x.into()).into(),
-        })
-    }));
+    let mut description = module_descriptors(db, module);
 
     // Handle associated items within a trait
     if let Some(assoc) = def.as_assoc_item(db) {
@@ -377,7 +377,44 @@ pub(crate) fn def_to_moniker(
     };
 
     description.push(name_desc);
+    moniker_result(db, module.krate(), description, from_crate)
+}
+
+/// The moniker of the Verus `broadcast group` `name` of `module`. Groups aren't definitions of
+/// their own, so they are named after the module like the items in it.
+pub(crate) fn broadcast_group_to_moniker(
+    db: &RootDatabase,
+    module: hir::Module,
+    name: &str,
+    from_crate: Crate,
+) -> Option<MonikerResult> {
+    let mut description = module_descriptors(db, module);
+    description.push(MonikerDescriptor {
+        name: name.to_owned(),
+        desc: SymbolInformationKind::Namespace.into(),
+    });
+    moniker_result(db, module.krate(), description, from_crate)
+}
 
+fn module_descriptors(db: &RootDatabase, module: hir::Module) -> Vec<MonikerDescriptor> {
+    module
+        .path_to_root(db)
+        .into_iter()
+        .filter_map(|x| {
+            Some(MonikerDescriptor {
+                name: x.name(db)?.display(db).to_string(),
+                desc: def_to_kind(db, x.into()).into(),
+            })
+        })
+        .collect()
+}
+
+fn moniker_result(
+    db: &RootDatabase,
+    krate: Crate,
+    description: Vec<MonikerDescriptor>,
+    from_crate: Crate,
+) -> Option<MonikerResult> {
     Some(MonikerResult {
         identifier: MonikerIdentifier {
             crate_name: krate.display_name(db)?.crate_name().to_string(),
@@ -5,8 +5,10 @@ use std::fmt;
 use arrayvec::ArrayVec;
 use either::Either;
 use hir::{
-    db::ExpandDatabase, symbols::FileSymbol, AssocItem, FieldSource, HasContainer, HasSource,
-    HirDisplay, HirFileId, InFile, LocalSource, ModuleSource,
+    db::ExpandDatabase,
+    symbols::{FileSymbol, VerusDirectiveSymbol, VerusSymbolCategory},
+    AssocItem, FieldSource, HasContainer, HasSource, HirDisplay, HirFileId, InFile, LocalSource,
+    ModuleSource, Semantics,
 };
 use ide_db::{
     base_db::{FileId, FileRange},
@@ -138,6 +140,53 @@ impl NavigationTarget {
         buf
     }
 
+    /// Broadcast groups aren't part of the `DefMap`, so they are navigated to by their syntax.
+    pub(crate) fn from_broadcast_group(
+        sema: &Semantics<'_, RootDatabase>,
+        group: &ast::BroadcastGroup,
+    ) -> Option<UpmappingResult<NavigationTarget>> {
+        let ident = group.broadcast_group_identifier()?;
+        let name: SmolStr = ident.ident_token()?.text().into();
+        let file_id = sema.hir_file_for(group.syntax());
+        Some(orig_range_with_focus(sema.db, file_id, group.syntax(), Some(ident)).map(
+            |(FileRange { file_id, range: full_range }, focus_range)| {
+                let mut res = NavigationTarget::from_syntax(
+                    file_id,
+                    name.clone(),
+                    focus_range,
+                    full_range,
+                    SymbolKind::Module,
+                );
+                res.description = Some(format!("broadcast group {name}"));
+                res
+            },
+        ))
+    }
+
+    /// `assume_specification` items have no name, they are shown as the path of the function they
+    /// specify.
+    pub(crate) fn from_assume_specification(
+        sema: &Semantics<'_, RootDatabase>,
+        stub: &ast::Fn,
+    ) -> Option<UpmappingResult<NavigationTarget>> {
+        let path = stub.assume_specification_path()?;
+        let name: SmolStr = path.syntax().text().to_string().into();
+        let file_id = sema.hir_file_for(stub.syntax());
+        Some(orig_range_with_focus(sema.db, file_id, stub.syntax(), Some(path)).map(
+            |(FileRange { file_id, range: full_range }, focus_range)| {
+                let mut res = NavigationTarget::from_syntax(
+                    file_id,
+                    name.clone(),
+                    focus_range,
+                    full_range,
+                    SymbolKind::Function,
+                );
+                res.description = Some(format!("assume_specification [{name}]"));
+                res
+            },
+        ))
+    }
+
     /// Allows `NavigationTarget` to be created from a `NameOwner`
     pub(crate) fn from_named(
         db: &RootDatabase,
@@ -216,6 +265,35 @@ impl TryToNav for FileSymbol {
     }
 }
 
+impl TryToNav for VerusDirectiveSymbol {
+    fn try_to_nav(&self, db: &RootDatabase) -> Option<UpmappingResult<NavigationTarget>> {
+        Some(
+            orig_range_with_focus_r(
+                db,
+                self.hir_file_id,
+                self.ptr.text_range(),
+                Some(self.name_ptr.text_range()),
+            )
+            .map(|(FileRange { file_id, range: full_range }, focus_range)| {
+                NavigationTarget {
+                    file_id,
+                    name: self.name.clone(),
+                    alias: None,
+                    kind: Some(match self.category {
+                        VerusSymbolCategory::BroadcastGroup => SymbolKind::Module,
+                        _ => SymbolKind::Const,
+                    }),
+                    full_range,
+                    focus_range,
+                    container_name: self.container_name.clone(),
+                    description: None,
+                    docs: None,
+                }
+            }),
+        )
+    }
+}
+
 impl TryToNav for Definition {
     fn try_to_nav(&self, db: &RootDatabase) -> Option<UpmappingResult<NavigationTarget>> {
         match self {
@@ -873,6 +951,8 @@ fn orig_range_r(
 #[cfg(test)]
 mod tests {
     use expect_test::expect;
+    use hir::symbols::VerusSymbolCategory;
+    use itertools::Itertools;
 
     use crate::{fixture, Query};
 
@@ -948,4 +1028,54 @@ static __FOO_CALLSITE: () = ();
         let navs = analysis.symbol_search(query, !0).unwrap();
         assert_eq!(navs.len(), 1);
     }
+
+    #[test]
+    fn test_world_symbols_verus_categories() {
+        let (analysis, _) = fixture::file(
+            r#"
+spec fn len_spec() -> int { 0 }
+proof fn len_lemma() {}
+broadcast proof fn len_broadcast() {}
+fn len_exec() {}
+"#,
+        );
+
+        let search = |category| {
+            let mut query = Query::new("len".to_owned());
+            query.verus_category(category);
+            let navs = analysis.symbol_search(query, !0).unwrap();
+            navs.into_iter().map(|nav| nav.name.to_string()).sorted().collect::<Vec<_>>()
+        };
+        assert_eq!(search(VerusSymbolCategory::Spec), ["len_spec"]);
+        assert_eq!(search(VerusSymbolCategory::Proof), ["len_broadcast", "len_lemma"]);
+        assert_eq!(search(VerusSymbolCategory::BroadcastProof), ["len_broadcast"]);
+    }
+
+    #[test]
+    fn test_world_symbols_verus_directives() {
+        let (analysis, _) = fixture::file(
+            r#"
+spec const LEN: int = 0;
+proof fn len_lemma() {}
+broadcast group group_len { len_lemma }
+global size_of usize == 8;
+"#,
+        );
+
+        let search = |text: &str, category: Option<VerusSymbolCategory>| {
+            let mut query = Query::new(text.to_owned());
+            if let Some(category) = category {
+                query.verus_category(category);
+            }
+            let navs = analysis.symbol_search(query, !0).unwrap();
+            navs.into_iter()
+                .map(|nav| format!("{:?} {}", nav.kind.unwrap(), nav.name))
+                .sorted()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(search("len", None), ["Const LEN", "Function len_lemma", "Module group_len"]);
+        assert_eq!(search("len", Some(VerusSymbolCategory::Spec)), ["Const LEN"]);
+        assert_eq!(search("len", Some(VerusSymbolCategory::BroadcastGroup)), ["Module group_len"]);
+        assert_eq!(search("size_of", None), ["Const size_of usize"]);
+    }
 }
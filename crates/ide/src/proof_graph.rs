@@ -0,0 +1,329 @@
+use dot::{Id, LabelText, Style};
+use hir::{symbols::VerusSymbolCategory, Crate, ModuleDef, PathResolution, Semantics};
+use ide_db::{
+    base_db::{FileRange, SourceDatabaseExt},
+    helpers::resolve_broadcast_group,
+    FxHashMap, FxHashSet, RootDatabase,
+};
+use itertools::Itertools;
+use syntax::{
+    ast::{self, HasName},
+    match_ast, AstNode, SyntaxNode,
+};
+
+/// A `spec` or `proof` function, or a `broadcast group`.
+#[derive(Debug, Clone)]
+pub struct ProofNode {
+    pub krate: String,
+    /// The path of the item within its crate, e.g. `module::S::lemma`.
+    pub path: String,
+    pub kind: VerusSymbolCategory,
+    pub range: FileRange,
+    /// Left to the caller, which knows the results of the Verus runs.
+    pub status: ProofStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofStatus {
+    Verified,
+    Failed,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProofEdgeKind {
+    /// A call in the contract or the body of a function.
+    Call,
+    /// A `broadcast use` in a function or in its module, or a member of a `broadcast group`.
+    BroadcastUse,
+}
+
+/// `from` relies on `to`, both are indices into the nodes of the graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProofEdge {
+    pub from: usize,
+    pub to: usize,
+    pub kind: ProofEdgeKind,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ProofGraph {
+    pub nodes: Vec<ProofNode>,
+    pub edges: Vec<ProofEdge>,
+}
+
+// Feature: Proof Dependency Graph
+//
+// Lists the `spec` and `proof` functions and the `broadcast group`s of the workspace, along with
+// what each of them relies on: the functions called in its contract and body, and the lemmas and
+// groups it broadcasts, either itself or through its module. It can be rendered as DOT to find
+// the lemmas nothing relies on and to review how proofs are layered.
+pub(crate) fn proof_graph(db: &RootDatabase) -> ProofGraph {
+    let sema = Semantics::new(db);
+    let mut files = FxHashMap::default();
+    for krate in Crate::all(db) {
+        let source_root = db.source_root(db.file_source_root(krate.root_file(db)));
+        if source_root.is_library {
+            continue;
+        }
+        let name = krate.display_name(db).map_or_else(String::new, |it| it.to_string());
+        for module in krate.modules(db) {
+            let file_id = module.definition_source_file_id(db).original_file(db);
+            files.entry(file_id).or_insert_with(|| name.clone());
+        }
+    }
+
+    let mut graph = ProofGraph::default();
+    let mut fns = FxHashMap::default();
+    let mut groups = FxHashMap::default();
+    // The items whose dependencies are collected once all nodes are known.
+    let mut items = Vec::new();
+    for (file_id, krate) in files.into_iter().sorted_by_key(|(file_id, _)| *file_id) {
+        let source_file = sema.parse(file_id);
+        let module_path = sema.file_to_module_def(file_id).map_or_else(Vec::new, |module| {
+            module
+                .path_to_root(db)
+                .into_iter()
+                .rev()
+                .filter_map(|it| Some(it.name(db)?.display(db).to_string()))
+                .collect()
+        });
+        for node in source_file.syntax().descendants() {
+            let (name, kind) = match_ast! {
+                match node {
+                    ast::Fn(it) => {
+                        let Some(kind) = VerusSymbolCategory::of(it.syntax()) else { continue };
+                        let (Some(name), Some(def)) = (it.name(), sema.to_def(&it)) else {
+                            continue;
+                        };
+                        fns.insert(def, graph.nodes.len());
+                        (name.text().to_string(), kind)
+                    },
+                    ast::BroadcastGroup(it) => {
+                        let Some(name) =
+                            it.broadcast_group_identifier().and_then(|it| it.ident_token())
+                        else {
+                            continue;
+                        };
+                        let range = FileRange { file_id, range: it.syntax().text_range() };
+                        groups.insert(range, graph.nodes.len());
+                        (name.text().to_owned(), VerusSymbolCategory::BroadcastGroup)
+                    },
+                    _ => continue,
+                }
+            };
+            items.push((graph.nodes.len(), node.clone()));
+            graph.nodes.push(ProofNode {
+                krate: krate.clone(),
+                path: item_path(&module_path, &node, &name),
+                kind,
+                range: FileRange { file_id, range: node.text_range() },
+                status: ProofStatus::Unknown,
+            });
+        }
+    }
+
+    let resolve = |path: &ast::Path| -> Option<usize> {
+        if let Some(PathResolution::Def(ModuleDef::Function(func))) = sema.resolve_path(path) {
+            return fns.get(&func).copied();
+        }
+        let group = resolve_broadcast_group(&sema, path)?;
+        let file_id = group.file_id.file_id()?;
+        groups.get(&FileRange { file_id, range: group.value.syntax().text_range() }).copied()
+    };
+    let mut edges = FxHashSet::default();
+    for (from, item) in &items {
+        let mut add = |to: Option<usize>, kind| {
+            if let Some(to) = to.filter(|to| to != from) {
+                edges.insert(ProofEdge { from: *from, to, kind });
+            }
+        };
+        if let Some(group) = ast::BroadcastGroup::cast(item.clone()) {
+            let members = group.broadcast_group_list().into_iter().flat_map(|it| {
+                it.broadcast_group_members().filter_map(|it| it.path()).collect::<Vec<_>>()
+            });
+            for path in members {
+                add(resolve(&path), ProofEdgeKind::BroadcastUse);
+            }
+            continue;
+        }
+        for node in item.descendants() {
+            match_ast! {
+                match node {
+                    ast::CallExpr(it) => {
+                        let to = match it.expr() {
+                            Some(ast::Expr::PathExpr(it)) => it.path().and_then(|it| resolve(&it)),
+                            _ => None,
+                        };
+                        add(to, ProofEdgeKind::Call);
+                    },
+                    ast::MethodCallExpr(it) => {
+                        let func = sema.resolve_method_call(&it);
+                        add(func.and_then(|it| fns.get(&it).copied()), ProofEdgeKind::Call);
+                    },
+                    ast::BroadcastUseList(it) => {
+                        for path in it.paths() {
+                            add(resolve(&path), ProofEdgeKind::BroadcastUse);
+                        }
+                    },
+                    _ => (),
+                }
+            }
+        }
+        // A `broadcast use` of the module applies to all of its functions.
+        let Some(scope) = item.parent().filter(|it| is_module_scope(it)) else { continue };
+        for list in scope
+            .children()
+            .filter_map(ast::BroadcastUse::cast)
+            .filter_map(|it| it.broadcast_use_list())
+        {
+            for path in list.paths() {
+                add(resolve(&path), ProofEdgeKind::BroadcastUse);
+            }
+        }
+    }
+    graph.edges = edges.into_iter().sorted_by_key(|it| (it.from, it.to)).collect();
+    graph
+}
+
+/// The path of the item `node` named `name`, in the file of the module at `module_path`.
+fn item_path(module_path: &[String], node: &SyntaxNode, name: &str) -> String {
+    let mut segments = vec![name.to_owned()];
+    for ancestor in node.ancestors().skip(1) {
+        if let Some(module) = ast::Module::cast(ancestor.clone()) {
+            segments.extend(module.name().map(|it| it.text().to_string()));
+        } else if let Some(imp) = ast::Impl::cast(ancestor) {
+            segments.extend(imp.self_ty().map(|it| it.syntax().text().to_string()));
+        }
+    }
+    module_path.iter().cloned().chain(segments.into_iter().rev()).join("::")
+}
+
+fn is_module_scope(node: &SyntaxNode) -> bool {
+    ast::SourceFile::can_cast(node.kind()) || ast::ItemList::can_cast(node.kind())
+}
+
+impl ProofGraph {
+    /// Renders the graph in the DOT format of graphviz: verified nodes are green and failed ones
+    /// red, broadcasts are dashed.
+    pub fn to_dot(&self) -> String {
+        let mut dot = Vec::new();
+        dot::render(&DotProofGraph(self), &mut dot).unwrap();
+        String::from_utf8(dot).unwrap()
+    }
+}
+
+struct DotProofGraph<'a>(&'a ProofGraph);
+
+impl<'a> dot::GraphWalk<'a, usize, ProofEdge> for DotProofGraph<'a> {
+    fn nodes(&'a self) -> dot::Nodes<'a, usize> {
+        (0..self.0.nodes.len()).collect()
+    }
+
+    fn edges(&'a self) -> dot::Edges<'a, ProofEdge> {
+        self.0.edges.iter().copied().collect()
+    }
+
+    fn source(&'a self, edge: &ProofEdge) -> usize {
+        edge.from
+    }
+
+    fn target(&'a self, edge: &ProofEdge) -> usize {
+        edge.to
+    }
+}
+
+impl<'a> dot::Labeller<'a, usize, ProofEdge> for DotProofGraph<'a> {
+    fn graph_id(&'a self) -> Id<'a> {
+        Id::new("verus_proof_graph").unwrap()
+    }
+
+    fn node_id(&'a self, n: &usize) -> Id<'a> {
+        Id::new(format!("_{n}")).unwrap()
+    }
+
+    fn node_shape(&'a self, n: &usize) -> Option<LabelText<'a>> {
+        let shape = match self.0.nodes[*n].kind {
+            VerusSymbolCategory::BroadcastGroup => "folder",
+            VerusSymbolCategory::Spec => "ellipse",
+            _ => "box",
+        };
+        Some(LabelText::LabelStr(shape.into()))
+    }
+
+    fn node_label(&'a self, n: &usize) -> LabelText<'a> {
+        let node = &self.0.nodes[*n];
+        LabelText::LabelStr(format!("{}::{}", node.krate, node.path).into())
+    }
+
+    fn node_color(&'a self, n: &usize) -> Option<LabelText<'a>> {
+        let color = match self.0.nodes[*n].status {
+            ProofStatus::Verified => "green",
+            ProofStatus::Failed => "red",
+            ProofStatus::Unknown => return None,
+        };
+        Some(LabelText::LabelStr(color.into()))
+    }
+
+    fn edge_style(&'a self, e: &ProofEdge) -> Style {
+        match e.kind {
+            ProofEdgeKind::Call => Style::None,
+            ProofEdgeKind::BroadcastUse => Style::Dashed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::fixture;
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let (analysis, _) = fixture::file(ra_fixture);
+        let graph = analysis.proof_graph().unwrap();
+        let mut actual = String::new();
+        for node in &graph.nodes {
+            actual += &format!("{:?} {}\n", node.kind, node.path);
+        }
+        for edge in &graph.edges {
+            let (from, to) = (&graph.nodes[edge.from].path, &graph.nodes[edge.to].path);
+            actual += &format!("{from} -> {to} {:?}\n", edge.kind);
+        }
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn calls_and_broadcasts() {
+        check(
+            r#"
+spec fn double(x: int) -> int { x + x }
+broadcast proof fn lemma_double(x: int)
+    ensures #[trigger] double(x) == 2 * x,
+{
+}
+broadcast group group_double {
+    lemma_double,
+}
+mod m {
+    broadcast use super::group_double;
+    proof fn uses_group(x: int)
+        ensures super::double(x) == 2 * x,
+    {
+    }
+}
+fn exec_fn() {}
+"#,
+            expect![[r#"
+                Spec double
+                BroadcastProof lemma_double
+                BroadcastGroup group_double
+                Proof m::uses_group
+                lemma_double -> double Call
+                group_double -> lemma_double BroadcastUse
+                m::uses_group -> double Call
+                m::uses_group -> group_double BroadcastUse
+            "#]],
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use hir::{HasSource, Semantics};
+use ide_db::{
+    base_db::{FileId, FilePosition, FileRange},
+    RootDatabase,
+};
+use itertools::Itertools;
+use syntax::{
+    algo::ancestors_at_offset,
+    ast::{self, HasName},
+    match_ast, AstNode, SyntaxNode,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofObligationKind {
+    Function,
+    /// A call to a function with preconditions, its children are the callee's `requires`.
+    Call,
+    Requires,
+    Ensures,
+    Assert,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProofObligation {
+    pub kind: ProofObligationKind,
+    pub label: String,
+    /// For `Requires`, this points at the callee's clause, the parent `Call` holds the callsite.
+    pub range: FileRange,
+    pub children: Vec<ProofObligation>,
+}
+
+// Feature: Proof Obligations
+//
+// Lists what Verus has to prove for the function at the cursor: each of its `ensures` clauses,
+// each `assert` in its body and, at every callsite, each `requires` clause of the callee.
+pub(crate) fn proof_obligations(
+    db: &RootDatabase,
+    position: FilePosition,
+) -> Option<ProofObligation> {
+    let sema = Semantics::new(db);
+    let source_file = sema.parse(position.file_id);
+    let func =
+        ancestors_at_offset(source_file.syntax(), position.offset).find_map(ast::Fn::cast)?;
+    let file_id = position.file_id;
+
+    let mut children = Vec::new();
+    if let Some(ensures) = func.ensures_clause() {
+        children.extend(
+            ensures.exprs().map(|it| leaf(ProofObligationKind::Ensures, file_id, it.syntax())),
+        );
+    }
+    if let Some(body) = func.body() {
+        for node in body.syntax().descendants() {
+            let obligation = match_ast! {
+                match node {
+                    ast::AssertExpr(it) => {
+                        Some(leaf(ProofObligationKind::Assert, file_id, it.syntax()))
+                    },
+                    ast::AssertForallExpr(it) => {
+                        Some(leaf(ProofObligationKind::Assert, file_id, it.syntax()))
+                    },
+                    ast::CallExpr(it) => callee(&sema, &it)
+                        .and_then(|callee| call(db, file_id, it.syntax(), callee)),
+                    ast::MethodCallExpr(it) => sema
+                        .resolve_method_call(&it)
+                        .and_then(|callee| call(db, file_id, it.syntax(), callee)),
+                    _ => None,
+                }
+            };
+            children.extend(obligation);
+        }
+    }
+
+    Some(ProofObligation {
+        kind: ProofObligationKind::Function,
+        label: func.name()?.text().to_string(),
+        range: FileRange { file_id, range: func.syntax().text_range() },
+        children,
+    })
+}
+
+fn callee(sema: &Semantics<'_, RootDatabase>, call: &ast::CallExpr) -> Option<hir::Function> {
+    let ast::Expr::PathExpr(path) = call.expr()? else { return None };
+    match sema.resolve_path(&path.path()?)? {
+        hir::PathResolution::Def(hir::ModuleDef::Function(it)) => Some(it),
+        _ => None,
+    }
+}
+
+fn call(
+    db: &RootDatabase,
+    file_id: FileId,
+    node: &SyntaxNode,
+    callee: hir::Function,
+) -> Option<ProofObligation> {
+    let source = callee.source(db)?;
+    let requires: Vec<_> = source
+        .value
+        .requires_clause()?
+        .exprs()
+        .map(|it| ProofObligation {
+            kind: ProofObligationKind::Requires,
+            label: label(it.syntax()),
+            range: source.with_value(it.syntax()).original_file_range_rooted(db),
+            children: Vec::new(),
+        })
+        .collect();
+    if requires.is_empty() {
+        return None;
+    }
+    Some(ProofObligation {
+        kind: ProofObligationKind::Call,
+        label: label(node),
+        range: FileRange { file_id, range: node.text_range() },
+        children: requires,
+    })
+}
+
+fn leaf(kind: ProofObligationKind, file_id: FileId, node: &SyntaxNode) -> ProofObligation {
+    ProofObligation {
+        kind,
+        label: label(node),
+        range: FileRange { file_id, range: node.text_range() },
+        children: Vec::new(),
+    }
+}
+
+/// Multi-line conditions are shown on a single line.
+fn label(node: &SyntaxNode) -> String {
+    node.text().to_string().split_whitespace().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::fixture;
+
+    use super::ProofObligation;
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        fn render(buf: &mut String, depth: usize, obligation: &ProofObligation) {
+            buf.push_str(&format!(
+                "{}{:?} `{}` {:?}\n",
+                "  ".repeat(depth),
+                obligation.kind,
+                obligation.label,
+                obligation.range.range
+            ));
+            for child in &obligation.children {
+                render(buf, depth + 1, child);
+            }
+        }
+
+        let (analysis, position) = fixture::position(ra_fixture);
+        let obligation = analysis.proof_obligations(position).unwrap().unwrap();
+        let mut actual = String::new();
+        render(&mut actual, 0, &obligation);
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn lists_obligations() {
+        check(
+            r#"
+proof fn lemma(x: int)
+    requires
+        x > 0,
+        x < 10,
+{
+}
+proof fn helper() {}
+proof fn f$0(y: int) -> (r: int)
+    ensures
+        r == y,
+{
+    assert(y == y);
+    lemma(y);
+    helper();
+    y
+}
+"#,
+            expect![[r#"
+                Function `f` 92..208
+                  Ensures `r == y` 143..149
+                  Assert `assert(y == y)` 157..171
+                  Call `lemma(y)` 177..185
+                    Requires `x > 0` 44..49
+                    Requires `x < 10` 59..65
+            "#]],
+        );
+    }
+}
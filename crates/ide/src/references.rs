@@ -19,8 +19,9 @@ use ide_db::{
 use itertools::Itertools;
 use nohash_hasher::IntMap;
 use syntax::{
+    algo::non_trivia_sibling,
     ast::{self, HasName},
-    match_ast, AstNode,
+    match_ast, AstNode, Direction, NodeOrToken,
     SyntaxKind::*,
     SyntaxNode, TextRange, TextSize, T,
 };
@@ -279,6 +280,35 @@ fn is_enum_lit_name_ref(
         .unwrap_or(false)
 }
 
+/// Whether the item at `position` is used from `broadcast group`s, `broadcast use`s, `via` clauses
+/// or triggers. Verus erases these before rustc runs its lints, so rustc reports such items as
+/// dead code.
+pub(crate) fn is_referenced_from_ghost_code(
+    sema: &Semantics<'_, RootDatabase>,
+    position: FilePosition,
+) -> bool {
+    let Some(results) = find_all_refs(sema, position, None) else { return false };
+    results.iter().flat_map(|it| it.references.iter()).any(|(&file_id, refs)| {
+        let source_file = sema.parse(file_id);
+        refs.iter().any(|&(range, _)| {
+            let node = match source_file.syntax().covering_element(range) {
+                NodeOrToken::Node(it) => it,
+                NodeOrToken::Token(it) => match it.parent() {
+                    Some(it) => it,
+                    None => return false,
+                },
+            };
+            node.ancestors().any(|it| is_ghost_use_site(&it))
+        })
+    })
+}
+
+fn is_ghost_use_site(node: &SyntaxNode) -> bool {
+    matches!(node.kind(), BROADCAST_GROUP_MEMBER | BROADCAST_USE_LIST | TRIGGER_ATTRIBUTE)
+        || non_trivia_sibling(node.clone().into(), Direction::Prev)
+            .map_or(false, |it| it.kind() == T![via])
+}
+
 fn path_ends_with(path: Option<ast::Path>, name_ref: &ast::NameRef) -> bool {
     path.and_then(|path| path.segment())
         .and_then(|segment| segment.name_ref())
@@ -1191,6 +1221,50 @@ impl Foo {
         );
     }
 
+    #[test]
+    fn test_referenced_from_ghost_code() {
+        let check = |ra_fixture: &str, expected: bool| {
+            let (analysis, pos) = fixture::position(ra_fixture);
+            assert_eq!(analysis.is_referenced_from_ghost_code(pos).unwrap(), expected);
+        };
+        check(
+            r#"
+proof fn lemma$0() {}
+broadcast group group_lemmas { lemma }
+"#,
+            true,
+        );
+        check(
+            r#"
+spec fn f$0(x: int) -> bool { true }
+proof fn p() {
+    assert(forall|x: int| #![trigger f(x)] x == x);
+}
+"#,
+            true,
+        );
+        check(
+            r#"
+proof fn termination$0(n: nat) {}
+spec fn count(n: nat) -> nat
+    decreases n via termination
+{
+    n
+}
+"#,
+            true,
+        );
+        check(
+            r#"
+fn f$0() {}
+fn main() {
+    f();
+}
+"#,
+            false,
+        );
+    }
+
     fn check(ra_fixture: &str, expect: Expect) {
         check_with_scope(ra_fixture, None, expect)
     }
@@ -2187,4 +2261,99 @@ fn test() {
             "#]],
         );
     }
+
+    #[test]
+    fn test_find_all_refs_in_spec_positions() {
+        check(
+            r#"
+spec fn pos$0(x: int) -> bool { x > 0 }
+
+proof fn lemma(x: int)
+    requires
+        pos(x),
+    ensures
+        forall|y: int| #![trigger pos(y)] pos(y) ==> y > 0,
+{
+    reveal(pos);
+}
+"#,
+            expect![[r#"
+                pos Function FileId(0) 0..37 8..11
+
+                FileId(0) 83..86 requires
+                FileId(0) 137..140 trigger
+                FileId(0) 145..148 ensures
+                FileId(0) 176..179 reveal
+            "#]],
+        );
+        check(
+            r#"
+proof fn dbl_recommends$0(x: int) {}
+
+spec fn dbl(x: int) -> int
+    recommends
+        x > 0,
+    via dbl_recommends
+{
+    x + x
+}
+"#,
+            expect![[r#"
+                dbl_recommends Function FileId(0) 0..34 9..23
+
+                FileId(0) 101..115
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_find_all_refs_spec_fn_categories() {
+        check(
+            r#"
+spec fn pos$0(x: int) -> bool { x > 0 }
+
+fn run(n: u64) {
+    let mut i = 0;
+    while i < n
+        invariant
+            pos(i as int),
+    {
+        i += 1;
+    }
+    assert(pos(n as int));
+    pos(1);
+}
+"#,
+            expect![[r#"
+                pos Function FileId(0) 0..37 8..11
+
+                FileId(0) 121..124 invariant
+                FileId(0) 175..178 assert
+                FileId(0) 195..198 exec_call
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_find_all_refs_for_param_in_clauses() {
+        check(
+            r#"
+proof fn lemma(x$0: int, y: int)
+    requires
+        x > 0,
+        y > x,
+    ensures
+        x + y > 0,
+{
+}
+"#,
+            expect![[r#"
+                x ValueParam FileId(0) 15..16 15..16
+
+                FileId(0) 52..53 read
+                FileId(0) 71..72 read
+                FileId(0) 94..95 read
+            "#]],
+        );
+    }
 }
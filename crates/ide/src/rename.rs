@@ -10,12 +10,15 @@ use ide_db::{
     defs::{Definition, NameClass, NameRefClass},
     rename::{bail, format_err, source_edit_from_references, IdentifierKind},
     source_change::SourceChangeBuilder,
+    syntax_helpers::node_ext::is_bound_by_quantifier,
     RootDatabase,
 };
 use itertools::Itertools;
 use stdx::{always, never};
 use syntax::{
-    ast, utils::is_raw_identifier, AstNode, SmolStr, SyntaxKind, SyntaxNode, TextRange, TextSize,
+    ast::{self, HasName},
+    utils::is_raw_identifier,
+    AstNode, SmolStr, SyntaxKind, SyntaxNode, TextRange, TextSize,
 };
 
 use text_edit::TextEdit;
@@ -36,6 +39,10 @@ pub(crate) fn prepare_rename(
     let source_file = sema.parse(position.file_id);
     let syntax = source_file.syntax();
 
+    if let Some((range, ..)) = named_return_at(syntax, position.offset) {
+        return Ok(RangeInfo::new(range, ()));
+    }
+
     let res = find_definitions(&sema, syntax, position)?
         .map(|(frange, kind, def)| {
             // ensure all ranges are valid
@@ -90,6 +97,10 @@ pub(crate) fn rename(
     let source_file = sema.parse(position.file_id);
     let syntax = source_file.syntax();
 
+    if let Some((_, func, name)) = named_return_at(syntax, position.offset) {
+        return rename_named_return(position.file_id, &func, &name, new_name);
+    }
+
     let defs = find_definitions(&sema, syntax, position)?;
     let alias_fallback = alias_fallback(syntax, position, new_name);
 
@@ -307,6 +318,91 @@ fn find_definitions(
     }
 }
 
+/// Finds the named return value (`-> (r: u64)`) of a Verus function at `offset`, either at its
+/// declaration or at a use inside the function's `ensures` clause.
+///
+/// The named return value is not lowered into HIR, so it is renamed syntactically.
+fn named_return_at(
+    syntax: &SyntaxNode,
+    offset: TextSize,
+) -> Option<(TextRange, ast::Fn, ast::Name)> {
+    let token = syntax.token_at_offset(offset).find(|it| it.kind() == SyntaxKind::IDENT)?;
+    let parent = token.parent()?;
+    let func = match ast::NameRef::cast(parent.clone()) {
+        Some(name_ref) => {
+            let ensures = name_ref.syntax().ancestors().find_map(ast::EnsuresClause::cast)?;
+            let func = ensures.syntax().parent().and_then(ast::Fn::cast)?;
+            let name = named_return(&func)?;
+            if !is_named_return_ref(&name, &name_ref) {
+                return None;
+            }
+            func
+        }
+        None => {
+            let ret_type = ast::Name::cast(parent)?.syntax().parent()?.parent()?;
+            ast::RetType::cast(ret_type)?.syntax().parent().and_then(ast::Fn::cast)?
+        }
+    };
+    let name = named_return(&func)?;
+    Some((token.text_range(), func, name))
+}
+
+fn named_return(func: &ast::Fn) -> Option<ast::Name> {
+    match func.ret_type()?.pat()? {
+        ast::Pat::IdentPat(it) => it.name(),
+        _ => None,
+    }
+}
+
+fn is_named_return_ref(name: &ast::Name, name_ref: &ast::NameRef) -> bool {
+    let path =
+        name_ref.syntax().parent().and_then(ast::PathSegment::cast).map(|it| it.parent_path());
+    let is_local_path = path.map_or(false, |path| {
+        path.qualifier().is_none()
+            && path.syntax().parent().map_or(false, |it| ast::PathExpr::can_cast(it.kind()))
+    });
+    is_local_path
+        && name_ref.text() == name.text()
+        && !is_bound_by_quantifier(name_ref.syntax(), &name.text())
+}
+
+fn rename_named_return(
+    file_id: FileId,
+    func: &ast::Fn,
+    name: &ast::Name,
+    new_name: &str,
+) -> RenameResult<SourceChange> {
+    if IdentifierKind::classify(new_name)? != IdentifierKind::Ident {
+        bail!("Invalid name `{}`: not an identifier", new_name);
+    }
+    let params = func.param_list().into_iter().flat_map(|it| it.params());
+    let clashes_with_param = params.filter_map(|it| it.pat()).any(|pat| match pat {
+        ast::Pat::IdentPat(it) => it.name().map_or(false, |it| it.text() == new_name),
+        _ => false,
+    });
+    if clashes_with_param {
+        bail!("Cannot rename to `{new_name}`: the function already has a parameter with that name");
+    }
+
+    let refs = func
+        .ensures_clause()
+        .into_iter()
+        .flat_map(|it| it.syntax().descendants())
+        .filter_map(ast::NameRef::cast)
+        .filter(|it| is_named_return_ref(name, it))
+        .collect::<Vec<_>>();
+    if refs.iter().any(|it| is_bound_by_quantifier(it.syntax(), new_name)) {
+        bail!("Cannot rename to `{new_name}`: a quantifier already binds that name at a usage");
+    }
+
+    let mut edit = TextEdit::builder();
+    edit.replace(name.syntax().text_range(), new_name.to_owned());
+    for name_ref in refs {
+        edit.replace(name_ref.syntax().text_range(), new_name.to_owned());
+    }
+    Ok(SourceChange::from_text_edit(file_id, edit.finish()))
+}
+
 fn rename_to_self(
     sema: &Semantics<'_, RootDatabase>,
     local: hir::Local,
@@ -2799,4 +2895,146 @@ fn main() { let _: Baz; }
 "#,
         );
     }
+
+    #[test]
+    fn rename_param_in_contract_clauses() {
+        check(
+            "len",
+            r#"
+proof fn lemma(n$0: nat)
+    requires
+        n > 0,
+    ensures
+        forall|i: nat| i < n ==> i + 1 <= n,
+    decreases n,
+{
+}
+"#,
+            r#"
+proof fn lemma(len: nat)
+    requires
+        len > 0,
+    ensures
+        forall|i: nat| i < len ==> i + 1 <= len,
+    decreases len,
+{
+}
+"#,
+        );
+        check(
+            "len",
+            r#"
+fn count(n$0: u64) {
+    let mut i = 0;
+    while i < n
+        invariant
+            i <= n,
+    {
+        i = i + 1;
+    }
+}
+"#,
+            r#"
+fn count(len: u64) {
+    let mut i = 0;
+    while i < len
+        invariant
+            i <= len,
+    {
+        i = i + 1;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn rename_param_skips_shadowing_quantifier() {
+        check(
+            "y",
+            r#"
+proof fn lemma(x$0: int)
+    ensures
+        forall|x: int| x == x,
+        x >= 0 || x < 0,
+{
+}
+"#,
+            r#"
+proof fn lemma(y: int)
+    ensures
+        forall|x: int| x == x,
+        y >= 0 || y < 0,
+{
+}
+"#,
+        );
+        check(
+            "y",
+            r#"
+proof fn lemma(x$0: int)
+    ensures
+        forall|y: int| x < y + 1,
+{
+}
+"#,
+            "error: Cannot rename to `y`: a quantifier already binds that name at a usage",
+        );
+    }
+
+    #[test]
+    fn rename_named_return() {
+        check(
+            "res",
+            r#"
+fn double(x: u64) -> (r$0: u64)
+    ensures
+        r == 2 * x,
+        forall|r: int| r == r,
+{
+    x + x
+}
+"#,
+            r#"
+fn double(x: u64) -> (res: u64)
+    ensures
+        res == 2 * x,
+        forall|r: int| r == r,
+{
+    x + x
+}
+"#,
+        );
+        check(
+            "res",
+            r#"
+fn double(x: u64) -> (r: u64)
+    ensures
+        r$0 == 2 * x,
+{
+    x + x
+}
+"#,
+            r#"
+fn double(x: u64) -> (res: u64)
+    ensures
+        res == 2 * x,
+{
+    x + x
+}
+"#,
+        );
+        check(
+            "x",
+            r#"
+fn double(x: u64) -> (r$0: u64)
+    ensures
+        r == 2 * x,
+{
+    x + x
+}
+"#,
+            "error: Cannot rename to `x`: the function already has a parameter with that name",
+        );
+    }
 }
@@ -0,0 +1,244 @@
+use hir::{HasSource, Semantics};
+use ide_db::{
+    base_db::{FileId, FileRange},
+    defs::Definition,
+    FxHashMap, FxHashSet, RootDatabase,
+};
+use itertools::Itertools;
+use syntax::{
+    algo::ancestors_at_offset,
+    ast::{self, HasName},
+    AstNode, SourceFile,
+};
+
+use crate::VerifyTarget;
+
+/// A function to verify again after an edit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reverification {
+    /// What to run Verus on, in the file of `range`.
+    pub target: VerifyTarget,
+    /// The range of the function: results of earlier runs outside of it are still valid.
+    pub range: FileRange,
+}
+
+// Feature: Incremental Verification
+//
+// Compares the functions of a file with the ones it had at the last Verus run, and lists what
+// needs to be verified again: the functions that changed, then the functions relying on the
+// contract of a changed one. A change to a spec function or to a contract propagates to the
+// functions using it, transitively through spec functions and contracts.
+pub(crate) fn reverifications(
+    db: &RootDatabase,
+    file_id: FileId,
+    old_text: &str,
+) -> Vec<Reverification> {
+    let sema = Semantics::new(db);
+    let source_file = sema.parse(file_id);
+    let old_file = SourceFile::parse(old_text, span::Edition::CURRENT).tree();
+    let old_fns: FxHashMap<String, ast::Fn> = old_file
+        .syntax()
+        .descendants()
+        .filter_map(ast::Fn::cast)
+        .filter_map(|it| Some((fn_path(&it)?, it)))
+        .collect();
+
+    let mut changed = Vec::new();
+    // Functions whose contract changed, so that the functions using them have to be checked too.
+    let mut worklist = Vec::new();
+    for func in source_file.syntax().descendants().filter_map(ast::Fn::cast) {
+        let Some(path) = fn_path(&func) else { continue };
+        let (contract_changed, body_changed) = match old_fns.get(&path) {
+            Some(old) => (contract(old) != contract(&func), body(old) != body(&func)),
+            None => (true, true),
+        };
+        if !contract_changed && !body_changed {
+            continue;
+        }
+        let Some(def) = sema.to_def(&func) else { continue };
+        changed.push(def);
+        // The body of a spec function is part of what its users rely on.
+        if contract_changed || is_spec(&func) {
+            worklist.push(def);
+        }
+    }
+
+    let mut seen: FxHashSet<hir::Function> = changed.iter().copied().collect();
+    while let Some(func) = worklist.pop() {
+        for (user_file, references) in Definition::Function(func).usages(&sema).all() {
+            let user_source = sema.parse(user_file);
+            for reference in references {
+                let Some(user) = ancestors_at_offset(user_source.syntax(), reference.range.start())
+                    .find_map(ast::Fn::cast)
+                else {
+                    continue;
+                };
+                let Some(def) = sema.to_def(&user) else { continue };
+                if !seen.insert(def) {
+                    continue;
+                }
+                changed.push(def);
+                let in_body = user
+                    .body()
+                    .map_or(false, |it| it.syntax().text_range().contains_range(reference.range));
+                if !in_body || is_spec(&user) {
+                    worklist.push(def);
+                }
+            }
+        }
+    }
+
+    changed
+        .into_iter()
+        .filter_map(|func| {
+            let source = func.source(db)?;
+            source.value.body()?;
+            // Verus looks functions up by name in the module backing the file.
+            let target = match func.module(db).as_source_file_id(db) {
+                Some(_) => VerifyTarget::Function(func.name(db).display(db).to_string()),
+                None => VerifyTarget::File,
+            };
+            let range = source.syntax().original_file_range_rooted(db);
+            Some(Reverification { target, range })
+        })
+        .collect()
+}
+
+/// The path of `func` within its file, to match it with its previous version.
+fn fn_path(func: &ast::Fn) -> Option<String> {
+    let mut segments = vec![func.name()?.text().to_string()];
+    for ancestor in func.syntax().ancestors().skip(1) {
+        if let Some(module) = ast::Module::cast(ancestor.clone()) {
+            segments.extend(module.name().map(|it| it.text().to_string()));
+        } else if let Some(imp) = ast::Impl::cast(ancestor) {
+            segments.extend(imp.self_ty().map(|it| it.syntax().text().to_string()));
+        }
+    }
+    Some(segments.into_iter().rev().join("::"))
+}
+
+/// Everything but the body of `func`, with whitespace normalized.
+fn contract(func: &ast::Fn) -> String {
+    let body = func.body().map(|it| it.syntax().clone());
+    func.syntax()
+        .children_with_tokens()
+        .filter(|it| it.as_node() != body.as_ref())
+        .map(|it| it.to_string())
+        .join(" ")
+        .split_whitespace()
+        .join(" ")
+}
+
+fn body(func: &ast::Fn) -> String {
+    func.body()
+        .map_or_else(String::new, |it| it.syntax().text().to_string().split_whitespace().join(" "))
+}
+
+fn is_spec(func: &ast::Fn) -> bool {
+    func.fn_mode().map_or(false, |mode| mode.spec_token().is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::fixture;
+
+    fn check(old_text: &str, ra_fixture: &str, expect: Expect) {
+        let (analysis, file_id) = fixture::file(ra_fixture);
+        let actual = analysis
+            .reverifications(file_id, old_text)
+            .unwrap()
+            .into_iter()
+            .map(|it| format!("{:?} {:?}\n", it.target, it.range.range))
+            .collect::<String>();
+        expect.assert_eq(&actual);
+    }
+
+    const OLD: &str = r#"
+spec fn double(x: int) -> int { x + x }
+proof fn lemma_double(x: int)
+    ensures double(x) == 2 * x,
+{
+}
+proof fn uses_lemma(x: int) {
+    lemma_double(x);
+}
+proof fn unrelated() {
+    assert(true);
+}
+"#;
+
+    #[test]
+    fn changed_body_of_proof_fn() {
+        check(
+            OLD,
+            r#"
+spec fn double(x: int) -> int { x + x }
+proof fn lemma_double(x: int)
+    ensures double(x) == 2 * x,
+{
+    assert(true);
+}
+proof fn uses_lemma(x: int) {
+    lemma_double(x);
+}
+proof fn unrelated() {
+    assert(true);
+}
+"#,
+            expect![[r#"
+                Function("lemma_double") 40..123
+            "#]],
+        );
+    }
+
+    #[test]
+    fn changed_contract_reaches_callers() {
+        check(
+            OLD,
+            r#"
+spec fn double(x: int) -> int { x + x }
+proof fn lemma_double(x: int)
+    ensures double(x) == x + x,
+{
+}
+proof fn uses_lemma(x: int) {
+    lemma_double(x);
+}
+proof fn unrelated() {
+    assert(true);
+}
+"#,
+            expect![[r#"
+                Function("lemma_double") 40..105
+                Function("uses_lemma") 106..158
+            "#]],
+        );
+    }
+
+    #[test]
+    fn changed_spec_fn_reaches_contracts() {
+        check(
+            OLD,
+            r#"
+spec fn double(x: int) -> int { 2 * x }
+proof fn lemma_double(x: int)
+    ensures double(x) == 2 * x,
+{
+}
+proof fn uses_lemma(x: int) {
+    lemma_double(x);
+}
+proof fn unrelated() {
+    assert(true);
+}
+"#,
+            expect![[r#"
+                Function("double") 0..39
+                Function("lemma_double") 40..105
+                Function("uses_lemma") 106..158
+            "#]],
+        );
+    }
+}
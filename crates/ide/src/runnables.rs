@@ -54,6 +54,18 @@ pub enum RunnableKind {
     Bench { test_id: TestId },
     DocTest { test_id: TestId },
     Bin,
+    VerusVerify { target: VerifyTarget },
+}
+
+/// What a [`RunnableKind::VerusVerify`] runnable asks Verus to verify.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum VerifyTarget {
+    /// The module backing the file.
+    File,
+    /// The inline module with the given path, e.g. `foo::bar`.
+    Module(String),
+    /// The function with the given name, in the module backing the file.
+    Function(String),
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -63,6 +75,7 @@ enum RunnableDiscKind {
     DocTest,
     Bench,
     Bin,
+    VerusVerify,
 }
 
 impl RunnableKind {
@@ -73,6 +86,7 @@ impl RunnableKind {
             RunnableKind::DocTest { .. } => RunnableDiscKind::DocTest,
             RunnableKind::Bench { .. } => RunnableDiscKind::Bench,
             RunnableKind::Bin => RunnableDiscKind::Bin,
+            RunnableKind::VerusVerify { .. } => RunnableDiscKind::VerusVerify,
         }
     }
 }
@@ -88,6 +102,12 @@ impl Runnable {
             RunnableKind::Bin => {
                 target.map_or_else(|| "run binary".to_owned(), |t| format!("run {t}"))
             }
+            RunnableKind::VerusVerify { target } => match target {
+                VerifyTarget::File => format!("verify {}", self.nav.name),
+                VerifyTarget::Module(path) | VerifyTarget::Function(path) => {
+                    format!("verify {path}")
+                }
+            },
         }
     }
 
@@ -104,6 +124,7 @@ impl Runnable {
             RunnableKind::Test { .. } => "Test",
             RunnableKind::DocTest { .. } => "Doctest",
             RunnableKind::Bench { .. } => "Bench",
+            RunnableKind::VerusVerify { .. } => "Verification",
             RunnableKind::Bin => return s,
         };
         s.push_str(suffix);
@@ -123,6 +144,8 @@ impl Runnable {
 // | VS Code | **rust-analyzer: Run**
 // |===
 // image::https://user-images.githubusercontent.com/48062697/113065583-055aae80-91b1-11eb-958f-d67efcaf6a2f.gif[]
+//
+// Proof functions and modules containing Verus items can also be verified this way.
 pub(crate) fn runnables(db: &RootDatabase, file_id: FileId) -> Vec<Runnable> {
     let sema = Semantics::new(db);
 
@@ -169,6 +192,7 @@ pub(crate) fn runnables(db: &RootDatabase, file_id: FileId) -> Vec<Runnable> {
                 .filter(|it| it.nav.file_id == file_id),
             Some(def),
         );
+        add_opt(runnable_verus(&sema, def).filter(|it| it.nav.file_id == file_id), Some(def));
         if let Definition::SelfType(impl_) = def {
             impl_.items(db).into_iter().for_each(|assoc| {
                 let runnable = match assoc {
@@ -186,6 +210,9 @@ pub(crate) fn runnables(db: &RootDatabase, file_id: FileId) -> Vec<Runnable> {
     sema.file_to_module_defs(file_id)
         .map(|it| runnable_mod_outline_definition(&sema, it))
         .for_each(|it| add_opt(it, None));
+    sema.file_to_module_defs(file_id)
+        .map(|it| runnable_verus_file(&sema, it))
+        .for_each(|it| add_opt(it, None));
 
     res.extend(in_macro_expansion.into_iter().flat_map(|(_, runnables)| {
         let use_name_in_title = runnables.len() != 1;
@@ -403,6 +430,78 @@ pub(crate) fn runnable_impl(
     Some(Runnable { use_name_in_title: false, nav, kind: RunnableKind::DocTest { test_id }, cfg })
 }
 
+/// Creates a verification runnable for proof functions and inline modules with Verus items.
+fn runnable_verus(sema: &Semantics<'_, RootDatabase>, def: Definition) -> Option<Runnable> {
+    let (nav, target, cfg) = match def {
+        Definition::Function(it) => {
+            // Functions in inline modules are verified with their module, since Verus looks
+            // functions up by name in the module backing the file.
+            let in_file_module = it.module(sema.db).as_source_file_id(sema.db).is_some();
+            if !in_file_module || !has_proof_obligations(&it.source(sema.db)?.value) {
+                return None;
+            }
+            let nav = NavigationTarget::from_named(
+                sema.db,
+                it.source(sema.db)?.as_ref().map(|it| it as &dyn ast::HasName),
+                SymbolKind::Function,
+            )
+            .call_site();
+            let name = it.name(sema.db).display(sema.db).to_string();
+            (nav, VerifyTarget::Function(name), it.attrs(sema.db).cfg())
+        }
+        Definition::Module(it) => {
+            if !it.is_inline(sema.db) || !has_verus_items(sema.db, it) {
+                return None;
+            }
+            let path = it
+                .path_to_root(sema.db)
+                .into_iter()
+                .rev()
+                .filter_map(|it| it.name(sema.db))
+                .map(|it| it.display(sema.db).to_string())
+                .join("::");
+            let nav = NavigationTarget::from_module_to_decl(sema.db, it).call_site();
+            (nav, VerifyTarget::Module(path), it.attrs(sema.db).cfg())
+        }
+        _ => return None,
+    };
+    Some(Runnable {
+        use_name_in_title: false,
+        nav,
+        kind: RunnableKind::VerusVerify { target },
+        cfg,
+    })
+}
+
+/// Creates a verification runnable for the module backing a file, at the top of the file.
+fn runnable_verus_file(sema: &Semantics<'_, RootDatabase>, def: hir::Module) -> Option<Runnable> {
+    def.as_source_file_id(sema.db)?;
+    if !has_verus_items(sema.db, def) {
+        return None;
+    }
+    Some(Runnable {
+        use_name_in_title: false,
+        nav: def.to_nav(sema.db).call_site(),
+        kind: RunnableKind::VerusVerify { target: VerifyTarget::File },
+        cfg: def.attrs(sema.db).cfg(),
+    })
+}
+
+/// Proof functions, and functions with a contract, give Verus something to prove.
+fn has_proof_obligations(func: &ast::Fn) -> bool {
+    let is_proof = func.fn_mode().map_or(false, |mode| mode.proof_token().is_some());
+    is_proof || func.requires_clause().is_some() || func.ensures_clause().is_some()
+}
+
+fn has_verus_items(db: &RootDatabase, module: hir::Module) -> bool {
+    module.declarations(db).into_iter().any(|def| match def {
+        hir::ModuleDef::Function(it) => it.source(db).map_or(false, |src| {
+            src.value.fn_mode().is_some() || has_proof_obligations(&src.value)
+        }),
+        _ => false,
+    })
+}
+
 fn has_cfg_test(attrs: AttrsWithOwner) -> bool {
     attrs.cfgs().any(|cfg| matches!(cfg, CfgExpr::Atom(CfgAtom::Flag(s)) if s == "test"))
 }
@@ -1554,4 +1653,46 @@ fn exp_main() {}
             "#]],
         )
     }
+
+    #[test]
+    fn verus_verify_runnables() {
+        check(
+            r#"
+//- /lib.rs
+$0
+proof fn lemma(x: int)
+    requires
+        x > 0,
+{
+}
+
+spec fn double(x: int) -> int { x * 2 }
+
+fn exec(x: u64) -> (r: u64)
+    ensures
+        r == x,
+{
+    x
+}
+
+fn plain() {}
+
+mod proofs {
+    proof fn inner() {}
+}
+
+mod plain_mod {
+    fn f() {}
+}
+"#,
+            expect![[r#"
+                [
+                    "(VerusVerify, NavigationTarget { file_id: FileId(0), full_range: 0..252, name: \"\", kind: Module })",
+                    "(VerusVerify, NavigationTarget { file_id: FileId(0), full_range: 1..55, focus_range: 10..15, name: \"lemma\", kind: Function })",
+                    "(VerusVerify, NavigationTarget { file_id: FileId(0), full_range: 98..163, focus_range: 101..105, name: \"exec\", kind: Function })",
+                    "(VerusVerify, NavigationTarget { file_id: FileId(0), full_range: 180..218, focus_range: 184..190, name: \"proofs\", kind: Module, description: \"mod proofs\" })",
+                ]
+            "#]],
+        );
+    }
 }
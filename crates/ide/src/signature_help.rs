@@ -5,19 +5,20 @@ use std::collections::BTreeSet;
 
 use either::Either;
 use hir::{
-    AssocItem, DescendPreference, GenericParam, HirDisplay, ModuleDef, PathResolution, Semantics,
-    Trait,
+    AssocItem, DescendPreference, GenericParam, HasSource, HirDisplay, ModuleDef, PathResolution,
+    Semantics, Trait,
 };
 use ide_db::{
     active_parameter::{callable_for_node, generic_def_for_node},
     base_db::FilePosition,
     documentation::{Documentation, HasDocs},
+    syntax_helpers::insert_whitespace_into_node::insert_ws_into,
     FxIndexMap,
 };
 use stdx::format_to;
 use syntax::{
     algo,
-    ast::{self, AstChildren, HasArgList},
+    ast::{self, AstChildren, HasArgList, HasName},
     match_ast, AstNode, Direction, NodeOrToken, SyntaxElementChildren, SyntaxNode, SyntaxToken,
     TextRange, TextSize, T,
 };
@@ -33,6 +34,9 @@ pub struct SignatureHelp {
     pub doc: Option<Documentation>,
     pub signature: String,
     pub active_parameter: Option<usize>,
+    /// The `requires` conditions of the called function, paired with whether the condition
+    /// mentions the active parameter.
+    pub requires: Vec<(String, bool)>,
     parameters: Vec<TextRange>,
 }
 
@@ -174,8 +178,13 @@ fn signature_help_for_call(
 
     let (callable, active_parameter) = callable_for_node(sema, &calling_node, &token)?;
 
-    let mut res =
-        SignatureHelp { doc: None, signature: String::new(), parameters: vec![], active_parameter };
+    let mut res = SignatureHelp {
+        doc: None,
+        signature: String::new(),
+        parameters: vec![],
+        active_parameter,
+        requires: vec![],
+    };
 
     let db = sema.db;
     let mut fn_params = None;
@@ -218,6 +227,7 @@ fn signature_help_for_call(
         },
     }
 
+    let mut active_param_name = None;
     res.signature.push('(');
     {
         if let Some((self_param, _)) = callable.receiver_param(db) {
@@ -229,7 +239,15 @@ fn signature_help_for_call(
             if let Some(param) = sema.source(p.clone()) {
                 match param.value {
                     Either::Right(param) => match param.pat() {
-                        Some(pat) => format_to!(buf, "{}: ", pat),
+                        Some(pat) => {
+                            if res.active_parameter == Some(idx) {
+                                active_param_name = match &pat {
+                                    ast::Pat::IdentPat(it) => it.name().map(|it| it.to_string()),
+                                    _ => None,
+                                };
+                            }
+                            format_to!(buf, "{}: ", pat)
+                        }
                         None => format_to!(buf, "?: "),
                     },
                     Either::Left(_) => format_to!(buf, "self: "),
@@ -264,9 +282,38 @@ fn signature_help_for_call(
         | hir::CallableKind::FnImpl(_) => render(callable.return_type()),
         hir::CallableKind::TupleStruct(_) | hir::CallableKind::TupleEnumVariant(_) => {}
     }
+
+    if let hir::CallableKind::Function(func) = callable.kind() {
+        res.requires = fn_requires(db, func, active_param_name.as_deref());
+    }
     Some(res)
 }
 
+/// Collects the `requires` conditions of `func`, flagging those that mention `active_param`.
+fn fn_requires(
+    db: &RootDatabase,
+    func: hir::Function,
+    active_param: Option<&str>,
+) -> Vec<(String, bool)> {
+    let Some(source) = func.source(db) else { return Vec::new() };
+    let Some(requires) = source.value.requires_clause() else { return Vec::new() };
+    let is_macro = source.file_id.is_macro();
+    requires
+        .exprs()
+        .map(|expr| {
+            let mentions_active = active_param.map_or(false, |name| {
+                expr.syntax()
+                    .descendants()
+                    .filter_map(ast::NameRef::cast)
+                    .any(|it| it.text() == name)
+            });
+            let node = expr.syntax().clone();
+            let node = if is_macro { insert_ws_into(node) } else { node };
+            (node.to_string(), mentions_active)
+        })
+        .collect()
+}
+
 fn signature_help_for_generics(
     sema: &Semantics<'_, RootDatabase>,
     arg_list: ast::GenericArgList,
@@ -279,6 +326,7 @@ fn signature_help_for_generics(
         signature: String::new(),
         parameters: vec![],
         active_parameter: None,
+        requires: vec![],
     };
 
     let db = sema.db;
@@ -436,6 +484,7 @@ fn signature_help_for_tuple_struct_pat(
         signature: String::new(),
         parameters: vec![],
         active_parameter: None,
+        requires: vec![],
     };
     let db = sema.db;
 
@@ -494,6 +543,7 @@ fn signature_help_for_tuple_pat(
             signature: String::from('('),
             parameters: vec![],
             active_parameter: None,
+            requires: vec![],
         },
         pat.syntax(),
         token,
@@ -522,6 +572,7 @@ fn signature_help_for_tuple_expr(
         signature: String::from('('),
         parameters: vec![],
         active_parameter,
+        requires: vec![],
     };
     let expr = sema.type_of_expr(&expr.into())?;
     let fields = expr.original.tuple_fields(db);
@@ -553,6 +604,7 @@ fn signature_help_for_record_(
         signature: String::new(),
         parameters: vec![],
         active_parameter: Some(active_parameter),
+        requires: vec![],
     };
 
     let fields;
@@ -712,6 +764,13 @@ mod tests {
                 if !sig_help.parameter_ranges().is_empty() {
                     format_to!(rendered, "\n");
                 }
+                if !sig_help.requires.is_empty() {
+                    format_to!(rendered, "------\nrequires\n");
+                    for (cond, mentions_active) in &sig_help.requires {
+                        let marker = if *mentions_active { '^' } else { ' ' };
+                        format_to!(rendered, "  {marker} {cond}\n");
+                    }
+                }
                 rendered
             }
             None => String::new(),
@@ -2367,4 +2426,31 @@ fn main() {
             "#]],
         );
     }
+
+    #[test]
+    fn shows_requires_of_callee() {
+        check(
+            r#"
+proof fn lemma_div(x: u64, y: u64)
+    requires
+        y > 0,
+        x >= 10,
+        x < y,
+{
+}
+fn main() {
+    lemma_div(20, $0);
+}
+"#,
+            expect![[r#"
+                fn lemma_div(x: u64, y: u64)
+                             ------  ^^^^^^
+                ------
+                requires
+                  ^ y > 0
+                    x >= 10
+                  ^ x < y
+            "#]],
+        );
+    }
 }
@@ -7,17 +7,20 @@ use ide_db::{
     defs::Definition,
     documentation::Documentation,
     famous_defs::FamousDefs,
-    helpers::get_definition,
+    helpers::{get_definition, resolve_broadcast_group},
     FxHashMap, FxHashSet, RootDatabase,
 };
-use syntax::{AstNode, SyntaxKind::*, SyntaxNode, TextRange, T};
+use syntax::{algo::find_node_at_range, ast, AstNode, SyntaxKind::*, SyntaxNode, TextRange, T};
 
 use crate::inlay_hints::InlayFieldsToResolve;
 use crate::navigation_target::UpmappingResult;
 use crate::{
     hover::hover_for_definition,
     inlay_hints::AdjustmentHintsMode,
-    moniker::{def_to_kind, def_to_moniker, MonikerResult, SymbolInformationKind},
+    moniker::{
+        broadcast_group_to_moniker, def_to_kind, def_to_moniker, MonikerResult,
+        SymbolInformationKind,
+    },
     parent_module::crates_for,
     Analysis, Fold, HoverConfig, HoverResult, InlayHint, InlayHintsConfig, TryToNav,
 };
@@ -32,12 +35,16 @@ pub struct StaticIndex<'a> {
     analysis: &'a Analysis,
     db: &'a RootDatabase,
     def_map: FxHashMap<Definition, TokenId>,
+    /// Verus `broadcast group`s, by the range of their name.
+    group_map: FxHashMap<FileRange, TokenId>,
 }
 
 #[derive(Debug)]
 pub struct ReferenceData {
     pub range: FileRange,
     pub is_definition: bool,
+    /// Whether the reference is in a Verus contract clause, like `requires` or `ensures`.
+    pub is_in_contract: bool,
 }
 
 #[derive(Debug)]
@@ -106,6 +113,44 @@ fn all_modules(db: &dyn HirDatabase) -> Vec<Module> {
     modules
 }
 
+/// The `broadcast group` named by the token of `node`, in its declaration or in a `broadcast use`
+/// list or group, along with its file.
+fn broadcast_group_at(
+    sema: &Semantics<'_, RootDatabase>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<(FileId, ast::BroadcastGroup)> {
+    if ast::BroadcastGroupIdentifier::can_cast(node.kind()) {
+        return Some((file_id, node.parent().and_then(ast::BroadcastGroup::cast)?));
+    }
+    let path = node.ancestors().find_map(ast::Path::cast)?;
+    if !path
+        .syntax()
+        .ancestors()
+        .any(|it| matches!(it.kind(), BROADCAST_USE_LIST | BROADCAST_GROUP_MEMBER))
+    {
+        return None;
+    }
+    let group = resolve_broadcast_group(sema, &path)?;
+    let group_file = group.file_id.file_id()?;
+    // Look the group up again in the tree known to `sema`.
+    let source_file = sema.parse(group_file);
+    let group = find_node_at_range(source_file.syntax(), group.value.syntax().text_range())?;
+    Some((group_file, group))
+}
+
+fn is_contract_clause(node: &SyntaxNode) -> bool {
+    matches!(
+        node.kind(),
+        REQUIRES_CLAUSE
+            | ENSURES_CLAUSE
+            | RECOMMENDS_CLAUSE
+            | DECREASES_CLAUSE
+            | INVARIANT_CLAUSE
+            | INVARIANT_EXCEPT_BREAK_CLAUSE
+    )
+}
+
 fn documentation_for_definition(
     sema: &Semantics<'_, RootDatabase>,
     def: Definition,
@@ -148,6 +193,7 @@ impl StaticIndex<'_> {
                     closing_brace_hints_min_lines: Some(25),
                     fields_to_resolve: InlayFieldsToResolve::empty(),
                     range_exclusive_hints: false,
+                    ghost_binding_hints: false,
                 },
                 file_id,
                 None,
@@ -182,7 +228,24 @@ impl StaticIndex<'_> {
             let node = token.parent().unwrap();
             let def = match get_definition(&sema, token.clone()) {
                 Some(it) => it,
-                None => continue,
+                None => {
+                    // Broadcast groups aren't definitions, their names are looked up separately.
+                    let Some((group_file, group)) = broadcast_group_at(&sema, file_id, &node)
+                    else {
+                        continue;
+                    };
+                    let Some(id) = self.add_broadcast_group(&sema, group_file, &group) else {
+                        continue;
+                    };
+                    let token = self.tokens.get_mut(id).unwrap();
+                    token.references.push(ReferenceData {
+                        range: FileRange { range, file_id },
+                        is_definition: token.definition == Some(FileRange { range, file_id }),
+                        is_in_contract: false,
+                    });
+                    result.tokens.push((range, id));
+                    continue;
+                }
             };
             let id = if let Some(it) = self.def_map.get(&def) {
                 *it
@@ -219,12 +282,43 @@ impl StaticIndex<'_> {
                     Some(it) => it.file_id == file_id && it.focus_or_full_range() == range,
                     None => false,
                 },
+                is_in_contract: node.ancestors().any(|it| is_contract_clause(&it)),
             });
             result.tokens.push((range, id));
         }
         self.files.push(result);
     }
 
+    fn add_broadcast_group(
+        &mut self,
+        sema: &Semantics<'_, RootDatabase>,
+        file_id: FileId,
+        group: &ast::BroadcastGroup,
+    ) -> Option<TokenId> {
+        let name = group.broadcast_group_identifier()?.ident_token()?;
+        let definition = FileRange { file_id, range: name.text_range() };
+        if let Some(it) = self.group_map.get(&definition) {
+            return Some(*it);
+        }
+        let module = sema.scope(group.syntax())?.module();
+        let current_crate = crates_for(self.db, file_id).pop().map(Into::into);
+        let id = self.tokens.insert(TokenStaticData {
+            documentation: None,
+            hover: None,
+            definition: Some(definition),
+            references: vec![],
+            moniker: current_crate
+                .and_then(|cc| broadcast_group_to_moniker(self.db, module, name.text(), cc)),
+            display_name: Some(name.text().to_owned()),
+            enclosing_moniker: current_crate
+                .and_then(|cc| def_to_moniker(self.db, module.into(), cc)),
+            signature: Some(format!("broadcast group {}", name.text())),
+            kind: SymbolInformationKind::Namespace,
+        });
+        self.group_map.insert(definition, id);
+        Some(id)
+    }
+
     pub fn compute(analysis: &Analysis) -> StaticIndex<'_> {
         let db = &*analysis.db;
         let work = all_modules(db).into_iter().filter(|module| {
@@ -239,6 +333,7 @@ impl StaticIndex<'_> {
             analysis,
             db,
             def_map: Default::default(),
+            group_map: Default::default(),
         };
         let mut visited_files = FxHashSet::default();
         for module in work {
@@ -257,6 +352,7 @@ impl StaticIndex<'_> {
 #[cfg(test)]
 mod tests {
     use crate::{fixture, StaticIndex};
+    use expect_test::expect;
     use ide_db::{base_db::FileRange, FxHashSet};
     use syntax::TextSize;
 
@@ -358,4 +454,62 @@ struct Hello(i32);
 "#,
         );
     }
+
+    #[test]
+    fn broadcast_groups() {
+        check_all_ranges(
+            r#"
+proof fn lemma_a() {}
+       //^^^^^^^
+broadcast group group_a {
+              //^^^^^^^
+    lemma_a,
+  //^^^^^^^
+}
+broadcast use group_a;
+            //^^^^^^^
+"#,
+        );
+        check_definitions(
+            r#"
+proof fn lemma_a() {}
+       //^^^^^^^
+broadcast group group_a {
+              //^^^^^^^
+    lemma_a,
+}
+broadcast use group_a;
+"#,
+        );
+    }
+
+    #[test]
+    fn contract_references() {
+        let (analysis, _) = fixture::file(
+            r#"
+spec fn double(x: nat) -> nat { x + x }
+proof fn lemma_double(x: nat)
+    ensures double(x) == x + x,
+{
+    let y = double(x);
+}
+"#,
+        );
+        let s = StaticIndex::compute(&analysis);
+        let (_, token) =
+            s.tokens.iter().find(|(_, it)| it.display_name.as_deref() == Some("double")).unwrap();
+        let actual: Vec<_> = token
+            .references
+            .iter()
+            .map(|it| format!("{:?} {} {}", it.range.range, it.is_definition, it.is_in_contract))
+            .collect();
+        expect![[r#"
+            [
+                "8..14 true false",
+                "82..88 false true",
+                "116..122 false false",
+            ]
+        "#]]
+        .assert_debug_eq(&actual);
+    }
 }
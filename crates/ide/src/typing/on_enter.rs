@@ -6,9 +6,9 @@ use ide_db::RootDatabase;
 use syntax::{
     algo::find_node_at_offset,
     ast::{self, edit::IndentLevel, AstToken},
-    AstNode, SmolStr, SourceFile,
+    AstNode, Direction, SmolStr, SourceFile,
     SyntaxKind::*,
-    SyntaxNode, SyntaxToken, TextRange, TextSize, TokenAtOffset,
+    SyntaxNode, SyntaxToken, TextRange, TextSize, TokenAtOffset, T,
 };
 
 use text_edit::TextEdit;
@@ -21,6 +21,8 @@ use text_edit::TextEdit;
 // - kbd:[Enter] in the middle or after a trailing space in `//` inserts `//`
 // - kbd:[Enter] inside `//!` doc comments automatically inserts `//!`
 // - kbd:[Enter] after `{` indents contents and closing `}` of single-line block
+// - kbd:[Enter] in a `requires`/`ensures`/... clause list continues the list, adding the `,`
+// - kbd:[Enter] at the end of a `&&&`/`|||` bullet line starts the next bullet
 //
 // This action needs to be assigned to shortcut explicitly.
 //
@@ -60,6 +62,13 @@ pub(crate) fn on_enter(db: &RootDatabase, position: FilePosition) -> Option<Text
         return on_enter_in_comment(&comment, &file, position.offset);
     }
 
+    if let Some(edit) = on_enter_after_bullet(&file, &token, position.offset) {
+        return Some(edit);
+    }
+    if let Some(edit) = on_enter_in_clause(&token, position.offset) {
+        return Some(edit);
+    }
+
     if token.kind() == L_CURLY {
         // Typing enter after the `{` of a block expression, where the `}` is on the same line
         if let Some(edit) = find_node_at_offset(file.syntax(), position.offset - TextSize::of('{'))
@@ -120,6 +129,90 @@ fn on_enter_in_comment(
     Some(edit)
 }
 
+fn on_enter_in_clause(token: &SyntaxToken, offset: TextSize) -> Option<TextEdit> {
+    if !at_end_of_line(token, offset) {
+        return None;
+    }
+    let is_clause = |node: &SyntaxNode| {
+        matches!(
+            node.kind(),
+            REQUIRES_CLAUSE
+                | RECOMMENDS_CLAUSE
+                | ENSURES_CLAUSE
+                | DECREASES_CLAUSE
+                | INVARIANT_CLAUSE
+                | INVARIANT_EXCEPT_BREAK_CLAUSE
+        )
+    };
+
+    let parent = token.parent()?;
+    if is_clause(&parent) && token.kind().is_keyword() {
+        // `requires$0`: the conditions go one level deeper than the keyword.
+        let indent = IndentLevel::from_token(token) + 1;
+        return Some(TextEdit::insert(offset, format!("\n{indent}$0")));
+    }
+    if is_clause(&parent) && token.kind() == T![,] {
+        let prev = token
+            .siblings_with_tokens(Direction::Prev)
+            .skip(1)
+            .find(|it| !it.kind().is_trivia())?
+            .into_node()?;
+        let indent = condition_indent(&parent, &prev);
+        return Some(TextEdit::insert(offset, format!("\n{indent}$0")));
+    }
+
+    // `x > 0$0` as the last condition of the line: add the missing comma.
+    let expr =
+        token.parent_ancestors().take_while(|it| it.text_range().end() == offset).find(|it| {
+            ast::Expr::can_cast(it.kind()) && it.parent().map_or(false, |it| is_clause(&it))
+        })?;
+    let next = expr.siblings_with_tokens(Direction::Next).skip(1).find(|it| !it.kind().is_trivia());
+    if next.map_or(false, |it| it.kind() == T![,]) {
+        return None;
+    }
+    let indent = condition_indent(&expr.parent()?, &expr);
+    Some(TextEdit::insert(offset, format!(",\n{indent}$0")))
+}
+
+/// Conditions are indented past their clause keyword, even if the first one shares its line.
+fn condition_indent(clause: &SyntaxNode, condition: &SyntaxNode) -> IndentLevel {
+    let min = IndentLevel::from_node(clause) + 1;
+    let indent = IndentLevel::from_node(condition);
+    if indent.0 < min.0 {
+        min
+    } else {
+        indent
+    }
+}
+
+fn on_enter_after_bullet(
+    file: &SourceFile,
+    token: &SyntaxToken,
+    offset: TextSize,
+) -> Option<TextEdit> {
+    if !at_end_of_line(token, offset) {
+        return None;
+    }
+    // Find the first token of the line.
+    let mut first = token.clone();
+    while let Some(prev) = first.prev_token() {
+        if prev.kind() == WHITESPACE && prev.text().contains('\n') {
+            break;
+        }
+        first = prev;
+    }
+    if !matches!(first.kind(), T![&&&] | T![|||]) {
+        return None;
+    }
+    let indent = node_indent(file, &first)?;
+    Some(TextEdit::insert(offset, format!("\n{indent}{} $0", first.text())))
+}
+
+fn at_end_of_line(token: &SyntaxToken, offset: TextSize) -> bool {
+    token.text_range().end() == offset
+        && token.next_token().map_or(true, |it| it.kind() == WHITESPACE && it.text().contains('\n'))
+}
+
 fn on_enter_in_block(block: ast::BlockExpr, position: FilePosition) -> Option<TextEdit> {
     let contents = block_contents(&block)?;
 
@@ -217,6 +310,89 @@ mod tests {
         assert!(apply_on_enter(ra_fixture_text).is_none())
     }
 
+    #[test]
+    fn continues_clause_list() {
+        do_check(
+            r"
+fn foo(x: u32)
+    requires$0
+{
+}
+",
+            r"
+fn foo(x: u32)
+    requires
+        $0
+{
+}
+",
+        );
+        do_check(
+            r"
+fn foo(x: u32)
+    requires
+        x > 0,$0
+{
+}
+",
+            r"
+fn foo(x: u32)
+    requires
+        x > 0,
+        $0
+{
+}
+",
+        );
+        do_check(
+            r"
+fn foo(x: u32) -> (r: u32)
+    ensures r > x$0
+{
+}
+",
+            r"
+fn foo(x: u32) -> (r: u32)
+    ensures r > x,
+        $0
+{
+}
+",
+        );
+    }
+
+    #[test]
+    fn does_not_continue_clause_mid_line() {
+        do_check_noop(
+            r"
+fn foo(x: u32)
+    requires
+        x >$0 0,
+{
+}
+",
+        );
+    }
+
+    #[test]
+    fn continues_bullet() {
+        do_check(
+            r"
+spec fn foo(x: int) -> bool {
+    &&& x > 0
+    &&& x < 10$0
+}
+",
+            r"
+spec fn foo(x: int) -> bool {
+    &&& x > 0
+    &&& x < 10
+    &&& $0
+}
+",
+        );
+    }
+
     #[test]
     fn continues_doc_comment() {
         do_check(
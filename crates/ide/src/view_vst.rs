@@ -0,0 +1,58 @@
+use hir::Semantics;
+use ide_db::base_db::FilePosition;
+use ide_db::RootDatabase;
+use syntax::{
+    algo::ancestors_at_offset,
+    ast::{self, vst},
+    AstNode,
+};
+
+// Feature: View VST
+//
+// Displays the VST of the item at the cursor, both pretty-printed and as a tree, which is what
+// proof actions operate on. If the item cannot be converted, shows why.
+//
+// |===
+// | Editor  | Action Name
+//
+// | VS Code | **verus-analyzer: View VST**
+// |===
+pub(crate) fn view_vst(db: &RootDatabase, position: FilePosition) -> String {
+    let sema = Semantics::new(db);
+    let source_file = sema.parse(position.file_id);
+
+    let item = ancestors_at_offset(source_file.syntax(), position.offset)
+        .filter(|it| !ast::MacroCall::can_cast(it.kind()))
+        .find_map(ast::Item::cast);
+    match item.map(vst::Item::try_from) {
+        Some(Ok(item)) => format!("{item}\n\n{item:#?}"),
+        Some(Err(err)) => format!("Failed to convert the item to VST: {err}"),
+        None => "Not inside an item".to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::fixture;
+
+    #[test]
+    fn view_vst_of_item_at_cursor() {
+        let (analysis, position) = fixture::position(
+            r#"
+proof fn lemma$0(x: int)
+    ensures
+        x + 0 == x,
+{
+}
+"#,
+        );
+        let vst = analysis.view_vst(position).unwrap();
+        assert!(vst.contains("lemma"), "{vst}");
+        assert!(vst.contains("Fn {"), "{vst}");
+
+        let (analysis, position) = fixture::position("$0");
+        expect!["Not inside an item"].assert_eq(&analysis.view_vst(position).unwrap());
+    }
+}
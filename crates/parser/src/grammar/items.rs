@@ -49,17 +49,21 @@ pub(super) fn item_or_macro(p: &mut Parser<'_>, stop_on_r_curly: bool) {
     // "verus!{" and the closing "}" will be thrown away by the parser
     // this is to avoid additional hassel to make this new item to work with the rest of many rust-analyzer features
     if p.at_contextual_kw(T![verus]) && p.nth_at(1, T![!]) && p.nth_at(2, T!['{']) {
+        let span = tracing::info_span!("parse_verus_item", items = tracing::field::Empty).entered();
         let m = p.start();
         p.eat_contextual_kw(T![verus]);
         p.bump(T![!]);
         p.bump(T!['{']);
         m.abandon(p);
+        let mut items = 0;
         while !p.at(EOF) && !p.at(T!['}']) {
             if p.at(T!['}']) {
                 break;
             }
             item_or_macro(p, true);
+            items += 1;
         }
+        span.record("items", items);
         let m = p.start();
         p.expect(T!['}']);
         m.abandon(p);
@@ -289,6 +293,10 @@ fn opt_item_without_modifiers(p: &mut Parser<'_>, m: Marker) -> Result<(), Marke
         verus::global_clause(p, m);
         return Ok(());
     }
+    if p.at_contextual_kw(T![assume_specification]) && matches!(la, T![<] | T!['[']) { // verus
+        verus::assume_specification(p, m);
+        return Ok(());
+    }
     match p.current() {
         T![extern] if la == T![crate] => extern_crate(p, m),
         T![use] => use_item::use_(p, m),
@@ -482,7 +490,12 @@ fn fn_(p: &mut Parser<'_>, m: Marker) {
     // test function_type_params
     // fn foo<T: Clone + Copy>(){}
     generic_params::opt_generic_param_list(p);
+    fn_signature(p, m);
+}
 
+/// Everything after the generic parameters of a function: its parameters, return type, where
+/// clause and verus specifications, then its body or a `;`.
+pub(super) fn fn_signature(p: &mut Parser<'_>, m: Marker) {
     if p.at(T!['(']) {
         params::param_list_fn_def(p);
     } else {
@@ -528,6 +528,17 @@ pub(crate) fn trigger_attribute(p: &mut Parser<'_>, inner:bool) -> CompletedMark
     m.complete(p, TRIGGER_ATTRIBUTE)
 }
 
+pub(crate) fn assume_specification(p: &mut Parser<'_>, m: Marker) {
+    // assume_specification<T> [ std::mem::swap::<T> ] (a: &mut T, b: &mut T)
+    //     ensures *a == *old(b), *b == *old(a);
+    p.bump_remap(T![assume_specification]);
+    generic_params::opt_generic_param_list(p);
+    p.expect(T!['[']);
+    paths::expr_path(p);
+    p.expect(T![']']);
+    items::fn_signature(p, m);
+}
+
 pub(crate) fn global_clause(p: &mut Parser<'_>, m: Marker) {
     //global size_of usize == 8;
     p.eat_contextual_kw(T![global]);
@@ -13,7 +13,7 @@ use serde_json::from_value;
 use span::Edition;
 use toolchain::Tool;
 
-use crate::{utf8_stdout, InvocationLocation, ManifestPath, Sysroot};
+use crate::{utf8_stdout, InvocationLocation, ManifestPath, Sysroot, Vstd};
 use crate::{CfgOverrides, InvocationStrategy};
 
 /// [`CargoWorkspace`] represents the logical structure of, well, a Cargo
@@ -88,6 +88,8 @@ pub struct CargoConfig {
     /// Whether to invoke `cargo metadata` on the sysroot crate.
     pub sysroot_query_metadata: bool,
     pub sysroot_src: Option<AbsPathBuf>,
+    /// Verus' `vstd`, loaded along with the sysroot.
+    pub vstd: Option<Vstd>,
     /// rustc private crate source
     pub rustc_source: Option<RustLibSource>,
     pub cfg_overrides: CfgOverrides,
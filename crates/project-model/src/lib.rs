@@ -26,6 +26,7 @@ pub mod project_json;
 mod rustc_cfg;
 mod sysroot;
 pub mod target_data_layout;
+mod vstd;
 mod workspace;
 
 #[cfg(test)]
@@ -52,6 +53,7 @@ pub use crate::{
     manifest_path::ManifestPath,
     project_json::{ProjectJson, ProjectJsonData},
     sysroot::Sysroot,
+    vstd::Vstd,
     workspace::{FileLoader, PackageRoot, ProjectWorkspace, ProjectWorkspaceKind},
 };
 pub use cargo_metadata::Metadata;
@@ -14,7 +14,7 @@ use paths::{AbsPath, AbsPathBuf, Utf8PathBuf};
 use rustc_hash::FxHashMap;
 use toolchain::{probe_for_binary, Tool};
 
-use crate::{utf8_stdout, CargoConfig, CargoWorkspace, ManifestPath};
+use crate::{utf8_stdout, CargoConfig, CargoWorkspace, ManifestPath, Vstd};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Sysroot {
@@ -22,6 +22,8 @@ pub struct Sysroot {
     src_root: Option<AbsPathBuf>,
     mode: SysrootMode,
     error: Option<String>,
+    /// Verus' `vstd`, loaded along with the sysroot crates.
+    vstd: Option<Vstd>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -79,7 +81,7 @@ pub(crate) struct SysrootCrateData {
 
 impl Sysroot {
     pub const fn empty() -> Sysroot {
-        Sysroot { root: None, src_root: None, mode: SysrootMode::Empty, error: None }
+        Sysroot { root: None, src_root: None, mode: SysrootMode::Empty, error: None, vstd: None }
     }
 
     /// Returns sysroot "root" directory, where `bin/`, `etc/`, `lib/`, `libexec/`
@@ -118,6 +120,15 @@ impl Sysroot {
     pub(crate) fn mode(&self) -> &SysrootMode {
         &self.mode
     }
+
+    pub fn vstd(&self) -> Option<&Vstd> {
+        self.vstd.as_ref()
+    }
+
+    /// Loads `vstd` along with the sysroot crates.
+    pub fn with_vstd(self, vstd: Option<Vstd>) -> Sysroot {
+        Sysroot { vstd, ..self }
+    }
 }
 
 // FIXME: Expose a builder api as loading the sysroot got way too modular and complicated.
@@ -240,6 +251,7 @@ impl Sysroot {
                     src_root: None,
                     mode: SysrootMode::Empty,
                     error: Some(e.to_string()),
+                    vstd: None,
                 }
             }
             None => None,
@@ -252,6 +264,7 @@ impl Sysroot {
                     src_root: None,
                     mode: SysrootMode::Empty,
                     error: Some(e.to_string()),
+                    vstd: None,
                 }
             }
             None => {
@@ -260,6 +273,7 @@ impl Sysroot {
                     src_root: None,
                     mode: SysrootMode::Empty,
                     error: None,
+                    vstd: None,
                 }
             }
         };
@@ -370,6 +384,7 @@ impl Sysroot {
                     src_root: Some(sysroot_src_dir.clone()),
                     mode: SysrootMode::Workspace(cargo_workspace),
                     error: None,
+                    vstd: None,
                 })
             })();
             if let Some(sysroot) = sysroot {
@@ -423,6 +438,7 @@ impl Sysroot {
             src_root: Some(sysroot_src_dir),
             mode: SysrootMode::Stitched(stitched),
             error: None,
+            vstd: None,
         }
     }
 }
@@ -0,0 +1,70 @@
+//! Loads Verus' `vstd` library from its sources.
+//!
+//! Verus ships `vstd` precompiled, so projects built with the `verus` binary rarely depend on it
+//! through cargo. Like the sysroot, it is then added to the crate graph from the sources of the
+//! Verus installation, along with the `builtin` crate it relies on.
+
+use std::{env, fs};
+
+use paths::{AbsPath, AbsPathBuf, Utf8Path, Utf8PathBuf};
+use toolchain::probe_for_binary;
+
+use crate::ManifestPath;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vstd {
+    source_dir: AbsPathBuf,
+    builtin: ManifestPath,
+    vstd: ManifestPath,
+}
+
+impl Vstd {
+    /// Loads `vstd` from `source_dir`, the `source` directory of a Verus checkout.
+    pub fn load(source_dir: AbsPathBuf) -> Option<Vstd> {
+        let root = |path: &str| {
+            let path = ManifestPath::try_from(source_dir.join(path)).ok()?;
+            fs::metadata(path.as_ref()).is_ok().then_some(path)
+        };
+        let builtin = root("builtin/src/lib.rs")?;
+        let vstd = root("vstd/vstd.rs")?;
+        Some(Vstd { source_dir, builtin, vstd })
+    }
+
+    /// Finds the sources of the Verus installation `verus_binary` belongs to, defaulting to the
+    /// `VERUS_BINARY_PATH` environment variable, then to `verus` in the `PATH`. A binary built
+    /// from a checkout lives in `source/target-verus/release`.
+    pub fn discover(verus_binary: Option<&Utf8Path>) -> Option<Vstd> {
+        let binary = match verus_binary {
+            Some(it) => it.to_owned(),
+            None => match env::var("VERUS_BINARY_PATH") {
+                Ok(it) => Utf8PathBuf::from(it),
+                Err(_) => env::split_paths(&env::var_os("PATH")?)
+                    .filter_map(|dir| Utf8PathBuf::from_path_buf(dir).ok())
+                    .find_map(|dir| probe_for_binary(dir.join("verus")))?,
+            },
+        };
+        let binary = binary.canonicalize_utf8().ok()?;
+        let res = binary
+            .ancestors()
+            .flat_map(|dir| [dir.to_owned(), dir.join("source")])
+            .find_map(|dir| Vstd::load(AbsPathBuf::assert(dir)));
+        if res.is_none() {
+            tracing::debug!(%binary, "no vstd sources next to the Verus binary");
+        }
+        res
+    }
+
+    pub fn source_dir(&self) -> &AbsPath {
+        &self.source_dir
+    }
+
+    /// The roots of the `builtin` and `vstd` crates, in dependency order.
+    pub(crate) fn crates(&self) -> [(&'static str, &ManifestPath); 2] {
+        [("builtin", &self.builtin), ("vstd", &self.vstd)]
+    }
+
+    /// The directories holding the sources of the crates.
+    pub(crate) fn include_dirs(&self) -> Vec<AbsPathBuf> {
+        vec![self.source_dir.join("builtin"), self.source_dir.join("vstd")]
+    }
+}
@@ -28,7 +28,7 @@ use crate::{
     sysroot::{SysrootCrate, SysrootMode},
     target_data_layout::{self, RustcDataLayoutConfig},
     utf8_stdout, CargoConfig, CargoWorkspace, InvocationStrategy, ManifestPath, Package,
-    ProjectJson, ProjectManifest, Sysroot, TargetData, TargetKind, WorkspaceBuildScripts,
+    ProjectJson, ProjectManifest, Sysroot, TargetData, TargetKind, Vstd, WorkspaceBuildScripts,
 };
 
 pub type FileLoader<'a> = &'a mut dyn for<'b> FnMut(&'b AbsPath) -> Option<FileId>;
@@ -235,6 +235,7 @@ impl ProjectWorkspace {
                     ),
                     (None, _) => Sysroot::empty(),
                 };
+                let sysroot = sysroot.with_vstd(config.vstd.clone());
                 tracing::info!(workspace = %cargo_toml, src_root = ?sysroot.src_root(), root = ?sysroot.root(), "Using sysroot");
 
                 let rustc_dir = match &config.rustc_source {
@@ -392,6 +393,7 @@ impl ProjectWorkspace {
             }
             None => Sysroot::empty(),
         };
+        let sysroot = sysroot.with_vstd(config.vstd.clone());
 
         let toolchain =
             match get_toolchain_version(dir, &sysroot, Tool::Rustc, &config.extra_env, "rustc ") {
@@ -576,6 +578,13 @@ impl ProjectWorkspace {
                 include: self.sysroot.src_root().map(|it| it.to_path_buf()).into_iter().collect(),
                 exclude: Vec::new(),
             });
+            if let Some(vstd) = self.sysroot.vstd() {
+                r.push(PackageRoot {
+                    is_local: false,
+                    include: vstd.include_dirs(),
+                    exclude: Vec::new(),
+                });
+            }
             r
         };
         match &self.kind {
@@ -776,6 +785,9 @@ impl ProjectWorkspace {
         } else {
             tracing::debug!("Did not patch std to depend on cfg-if")
         }
+        if let Some(vstd) = sysroot.vstd() {
+            vstd_to_crate_graph(&mut crate_graph, vstd, rustc_cfg.clone(), load);
+        }
         (crate_graph, proc_macros)
     }
 
@@ -1509,6 +1521,86 @@ fn sysroot_to_crate_graph(
     }
 }
 
+/// Adds `builtin` and `vstd` to the crate graph, as dependencies of the local crates that do not
+/// get them through cargo already.
+fn vstd_to_crate_graph(
+    crate_graph: &mut CrateGraph,
+    vstd: &Vstd,
+    rustc_cfg: Vec<CfgFlag>,
+    load: FileLoader<'_>,
+) {
+    let _p = tracing::info_span!("vstd_to_crate_graph").entered();
+    let is_verus_crate = |name: &str| matches!(name, "builtin" | "vstd");
+    let dependents: Vec<CrateId> = crate_graph
+        .iter()
+        .filter(|&id| {
+            let krate = &crate_graph[id];
+            krate.origin.is_local()
+                && !krate.display_name.as_ref().map_or(false, |it| is_verus_crate(it))
+                && !krate.dependencies.iter().any(|dep| &*dep.name == "vstd")
+        })
+        .collect();
+    if dependents.is_empty() {
+        return;
+    }
+
+    let sysroot_deps: Vec<(CrateName, CrateId, bool)> = crate_graph
+        .iter()
+        .filter_map(|id| match crate_graph[id].origin {
+            CrateOrigin::Lang(
+                lang @ (LangCrateOrigin::Core | LangCrateOrigin::Alloc | LangCrateOrigin::Std),
+            ) => Some((
+                CrateName::normalize_dashes(&lang.to_string()),
+                id,
+                lang != LangCrateOrigin::Alloc,
+            )),
+            _ => None,
+        })
+        .collect();
+    let cfg_options = Arc::new({
+        let mut cfg_options = CfgOptions::default();
+        cfg_options.extend(rustc_cfg);
+        cfg_options.insert_atom("verus_keep_ghost".into());
+        cfg_options.insert_key_value("feature".into(), "std".into());
+        cfg_options.insert_key_value("feature".into(), "alloc".into());
+        cfg_options
+    });
+
+    let mut verus_crates: Vec<(CrateName, CrateId)> = Vec::new();
+    for (name, root) in vstd.crates() {
+        let Some(file_id) = load(root) else {
+            tracing::warn!(%root, "failed to load the root of {name}");
+            return;
+        };
+        let crate_id = crate_graph.add_crate_root(
+            file_id,
+            Edition::Edition2018,
+            Some(CrateDisplayName::from_canonical_name(name.to_owned())),
+            None,
+            cfg_options.clone(),
+            None,
+            Env::default(),
+            false,
+            CrateOrigin::Library { repo: None, name: name.to_owned() },
+        );
+        for (dep_name, dep_id, prelude) in &sysroot_deps {
+            add_dep_with_prelude(crate_graph, crate_id, dep_name.clone(), *dep_id, *prelude, true);
+        }
+        for (dep_name, dep_id) in &verus_crates {
+            add_dep(crate_graph, crate_id, dep_name.clone(), *dep_id);
+        }
+        verus_crates.push((CrateName::new(name).unwrap(), crate_id));
+    }
+
+    for from in dependents {
+        for (name, to) in &verus_crates {
+            if !crate_graph[from].dependencies.iter().any(|dep| dep.name == *name) {
+                add_dep(crate_graph, from, name.clone(), *to);
+            }
+        }
+    }
+}
+
 fn add_dep(graph: &mut CrateGraph, from: CrateId, name: CrateName, to: CrateId) {
     add_dep_inner(graph, from, Dependency::new(name, to))
 }
@@ -4,7 +4,7 @@ use lsp_types::{
     CallHierarchyServerCapability, ClientCapabilities, CodeActionKind, CodeActionOptions,
     CodeActionProviderCapability, CodeLensOptions, CompletionOptions,
     CompletionOptionsCompletionItem, DeclarationCapability, DocumentOnTypeFormattingOptions,
-    FileOperationFilter, FileOperationPattern, FileOperationPatternKind,
+    ExecuteCommandOptions, FileOperationFilter, FileOperationPattern, FileOperationPatternKind,
     FileOperationRegistrationOptions, FoldingRangeProviderCapability, HoverProviderCapability,
     ImplementationProviderCapability, InlayHintOptions, InlayHintServerCapabilities, OneOf,
     PositionEncodingKind, RenameOptions, SaveOptions, SelectionRangeProviderCapability,
@@ -86,7 +86,16 @@ pub fn server_capabilities(config: &Config) -> ServerCapabilities {
         linked_editing_range_provider: None,
         document_link_provider: None,
         color_provider: None,
-        execute_command_provider: None,
+        execute_command_provider: Some(ExecuteCommandOptions {
+            commands: vec![
+                "verus.verifyFile".to_owned(),
+                "verus.verifyModule".to_owned(),
+                "verus.verifyFunction".to_owned(),
+                "verus.verifySlowProofs".to_owned(),
+                "verus.proofGraph".to_owned(),
+            ],
+            work_done_progress_options: WorkDoneProgressOptions { work_done_progress: None },
+        }),
         workspace: Some(WorkspaceServerCapabilities {
             workspace_folders: Some(WorkspaceFoldersServerCapabilities {
                 supported: Some(true),
@@ -152,7 +161,7 @@ pub fn server_capabilities(config: &Config) -> ServerCapabilities {
             "openCargoToml": true,
             "parentModule": true,
             "runnables": {
-                "kinds": [ "cargo" ],
+                "kinds": [ "cargo", "verusVerify" ],
             },
             "ssr": true,
             "workspaceSymbolScopeKindFiltering": true,
@@ -1023,6 +1023,7 @@ impl flags::AnalysisStats {
                     closing_brace_hints_min_lines: Some(20),
                     fields_to_resolve: InlayFieldsToResolve::empty(),
                     range_exclusive_hints: true,
+                    ghost_binding_hints: true,
                 },
                 file_id,
                 None,
@@ -127,6 +127,29 @@ xflags::xflags! {
             optional --proc-macro-srv path: PathBuf
         }
 
+        /// Run Verus on each crate of the workspace, exiting with a non-zero status if
+        /// verification fails.
+        cmd verus-verify {
+            /// Directory with Cargo.toml.
+            required path: PathBuf
+
+            /// Run Verus on each module file rather than on each crate.
+            optional --per-module
+            /// Print the results as JSON.
+            optional --json
+
+            /// Path to the Verus binary, defaults to `VERUS_BINARY_PATH` then to `verus`.
+            optional --verus path: PathBuf
+            /// Extra argument for Verus, can be repeated.
+            repeated --verus-arg arg: String
+            /// Solver resource limit per function.
+            optional --rlimit limit: usize
+            /// Verify each function in its own solver instance.
+            optional --per-function
+            /// Print the proof dependency graph, as `dot` or `json`, instead of the results.
+            optional --proof-graph format: ProofGraphFormat
+        }
+
         cmd ssr {
             /// A structured search replace rule (`$a.foo($b) ==>> bar($a, $b)`)
             repeated rule: SsrRule
@@ -178,6 +201,7 @@ pub enum RustAnalyzerCmd {
     RunTests(RunTests),
     RustcTests(RustcTests),
     Diagnostics(Diagnostics),
+    VerusVerify(VerusVerify),
     Ssr(Ssr),
     Search(Search),
     Lsif(Lsif),
@@ -248,6 +272,19 @@ pub struct Diagnostics {
     pub proc_macro_srv: Option<PathBuf>,
 }
 
+#[derive(Debug)]
+pub struct VerusVerify {
+    pub path: PathBuf,
+
+    pub per_module: bool,
+    pub json: bool,
+    pub verus: Option<PathBuf>,
+    pub verus_arg: Vec<String>,
+    pub rlimit: Option<usize>,
+    pub per_function: bool,
+    pub proof_graph: Option<ProofGraphFormat>,
+}
+
 #[derive(Debug)]
 pub struct Ssr {
     pub rule: Vec<SsrRule>,
@@ -296,6 +333,12 @@ pub enum OutputFormat {
     Csv,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofGraphFormat {
+    Dot,
+    Json,
+}
+
 impl RustAnalyzer {
     pub fn verbosity(&self) -> Verbosity {
         if self.quiet {
@@ -319,3 +362,15 @@ impl FromStr for OutputFormat {
         }
     }
 }
+
+impl FromStr for ProofGraphFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dot" => Ok(Self::Dot),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("unknown proof graph format `{s}`")),
+        }
+    }
+}
@@ -3,8 +3,8 @@
 use std::{path::PathBuf, time::Instant};
 
 use ide::{
-    AnalysisHost, LineCol, MonikerDescriptorKind, MonikerResult, StaticIndex, StaticIndexedFile,
-    SymbolInformationKind, TextRange, TokenId,
+    AnalysisHost, FileRange, LineCol, MonikerDescriptorKind, MonikerResult, StaticIndex,
+    StaticIndexedFile, SymbolInformationKind, TextRange, TokenId,
 };
 use ide_db::LineIndexDatabase;
 use load_cargo::{load_workspace_at, LoadCargoConfig, ProcMacroServerChoice};
@@ -136,6 +136,14 @@ impl flags::Scip {
 
                 let mut symbol_roles = Default::default();
 
+                // Contracts only read what they refer to.
+                let in_contract = token.references.iter().any(|it| {
+                    it.is_in_contract && it.range == FileRange { file_id, range: text_range }
+                });
+                if in_contract {
+                    symbol_roles |= scip_types::SymbolRole::ReadAccess as i32;
+                }
+
                 if let Some(def) = token.definition {
                     if def.range == text_range {
                         symbol_roles |= scip_types::SymbolRole::Definition as i32;
@@ -270,9 +278,11 @@ fn symbol_kind(kind: SymbolInformationKind) -> scip_types::symbol_information::K
         SymbolInformationKind::EnumMember => ScipKind::EnumMember,
         SymbolInformationKind::Field => ScipKind::Field,
         SymbolInformationKind::Function => ScipKind::Function,
+        SymbolInformationKind::Lemma => ScipKind::Lemma,
         SymbolInformationKind::Macro => ScipKind::Macro,
         SymbolInformationKind::Method => ScipKind::Method,
         SymbolInformationKind::Module => ScipKind::Module,
+        SymbolInformationKind::Namespace => ScipKind::Namespace,
         SymbolInformationKind::Parameter => ScipKind::Parameter,
         SymbolInformationKind::SelfParameter => ScipKind::SelfParameter,
         SymbolInformationKind::StaticMethod => ScipKind::StaticMethod,
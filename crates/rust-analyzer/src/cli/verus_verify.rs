@@ -0,0 +1,279 @@
+//! Run Verus on all crates of a project, without an editor. Exits with a non-zero status code
+//! if anything fails to verify.
+
+use flycheck::{DiagnosticLevel, VerusOptions, VerusReport, VerusTarget};
+use hir::{db::HirDatabase, Crate, HirFileIdExt, Module};
+use ide::{AnalysisHost, ProofStatus};
+use ide_db::base_db::SourceDatabaseExt;
+use itertools::Itertools;
+use load_cargo::{load_workspace_at, LoadCargoConfig, ProcMacroServerChoice};
+use paths::{AbsPathBuf, Utf8PathBuf};
+use project_model::{CargoConfig, RustLibSource};
+use rustc_hash::FxHashSet;
+use serde::Serialize;
+
+use crate::{
+    cli::flags::{self, ProofGraphFormat},
+    verus_interaction,
+};
+
+/// One Verus invocation: a whole crate, or a single module of it.
+struct Run {
+    krate: String,
+    module: Option<String>,
+    file: String,
+    target: VerusTarget,
+    /// Only verify the crate root, not the modules it declares.
+    root_only: bool,
+}
+
+#[derive(Serialize)]
+struct RunResult {
+    #[serde(rename = "crate")]
+    krate: String,
+    module: Option<String>,
+    file: String,
+    success: bool,
+    elapsed_ms: u64,
+    diagnostics: Vec<DiagnosticResult>,
+    functions: Vec<FunctionResult>,
+}
+
+#[derive(Serialize)]
+struct DiagnosticResult {
+    level: String,
+    message: String,
+    file: Option<String>,
+    line: Option<usize>,
+    column: Option<usize>,
+    #[serde(skip)]
+    rendered: Option<String>,
+}
+
+#[derive(Serialize)]
+struct FunctionResult {
+    function: String,
+    success: bool,
+    time_ms: u64,
+    rlimit: Option<u64>,
+}
+
+impl flags::VerusVerify {
+    pub fn run(self) -> anyhow::Result<()> {
+        let cargo_config =
+            CargoConfig { sysroot: Some(RustLibSource::Discover), ..Default::default() };
+        // Only the module tree is needed, Verus does the actual analysis.
+        let load_cargo_config = LoadCargoConfig {
+            load_out_dirs_from_check: false,
+            with_proc_macro_server: ProcMacroServerChoice::None,
+            prefill_caches: false,
+        };
+        let (db, vfs, _proc_macro) =
+            load_workspace_at(&self.path, &cargo_config, &load_cargo_config, &|_| {})?;
+        let host = AnalysisHost::with_database(db);
+        let db = host.raw_database();
+
+        let root = AbsPathBuf::assert_utf8(std::env::current_dir()?.join(&self.path));
+        let binary_path = match &self.verus {
+            Some(path) => Some(
+                Utf8PathBuf::from_path_buf(path.clone())
+                    .map_err(|it| anyhow::format_err!("{} is not a UTF-8 path", it.display()))?,
+            ),
+            None => None,
+        };
+        let options = VerusOptions {
+            binary_path,
+            extra_args: self.verus_arg.clone(),
+            rlimit: self.rlimit,
+            per_function: self.per_function,
+            ..Default::default()
+        };
+
+        let mut runs = Vec::new();
+        let mut seen = FxHashSet::default();
+        for krate in Crate::all(db) {
+            let root_module = krate.root_module();
+            let file_id = root_module.definition_source_file_id(db).original_file(db);
+            let source_root = db.source_root(db.file_source_root(file_id));
+            // Several targets of a package can share their root file.
+            if source_root.is_library || !seen.insert(file_id) {
+                continue;
+            }
+            let krate =
+                krate.display_name(db).map_or_else(|| "unknown".to_owned(), |it| it.to_string());
+            runs.push(Run {
+                krate: krate.clone(),
+                module: None,
+                file: vfs.file_path(file_id).to_string(),
+                target: VerusTarget::File,
+                root_only: self.per_module,
+            });
+            if !self.per_module {
+                continue;
+            }
+            for module in module_files(db, root_module) {
+                let file_id = module.definition_source_file_id(db).original_file(db);
+                let path = module
+                    .path_to_root(db)
+                    .into_iter()
+                    .rev()
+                    .filter_map(|it| it.name(db))
+                    .map(|it| it.display(db).to_string())
+                    .join("::");
+                runs.push(Run {
+                    krate: krate.clone(),
+                    module: Some(path.clone()),
+                    file: vfs.file_path(file_id).to_string(),
+                    target: VerusTarget::Module(path),
+                    root_only: false,
+                });
+            }
+        }
+
+        // The graph replaces the results on stdout, progress still goes to stderr.
+        let text = !self.json && self.proof_graph.is_none();
+        let mut results = Vec::new();
+        for run in runs {
+            let mut options = options.clone();
+            if run.root_only {
+                options.extra_args.push("--verify-root".to_owned());
+            }
+            let label = match &run.module {
+                Some(module) => format!("{}::{module}", run.krate),
+                None => run.krate.clone(),
+            };
+            if text {
+                eprint!("verifying {label} ... ");
+            }
+            let report = flycheck::verify(&root, &options, &run.file, run.target)
+                .map_err(|err| anyhow::format_err!("failed to run Verus on {label}: {err}"))?;
+            let result = run_result(run.krate, run.module, run.file, report);
+            if text {
+                print_result(&result);
+            }
+            results.push(result);
+        }
+
+        let failed = results.iter().filter(|it| !it.success).count();
+        if let Some(format) = self.proof_graph {
+            let mut graph = host.analysis().proof_graph().unwrap();
+            for node in &mut graph.nodes {
+                let functions: Vec<_> = results
+                    .iter()
+                    .flat_map(|it| &it.functions)
+                    .filter(|it| {
+                        it.function == node.path
+                            || it.function.ends_with(&format!("::{}", node.path))
+                    })
+                    .collect();
+                node.status = if functions.is_empty() {
+                    ProofStatus::Unknown
+                } else if functions.iter().all(|it| it.success) {
+                    ProofStatus::Verified
+                } else {
+                    ProofStatus::Failed
+                };
+            }
+            match format {
+                ProofGraphFormat::Dot => print!("{}", graph.to_dot()),
+                ProofGraphFormat::Json => {
+                    let analysis = host.analysis();
+                    let json = verus_interaction::proof_graph_json(&graph, |range| {
+                        let line_index = analysis.file_line_index(range.file_id)?;
+                        let line = line_index.line_col(range.range.start()).line + 1;
+                        Ok((vfs.file_path(range.file_id).to_string(), line))
+                    })?;
+                    println!("{}", serde_json::to_string_pretty(&json)?);
+                }
+            }
+        } else if self.json {
+            println!("{}", serde_json::to_string_pretty(&results)?);
+        } else {
+            println!();
+            println!("{} runs, {failed} failed", results.len());
+        }
+        if failed != 0 {
+            anyhow::bail!("verification failed")
+        }
+        Ok(())
+    }
+}
+
+/// The modules below `root` that have a file of their own.
+fn module_files(db: &dyn HirDatabase, root: Module) -> Vec<Module> {
+    let mut worklist: Vec<_> = root.children(db).collect();
+    let mut modules = Vec::new();
+    while let Some(module) = worklist.pop() {
+        if !module.is_inline(db) {
+            modules.push(module);
+        }
+        worklist.extend(module.children(db));
+    }
+    modules
+}
+
+fn run_result(
+    krate: String,
+    module: Option<String>,
+    file: String,
+    report: VerusReport,
+) -> RunResult {
+    let diagnostics = report
+        .diagnostics
+        .into_iter()
+        .map(|it| {
+            let primary = it.spans.iter().find(|span| span.is_primary);
+            DiagnosticResult {
+                level: match it.level {
+                    DiagnosticLevel::Ice | DiagnosticLevel::Error => "error",
+                    DiagnosticLevel::Warning => "warning",
+                    _ => "note",
+                }
+                .to_owned(),
+                message: it.message,
+                file: primary.map(|span| span.file_name.clone()),
+                line: primary.map(|span| span.line_start),
+                column: primary.map(|span| span.column_start),
+                rendered: it.rendered,
+            }
+        })
+        .collect();
+    let functions = report
+        .functions
+        .into_iter()
+        .map(|it| FunctionResult {
+            function: it.function,
+            success: it.success,
+            time_ms: it.time_ms,
+            rlimit: it.rlimit,
+        })
+        .collect();
+    RunResult {
+        krate,
+        module,
+        file,
+        success: report.success,
+        elapsed_ms: report.elapsed.as_millis() as u64,
+        diagnostics,
+        functions,
+    }
+}
+
+fn print_result(result: &RunResult) {
+    let elapsed = result.elapsed_ms as f64 / 1000.0;
+    if result.success {
+        eprintln!("ok ({} functions, {elapsed:.1}s)", result.functions.len());
+        return;
+    }
+    let errors = result.diagnostics.iter().filter(|it| it.level == "error").count();
+    eprintln!("FAILED ({errors} errors, {elapsed:.1}s)");
+    for diagnostic in &result.diagnostics {
+        match &diagnostic.rendered {
+            Some(rendered) => println!("{rendered}"),
+            None => println!("{}: {}", diagnostic.level, diagnostic.message),
+        }
+    }
+    for function in result.functions.iter().filter(|it| !it.success) {
+        println!("failed to verify {}", function.function);
+    }
+}
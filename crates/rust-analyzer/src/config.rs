@@ -38,7 +38,7 @@ use vfs::{AbsPath, AbsPathBuf, VfsPath};
 
 use crate::{
     caps::completion_item_edit_resolve,
-    diagnostics::DiagnosticsMapConfig,
+    diagnostics::{DiagnosticsMapConfig, VerusSeverityMap},
     line_index::PositionEncoding,
     lsp_ext::{self, negotiated_encoding, WorkspaceSymbolSearchKind, WorkspaceSymbolSearchScope},
 };
@@ -260,6 +260,13 @@ config_data! {
         diagnostics_remapPrefix: FxHashMap<String, String> = FxHashMap::default(),
         /// Whether to run additional style lints.
         diagnostics_styleLints_enable: bool =    false,
+        /// Severity to report Verus verification failures (failed assertions, pre- and
+        /// postconditions, invariants, ...) with. Keeps Verus' own level if unset.
+        diagnostics_verus_failures_severity: Option<DiagnosticSeverityDef> = None,
+        /// Severity to report unmet `recommends` clauses with. Keeps Verus' own level if unset.
+        diagnostics_verus_recommends_severity: Option<DiagnosticSeverityDef> = None,
+        /// Severity to report Verus' notes about triggers with. Keeps Verus' own level if unset.
+        diagnostics_verus_triggerNotes_severity: Option<DiagnosticSeverityDef> = None,
         /// List of warnings that should be displayed with hint severity.
         ///
         /// The warnings will be indicated by faded text or three dots in code
@@ -331,6 +338,13 @@ config_data! {
         /// `textDocument/rangeFormatting` request. The rustfmt option is unstable and only
         /// available on a nightly build.
         rustfmt_rangeFormatting_enable: bool = false,
+        /// Format the contents of `verus!` blocks with verusfmt after the rest of the file has
+        /// been formatted with rustfmt.
+        rustfmt_verusfmt_enable: bool = true,
+        /// Run this command instead of the bundled verusfmt to format `verus!` blocks. The file
+        /// contents will be passed on the standard input and the formatted result will be read
+        /// from the standard output.
+        rustfmt_verusfmt_overrideCommand: Option<Vec<String>> = None,
     }
 }
 
@@ -510,6 +524,8 @@ config_data! {
         inlayHints_expressionAdjustmentHints_hideOutsideUnsafe: bool = false,
         /// Whether to show inlay hints as postfix ops (`.*` instead of `*`, etc).
         inlayHints_expressionAdjustmentHints_mode: AdjustmentHintsModeDef = AdjustmentHintsModeDef::Prefix,
+        /// Whether to show inlay hints marking ghost and tracked bindings, whose values are erased at runtime.
+        inlayHints_ghostBindingHints_enable: bool                  = true,
         /// Whether to show implicit drop hints.
         inlayHints_implicitDrops_enable: bool                      = false,
         /// Whether to show inlay type hints for elided lifetimes in function signatures.
@@ -576,6 +592,9 @@ config_data! {
         /// Whether to show `Run` lens. Only applies when
         /// `#rust-analyzer.lens.enable#` is set.
         lens_run_enable: bool              = true,
+        /// Whether to show the verification status lens (`✓ verified (0.8s)` or `✗ 2 errors`)
+        /// after a Verus run. Only applies when `#rust-analyzer.lens.enable#` is set.
+        lens_verification_enable: bool = true,
 
         /// Whether to show `can't find Cargo.toml` error message.
         notifications_cargoTomlNotFound: bool      = true,
@@ -644,6 +663,43 @@ config_data! {
         /// Whether to insert closing angle brackets when typing an opening angle bracket of a generic argument list.
         typing_autoClosingAngleBrackets_enable: bool = false,
 
+        /// Path to the Verus binary. Defaults to the `VERUS_BINARY_PATH` environment variable,
+        /// then to `verus` in the `PATH`.
+        verus_binaryPath: Option<Utf8PathBuf> = None,
+        /// Remember the verification result of each function across sessions, to show the last
+        /// known statuses when a project is reopened and to skip unchanged functions on the first
+        /// run.
+        verus_cache_enable: bool = true,
+        /// Path to the cvc5 solver, passed to Verus as `VERUS_CVC5_PATH`.
+        verus_cvc5Path: Option<Utf8PathBuf> = None,
+        /// Export the verified crate's specifications to this file (`--export`).
+        verus_export: Option<Utf8PathBuf> = None,
+        /// Extra arguments for Verus.
+        verus_extraArgs: Vec<String> = vec![],
+        /// Verified crates to import, as `crate=path` pairs (`--import`).
+        verus_import: Vec<String> = vec![],
+        /// Verify each function in its own solver instance (`--spinoff-all`), so that one
+        /// slow function does not slow down the others.
+        verus_perFunction: bool = false,
+        /// Solver resource limit per function (`--rlimit`).
+        verus_rlimit: Option<usize> = None,
+        /// Functions whose last verification took longer than this many milliseconds are not
+        /// verified again on save, but only by the `verus.verifySlowProofs` command.
+        verus_slowProofs_timeBudget: Option<usize> = None,
+        /// Run Verus on the saved file.
+        verus_verifyOnSave: bool = true,
+        /// Load `vstd` into the project, so that navigation and hover cover its items even when
+        /// no crate depends on it through cargo.
+        verus_vstd_enable: bool = true,
+        /// Path to the `source` directory of a Verus checkout to load `vstd` from. Defaults to
+        /// the sources of the Verus installation `#rust-analyzer.verus.binaryPath#` belongs to.
+        verus_vstd_path: Option<Utf8PathBuf> = None,
+        /// Run Verus once on a trivial file when the workspace is loaded, so that the first
+        /// verification does not pay for loading Verus and `vstd`.
+        verus_warmUp_enable: bool = true,
+        /// Path to the Z3 solver, passed to Verus as `VERUS_Z3_PATH`.
+        verus_z3Path: Option<Utf8PathBuf> = None,
+
         /// Workspace symbol search kind.
         workspace_symbol_search_kind: WorkspaceSymbolSearchKindDef = WorkspaceSymbolSearchKindDef::OnlyTypes,
         /// Limits the number of items returned from a workspace symbol search (Defaults to 128).
@@ -1011,6 +1067,9 @@ pub struct LensConfig {
     pub refs_trait: bool, // for Struct, Enum, Union and Trait
     pub enum_variant_refs: bool,
 
+    // verus
+    pub verification: bool,
+
     // annotations
     pub location: AnnotationLocation,
 }
@@ -1040,6 +1099,7 @@ impl LensConfig {
             || self.refs_adt
             || self.refs_trait
             || self.enum_variant_refs
+            || self.verification
     }
 
     pub fn none(&self) -> bool {
@@ -1110,6 +1170,12 @@ pub enum RustfmtConfig {
     CustomCommand { command: String, args: Vec<String> },
 }
 
+#[derive(Debug, Clone)]
+pub enum VerusfmtConfig {
+    Bundled,
+    CustomCommand { command: String, args: Vec<String> },
+}
+
 /// Configuration for runnable items, such as `main` function or tests.
 #[derive(Debug, Clone)]
 pub struct RunnablesConfig {
@@ -1137,6 +1203,7 @@ pub struct ClientCommandsConfig {
     pub debug_single: bool,
     pub show_reference: bool,
     pub goto_location: bool,
+    pub run_flycheck: bool,
     pub trigger_parameter_hints: bool,
 }
 
@@ -1270,6 +1337,7 @@ impl Config {
             assist_emit_must_use: self.assist_emitMustUse(source_root).to_owned(),
             prefer_prelude: self.imports_preferPrelude(source_root).to_owned(),
             term_search_fuel: self.assist_termSearch_fuel(source_root).to_owned() as u64,
+            verus_binary_path: self.verus_binaryPath().as_ref().map(|it| it.to_string()),
         }
     }
 
@@ -1480,6 +1548,7 @@ impl Config {
             },
             implicit_drop_hints: self.inlayHints_implicitDrops_enable().to_owned(),
             range_exclusive_hints: self.inlayHints_rangeExclusiveHints_enable().to_owned(),
+            ghost_binding_hints: self.inlayHints_ghostBindingHints_enable().to_owned(),
         }
     }
 
@@ -1736,6 +1805,10 @@ impl Config {
         self.experimental("testExplorer")
     }
 
+    pub fn verification_decorations(&self) -> bool {
+        self.experimental("verificationDecorations")
+    }
+
     pub fn publish_diagnostics(&self) -> bool {
         self.diagnostics_enable().to_owned()
     }
@@ -1746,6 +1819,11 @@ impl Config {
             warnings_as_info: self.diagnostics_warningsAsInfo().clone(),
             warnings_as_hint: self.diagnostics_warningsAsHint().clone(),
             check_ignore: self.check_ignore().clone(),
+            verus_severity: VerusSeverityMap {
+                failures: self.diagnostics_verus_failures_severity().map(Into::into),
+                recommends: self.diagnostics_verus_recommends_severity().map(Into::into),
+                trigger_notes: self.diagnostics_verus_triggerNotes_severity().map(Into::into),
+            },
         }
     }
 
@@ -1849,6 +1927,7 @@ impl Config {
             sysroot,
             sysroot_query_metadata: *sysroot_query_metadata,
             sysroot_src,
+            vstd: self.vstd(),
             rustc_source,
             cfg_overrides: project_model::CfgOverrides {
                 global: CfgDiff::new(
@@ -1896,6 +1975,20 @@ impl Config {
         }
     }
 
+    pub fn verusfmt(&self) -> Option<VerusfmtConfig> {
+        if !*self.rustfmt_verusfmt_enable() {
+            return None;
+        }
+        match &self.rustfmt_verusfmt_overrideCommand() {
+            Some(args) if !args.is_empty() => {
+                let mut args = args.clone();
+                let command = args.remove(0);
+                Some(VerusfmtConfig::CustomCommand { command, args })
+            }
+            Some(_) | None => Some(VerusfmtConfig::Bundled),
+        }
+    }
+
     pub fn flycheck_workspace(&self) -> bool {
         *self.check_workspace()
     }
@@ -1939,7 +2032,20 @@ impl Config {
                     },
                 }
             }
-            Some(_) | None => FlycheckConfig::VerusCommand { args: self.check_extra_args() },
+            Some(_) | None => FlycheckConfig::VerusCommand { options: self.verus_options() },
+        }
+    }
+
+    pub fn verus_options(&self) -> flycheck::VerusOptions {
+        flycheck::VerusOptions {
+            binary_path: self.verus_binaryPath().clone(),
+            extra_args: self.verus_extraArgs().clone(),
+            rlimit: *self.verus_rlimit(),
+            per_function: *self.verus_perFunction(),
+            z3_path: self.verus_z3Path().clone(),
+            cvc5_path: self.verus_cvc5Path().clone(),
+            import: self.verus_import().clone(),
+            export: self.verus_export().clone(),
         }
     }
 
@@ -1958,6 +2064,32 @@ impl Config {
         *self.checkOnSave()
     }
 
+    pub fn verify_on_save(&self) -> bool {
+        *self.verus_verifyOnSave()
+    }
+
+    pub fn verification_cache(&self) -> bool {
+        *self.verus_cache_enable()
+    }
+
+    pub fn verus_warm_up(&self) -> bool {
+        *self.verus_warmUp_enable()
+    }
+
+    fn vstd(&self) -> Option<project_model::Vstd> {
+        if !*self.verus_vstd_enable() {
+            return None;
+        }
+        match self.verus_vstd_path() {
+            Some(path) => project_model::Vstd::load(self.root_path.join(path)),
+            None => project_model::Vstd::discover(self.verus_binaryPath().as_deref()),
+        }
+    }
+
+    pub fn verus_time_budget(&self) -> Option<u64> {
+        self.verus_slowProofs_timeBudget().map(|it| it as u64)
+    }
+
     pub fn script_rebuild_on_save(&self) -> bool {
         *self.cargo_buildScripts_rebuildOnSave()
     }
@@ -2001,6 +2133,7 @@ impl Config {
             refs_adt: *self.lens_enable() && *self.lens_references_adt_enable(),
             refs_trait: *self.lens_enable() && *self.lens_references_trait_enable(),
             enum_variant_refs: *self.lens_enable() && *self.lens_references_enumVariant_enable(),
+            verification: *self.lens_enable() && *self.lens_verification_enable(),
             location: *self.lens_location(),
         }
     }
@@ -2061,6 +2194,7 @@ impl Config {
             debug_single: get("rust-analyzer.debugSingle"),
             show_reference: get("rust-analyzer.showReferences"),
             goto_location: get("rust-analyzer.gotoLocation"),
+            run_flycheck: get("verus-analyzer.runFlycheck"),
             trigger_parameter_hints: get("editor.action.triggerParameterHints"),
         }
     }
@@ -2432,6 +2566,26 @@ enum WorkspaceSymbolSearchKindDef {
     AllSymbols,
 }
 
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum DiagnosticSeverityDef {
+    Error,
+    Warning,
+    Information,
+    Hint,
+}
+
+impl From<DiagnosticSeverityDef> for lsp_types::DiagnosticSeverity {
+    fn from(severity: DiagnosticSeverityDef) -> Self {
+        match severity {
+            DiagnosticSeverityDef::Error => lsp_types::DiagnosticSeverity::ERROR,
+            DiagnosticSeverityDef::Warning => lsp_types::DiagnosticSeverity::WARNING,
+            DiagnosticSeverityDef::Information => lsp_types::DiagnosticSeverity::INFORMATION,
+            DiagnosticSeverityDef::Hint => lsp_types::DiagnosticSeverity::HINT,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
 enum MemoryLayoutHoverRenderKindDef {
@@ -3174,6 +3328,23 @@ fn field_props(field: &str, ty: &str, doc: &[&str], default: &str) -> serde_json
                 "`hide`: Shows `...` for every closure type",
             ],
         },
+        "Option<DiagnosticSeverityDef>" => set! {
+            "anyOf": [
+                {
+                    "type": "null"
+                },
+                {
+                    "type": "string",
+                    "enum": ["error", "warning", "information", "hint"],
+                    "enumDescriptions": [
+                        "Report as an error",
+                        "Report as a warning",
+                        "Report as information",
+                        "Report as a hint"
+                    ],
+                },
+            ],
+        },
         "Option<MemoryLayoutHoverRenderKindDef>" => set! {
             "anyOf": [
                 {
@@ -21,6 +21,18 @@ pub struct DiagnosticsMapConfig {
     pub warnings_as_info: Vec<String>,
     pub warnings_as_hint: Vec<String>,
     pub check_ignore: FxHashSet<String>,
+    pub verus_severity: VerusSeverityMap,
+}
+
+/// Severities to report classes of Verus diagnostics with, `None` keeps the level Verus uses.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct VerusSeverityMap {
+    /// Verification failures: failed assertions, pre- and postconditions, invariants, etc.
+    pub failures: Option<lsp_types::DiagnosticSeverity>,
+    /// `recommends` clauses that are not met.
+    pub recommends: Option<lsp_types::DiagnosticSeverity>,
+    /// Notes about triggers, e.g. the ones Verus chose automatically.
+    pub trigger_notes: Option<lsp_types::DiagnosticSeverity>,
 }
 
 pub(crate) type DiagnosticsGeneration = usize;
@@ -57,6 +69,29 @@ impl DiagnosticCollection {
         }
     }
 
+    /// Clears the check diagnostics of flycheck `flycheck_id` in `range` of `file_id`, along with
+    /// their fixes.
+    pub(crate) fn clear_check_in(
+        &mut self,
+        flycheck_id: usize,
+        file_id: FileId,
+        range: lsp_types::Range,
+    ) {
+        let in_range = |it: &lsp_types::Range| range.start <= it.start && it.end <= range.end;
+        if let Some(fixes) = Arc::make_mut(&mut self.check_fixes)
+            .get_mut(&flycheck_id)
+            .and_then(|it| it.get_mut(&file_id))
+        {
+            fixes.retain(|fix| !fix.ranges.iter().all(in_range));
+        }
+        if let Some(diagnostics) =
+            self.check.get_mut(&flycheck_id).and_then(|it| it.get_mut(&file_id))
+        {
+            diagnostics.retain(|it| !in_range(&it.range));
+            self.changes.insert(file_id);
+        }
+    }
+
     pub(crate) fn clear_check_all(&mut self) {
         Arc::make_mut(&mut self.check_fixes).clear();
         self.changes
@@ -2,14 +2,18 @@
 //! `cargo check` json format to the LSP diagnostic format.
 
 use flycheck::{Applicability, DiagnosticLevel, DiagnosticSpan};
+use ide::FileRange;
 use itertools::Itertools;
 use rustc_hash::FxHashMap;
 use stdx::format_to;
 use vfs::{AbsPath, AbsPathBuf};
 
 use crate::{
-    global_state::GlobalStateSnapshot, line_index::PositionEncoding,
-    lsp::to_proto::url_from_abs_path, lsp_ext,
+    global_state::GlobalStateSnapshot,
+    line_index::PositionEncoding,
+    lsp::{from_proto, to_proto::url_from_abs_path},
+    lsp_ext,
+    verus_interaction::ChosenTriggers,
 };
 
 use super::{DiagnosticsMapConfig, Fix};
@@ -49,6 +53,30 @@ fn diagnostic_severity(
     Some(res)
 }
 
+/// Verus reports its diagnostics without a code, so they are classified by message.
+fn verus_severity(
+    config: &DiagnosticsMapConfig,
+    rd: &flycheck::Diagnostic,
+) -> Option<lsp_types::DiagnosticSeverity> {
+    const FAILURES: &[&str] = &[
+        "assertion failed",
+        "not satisfied",
+        "possible arithmetic underflow/overflow",
+        "possible division by zero",
+    ];
+    if rd.code.is_some() {
+        return None;
+    }
+    let message = rd.message.as_str();
+    let map = &config.verus_severity;
+    match rd.level {
+        _ if message.contains("recommendation not met") => map.recommends,
+        DiagnosticLevel::Note if message.contains("trigger") => map.trigger_notes,
+        DiagnosticLevel::Error if FAILURES.iter().any(|it| message.contains(it)) => map.failures,
+        _ => None,
+    }
+}
+
 /// Checks whether a file name is from macro invocation and does not refer to an actual file.
 fn is_dummy_macro_file(file_name: &str) -> bool {
     // FIXME: current rustc does not seem to emit `<macro file>` files anymore?
@@ -114,6 +142,61 @@ fn position(
     }
 }
 
+/// rustc runs its lints after Verus erased ghost code, so items only used from `broadcast group`s,
+/// `via` clauses or triggers are reported as dead code.
+fn is_referenced_from_ghost_code(
+    config: &DiagnosticsMapConfig,
+    workspace_root: &AbsPath,
+    span: &DiagnosticSpan,
+    snap: &GlobalStateSnapshot,
+) -> bool {
+    let location = primary_location(config, workspace_root, span, snap);
+    let params = lsp_types::TextDocumentPositionParams::new(
+        lsp_types::TextDocumentIdentifier::new(location.uri),
+        location.range.start,
+    );
+    let Ok(position) = from_proto::file_position(snap, params) else { return false };
+    snap.analysis.is_referenced_from_ghost_code(position).unwrap_or(false)
+}
+
+/// Verus reports the triggers it chose for a quantifier as a note spanning the quantifier, with a
+/// child note per trigger spanning its terms.
+pub(crate) fn verus_chosen_triggers(
+    config: &DiagnosticsMapConfig,
+    rd: &flycheck::Diagnostic,
+    workspace_root: &AbsPath,
+    snap: &GlobalStateSnapshot,
+) -> Option<ChosenTriggers> {
+    if !matches!(rd.level, DiagnosticLevel::Note) || !rd.message.contains("chose triggers") {
+        return None;
+    }
+    let to_file_range = |span: &DiagnosticSpan| {
+        let location = location(config, workspace_root, span, snap);
+        let file_id = snap.url_to_file_id(&location.uri).ok()?;
+        let line_index = snap.file_line_index(file_id).ok()?;
+        let range = from_proto::text_range(&line_index, location.range).ok()?;
+        Some(FileRange { file_id, range })
+    };
+    let quantifier = to_file_range(rd.spans.iter().find(|it| it.is_primary)?)?;
+    let term = |span: &DiagnosticSpan| {
+        to_file_range(span).filter(|it| it.file_id == quantifier.file_id).map(|it| it.range)
+    };
+    let mut triggers: Vec<Vec<_>> =
+        rd.children.iter().map(|it| it.spans.iter().filter_map(term).collect()).collect();
+    triggers.retain(|it| !it.is_empty());
+    if triggers.is_empty() {
+        // Without per-trigger notes, each secondary span is a single-term trigger.
+        triggers = rd
+            .spans
+            .iter()
+            .filter(|it| !it.is_primary)
+            .filter_map(term)
+            .map(|it| vec![it])
+            .collect();
+    }
+    Some(ChosenTriggers { quantifier, triggers })
+}
+
 /// Extracts a suitable "primary" location from a rustc diagnostic.
 ///
 /// This takes locations pointing into the standard library, or generally outside the current
@@ -293,7 +376,8 @@ pub(crate) fn map_rust_diagnostic_to_lsp(
         return Vec::new();
     }
 
-    let severity = diagnostic_severity(config, rd.level, rd.code.clone());
+    let severity = verus_severity(config, rd)
+        .or_else(|| diagnostic_severity(config, rd.level, rd.code.clone()));
 
     let mut source = String::from("rustc");
     let mut code = rd.code.as_ref().map(|c| c.code.clone());
@@ -302,6 +386,11 @@ pub(crate) fn map_rust_diagnostic_to_lsp(
         if config.check_ignore.contains(code_val) {
             return Vec::new();
         }
+        if code_val == "dead_code"
+            && is_referenced_from_ghost_code(config, workspace_root, primary_spans[0], snap)
+        {
+            return Vec::new();
+        }
     }
 
     if let Some(code_val) = &code {
@@ -367,6 +456,44 @@ pub(crate) fn map_rust_diagnostic_to_lsp(
         _ => None,
     };
 
+    if let Some((site, clause)) = verus_pre_post_spans(rd) {
+        // Keep the failing clause as related information of the check site, rather than
+        // splitting the pair into disconnected diagnostics.
+        let site_location = primary_location(config, workspace_root, site, snap);
+        let clause_location = location(config, workspace_root, clause, snap);
+        let mut message = message;
+        if let Some(label) = &site.label {
+            format_to!(message, "\n{}", label);
+        }
+        // Secondary spans were already turned into related information above, skip those.
+        let site_span_location = location(config, workspace_root, site, snap);
+        let other_related = subdiagnostics
+            .into_iter()
+            .map(|sub| sub.related)
+            .filter(|it| it.location != clause_location && it.location != site_span_location);
+        let related_information = std::iter::once(lsp_types::DiagnosticRelatedInformation {
+            location: clause_location.clone(),
+            message: clause.label.clone().unwrap_or_else(|| rd.message.clone()),
+        })
+        .chain(other_related)
+        .collect();
+        return vec![MappedRustDiagnostic {
+            url: site_location.uri,
+            diagnostic: lsp_types::Diagnostic {
+                range: site_location.range,
+                severity,
+                code: code.map(lsp_types::NumberOrString::String),
+                code_description,
+                source: Some(source),
+                message,
+                related_information: Some(related_information),
+                tags: None,
+                data: Some(serde_json::json!({ "rendered": rd.rendered })),
+            },
+            fix: None,
+        }];
+    }
+
     primary_spans
         .iter()
         .flat_map(|primary_span| {
@@ -497,6 +624,29 @@ pub(crate) fn map_rust_diagnostic_to_lsp(
         .collect()
 }
 
+/// Verus reports a failed pre- or postcondition with two spans: the failing `requires` or
+/// `ensures` clause, and the callsite or exit point where it was checked.
+///
+/// Returns `(site, clause)` for such diagnostics.
+fn verus_pre_post_spans(rd: &flycheck::Diagnostic) -> Option<(&DiagnosticSpan, &DiagnosticSpan)> {
+    if !rd.message.contains("precondition not satisfied")
+        && !rd.message.contains("postcondition not satisfied")
+    {
+        return None;
+    }
+    let [a, b] = &rd.spans[..] else { return None };
+    // Verus labels the clause "failed precondition" / "failed this postcondition".
+    let is_clause = |span: &DiagnosticSpan| match &span.label {
+        Some(label) => label.starts_with("failed"),
+        None => !span.is_primary,
+    };
+    match (is_clause(a), is_clause(b)) {
+        (false, true) => Some((a, b)),
+        (true, false) => Some((b, a)),
+        _ => None,
+    }
+}
+
 fn rustc_code_description(code: Option<&str>) -> Option<lsp_types::CodeDescription> {
     code.filter(|code| {
         let mut chars = code.chars();
@@ -524,7 +674,7 @@ fn clippy_code_description(code: Option<&str>) -> Option<lsp_types::CodeDescript
 #[cfg(test)]
 #[cfg(not(windows))]
 mod tests {
-    use crate::{config::Config, global_state::GlobalState};
+    use crate::{config::Config, diagnostics::VerusSeverityMap, global_state::GlobalState};
 
     use super::*;
 
@@ -1858,4 +2008,127 @@ mod tests {
             expect_file!["./test_data/reasonable_line_numbers_from_empty_file.txt"],
         );
     }
+
+    #[test]
+    fn verus_precondition_failure() {
+        check(
+            r##"{
+    "message": "precondition not satisfied",
+    "code": null,
+    "level": "error",
+    "spans": [
+        {
+            "file_name": "src/lib.rs",
+            "byte_start": 52,
+            "byte_end": 57,
+            "line_start": 3,
+            "line_end": 3,
+            "column_start": 14,
+            "column_end": 19,
+            "is_primary": false,
+            "text": [
+                {
+                    "text": "    requires x > 0,",
+                    "highlight_start": 14,
+                    "highlight_end": 19
+                }
+            ],
+            "label": "failed precondition",
+            "suggested_replacement": null,
+            "suggestion_applicability": null,
+            "expansion": null
+        },
+        {
+            "file_name": "src/lib.rs",
+            "byte_start": 103,
+            "byte_end": 107,
+            "line_start": 9,
+            "line_end": 9,
+            "column_start": 5,
+            "column_end": 9,
+            "is_primary": true,
+            "text": [
+                {
+                    "text": "    f(0);",
+                    "highlight_start": 5,
+                    "highlight_end": 9
+                }
+            ],
+            "label": null,
+            "suggested_replacement": null,
+            "suggestion_applicability": null,
+            "expansion": null
+        }
+    ],
+    "children": [],
+    "rendered": "error: precondition not satisfied\n --> src/lib.rs:9:5\n  |\n3 |     requires x > 0,\n  |              ----- failed precondition\n...\n9 |     f(0);\n  |     ^^^^\n\n"
+}"##,
+            expect_file!["./test_data/verus_precondition_failure.txt"],
+        );
+    }
+
+    #[test]
+    fn verus_failure_severity_remapped() {
+        check_with_config(
+            DiagnosticsMapConfig {
+                verus_severity: VerusSeverityMap {
+                    failures: Some(lsp_types::DiagnosticSeverity::WARNING),
+                    ..VerusSeverityMap::default()
+                },
+                ..DiagnosticsMapConfig::default()
+            },
+            r##"{
+    "message": "precondition not satisfied",
+    "code": null,
+    "level": "error",
+    "spans": [
+        {
+            "file_name": "src/lib.rs",
+            "byte_start": 52,
+            "byte_end": 57,
+            "line_start": 3,
+            "line_end": 3,
+            "column_start": 14,
+            "column_end": 19,
+            "is_primary": false,
+            "text": [
+                {
+                    "text": "    requires x > 0,",
+                    "highlight_start": 14,
+                    "highlight_end": 19
+                }
+            ],
+            "label": "failed precondition",
+            "suggested_replacement": null,
+            "suggestion_applicability": null,
+            "expansion": null
+        },
+        {
+            "file_name": "src/lib.rs",
+            "byte_start": 103,
+            "byte_end": 107,
+            "line_start": 9,
+            "line_end": 9,
+            "column_start": 5,
+            "column_end": 9,
+            "is_primary": true,
+            "text": [
+                {
+                    "text": "    f(0);",
+                    "highlight_start": 5,
+                    "highlight_end": 9
+                }
+            ],
+            "label": null,
+            "suggested_replacement": null,
+            "suggestion_applicability": null,
+            "expansion": null
+        }
+    ],
+    "children": [],
+    "rendered": "error: precondition not satisfied\n --> src/lib.rs:9:5\n  |\n3 |     requires x > 0,\n  |              ----- failed precondition\n...\n9 |     f(0);\n  |     ^^^^\n\n"
+}"##,
+            expect_file!["./test_data/verus_failure_severity_remapped.txt"],
+        );
+    }
 }
@@ -36,9 +36,12 @@ use crate::{
     main_loop::Task,
     mem_docs::MemDocs,
     op_queue::OpQueue,
-    reload,
+    reload::{self, VerusWarmUp},
     target_spec::{CargoTargetSpec, ProjectJsonTargetSpec, TargetSpec},
     task_pool::{TaskPool, TaskQueue},
+    verification_cache::VerificationCache,
+    verification_queue::VerificationQueue,
+    verus_interaction::VerificationSummary,
 };
 
 // Enforces drop order
@@ -88,6 +91,12 @@ pub(crate) struct GlobalState {
     pub(crate) flycheck_sender: Sender<flycheck::Message>,
     pub(crate) flycheck_receiver: Receiver<flycheck::Message>,
     pub(crate) last_flycheck_error: Option<String>,
+    /// Ids of the flychecks with a verification run in flight.
+    pub(crate) verifying: FxHashSet<usize>,
+    /// The latest run of each flycheck that reported diagnostics, the ones of older runs are stale.
+    pub(crate) check_generations: FxHashMap<usize, u64>,
+    /// Verus reported errors since the verification decorations were last sent.
+    pub(crate) verification_decorations_changed: bool,
 
     // Test explorer
     pub(crate) test_run_session: Option<Vec<flycheck::CargoTestHandle>>,
@@ -152,6 +161,13 @@ pub(crate) struct GlobalState {
     pub(crate) deferred_task_queue: TaskQueue,
     // verus
     pub(crate) verus_errors: Vec<ide_assists::proof_plumber_api::verus_error::VerusError>,
+    pub(crate) verus_summary: Arc<VerificationSummary>,
+    /// Results of earlier runs, including those of previous sessions.
+    pub(crate) verification_cache: Arc<VerificationCache>,
+    /// The text of each file at its last Verus run, to only verify again what changed since.
+    pub(crate) verified_texts: FxHashMap<FileId, Arc<str>>,
+    pub(crate) verification_queue: VerificationQueue,
+    pub(crate) verus_warm_up: VerusWarmUp,
 }
 
 /// An immutable snapshot of the world's state at a point in time.
@@ -170,6 +186,9 @@ pub(crate) struct GlobalStateSnapshot {
     pub(crate) flycheck: Arc<[FlycheckHandle]>,
     // verus
     pub(crate) verus_errors: Vec<ide_assists::proof_plumber_api::verus_error::VerusError>,
+    pub(crate) verus_summary: Arc<VerificationSummary>,
+    /// Results of earlier runs, including those of previous sessions.
+    pub(crate) verification_cache: Arc<VerificationCache>,
 }
 
 impl std::panic::UnwindSafe for GlobalStateSnapshot {}
@@ -206,6 +225,11 @@ impl GlobalState {
         }
         let (flycheck_sender, flycheck_receiver) = unbounded();
         let (test_run_sender, test_run_receiver) = unbounded();
+        let verification_cache = if config.verification_cache() {
+            VerificationCache::load(&VerificationCache::path(config.root_path()))
+        } else {
+            VerificationCache::default()
+        };
         let mut this = GlobalState {
             sender,
             req_queue: ReqQueue::default(),
@@ -231,6 +255,9 @@ impl GlobalState {
             flycheck_sender,
             flycheck_receiver,
             last_flycheck_error: None,
+            verifying: FxHashSet::default(),
+            check_generations: FxHashMap::default(),
+            verification_decorations_changed: false,
 
             test_run_session: None,
             test_run_sender,
@@ -254,6 +281,11 @@ impl GlobalState {
 
             deferred_task_queue: task_queue,
             verus_errors: Vec::new(),
+            verus_summary: Arc::default(),
+            verification_cache: Arc::new(verification_cache),
+            verified_texts: FxHashMap::default(),
+            verification_queue: VerificationQueue::default(),
+            verus_warm_up: VerusWarmUp::NotStarted,
         };
         // Apply any required database inputs from the config.
         this.update_configuration(config);
@@ -445,6 +477,8 @@ impl GlobalState {
                 || *self.fetch_proc_macros_queue.last_op_result(),
             flycheck: self.flycheck.clone(),
             verus_errors: self.verus_errors.clone(),
+            verus_summary: Arc::clone(&self.verus_summary),
+            verification_cache: Arc::clone(&self.verification_cache),
         }
     }
 
@@ -3,6 +3,7 @@
 
 use std::ops::{Deref, Not as _};
 
+use crossbeam_channel::Sender;
 use itertools::Itertools;
 use lsp_types::{
     CancelParams, DidChangeConfigurationParams, DidChangeTextDocumentParams,
@@ -17,8 +18,9 @@ use crate::{
     global_state::GlobalState,
     lsp::{from_proto, utils::apply_document_changes},
     lsp_ext::{self, RunFlycheckParams},
+    main_loop::Task,
     mem_docs::DocumentData,
-    reload,
+    reload, verus_interaction,
 };
 
 pub(crate) fn handle_cancel(state: &mut GlobalState, params: CancelParams) -> anyhow::Result<()> {
@@ -162,7 +164,8 @@ pub(crate) fn handle_did_save_text_document(
             }
         }
 
-        if !state.config.check_on_save() || run_flycheck(state, vfs_path) {
+        let on_save = state.config.check_on_save() || state.config.verify_on_save();
+        if !on_save || run_flycheck(state, vfs_path) {
             return Ok(());
         }
     } else if state.config.check_on_save() {
@@ -264,8 +267,9 @@ fn run_flycheck(state: &mut GlobalState, vfs_path: VfsPath) -> bool {
     let file_id = state.vfs.read().0.file_id(&vfs_path);
     if let Some(file_id) = file_id {
         let world = state.snapshot();
+        let old_text = state.verified_texts.get(&file_id).cloned();
         let mut updated = false;
-        let task = move || -> std::result::Result<(), ide::Cancelled> {
+        let task = move |sender: Sender<Task>| -> std::result::Result<(), ide::Cancelled> {
             // Trigger flychecks for all workspaces that depend on the saved file
             // Crates containing or depending on the saved file
             let crate_ids: Vec<_> = world
@@ -322,11 +326,15 @@ fn run_flycheck(state: &mut GlobalState, vfs_path: VfsPath) -> bool {
             let saved_file = vfs_path.as_path().map(|p| p.to_owned());
 
             // Find and trigger corresponding flychecks
+            let mut verus_flychecks = Vec::new();
             for flycheck in world.flycheck.iter() {
                 for (id, package) in workspace_ids.clone() {
                     if id == flycheck.id() {
                         updated = true;
-                        flycheck.restart_verus(vfs_path.to_string());
+                        verus_flychecks.push(flycheck.id());
+                        if !world.config.check_on_save() {
+                            continue;
+                        }
                         match package.filter(|_| !world.config.flycheck_workspace()) {
                             Some(package) => flycheck.restart_for_package(package),
                             None => flycheck.restart_workspace(saved_file.clone()),
@@ -338,17 +346,36 @@ fn run_flycheck(state: &mut GlobalState, vfs_path: VfsPath) -> bool {
             // No specific flycheck was triggered, so let's trigger all of them.
             if !updated {
                 for flycheck in world.flycheck.iter() {
-                    flycheck.restart_verus(vfs_path.to_string());
-                    flycheck.restart_workspace(saved_file.clone());
+                    verus_flychecks.push(flycheck.id());
+                    if world.config.check_on_save() {
+                        flycheck.restart_workspace(saved_file.clone());
+                    }
                 }
             }
+
+            // Verus runs are queued, so that a run verifying part of the crate again does not
+            // cancel the previous one.
+            if world.config.verify_on_save() {
+                let text = world.analysis.file_text(file_id)?;
+                let runs = verus_interaction::verus_runs(
+                    &world,
+                    file_id,
+                    &vfs_path.to_string(),
+                    old_text.as_deref(),
+                    &verus_flychecks,
+                )?;
+                sender.send(Task::VerusRuns { file_id, text, runs }).unwrap();
+            }
             Ok(())
         };
-        state.task_pool.handle.spawn_with_sender(stdx::thread::ThreadIntent::Worker, move |_| {
-            if let Err(e) = std::panic::catch_unwind(task) {
-                tracing::error!("flycheck task panicked: {e:?}")
-            }
-        });
+        state.task_pool.handle.spawn_with_sender(
+            stdx::thread::ThreadIntent::Worker,
+            move |sender| {
+                if let Err(e) = std::panic::catch_unwind(move || task(sender)) {
+                    tracing::error!("flycheck task panicked: {e:?}")
+                }
+            },
+        );
         true
     } else {
         false
@@ -357,6 +384,7 @@ fn run_flycheck(state: &mut GlobalState, vfs_path: VfsPath) -> bool {
 
 pub(crate) fn handle_cancel_flycheck(state: &mut GlobalState, _: ()) -> anyhow::Result<()> {
     let _p = tracing::info_span!("handle_cancel_flycheck").entered();
+    state.verification_queue.clear();
     state.flycheck.iter().for_each(|flycheck| flycheck.cancel());
     Ok(())
 }
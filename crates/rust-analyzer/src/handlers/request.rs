@@ -11,8 +11,9 @@ use anyhow::Context;
 
 use ide::{
     AnnotationConfig, AssistKind, AssistResolveStrategy, Cancellable, FilePosition, FileRange,
-    HoverAction, HoverGotoTypeData, InlayFieldsToResolve, Query, RangeInfo, ReferenceCategory,
-    Runnable, RunnableKind, SingleResolve, SourceChange, TextEdit,
+    HoverAction, HoverGotoTypeData, HoverResult, InlayFieldsToResolve, Query, RangeInfo,
+    ReferenceCategory, Runnable, RunnableKind, SingleResolve, SourceChange, TextEdit,
+    VerusSymbolCategory,
 };
 use ide_db::SymbolKind;
 use itertools::Itertools;
@@ -35,7 +36,7 @@ use triomphe::Arc;
 use vfs::{AbsPath, AbsPathBuf, FileId, VfsPath};
 
 use crate::{
-    config::{Config, RustfmtConfig, WorkspaceSymbolConfig},
+    config::{Config, RustfmtConfig, VerusfmtConfig, WorkspaceSymbolConfig},
     diff::diff,
     global_state::{GlobalState, GlobalStateSnapshot},
     hack_recover_crate_name,
@@ -51,6 +52,7 @@ use crate::{
         FetchDependencyListResult, PositionOrRange, ViewCrateGraphParams, WorkspaceSymbolParams,
     },
     target_spec::TargetSpec,
+    verus_interaction,
 };
 
 pub(crate) fn handle_workspace_reload(state: &mut GlobalState, _: ()) -> anyhow::Result<()> {
@@ -69,6 +71,73 @@ pub(crate) fn handle_proc_macros_rebuild(state: &mut GlobalState, _: ()) -> anyh
     Ok(())
 }
 
+pub(crate) fn handle_execute_command(
+    state: &mut GlobalState,
+    params: lsp_types::ExecuteCommandParams,
+) -> anyhow::Result<Option<serde_json::Value>> {
+    let _p = tracing::info_span!("handle_execute_command", command = %params.command).entered();
+    let command = params.command;
+    if command == "verus.verifySlowProofs" {
+        state.verification_queue.resume_slow();
+        state.start_queued_verification();
+        return Ok(None);
+    }
+    if command == "verus.proofGraph" {
+        let snap = state.snapshot();
+        let graph = verus_interaction::proof_graph(&snap)?;
+        return match params.arguments.first().and_then(|it| it.as_str()) {
+            None | Some("dot") => Ok(Some(graph.to_dot().into())),
+            Some("json") => {
+                let json = verus_interaction::proof_graph_json(&graph, |range| {
+                    let line_index = snap.file_line_index(range.file_id)?;
+                    let line = to_proto::position(&line_index, range.range.start()).line + 1;
+                    Ok((snap.file_id_to_file_path(range.file_id).to_string(), line))
+                })?;
+                Ok(Some(json))
+            }
+            Some(format) => Err(invalid_params_error(format!(
+                "unknown proof graph format `{format}`, expected `dot` or `json`"
+            ))
+            .into()),
+        };
+    }
+    let mut arguments = params.arguments.into_iter().map(|it| match it {
+        serde_json::Value::String(it) => Some(it),
+        _ => None,
+    });
+    let Some(uri) = arguments.next().flatten() else {
+        return Err(invalid_params_error(format!(
+            "`{command}` expects a file URI as its first argument"
+        ))
+        .into());
+    };
+    let mut name = || {
+        arguments.next().flatten().ok_or_else(|| {
+            invalid_params_error(format!("`{command}` expects a name as its second argument"))
+        })
+    };
+    let target = match command.as_str() {
+        "verus.verifyFile" => flycheck::VerusTarget::File,
+        "verus.verifyModule" => flycheck::VerusTarget::Module(name()?),
+        "verus.verifyFunction" => flycheck::VerusTarget::Function(name()?),
+        _ => return Err(invalid_params_error(format!("unknown command `{command}`")).into()),
+    };
+
+    let vfs_path = from_proto::vfs_path(&Url::parse(&uri)?)?;
+    // Only run the flycheck of the workspace owning the file, if we can tell which one it is.
+    let owner = vfs_path.as_path().and_then(|path| {
+        state.workspaces.iter().position(|ws| {
+            ws.manifest_or_root().parent().map_or(false, |root| path.starts_with(root))
+        })
+    });
+    for flycheck in state.flycheck.iter() {
+        if owner.map_or(true, |id| id == flycheck.id()) {
+            flycheck.restart_verus_target(vfs_path.to_string(), target.clone());
+        }
+    }
+    Ok(None)
+}
+
 pub(crate) fn handle_analyzer_status(
     snap: GlobalStateSnapshot,
     params: lsp_ext::AnalyzerStatusParams,
@@ -153,6 +222,26 @@ pub(crate) fn handle_view_hir(
     Ok(res)
 }
 
+pub(crate) fn handle_view_vst(
+    snap: GlobalStateSnapshot,
+    params: lsp_types::TextDocumentPositionParams,
+) -> anyhow::Result<String> {
+    let _p = tracing::info_span!("handle_view_vst").entered();
+    let position = from_proto::file_position(&snap, params)?;
+    let res = snap.analysis.view_vst(position)?;
+    Ok(res)
+}
+
+pub(crate) fn handle_proof_obligations(
+    snap: GlobalStateSnapshot,
+    params: lsp_types::TextDocumentPositionParams,
+) -> anyhow::Result<Option<lsp_ext::ProofObligation>> {
+    let _p = tracing::info_span!("handle_proof_obligations").entered();
+    let position = from_proto::file_position(&snap, params)?;
+    let res = verus_interaction::proof_obligations(&snap, position)?;
+    Ok(res)
+}
+
 pub(crate) fn handle_view_mir(
     snap: GlobalStateSnapshot,
     params: lsp_types::TextDocumentPositionParams,
@@ -538,10 +627,12 @@ pub(crate) fn handle_workspace_symbol(
     let _p = tracing::info_span!("handle_workspace_symbol").entered();
 
     let config = snap.config.workspace_symbol();
+    let (category, text) = split_verus_category(&params.query);
     let (all_symbols, libs) = decide_search_scope_and_kind(&params, &config);
+    let all_symbols = all_symbols || category.is_some();
 
     let query = {
-        let query: String = params.query.chars().filter(|&c| c != '#' && c != '*').collect();
+        let query: String = text.chars().filter(|&c| c != '#' && c != '*').collect();
         let mut q = Query::new(query);
         if !all_symbols {
             q.only_types();
@@ -549,6 +640,9 @@ pub(crate) fn handle_workspace_symbol(
         if libs {
             q.libs();
         }
+        if let Some(category) = category {
+            q.verus_category(category);
+        }
         q
     };
     let mut res = exec_query(&snap, query, config.search_limit)?;
@@ -558,6 +652,22 @@ pub(crate) fn handle_workspace_symbol(
 
     return Ok(Some(lsp_types::WorkspaceSymbolResponse::Nested(res)));
 
+    /// Splits a leading `#spec`, `#proof`, `#lemma`, `#broadcast`, `#group` or `#global` filter off
+    /// the query.
+    fn split_verus_category(query: &str) -> (Option<VerusSymbolCategory>, &str) {
+        let Some(rest) = query.strip_prefix('#') else { return (None, query) };
+        let (word, rest) = rest.split_once(' ').unwrap_or((rest, ""));
+        let category = match word {
+            "spec" => VerusSymbolCategory::Spec,
+            "proof" | "lemma" => VerusSymbolCategory::Proof,
+            "broadcast" => VerusSymbolCategory::BroadcastProof,
+            "group" => VerusSymbolCategory::BroadcastGroup,
+            "global" => VerusSymbolCategory::Global,
+            _ => return (None, query),
+        };
+        (Some(category), rest.trim_start())
+    }
+
     fn decide_search_scope_and_kind(
         params: &WorkspaceSymbolParams,
         config: &WorkspaceSymbolConfig,
@@ -1079,9 +1189,18 @@ pub(crate) fn handle_hover(
     let file_range = from_proto::file_range(&snap, &params.text_document, range)?;
 
     let hover = snap.config.hover();
-    let info = match snap.analysis.hover(&hover, file_range)? {
-        None => return Ok(None),
-        Some(info) => info,
+    let triggers = verus_interaction::chosen_triggers_hover(&snap, file_range)?;
+    let info = match (snap.analysis.hover(&hover, file_range)?, triggers) {
+        (None, None) => return Ok(None),
+        (Some(mut info), triggers) => {
+            if let Some((_, triggers)) = triggers {
+                info.info.markup = format!("{}\n\n---\n\n{triggers}", info.info.markup).into();
+            }
+            info
+        }
+        (None, Some((range, triggers))) => {
+            RangeInfo::new(range, HoverResult { markup: triggers.into(), actions: Vec::new() })
+        }
     };
 
     let line_index = snap.file_line_index(file_range.file_id)?;
@@ -1257,7 +1376,14 @@ pub(crate) fn handle_code_action(
         frange,
         snap.verus_errors.clone(),
     )?;
+    // Proof actions fixing the Verus failure under the cursor, already resolved.
+    let verus_fixes =
+        snap.analysis.verus_quickfixes(&assists_config, frange, snap.verus_errors.clone())?;
     for (index, assist) in assists.into_iter().enumerate() {
+        // Offer those once, as a quick fix.
+        if verus_fixes.iter().any(|fix| fix.id.0 == assist.id.0 && fix.target == assist.target) {
+            continue;
+        }
         let resolve_data = if code_action_resolve_cap {
             Some((index, params.clone(), snap.file_version(file_id)))
         } else {
@@ -1278,6 +1404,12 @@ pub(crate) fn handle_code_action(
         res.push(code_action)
     }
 
+    for fix in verus_fixes {
+        let mut code_action = to_proto::code_action(&snap, fix, None)?;
+        code_action.is_preferred = Some(true);
+        res.push(code_action);
+    }
+
     // Fixes from `cargo check`.
     for fix in snap.check_fixes.values().filter_map(|it| it.get(&frange.file_id)).flatten() {
         // FIXME: this mapping is awkward and shouldn't exist. Refactor
@@ -1429,6 +1561,9 @@ pub(crate) fn handle_code_lens(
     for a in annotations {
         to_proto::code_lens(&mut res, &snap, a)?;
     }
+    if lens_config.verification {
+        verus_interaction::verification_lenses(&mut res, &snap, file_id)?;
+    }
 
     Ok(Some(res))
 }
@@ -2201,7 +2336,17 @@ fn run_rustfmt(
         };
     }
 
-    let (new_text, new_line_endings) = LineEndings::normalize(captured_stdout);
+    let (mut new_text, new_line_endings) = LineEndings::normalize(captured_stdout);
+
+    // rustfmt leaves the bodies of `verus!` invocations untouched, verusfmt formats those and
+    // nothing else, so running it on rustfmt's output yields the fully formatted file.
+    if let Some(verusfmt) = snap.config.verusfmt().filter(|_| new_text.contains("verus!")) {
+        match run_verusfmt(snap, &verusfmt, &new_text) {
+            Ok(text) => new_text = LineEndings::normalize(text).0,
+            // Most likely a syntax error inside a `verus!` block, keep the rustfmt result.
+            Err(err) => tracing::warn!(?err, "verusfmt failed"),
+        }
+    }
 
     if line_index.endings != new_line_endings {
         // If line endings are different, send the entire file.
@@ -2215,8 +2360,58 @@ fn run_rustfmt(
         // The document is already formatted correctly -- no edits needed.
         Ok(None)
     } else {
-        Ok(Some(to_proto::text_edit_vec(&line_index, diff(&file, &new_text))))
+        let mut edit = diff(&file, &new_text);
+        if let Some(range) = range {
+            // verusfmt always formats whole `verus!` blocks, drop its edits outside the range.
+            let frange = from_proto::file_range(snap, &text_document, range)?;
+            let mut builder = TextEdit::builder();
+            edit.into_iter()
+                .filter(|indel| indel.delete.intersect(frange.range).is_some())
+                .for_each(|indel| builder.replace(indel.delete, indel.insert));
+            edit = builder.finish();
+        }
+        Ok(Some(to_proto::text_edit_vec(&line_index, edit)))
+    }
+}
+
+fn run_verusfmt(
+    snap: &GlobalStateSnapshot,
+    config: &VerusfmtConfig,
+    text: &str,
+) -> anyhow::Result<String> {
+    let (command, args) = match config {
+        VerusfmtConfig::Bundled => {
+            let options = verusfmt::RunOptions {
+                file_name: None,
+                run_rustfmt: false,
+                rustfmt_config: Default::default(),
+            };
+            return verusfmt::run(text, options).map_err(|err| anyhow::anyhow!("{err}"));
+        }
+        VerusfmtConfig::CustomCommand { command, args } => (command, args),
+    };
+
+    let mut command = process::Command::new(command);
+    command.envs(snap.config.extra_env()).args(args);
+    tracing::debug!(?command, "created verusfmt command");
+
+    let mut verusfmt = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context(format!("Failed to spawn {command:?}"))?;
+    verusfmt.stdin.as_mut().unwrap().write_all(text.as_bytes())?;
+
+    let output = verusfmt.wait_with_output()?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "verusfmt exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        );
     }
+    Ok(String::from_utf8(output.stdout)?)
 }
 
 pub(crate) fn fetch_dependency_list(
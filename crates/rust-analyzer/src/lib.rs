@@ -12,6 +12,8 @@
 #![warn(rust_2018_idioms, unused_lifetimes)]
 
 pub mod cli;
+mod verification_cache;
+mod verification_queue;
 mod verus_interaction;
 
 mod caps;
@@ -125,6 +125,81 @@ impl Request for ViewHir {
     const METHOD: &'static str = "verus-analyzer/viewHir";
 }
 
+pub enum ViewVst {}
+
+impl Request for ViewVst {
+    type Params = lsp_types::TextDocumentPositionParams;
+    type Result = String;
+    const METHOD: &'static str = "verus-analyzer/viewVst";
+}
+
+pub enum ProofObligations {}
+
+impl Request for ProofObligations {
+    type Params = lsp_types::TextDocumentPositionParams;
+    type Result = Option<ProofObligation>;
+    const METHOD: &'static str = "verus-analyzer/proofObligations";
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofObligation {
+    pub kind: ProofObligationKind,
+    pub label: String,
+    pub location: lsp_types::Location,
+    pub status: VerificationStatus,
+    pub children: Vec<ProofObligation>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum ProofObligationKind {
+    Function,
+    Call,
+    Requires,
+    Ensures,
+    Assert,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum VerificationStatus {
+    Unknown,
+    Verified,
+    Failed,
+}
+
+pub enum VerificationDecorations {}
+
+impl Notification for VerificationDecorations {
+    type Params = VerificationDecorationsParams;
+    const METHOD: &'static str = "verus-analyzer/verificationDecorations";
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationDecorationsParams {
+    pub text_document: TextDocumentIdentifier,
+    pub functions: Vec<FunctionDecoration>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FunctionDecoration {
+    pub range: Range,
+    pub state: DecorationState,
+    /// Primary ranges of the errors reported inside the function, e.g. failing `assert`s.
+    pub failures: Vec<Range>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum DecorationState {
+    Verified,
+    Failed,
+    Running,
+}
+
 pub enum ViewMir {}
 
 impl Request for ViewMir {
@@ -455,6 +530,9 @@ pub enum RunnableArgs {
 pub enum RunnableKind {
     Cargo,
     Shell,
+    /// Runs Verus on a function or module, with [`ShellRunnableArgs`].
+    #[serde(rename = "verusVerify")]
+    VerusVerify,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
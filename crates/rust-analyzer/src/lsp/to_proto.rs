@@ -18,6 +18,7 @@ use itertools::Itertools;
 use paths::{Utf8Component, Utf8Prefix};
 use semver::VersionReq;
 use serde_json::to_value;
+use stdx::format_to;
 use vfs::AbsPath;
 
 use crate::{
@@ -87,6 +88,8 @@ pub(crate) fn structure_node_kind(kind: StructureNodeKind) -> lsp_types::SymbolK
     match kind {
         StructureNodeKind::SymbolKind(symbol) => symbol_kind(symbol),
         StructureNodeKind::Region => lsp_types::SymbolKind::NAMESPACE,
+        StructureNodeKind::BroadcastGroup => lsp_types::SymbolKind::PACKAGE,
+        StructureNodeKind::Clause => lsp_types::SymbolKind::KEY,
     }
 }
 
@@ -423,10 +426,26 @@ pub(crate) fn signature_help(
         }
     };
 
-    let documentation = call_info.doc.filter(|_| config.docs).map(|doc| {
+    let mut documentation =
+        call_info.doc.filter(|_| config.docs).map(|doc| format_docs(&doc)).unwrap_or_default();
+    if !call_info.requires.is_empty() {
+        if !documentation.is_empty() {
+            documentation.push_str("\n\n---\n\n");
+        }
+        documentation.push_str("**requires**\n");
+        // Conditions mentioning the parameter currently being edited are emphasized.
+        for (cond, mentions_active) in &call_info.requires {
+            if *mentions_active {
+                format_to!(documentation, "\n- **`{cond}`**");
+            } else {
+                format_to!(documentation, "\n- `{cond}`");
+            }
+        }
+    }
+    let documentation = (!documentation.is_empty()).then(|| {
         lsp_types::Documentation::MarkupContent(lsp_types::MarkupContent {
             kind: lsp_types::MarkupKind::Markdown,
-            value: format_docs(&doc),
+            value: documentation,
         })
     });
 
@@ -777,7 +796,8 @@ pub(crate) fn folding_range(
         | FoldKind::WhereClause
         | FoldKind::ReturnType
         | FoldKind::Array
-        | FoldKind::MatchArm => None,
+        | FoldKind::MatchArm
+        | FoldKind::VerusClause => None,
     };
 
     let range = range(line_index, fold.range);
@@ -1358,6 +1378,10 @@ pub(crate) fn runnable(
     snap: &GlobalStateSnapshot,
     runnable: Runnable,
 ) -> Cancellable<Option<lsp_ext::Runnable>> {
+    if let ide::RunnableKind::VerusVerify { target } = &runnable.kind {
+        let target = target.clone();
+        return verus_runnable(snap, runnable, target);
+    }
     let config = snap.config.runnables();
     let target_spec = TargetSpec::for_file(snap, runnable.nav.file_id)?;
 
@@ -1443,6 +1467,41 @@ pub(crate) fn runnable(
     }
 }
 
+/// Verification runnables run the same Verus command as `verus.verifyFunction` and friends.
+fn verus_runnable(
+    snap: &GlobalStateSnapshot,
+    runnable: Runnable,
+    target: ide::VerifyTarget,
+) -> Cancellable<Option<lsp_ext::Runnable>> {
+    let flycheck::FlycheckConfig::VerusCommand { options } = snap.config.flycheck() else {
+        return Ok(None);
+    };
+    let root = match TargetSpec::for_file(snap, runnable.nav.file_id)? {
+        Some(TargetSpec::Cargo(spec)) => spec.workspace_root,
+        _ => snap.config.root_path().clone(),
+    };
+    let target = match target {
+        ide::VerifyTarget::File => flycheck::VerusTarget::File,
+        ide::VerifyTarget::Module(path) => flycheck::VerusTarget::Module(path),
+        ide::VerifyTarget::Function(name) => flycheck::VerusTarget::Function(name),
+    };
+    let file = snap.file_id_to_file_path(runnable.nav.file_id).to_string();
+    let command = flycheck::verus_command(&root, &options, &file, target);
+
+    let label = runnable.label(None);
+    let location = location_link(snap, None, runnable.nav)?;
+    Ok(Some(lsp_ext::Runnable {
+        label,
+        location: Some(location),
+        kind: lsp_ext::RunnableKind::VerusVerify,
+        args: lsp_ext::RunnableArgs::Shell(ShellRunnableArgs {
+            program: command.get_program().to_string_lossy().into_owned(),
+            args: command.get_args().map(|it| it.to_string_lossy().into_owned()).collect(),
+            cwd: root.into(),
+        }),
+    }))
+}
+
 pub(crate) fn code_lens(
     acc: &mut Vec<lsp_types::CodeLens>,
     snap: &GlobalStateSnapshot,
@@ -1456,7 +1515,7 @@ pub(crate) fn code_lens(
 
             let title = run.title();
             let can_debug = match run.kind {
-                ide::RunnableKind::DocTest { .. } => false,
+                ide::RunnableKind::DocTest { .. } | ide::RunnableKind::VerusVerify { .. } => false,
                 ide::RunnableKind::TestMod { .. }
                 | ide::RunnableKind::Test { .. }
                 | ide::RunnableKind::Bench { .. }
@@ -1704,6 +1763,35 @@ pub(crate) mod command {
         })
     }
 
+    pub(crate) fn goto_range(
+        snap: &GlobalStateSnapshot,
+        title: String,
+        frange: FileRange,
+    ) -> Option<lsp_types::Command> {
+        let location = location(snap, frange).ok()?;
+        let value = if snap.config.location_link() {
+            let link = lsp_types::LocationLink {
+                origin_selection_range: None,
+                target_uri: location.uri,
+                target_range: location.range,
+                target_selection_range: location.range,
+            };
+            to_value(link).ok()?
+        } else {
+            to_value(location).ok()?
+        };
+
+        Some(lsp_types::Command {
+            title,
+            command: "rust-analyzer.gotoLocation".into(),
+            arguments: Some(vec![value]),
+        })
+    }
+
+    pub(crate) fn run_flycheck(title: String) -> lsp_types::Command {
+        lsp_types::Command { title, command: "verus-analyzer.runFlycheck".into(), arguments: None }
+    }
+
     pub(crate) fn trigger_parameter_hints() -> lsp_types::Command {
         lsp_types::Command {
             title: "triggerParameterHints".into(),
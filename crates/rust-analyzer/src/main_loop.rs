@@ -2,18 +2,21 @@
 //! requests/replies and notifications back to the client.
 
 use std::{
-    fmt,
+    fmt, mem,
     ops::Div as _,
     time::{Duration, Instant},
 };
 
 use always_assert::always;
 use crossbeam_channel::{select, Receiver};
-use ide_db::base_db::{SourceDatabase, SourceDatabaseExt, VfsPath};
+use ide::TextRange;
+use ide_assists::proof_plumber_api::verus_error::VerusError;
+use ide_db::base_db::{FileRange, SourceDatabase, SourceDatabaseExt, VfsPath};
 use lsp_server::{Connection, Notification, Request};
 use lsp_types::{notification::Notification as _, TextDocumentIdentifier};
 use stdx::thread::ThreadIntent;
 use tracing::{span, Level};
+use triomphe::Arc;
 use vfs::FileId;
 
 use crate::{
@@ -27,7 +30,9 @@ use crate::{
         utils::{notification_is, Progress},
     },
     lsp_ext,
-    reload::{BuildDataProgress, ProcMacroProgress, ProjectWorkspaceProgress},
+    reload::{BuildDataProgress, ProcMacroProgress, ProjectWorkspaceProgress, VerusWarmUp},
+    verification_cache::VerificationCache,
+    verification_queue::QueuedRun,
     verus_interaction,
 };
 
@@ -96,6 +101,8 @@ pub(crate) enum Task {
     FetchBuildData(BuildDataProgress),
     LoadProcMacros(ProcMacroProgress),
     BuildDepsHaveChanged,
+    VerusRuns { file_id: FileId, text: Arc<str>, runs: Vec<QueuedRun> },
+    VerusWarmUp(VerusWarmUp),
 }
 
 #[derive(Debug)]
@@ -332,6 +339,10 @@ impl GlobalState {
                 while let Ok(message) = self.flycheck_receiver.try_recv() {
                     self.handle_flycheck_msg(message);
                 }
+                // Show the failures of a long Verus run as they arrive rather than once it ends.
+                if mem::take(&mut self.verification_decorations_changed) {
+                    self.update_verification_decorations();
+                }
             }
             Event::TestResult(message) => {
                 let _p = tracing::info_span!("GlobalState::handle_event/test_result").entered();
@@ -576,6 +587,44 @@ impl GlobalState {
         });
     }
 
+    /// Sends the per-function verification state of each open file, for end-of-line decorations.
+    fn update_verification_decorations(&mut self) {
+        if !self.config.verification_decorations() {
+            return;
+        }
+        let snap = self.snapshot();
+        let running = !self.verifying.is_empty();
+        let files = {
+            let vfs = &self.vfs.read().0;
+            self.mem_docs.iter().filter_map(|path| vfs.file_id(path)).collect::<Vec<_>>()
+        };
+        for file_id in files {
+            match verus_interaction::verification_decorations(&snap, file_id, running) {
+                Ok(params) => self.send_notification::<lsp_ext::VerificationDecorations>(params),
+                Err(err) => tracing::error!("failed to compute verification decorations: {err}"),
+            }
+        }
+    }
+
+    /// Records the results of the finished Verus run for the functions of each open file, and
+    /// writes the cache out in the background.
+    fn update_verification_cache(&mut self) {
+        let snap = self.snapshot();
+        let files = {
+            let vfs = &self.vfs.read().0;
+            self.mem_docs.iter().filter_map(|path| vfs.file_id(path)).collect::<Vec<_>>()
+        };
+        let cache = Arc::make_mut(&mut self.verification_cache);
+        for file_id in files {
+            if let Err(err) = verus_interaction::cache_results(&snap, file_id, cache) {
+                tracing::error!("failed to cache verification results: {err}");
+            }
+        }
+        let cache = Arc::clone(&self.verification_cache);
+        let path = VerificationCache::path(self.config.root_path());
+        self.task_pool.handle.spawn_with_sender(ThreadIntent::Worker, move |_| cache.save(&path));
+    }
+
     fn update_status_or_notify(&mut self) {
         let status = self.current_status();
         if self.last_reported_status.as_ref() != Some(&status) {
@@ -687,9 +736,73 @@ impl GlobalState {
             Task::DiscoverTest(tests) => {
                 self.send_notification::<lsp_ext::DiscoveredTests>(tests);
             }
+            Task::VerusRuns { file_id, text, runs } => {
+                self.verified_texts.insert(file_id, text);
+                self.verification_queue.extend(runs);
+                self.start_queued_verification();
+            }
+            Task::VerusWarmUp(state) => self.verus_warm_up = state,
+        }
+    }
+
+    /// Starts the next queued Verus run, unless one is still in progress.
+    pub(crate) fn start_queued_verification(&mut self) {
+        let Some(run) = self.verification_queue.start_next() else { return };
+        match self.flycheck.iter().find(|it| it.id() == run.flycheck_id) {
+            Some(flycheck) => flycheck.restart_verus_target(run.file, run.target),
+            // The workspace was reloaded since the run was planned.
+            None => {
+                self.verification_queue.finish(run.flycheck_id);
+                self.start_queued_verification();
+            }
         }
     }
 
+    /// Whether `generation` is older than the latest run of flycheck `id` that reported anything.
+    fn is_stale_check(&mut self, id: usize, generation: u64) -> bool {
+        let latest = self.check_generations.entry(id).or_default();
+        if generation < *latest {
+            return true;
+        }
+        *latest = generation;
+        false
+    }
+
+    /// Clears the check diagnostics of flycheck `id` that the run in progress replaces: the ones
+    /// in the function it verifies again, or all of them.
+    fn clear_replaced_check_diagnostics(&mut self, id: usize) {
+        match self.verification_queue.running(id).and_then(|it| it.scope) {
+            Some(scope) => {
+                if let Ok(line_index) = self.snapshot().file_line_index(scope.file_id) {
+                    let range = to_proto::range(&line_index, scope.range);
+                    self.diagnostics.clear_check_in(id, scope.file_id, range);
+                }
+            }
+            None => self.diagnostics.clear_check(id),
+        }
+    }
+
+    /// Clears the verification results that the run starting on flycheck `id` replaces.
+    fn clear_replaced_verification_results(&mut self, id: usize) {
+        self.clear_replaced_check_diagnostics(id);
+        let Some(scope) = self.verification_queue.running(id).and_then(|it| it.scope) else {
+            self.verus_errors = vec![];
+            self.verus_summary = Arc::default();
+            return;
+        };
+        let in_scope = |range: TextRange| scope.range.contains_range(range);
+        self.verus_errors.retain(|it| match it {
+            VerusError::Pre(pre) => !in_scope(pre.callsite),
+            VerusError::Post(post) => !in_scope(post.func_body),
+            VerusError::Assert(assert) => !in_scope(assert.range),
+        });
+        let summary = Arc::make_mut(&mut self.verus_summary);
+        summary.errors.retain(|it| it.file_id != scope.file_id || !in_scope(it.range));
+        summary
+            .triggers
+            .retain(|it| it.quantifier.file_id != scope.file_id || !in_scope(it.quantifier.range));
+    }
+
     fn handle_vfs_msg(&mut self, message: vfs::loader::Message) {
         let _p = tracing::info_span!("GlobalState::handle_vfs_msg").entered();
         let is_changed = matches!(message, vfs::loader::Message::Changed { .. });
@@ -822,14 +935,26 @@ impl GlobalState {
 
     fn handle_flycheck_msg(&mut self, message: flycheck::Message) {
         match message {
-            flycheck::Message::AddDiagnostic { id, workspace_root, diagnostic } => {
+            flycheck::Message::AddDiagnostic { id, generation, workspace_root, diagnostic } => {
+                if self.is_stale_check(id, generation) {
+                    tracing::debug!(flycheck_id = id, generation, "dropping a stale diagnostic");
+                    return;
+                }
                 // register verus errors
                 // should flush out errors on save
-                if let Some(verr) = verus_interaction::diagnostic_to_verus_err(&diagnostic) {
+                if let Some(verr) = verus_ide::verus_error(&diagnostic) {
                     self.verus_errors.push(verr)
                 };
 
                 let snap = self.snapshot();
+                if let Some(triggers) = crate::diagnostics::to_proto::verus_chosen_triggers(
+                    &self.config.diagnostics_map(),
+                    &diagnostic,
+                    &workspace_root,
+                    &snap,
+                ) {
+                    Arc::make_mut(&mut self.verus_summary).triggers.push(triggers);
+                }
                 let diagnostics = crate::diagnostics::to_proto::map_rust_diagnostic_to_lsp(
                     &self.config.diagnostics_map(),
                     &diagnostic,
@@ -838,12 +963,27 @@ impl GlobalState {
                 );
                 for diag in diagnostics {
                     match url_to_file_id(&self.vfs.read().0, &diag.url) {
-                        Ok(file_id) => self.diagnostics.add_check_diagnostic(
-                            id,
-                            file_id,
-                            diag.diagnostic,
-                            diag.fix,
-                        ),
+                        Ok(file_id) => {
+                            if diag.diagnostic.severity
+                                == Some(lsp_types::DiagnosticSeverity::ERROR)
+                            {
+                                let range = snap.file_line_index(file_id).ok().and_then(|it| {
+                                    from_proto::text_range(&it, diag.diagnostic.range).ok()
+                                });
+                                if let Some(range) = range {
+                                    Arc::make_mut(&mut self.verus_summary)
+                                        .errors
+                                        .push(FileRange { file_id, range });
+                                    self.verification_decorations_changed = true;
+                                }
+                            }
+                            self.diagnostics.add_check_diagnostic(
+                                id,
+                                file_id,
+                                diag.diagnostic,
+                                diag.fix,
+                            )
+                        }
                         Err(err) => {
                             tracing::error!(
                                 "flycheck {id}: File with cargo diagnostic not found in VFS: {}",
@@ -854,26 +994,59 @@ impl GlobalState {
                 }
             }
 
-            flycheck::Message::ClearDiagnostics { id } => self.diagnostics.clear_check(id),
+            flycheck::Message::ClearDiagnostics { id, generation } => {
+                if !self.is_stale_check(id, generation) {
+                    self.clear_replaced_check_diagnostics(id);
+                }
+            }
+
+            flycheck::Message::VerusFunctionResults { id: _, results } => {
+                let summary = Arc::make_mut(&mut self.verus_summary);
+                // Runs verifying a single function again only replace its result.
+                summary
+                    .functions
+                    .retain(|it| !results.iter().any(|new| new.function == it.function));
+                summary.functions.extend(results);
+                if self.config.verification_cache() {
+                    self.update_verification_cache();
+                }
+                if self.config.code_lens_refresh() {
+                    self.send_request::<lsp_types::request::CodeLensRefresh>((), |_, _| ());
+                }
+                self.update_verification_decorations();
+            }
 
             flycheck::Message::Progress { id, progress } => {
                 let (state, message) = match progress {
                     flycheck::Progress::DidStart => {
-                        self.diagnostics.clear_check(id);
-                        self.verus_errors = vec![]; // verus: clear out errors
+                        self.clear_replaced_verification_results(id);
+                        self.verifying.insert(id);
+                        self.update_verification_decorations();
                         (Progress::Begin, None)
                     }
                     flycheck::Progress::DidCheckCrate(target) => (Progress::Report, Some(target)),
                     flycheck::Progress::DidCancel => {
                         self.last_flycheck_error = None;
+                        self.verifying.remove(&id);
+                        // Whatever cancelled the run starts the next one once it finishes.
+                        self.verification_queue.finish(id);
+                        self.update_verification_decorations();
                         (Progress::End, None)
                     }
                     flycheck::Progress::DidFailToRestart(err) => {
+                        self.verifying.remove(&id);
+                        self.verification_queue.finish(id);
+                        self.start_queued_verification();
+                        self.update_verification_decorations();
                         self.last_flycheck_error =
                             Some(format!("cargo check failed to start: {err}"));
                         return;
                     }
                     flycheck::Progress::DidFinish(result) => {
+                        self.verifying.remove(&id);
+                        self.verification_queue.finish(id);
+                        self.start_queued_verification();
+                        self.update_verification_decorations();
                         self.last_flycheck_error =
                             result.err().map(|err| format!("cargo check failed to start: {err}"));
                         (Progress::End, None)
@@ -950,6 +1123,7 @@ impl GlobalState {
             .on_sync_mut::<lsp_ext::MemoryUsage>(handlers::handle_memory_usage)
             .on_sync_mut::<lsp_ext::ShuffleCrateGraph>(handlers::handle_shuffle_crate_graph)
             .on_sync_mut::<lsp_ext::RunTest>(handlers::handle_run_test)
+            .on_sync_mut::<lsp_request::ExecuteCommand>(handlers::handle_execute_command)
             // Request handlers which are related to the user typing
             // are run on the main thread to reduce latency:
             .on_sync::<lsp_ext::JoinLines>(handlers::handle_join_lines)
@@ -1007,6 +1181,8 @@ impl GlobalState {
             .on::<NO_RETRY, lsp_ext::ViewRecursiveMemoryLayout>(handlers::handle_view_recursive_memory_layout)
             .on::<NO_RETRY, lsp_ext::SyntaxTree>(handlers::handle_syntax_tree)
             .on::<NO_RETRY, lsp_ext::ViewHir>(handlers::handle_view_hir)
+            .on::<NO_RETRY, lsp_ext::ViewVst>(handlers::handle_view_vst)
+            .on::<NO_RETRY, lsp_ext::ProofObligations>(handlers::handle_proof_obligations)
             .on::<NO_RETRY, lsp_ext::ViewMir>(handlers::handle_view_mir)
             .on::<NO_RETRY, lsp_ext::InterpretFunction>(handlers::handle_interpret_function)
             .on::<NO_RETRY, lsp_ext::ExpandMacro>(handlers::handle_expand_macro)
@@ -60,6 +60,15 @@ pub(crate) enum ProcMacroProgress {
     End(ProcMacros),
 }
 
+/// The state of the Verus run started when the workspace loads, see [`flycheck::warm_up_verus`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum VerusWarmUp {
+    NotStarted,
+    Running,
+    Done,
+    Failed(String),
+}
+
 impl GlobalState {
     pub(crate) fn is_quiescent(&self) -> bool {
         !(self.last_reported_status.is_none()
@@ -101,7 +110,8 @@ impl GlobalState {
     pub(crate) fn current_status(&self) -> lsp_ext::ServerStatusParams {
         let mut status = lsp_ext::ServerStatusParams {
             health: lsp_ext::Health::Ok,
-            quiescent: self.is_quiescent(),
+            // Diagnostics are only final once verification is done as well.
+            quiescent: self.is_quiescent() && self.verifying.is_empty(),
             message: None,
         };
         let mut message = String::new();
@@ -196,6 +206,21 @@ impl GlobalState {
             }
         }
 
+        match self.verifying.len() {
+            0 => (),
+            1 => message.push_str("verifying (1 job)\n\n"),
+            n => format_to!(message, "verifying ({n} jobs)\n\n"),
+        }
+        match &self.verus_warm_up {
+            VerusWarmUp::NotStarted | VerusWarmUp::Done => (),
+            VerusWarmUp::Running => message.push_str("warming up Verus\n\n"),
+            VerusWarmUp::Failed(err) => {
+                status.health |= lsp_ext::Health::Warning;
+                message.push_str(err);
+                message.push_str("\n\n");
+            }
+        }
+
         if !message.is_empty() {
             status.message = Some(message.trim_end().to_owned());
         }
@@ -693,6 +718,7 @@ impl GlobalState {
             FlycheckConfig::VerusCommand { .. } => flycheck::InvocationStrategy::PerWorkspace,
         };
 
+        self.verifying.clear();
         self.flycheck = match invocation_strategy {
             flycheck::InvocationStrategy::Once => vec![FlycheckHandle::spawn(
                 0,
@@ -745,6 +771,28 @@ impl GlobalState {
             }
         }
         .into();
+        self.start_verus_warm_up();
+    }
+
+    /// Runs Verus once in the background when the first Verus workspace is loaded, so that the
+    /// first verification the user asks for does not pay for loading Verus and `vstd`. A failed
+    /// warm-up is tried again when the workspace or the configuration changes.
+    fn start_verus_warm_up(&mut self) {
+        if matches!(self.verus_warm_up, VerusWarmUp::Running | VerusWarmUp::Done)
+            || !self.config.verus_warm_up()
+            || self.flycheck.is_empty()
+        {
+            return;
+        }
+        let FlycheckConfig::VerusCommand { options } = self.config.flycheck() else { return };
+        self.verus_warm_up = VerusWarmUp::Running;
+        self.task_pool.handle.spawn_with_sender(ThreadIntent::Worker, move |sender| {
+            let state = match flycheck::warm_up_verus(&options) {
+                Ok(_) => VerusWarmUp::Done,
+                Err(err) => VerusWarmUp::Failed(err),
+            };
+            sender.send(Task::VerusWarmUp(state)).unwrap();
+        });
     }
 }
 
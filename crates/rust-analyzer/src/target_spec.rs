@@ -99,6 +99,7 @@ impl ProjectJsonTargetSpec {
             RunnableKind::TestMod { .. } => None,
             RunnableKind::Bench { .. } => None,
             RunnableKind::DocTest { .. } => None,
+            RunnableKind::VerusVerify { .. } => None,
         }
     }
 }
@@ -153,6 +154,8 @@ impl CargoTargetSpec {
                 };
                 cargo_args.push(subcommand.to_owned());
             }
+            // Verification doesn't go through cargo, see `to_proto::runnable`.
+            RunnableKind::VerusVerify { .. } => (),
         }
 
         let (allowed_features, target_required_features) = if let Some(mut spec) = spec {
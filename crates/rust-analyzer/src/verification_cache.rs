@@ -0,0 +1,95 @@
+//! Per-function verification results that outlive the session, so that reopening a project shows
+//! the last known status of each function right away.
+//!
+//! Results are stored in `target/verus-analyzer/verification-cache.json` and keyed by a hash of
+//! the function's text along with its path within the file: an edit to the function invalidates
+//! its entry, while edits elsewhere in the file do not. The time each function last took is also
+//! kept by path alone, so that the runs after an edit can be ordered by how long they should take.
+
+use std::hash::{Hash, Hasher};
+
+use itertools::Itertools;
+use paths::{AbsPath, AbsPathBuf};
+use rustc_hash::{FxHashMap, FxHasher};
+use serde::{Deserialize, Serialize};
+use syntax::{ast, AstNode};
+
+use crate::verus_interaction::fn_path_suffix;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct VerificationCache {
+    functions: FxHashMap<String, CachedResult>,
+    /// The last verification time of each function, by path within its file.
+    #[serde(default)]
+    times_ms: FxHashMap<String, u64>,
+}
+
+/// What the last Verus run that included a function reported for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct CachedResult {
+    pub(crate) success: bool,
+    pub(crate) time_ms: u64,
+    pub(crate) rlimit: Option<u64>,
+}
+
+impl VerificationCache {
+    pub(crate) fn path(root: &AbsPath) -> AbsPathBuf {
+        root.join("target").join("verus-analyzer").join("verification-cache.json")
+    }
+
+    /// Loads the cache at `path`, starting over if it is missing or unreadable.
+    pub(crate) fn load(path: &AbsPath) -> VerificationCache {
+        let text = match std::fs::read_to_string(path) {
+            Ok(it) => it,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Self::default(),
+            Err(err) => {
+                tracing::warn!("failed to read the verification cache at {path}: {err}");
+                return Self::default();
+            }
+        };
+        serde_json::from_str(&text).unwrap_or_else(|err| {
+            tracing::warn!("discarding the malformed verification cache at {path}: {err}");
+            Self::default()
+        })
+    }
+
+    pub(crate) fn save(&self, path: &AbsPath) {
+        let res = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| std::fs::write(path, serde_json::to_string(self)?));
+        if let Err(err) = res {
+            tracing::warn!("failed to write the verification cache to {path}: {err}");
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.functions.is_empty()
+    }
+
+    pub(crate) fn get(&self, func: &ast::Fn) -> Option<CachedResult> {
+        self.functions.get(&fn_key(func)?).copied()
+    }
+
+    pub(crate) fn insert(&mut self, func: &ast::Fn, result: CachedResult) {
+        if let Some(path) = fn_path_suffix(func) {
+            self.times_ms.insert(path, result.time_ms);
+        }
+        if let Some(key) = fn_key(func) {
+            self.functions.insert(key, result);
+        }
+    }
+
+    /// How long `func` took to verify the last time, even if it changed since.
+    pub(crate) fn last_time_ms(&self, func: &ast::Fn) -> Option<u64> {
+        self.times_ms.get(&fn_path_suffix(func)?).copied()
+    }
+}
+
+/// Whitespace is normalized so that reformatting a function keeps its entry.
+fn fn_key(func: &ast::Fn) -> Option<String> {
+    let mut hasher = FxHasher::default();
+    fn_path_suffix(func)?.hash(&mut hasher);
+    func.syntax().text().to_string().split_whitespace().join(" ").hash(&mut hasher);
+    Some(format!("{:016x}", hasher.finish()))
+}
@@ -0,0 +1,87 @@
+//! Verus runs planned after a save, started one at a time so that a run does not cancel the
+//! previous one: each of them only verifies part of the crate again. Runs of functions that are
+//! too slow to verify on save wait for the `verus.verifySlowProofs` command instead.
+
+use std::collections::VecDeque;
+
+use ide::FileRange;
+
+/// A Verus run waiting for its turn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct QueuedRun {
+    pub(crate) flycheck_id: usize,
+    /// The file to run Verus on, as a VFS path.
+    pub(crate) file: String,
+    pub(crate) target: flycheck::VerusTarget,
+    /// The function verified again, results of earlier runs outside of it are kept. `None` if the
+    /// run replaces all earlier results.
+    pub(crate) scope: Option<FileRange>,
+    /// The run exceeds the time budget, it is only started by `verus.verifySlowProofs`.
+    pub(crate) slow: bool,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct VerificationQueue {
+    pending: VecDeque<QueuedRun>,
+    running: Option<QueuedRun>,
+    slow: VecDeque<QueuedRun>,
+}
+
+impl VerificationQueue {
+    /// Adds `runs` after the pending ones, or to the slow ones. A run already pending for the same
+    /// target is updated in place rather than queued twice.
+    pub(crate) fn extend(&mut self, runs: impl IntoIterator<Item = QueuedRun>) {
+        for run in runs {
+            if run.slow {
+                self.pending.retain(|it| !same_target(it, &run));
+                push_or_update(&mut self.slow, run);
+            } else {
+                self.slow.retain(|it| !same_target(it, &run));
+                push_or_update(&mut self.pending, run);
+            }
+        }
+    }
+
+    /// Queues the slow runs after the pending ones.
+    pub(crate) fn resume_slow(&mut self) {
+        let slow = std::mem::take(&mut self.slow);
+        self.extend(slow.into_iter().map(|it| QueuedRun { slow: false, ..it }));
+    }
+
+    /// Takes the next run to start, unless one is still in progress.
+    pub(crate) fn start_next(&mut self) -> Option<QueuedRun> {
+        if self.running.is_some() {
+            return None;
+        }
+        let run = self.pending.pop_front()?;
+        self.running = Some(run.clone());
+        Some(run)
+    }
+
+    /// The run in progress on flycheck `flycheck_id`, if it was started by the queue.
+    pub(crate) fn running(&self, flycheck_id: usize) -> Option<&QueuedRun> {
+        self.running.as_ref().filter(|it| it.flycheck_id == flycheck_id)
+    }
+
+    /// Drops the pending runs.
+    pub(crate) fn clear(&mut self) {
+        self.pending.clear();
+    }
+
+    pub(crate) fn finish(&mut self, flycheck_id: usize) {
+        if self.running(flycheck_id).is_some() {
+            self.running = None;
+        }
+    }
+}
+
+fn same_target(a: &QueuedRun, b: &QueuedRun) -> bool {
+    a.flycheck_id == b.flycheck_id && a.file == b.file && a.target == b.target
+}
+
+fn push_or_update(queue: &mut VecDeque<QueuedRun>, run: QueuedRun) {
+    match queue.iter_mut().find(|it| same_target(it, &run)) {
+        Some(it) => *it = run,
+        None => queue.push_back(run),
+    }
+}
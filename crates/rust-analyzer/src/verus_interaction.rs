@@ -1,61 +1,520 @@
-use ide_assists::proof_plumber_api::verus_error::{
-    AssertFailure, PostFailure, PreFailure, VerusError,
+use ide::{
+    Cancellable, FileId, FilePosition, FileRange, ProofEdgeKind, ProofGraph, ProofObligation,
+    ProofObligationKind, ProofStatus, VerusSymbolCategory,
 };
-use syntax::{TextRange, TextSize};
-
-pub(crate) fn diagnostic_to_verus_err(diagnostic: &flycheck::Diagnostic) -> Option<VerusError> {
-    if diagnostic.message.contains("precondition not satisfied") {
-        if diagnostic.spans.len() == 2 {
-            let range0 = TextRange::new(
-                TextSize::from(diagnostic.spans[0].byte_start),
-                TextSize::from(diagnostic.spans[0].byte_end),
-            );
-            let range1 = TextRange::new(
-                TextSize::from(diagnostic.spans[1].byte_start),
-                TextSize::from(diagnostic.spans[1].byte_end),
+use ide_assists::proof_plumber_api::verus_error::VerusError;
+use itertools::Itertools;
+use lsp_types::CodeLens;
+use stdx::format_to;
+use syntax::{
+    algo::{ancestors_at_offset, find_node_at_range},
+    ast::{self, HasName},
+    AstNode, TextRange,
+};
+
+use crate::{
+    global_state::GlobalStateSnapshot,
+    lsp::to_proto,
+    lsp_ext::{self, VerificationStatus},
+    verification_cache::{CachedResult, VerificationCache},
+    verification_queue::QueuedRun,
+};
+
+/// Outcome of the most recent Verus run, used to render the per-function
+/// verification lens.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct VerificationSummary {
+    /// Per-function results reported by Verus once the run finished.
+    pub(crate) functions: Vec<flycheck::VerusFunctionResult>,
+    /// Primary locations of the errors reported during the run.
+    pub(crate) errors: Vec<FileRange>,
+    /// Triggers Verus chose for quantifiers without explicit ones.
+    pub(crate) triggers: Vec<ChosenTriggers>,
+}
+
+/// The triggers Verus chose for a quantifier.
+#[derive(Debug, Clone)]
+pub(crate) struct ChosenTriggers {
+    pub(crate) quantifier: FileRange,
+    /// Each trigger is a group of terms in the quantifier's file.
+    pub(crate) triggers: Vec<Vec<TextRange>>,
+}
+
+/// Add a "✓ verified (0.8s)" / "✗ 2 errors" lens above each function of `file_id`
+/// that was part of the last Verus run, or of an earlier one if it has not changed since.
+pub(crate) fn verification_lenses(
+    acc: &mut Vec<CodeLens>,
+    snap: &GlobalStateSnapshot,
+    file_id: FileId,
+) -> anyhow::Result<()> {
+    let summary = &snap.verus_summary;
+    if summary.functions.is_empty() && snap.verification_cache.is_empty() {
+        return Ok(());
+    }
+    let client_commands = snap.config.client_commands();
+    let line_index = snap.file_line_index(file_id)?;
+    let source_file = snap.analysis.parse(file_id)?;
+
+    for func in source_file.syntax().descendants().filter_map(ast::Fn::cast) {
+        let Some(name) = func.name() else { continue };
+        let (success, time_ms, cached) =
+            match summary.functions.iter().find(|it| fn_path_matches(&it.function, &func)) {
+                Some(result) => (result.success, result.time_ms, false),
+                None => match snap.verification_cache.get(&func) {
+                    Some(result) => (result.success, result.time_ms, true),
+                    None => continue,
+                },
+            };
+        let fn_range = func.syntax().text_range();
+        let errors: Vec<FileRange> = summary
+            .errors
+            .iter()
+            .filter(|it| it.file_id == file_id && fn_range.contains_range(it.range))
+            .copied()
+            .collect();
+
+        let (title, command) = if success && errors.is_empty() {
+            let title = format!(
+                "✓ verified ({:.1}s{})",
+                time_ms as f64 / 1000.0,
+                if cached { ", cached" } else { "" }
             );
-            let verr;
-            if diagnostic.spans[0].is_primary {
-                verr = VerusError::Pre(PreFailure { failing_pre: range1, callsite: range0 });
-            } else {
-                verr = VerusError::Pre(PreFailure { failing_pre: range0, callsite: range1 });
-            }
-            Some(verr)
+            let command = client_commands
+                .run_flycheck
+                .then(|| to_proto::command::run_flycheck(title.clone()));
+            (title, command)
         } else {
-            // panic!("pre unexpected num of span");
-            None
+            let title = match errors.len() {
+                0 if cached => "✗ failed (cached)".to_owned(),
+                0 => "✗ failed".to_owned(),
+                1 => "✗ 1 error".to_owned(),
+                n => format!("✗ {n} errors"),
+            };
+            let command = match errors.first() {
+                Some(&first) if client_commands.goto_location => {
+                    to_proto::command::goto_range(snap, title.clone(), first)
+                }
+                _ => None,
+            };
+            (title, command)
+        };
+        let range = to_proto::range(&line_index, name.syntax().text_range());
+        acc.push(CodeLens {
+            range,
+            command: Some(command.unwrap_or_else(|| lsp_types::Command {
+                title,
+                command: String::new(),
+                arguments: None,
+            })),
+            data: None,
+        });
+    }
+    Ok(())
+}
+
+/// Returns the state of each function of `file_id` in the last Verus run, or in the current one if
+/// `running`, falling back to the cached result of an earlier run. Functions that are neither part
+/// of a run nor have errors are left out.
+pub(crate) fn verification_decorations(
+    snap: &GlobalStateSnapshot,
+    file_id: FileId,
+    running: bool,
+) -> anyhow::Result<lsp_ext::VerificationDecorationsParams> {
+    let summary = &snap.verus_summary;
+    let line_index = snap.file_line_index(file_id)?;
+    let source_file = snap.analysis.parse(file_id)?;
+
+    let mut functions = Vec::new();
+    for func in source_file.syntax().descendants().filter_map(ast::Fn::cast) {
+        if func.body().is_none() {
+            continue;
         }
-    } else if diagnostic.message.contains("postcondition not satisfied") {
-        if diagnostic.spans.len() == 2 {
-            let range0 = TextRange::new(
-                TextSize::from(diagnostic.spans[0].byte_start),
-                TextSize::from(diagnostic.spans[0].byte_end),
-            );
-            let range1 = TextRange::new(
-                TextSize::from(diagnostic.spans[1].byte_start),
-                TextSize::from(diagnostic.spans[1].byte_end),
-            );
-            let verr;
-            if diagnostic.spans[0].is_primary {
-                verr = VerusError::Post(PostFailure { failing_post: range1, func_body: range0 });
-            } else {
-                verr = VerusError::Post(PostFailure { failing_post: range0, func_body: range1 });
+        let fn_range = func.syntax().text_range();
+        let failures: Vec<_> = summary
+            .errors
+            .iter()
+            .filter(|it| it.file_id == file_id && fn_range.contains_range(it.range))
+            .map(|it| to_proto::range(&line_index, it.range))
+            .collect();
+        let Some(state) = function_state(snap, &func, !failures.is_empty(), running) else {
+            continue;
+        };
+        functions.push(lsp_ext::FunctionDecoration {
+            range: to_proto::range(&line_index, fn_range),
+            state,
+            failures,
+        });
+    }
+    Ok(lsp_ext::VerificationDecorationsParams {
+        text_document: lsp_types::TextDocumentIdentifier { uri: snap.file_id_to_url(file_id) },
+        functions,
+    })
+}
+
+/// What the last Verus run says of `func`, or the cache if the function was not part of it.
+fn function_state(
+    snap: &GlobalStateSnapshot,
+    func: &ast::Fn,
+    has_failures: bool,
+    running: bool,
+) -> Option<lsp_ext::DecorationState> {
+    let result = snap.verus_summary.functions.iter().find(|it| fn_path_matches(&it.function, func));
+    let state = match result {
+        Some(result) if result.success && !has_failures => lsp_ext::DecorationState::Verified,
+        Some(_) => lsp_ext::DecorationState::Failed,
+        None if has_failures => lsp_ext::DecorationState::Failed,
+        None if running => lsp_ext::DecorationState::Running,
+        None => match snap.verification_cache.get(func) {
+            Some(cached) if cached.success => lsp_ext::DecorationState::Verified,
+            Some(_) => lsp_ext::DecorationState::Failed,
+            None => return None,
+        },
+    };
+    Some(state)
+}
+
+/// The proof dependency graph of the workspace, with the state of each function as shown by the
+/// verification decorations.
+pub(crate) fn proof_graph(snap: &GlobalStateSnapshot) -> anyhow::Result<ProofGraph> {
+    let mut graph = snap.analysis.proof_graph()?;
+    for node in &mut graph.nodes {
+        let FileRange { file_id, range } = node.range;
+        let source_file = snap.analysis.parse(file_id)?;
+        let Some(func) = find_node_at_range::<ast::Fn>(source_file.syntax(), range) else {
+            continue;
+        };
+        let has_failures = snap
+            .verus_summary
+            .errors
+            .iter()
+            .any(|it| it.file_id == file_id && range.contains_range(it.range));
+        node.status = match function_state(snap, &func, has_failures, false) {
+            Some(lsp_ext::DecorationState::Verified) => ProofStatus::Verified,
+            Some(lsp_ext::DecorationState::Failed) => ProofStatus::Failed,
+            Some(lsp_ext::DecorationState::Running) | None => ProofStatus::Unknown,
+        };
+    }
+    Ok(graph)
+}
+
+/// `graph` as JSON, with `location` giving the file and the line of each node.
+pub(crate) fn proof_graph_json(
+    graph: &ProofGraph,
+    location: impl Fn(FileRange) -> anyhow::Result<(String, u32)>,
+) -> anyhow::Result<serde_json::Value> {
+    let nodes = graph
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(id, node)| {
+            let (file, line) = location(node.range)?;
+            let kind = match node.kind {
+                VerusSymbolCategory::Spec => "spec",
+                VerusSymbolCategory::Proof => "proof",
+                VerusSymbolCategory::BroadcastProof => "broadcast_proof",
+                VerusSymbolCategory::BroadcastGroup => "broadcast_group",
+                VerusSymbolCategory::Global => "global",
+            };
+            let status = match node.status {
+                ProofStatus::Verified => "verified",
+                ProofStatus::Failed => "failed",
+                ProofStatus::Unknown => "unknown",
+            };
+            Ok(serde_json::json!({
+                "id": id,
+                "crate": node.krate,
+                "path": node.path,
+                "kind": kind,
+                "status": status,
+                "file": file,
+                "line": line,
+            }))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let edges = graph
+        .edges
+        .iter()
+        .map(|edge| {
+            let kind = match edge.kind {
+                ProofEdgeKind::Call => "call",
+                ProofEdgeKind::BroadcastUse => "broadcast_use",
+            };
+            serde_json::json!({ "from": edge.from, "to": edge.to, "kind": kind })
+        })
+        .collect::<Vec<_>>();
+    Ok(serde_json::json!({ "nodes": nodes, "edges": edges }))
+}
+
+/// Records the outcome of the last Verus run for the functions of `file_id` that were part of it.
+pub(crate) fn cache_results(
+    snap: &GlobalStateSnapshot,
+    file_id: FileId,
+    cache: &mut VerificationCache,
+) -> anyhow::Result<()> {
+    let summary = &snap.verus_summary;
+    let source_file = snap.analysis.parse(file_id)?;
+    for func in source_file.syntax().descendants().filter_map(ast::Fn::cast) {
+        let Some(result) = summary.functions.iter().find(|it| fn_path_matches(&it.function, &func))
+        else {
+            continue;
+        };
+        let fn_range = func.syntax().text_range();
+        let has_errors = summary
+            .errors
+            .iter()
+            .any(|it| it.file_id == file_id && fn_range.contains_range(it.range));
+        cache.insert(
+            &func,
+            CachedResult {
+                success: result.success && !has_errors,
+                time_ms: result.time_ms,
+                rlimit: result.rlimit,
+            },
+        );
+    }
+    Ok(())
+}
+
+/// Plans the Verus runs for the save of `file_id`, the file at VFS path `file`, on each of
+/// `flychecks`. With `old_text`, the text of the file at its last run, only the functions that
+/// changed since and the ones relying on them are verified again: the ones that verified the
+/// fastest last time first, so that most results arrive early. Functions that took longer than the
+/// time budget are marked slow.
+pub(crate) fn verus_runs(
+    snap: &GlobalStateSnapshot,
+    file_id: FileId,
+    file: &str,
+    old_text: Option<&str>,
+    flychecks: &[usize],
+) -> Cancellable<Vec<QueuedRun>> {
+    let targets = match old_text {
+        Some(old_text) => {
+            let mut targets = Vec::new();
+            for it in snap.analysis.reverifications(file_id, old_text)? {
+                let target = match it.target {
+                    ide::VerifyTarget::File => flycheck::VerusTarget::File,
+                    ide::VerifyTarget::Module(path) => flycheck::VerusTarget::Module(path),
+                    ide::VerifyTarget::Function(name) => flycheck::VerusTarget::Function(name),
+                };
+                let file = snap.file_id_to_file_path(it.range.file_id).to_string();
+                let time_ms = last_time_ms(snap, it.range)?;
+                targets.push((file, target, Some(it.range), time_ms));
             }
-            Some(verr)
-        } else {
-            // panic!("post unexpected num of span");
-            None
+            // Functions without a recorded time are likely new and small.
+            targets.sort_by_key(|&(.., time_ms)| time_ms.unwrap_or(0));
+            targets
         }
-    } else if diagnostic.message.contains("assertion failed") {
-        // only reading first span now
-        // dbg!(&diagnostic.spans);
-        let range = TextRange::new(
-            TextSize::from(diagnostic.spans[0].byte_start),
-            TextSize::from(diagnostic.spans[0].byte_end),
-        );
-        let verr = VerusError::Assert(AssertFailure { range });
-        Some(verr)
+        None => first_run_target(snap, file_id)?
+            .map(|target| (file.to_owned(), target, None, None))
+            .into_iter()
+            .collect::<Vec<_>>(),
+    };
+    let time_budget = snap.config.verus_time_budget();
+    Ok(flychecks
+        .iter()
+        .flat_map(|&flycheck_id| {
+            targets.iter().map(move |(file, target, scope, time_ms)| QueuedRun {
+                flycheck_id,
+                file: file.clone(),
+                target: target.clone(),
+                scope: *scope,
+                slow: time_budget.zip(*time_ms).map_or(false, |(budget, time)| time > budget),
+            })
+        })
+        .collect())
+}
+
+/// How long the function at `range` took to verify the last time.
+fn last_time_ms(snap: &GlobalStateSnapshot, range: FileRange) -> Cancellable<Option<u64>> {
+    let source_file = snap.analysis.parse(range.file_id)?;
+    let time_ms = source_file
+        .syntax()
+        .descendants()
+        .filter_map(ast::Fn::cast)
+        .find(|it| it.syntax().text_range() == range.range)
+        .and_then(|it| snap.verification_cache.last_time_ms(&it));
+    Ok(time_ms)
+}
+
+/// Narrows down the first Verus run of a session on `file_id` using the results cached by earlier
+/// sessions: nothing needs to run if every function of the file is unchanged and verified, and
+/// only the stale function if there is a single one. Returns `None` if the run can be skipped.
+fn first_run_target(
+    snap: &GlobalStateSnapshot,
+    file_id: FileId,
+) -> Cancellable<Option<flycheck::VerusTarget>> {
+    if !snap.verus_summary.functions.is_empty() || snap.verification_cache.is_empty() {
+        return Ok(Some(flycheck::VerusTarget::File));
+    }
+    let source_file = snap.analysis.parse(file_id)?;
+    let stale: Vec<_> = source_file
+        .syntax()
+        .descendants()
+        .filter_map(ast::Fn::cast)
+        .filter(|it| it.body().is_some())
+        .filter(|it| !snap.verification_cache.get(it).map_or(false, |cached| cached.success))
+        .collect();
+    let target = match stale.as_slice() {
+        [] => None,
+        // `--verify-function` looks functions up by name in the module backing the file.
+        [func] if func.syntax().ancestors().skip(1).all(|it| !ast::Item::can_cast(it.kind())) => {
+            match func.name() {
+                Some(name) => Some(flycheck::VerusTarget::Function(name.text().to_string())),
+                None => Some(flycheck::VerusTarget::File),
+            }
+        }
+        _ => Some(flycheck::VerusTarget::File),
+    };
+    Ok(target)
+}
+
+/// Lists the triggers Verus chose in the last run for the quantifier whose `forall|..|` or
+/// `exists|..|` head is at `range`, along with the range of that head.
+pub(crate) fn chosen_triggers_hover(
+    snap: &GlobalStateSnapshot,
+    range: FileRange,
+) -> anyhow::Result<Option<(TextRange, String)>> {
+    if snap.verus_summary.triggers.is_empty() {
+        return Ok(None);
+    }
+    let source_file = snap.analysis.parse(range.file_id)?;
+    let Some(quantifier) = ancestors_at_offset(source_file.syntax(), range.range.start())
+        .filter_map(ast::ClosureExpr::cast)
+        .find(|it| it.forall_token().is_some() || it.exists_token().is_some())
+    else {
+        return Ok(None);
+    };
+    let quantifier_range = quantifier.syntax().text_range();
+    let head_end =
+        quantifier.body().map_or(quantifier_range.end(), |it| it.syntax().text_range().start());
+    if range.range.start() >= head_end {
+        return Ok(None);
+    }
+    let Some(chosen) = snap.verus_summary.triggers.iter().find(|it| {
+        it.quantifier.file_id == range.file_id
+            && it.quantifier.range.start() == quantifier_range.start()
+    }) else {
+        return Ok(None);
+    };
+
+    // The file may have changed since the run, skip terms that no longer fit.
+    let text = snap.analysis.file_text(range.file_id)?;
+    let mut markup = String::from("Triggers chosen by Verus:\n");
+    for trigger in &chosen.triggers {
+        let terms = trigger
+            .iter()
+            .filter_map(|&it| text.get(std::ops::Range::<usize>::from(it)))
+            .map(|it| format!("`{}`", it.split_whitespace().join(" ")))
+            .join(", ");
+        format_to!(markup, "\n- {terms}");
+    }
+    Ok(Some((TextRange::new(quantifier_range.start(), head_end), markup)))
+}
+
+/// Returns the proof obligations of the function at `position`, each annotated with the outcome
+/// of the last Verus run: failed if Verus reported an error for it, verified if the function was
+/// part of the run otherwise.
+pub(crate) fn proof_obligations(
+    snap: &GlobalStateSnapshot,
+    position: FilePosition,
+) -> anyhow::Result<Option<lsp_ext::ProofObligation>> {
+    let Some(obligation) = snap.analysis.proof_obligations(position)? else { return Ok(None) };
+    let source_file = snap.analysis.parse(position.file_id)?;
+    let was_verified = source_file
+        .syntax()
+        .descendants()
+        .filter_map(ast::Fn::cast)
+        .find(|it| it.syntax().text_range() == obligation.range.range)
+        .map_or(false, |func| {
+            snap.verus_summary.functions.iter().any(|it| fn_path_matches(&it.function, &func))
+        });
+    obligation_to_lsp(snap, was_verified, None, obligation).map(Some)
+}
+
+fn obligation_to_lsp(
+    snap: &GlobalStateSnapshot,
+    was_verified: bool,
+    callsite: Option<FileRange>,
+    obligation: ProofObligation,
+) -> anyhow::Result<lsp_ext::ProofObligation> {
+    let range = obligation.range;
+    let failed = match obligation.kind {
+        // The failing clause is reported as a secondary span next to the callsite.
+        ProofObligationKind::Requires => snap.verus_errors.iter().any(|it| match it {
+            VerusError::Pre(pre) => {
+                overlaps(pre.failing_pre, range.range)
+                    && callsite.map_or(false, |callsite| overlaps(pre.callsite, callsite.range))
+            }
+            _ => false,
+        }),
+        ProofObligationKind::Ensures => snap.verus_errors.iter().any(|it| match it {
+            VerusError::Post(post) => overlaps(post.failing_post, range.range),
+            _ => false,
+        }),
+        ProofObligationKind::Function | ProofObligationKind::Call | ProofObligationKind::Assert => {
+            snap.verus_summary
+                .errors
+                .iter()
+                .any(|it| it.file_id == range.file_id && overlaps(it.range, range.range))
+        }
+    };
+    let kind = match obligation.kind {
+        ProofObligationKind::Function => lsp_ext::ProofObligationKind::Function,
+        ProofObligationKind::Call => lsp_ext::ProofObligationKind::Call,
+        ProofObligationKind::Requires => lsp_ext::ProofObligationKind::Requires,
+        ProofObligationKind::Ensures => lsp_ext::ProofObligationKind::Ensures,
+        ProofObligationKind::Assert => lsp_ext::ProofObligationKind::Assert,
+    };
+    let callsite = (obligation.kind == ProofObligationKind::Call).then_some(range);
+    let children = obligation
+        .children
+        .into_iter()
+        .map(|it| obligation_to_lsp(snap, was_verified, callsite, it))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let status = if failed || children.iter().any(|it| it.status == VerificationStatus::Failed) {
+        VerificationStatus::Failed
+    } else if was_verified {
+        VerificationStatus::Verified
     } else {
-        None
+        VerificationStatus::Unknown
+    };
+    Ok(lsp_ext::ProofObligation {
+        kind,
+        label: obligation.label,
+        location: to_proto::location(snap, range)?,
+        status,
+        children,
+    })
+}
+
+fn overlaps(a: TextRange, b: TextRange) -> bool {
+    a.intersect(b).map_or(false, |it| !it.is_empty())
+}
+
+/// Verus reports functions by their path, e.g. `crate::module::lemma_foo` or
+/// `crate::module::S::method`. Match it against the modules (and impl self type)
+/// enclosing `func` inside the file.
+fn fn_path_matches(verus_path: &str, func: &ast::Fn) -> bool {
+    let Some(suffix) = fn_path_suffix(func) else { return false };
+    verus_path == suffix || verus_path.ends_with(&format!("::{suffix}"))
+}
+
+/// The path of `func` within its file, e.g. `module::S::method`.
+pub(crate) fn fn_path_suffix(func: &ast::Fn) -> Option<String> {
+    let name = func.name()?;
+    let mut segments = vec![name.text().to_string()];
+    for ancestor in func.syntax().ancestors().skip(1) {
+        if let Some(module) = ast::Module::cast(ancestor.clone()) {
+            if let Some(name) = module.name() {
+                segments.push(name.text().to_string());
+            }
+        } else if let Some(imp) = ast::Impl::cast(ancestor) {
+            if let Some(ty) = imp.self_ty() {
+                segments.push(ty.syntax().text().to_string());
+            }
+        }
     }
+    segments.reverse();
+    Some(segments.join("::"))
 }
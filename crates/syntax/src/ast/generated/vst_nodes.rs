@@ -1,7 +1,10 @@
 //! Generated by `sourcegen_vst`, do not edit by hand.
 
 #![allow(non_snake_case)]
-use crate::ast::{traits::*, vst::*};
+use crate::{
+    ast::{traits::*, vst::*},
+    SmolStr,
+};
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Abi {
     pub extern_token: bool,
@@ -168,7 +171,7 @@ pub struct BroadcastGroup {
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct BroadcastGroupIdentifier {
-    pub ident_token: Option<String>,
+    pub ident_token: Option<SmolStr>,
     pub cst: Option<super::nodes::BroadcastGroupIdentifier>,
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -555,7 +558,7 @@ pub struct LetStmt {
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Lifetime {
-    pub lifetime_ident_token: Option<String>,
+    pub lifetime_ident_token: Option<SmolStr>,
     pub cst: Option<super::nodes::Lifetime>,
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -732,13 +735,13 @@ pub struct Module {
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Name {
-    pub ident_token: Option<String>,
+    pub ident_token: Option<SmolStr>,
     pub self_token: bool,
     pub cst: Option<super::nodes::Name>,
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NameRef {
-    pub ident_token: Option<String>,
+    pub ident_token: Option<SmolStr>,
     pub self_token: bool,
     pub super_token: bool,
     pub crate_token: bool,
@@ -865,12 +868,6 @@ pub struct PathType {
     pub cst: Option<super::nodes::PathType>,
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PrefixExpr {
-    pub attrs: Vec<Attr>,
-    pub expr: Box<Expr>,
-    pub cst: Option<super::nodes::PrefixExpr>,
-}
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Prover {
     pub by_token: bool,
     pub l_paren_token: bool,
@@ -1357,15 +1354,6 @@ pub struct WherePred {
     pub cst: Option<super::nodes::WherePred>,
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct WhileExpr {
-    pub attrs: Vec<Attr>,
-    pub label: Option<Box<Label>>,
-    pub while_token: bool,
-    pub loop_clauses: Vec<LoopClause>,
-    pub loop_body: Box<BlockExpr>,
-    pub cst: Option<super::nodes::WhileExpr>,
-}
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WildcardPat {
     pub underscore_token: bool,
     pub cst: Option<super::nodes::WildcardPat>,
@@ -1559,7 +1547,7 @@ impl TryFrom<super::nodes::ArgList> for ArgList {
                 .map(Expr::try_from)
                 .collect::<Result<Vec<Expr>, String>>()?,
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -1578,14 +1566,10 @@ impl TryFrom<super::nodes::ArrayExpr> for ArrayExpr {
                 .into_iter()
                 .map(Expr::try_from)
                 .collect::<Result<Vec<Expr>, String>>()?,
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
             semicolon_token: item.semicolon_token().is_some(),
             r_brack_token: item.r_brack_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -1601,11 +1585,11 @@ impl TryFrom<super::nodes::ArrayType> for ArrayType {
             semicolon_token: item.semicolon_token().is_some(),
             const_arg: Box::new(
                 item.const_arg()
-                    .ok_or(format!("{}", stringify!(const_arg)))
+                    .ok_or(stringify!(const_arg))
                     .map(|it| ConstArg::try_from(it))??,
             ),
             r_brack_token: item.r_brack_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -1618,17 +1602,13 @@ impl TryFrom<super::nodes::ArrowExpr> for ArrowExpr {
                 .into_iter()
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
             thin_arrow_token: item.thin_arrow_token().is_some(),
             name_ref: match item.name_ref() {
                 Some(it) => Some(Box::new(NameRef::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -1645,13 +1625,9 @@ impl TryFrom<super::nodes::AsmExpr> for AsmExpr {
             pound_token: item.pound_token().is_some(),
             asm_token: item.asm_token().is_some(),
             l_paren_token: item.l_paren_token().is_some(),
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -1666,11 +1642,7 @@ impl TryFrom<super::nodes::AssertExpr> for AssertExpr {
                 .collect::<Result<Vec<Attr>, String>>()?,
             assert_token: item.assert_token().is_some(),
             l_paren_token: item.l_paren_token().is_some(),
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
             r_paren_token: item.r_paren_token().is_some(),
             by_token: item.by_token().is_some(),
             name: match item.name() {
@@ -1685,7 +1657,7 @@ impl TryFrom<super::nodes::AssertExpr> for AssertExpr {
                 Some(it) => Some(Box::new(BlockExpr::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -1705,7 +1677,7 @@ impl TryFrom<super::nodes::AssocItemList> for AssocItemList {
                 .map(AssocItem::try_from)
                 .collect::<Result<Vec<AssocItem>, String>>()?,
             r_curly_token: item.r_curly_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -1714,9 +1686,7 @@ impl TryFrom<super::nodes::AssocTypeArg> for AssocTypeArg {
     fn try_from(item: super::nodes::AssocTypeArg) -> Result<Self, Self::Error> {
         Ok(Self {
             name_ref: Box::new(
-                item.name_ref()
-                    .ok_or(format!("{}", stringify!(name_ref)))
-                    .map(|it| NameRef::try_from(it))??,
+                item.name_ref().ok_or(stringify!(name_ref)).map(|it| NameRef::try_from(it))??,
             ),
             generic_arg_list: match item.generic_arg_list() {
                 Some(it) => Some(Box::new(GenericArgList::try_from(it)?)),
@@ -1733,7 +1703,7 @@ impl TryFrom<super::nodes::AssocTypeArg> for AssocTypeArg {
             colon_token: item.colon_token().is_some(),
             type_bound_list: Box::new(
                 item.type_bound_list()
-                    .ok_or(format!("{}", stringify!(type_bound_list)))
+                    .ok_or(stringify!(type_bound_list))
                     .map(|it| TypeBoundList::try_from(it))??,
             ),
             eq_token: item.eq_token().is_some(),
@@ -1745,7 +1715,7 @@ impl TryFrom<super::nodes::AssocTypeArg> for AssocTypeArg {
                 Some(it) => Some(Box::new(ConstArg::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -1760,13 +1730,9 @@ impl TryFrom<super::nodes::AssumeExpr> for AssumeExpr {
                 .collect::<Result<Vec<Attr>, String>>()?,
             assume_token: item.assume_token().is_some(),
             l_paren_token: item.l_paren_token().is_some(),
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -1786,7 +1752,7 @@ impl TryFrom<super::nodes::Attr> for Attr {
                 None => None,
             },
             r_brack_token: item.r_brack_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -1799,14 +1765,10 @@ impl TryFrom<super::nodes::AwaitExpr> for AwaitExpr {
                 .into_iter()
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
             dot_token: item.dot_token().is_some(),
             await_token: item.await_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -1820,12 +1782,8 @@ impl TryFrom<super::nodes::BecomeExpr> for BecomeExpr {
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
             become_token: item.become_token().is_some(),
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
-            cst: Some(item.clone()),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
+            cst: Some(item),
         })
     }
 }
@@ -1848,10 +1806,10 @@ impl TryFrom<super::nodes::BlockExpr> for BlockExpr {
             const_token: item.const_token().is_some(),
             stmt_list: Box::new(
                 item.stmt_list()
-                    .ok_or(format!("{}", stringify!(stmt_list)))
+                    .ok_or(stringify!(stmt_list))
                     .map(|it| StmtList::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -1864,7 +1822,7 @@ impl TryFrom<super::nodes::BoxPat> for BoxPat {
                 Some(it) => Some(Box::new(Pat::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -1886,7 +1844,7 @@ impl TryFrom<super::nodes::BreakExpr> for BreakExpr {
                 Some(it) => Some(Box::new(Expr::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -1907,25 +1865,22 @@ impl TryFrom<super::nodes::BroadcastGroup> for BroadcastGroup {
             group_token: item.group_token().is_some(),
             broadcast_group_identifier: Box::new(
                 item.broadcast_group_identifier()
-                    .ok_or(format!("{}", stringify!(broadcast_group_identifier)))
+                    .ok_or(stringify!(broadcast_group_identifier))
                     .map(|it| BroadcastGroupIdentifier::try_from(it))??,
             ),
             broadcast_group_list: Box::new(
                 item.broadcast_group_list()
-                    .ok_or(format!("{}", stringify!(broadcast_group_list)))
+                    .ok_or(stringify!(broadcast_group_list))
                     .map(|it| BroadcastGroupList::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
 impl TryFrom<super::nodes::BroadcastGroupIdentifier> for BroadcastGroupIdentifier {
     type Error = String;
     fn try_from(item: super::nodes::BroadcastGroupIdentifier) -> Result<Self, Self::Error> {
-        Ok(Self {
-            ident_token: item.ident_token().map(|it| it.text().to_string()),
-            cst: Some(item.clone()),
-        })
+        Ok(Self { ident_token: item.ident_token().map(|it| it.text().into()), cst: Some(item) })
     }
 }
 impl TryFrom<super::nodes::BroadcastGroupList> for BroadcastGroupList {
@@ -1939,7 +1894,7 @@ impl TryFrom<super::nodes::BroadcastGroupList> for BroadcastGroupList {
                 .map(BroadcastGroupMember::try_from)
                 .collect::<Result<Vec<BroadcastGroupMember>, String>>()?,
             r_curly_token: item.r_curly_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -1952,12 +1907,8 @@ impl TryFrom<super::nodes::BroadcastGroupMember> for BroadcastGroupMember {
                 .into_iter()
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
-            path: Box::new(
-                item.path()
-                    .ok_or(format!("{}", stringify!(path)))
-                    .map(|it| Path::try_from(it))??,
-            ),
-            cst: Some(item.clone()),
+            path: Box::new(item.path().ok_or(stringify!(path)).map(|it| Path::try_from(it))??),
+            cst: Some(item),
         })
     }
 }
@@ -1974,11 +1925,11 @@ impl TryFrom<super::nodes::BroadcastUse> for BroadcastUse {
             use_token: item.use_token().is_some(),
             broadcast_use_list: Box::new(
                 item.broadcast_use_list()
-                    .ok_or(format!("{}", stringify!(broadcast_use_list)))
+                    .ok_or(stringify!(broadcast_use_list))
                     .map(|it| BroadcastUseList::try_from(it))??,
             ),
             semicolon_token: item.semicolon_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -1991,7 +1942,7 @@ impl TryFrom<super::nodes::BroadcastUseList> for BroadcastUseList {
                 .into_iter()
                 .map(Path::try_from)
                 .collect::<Result<Vec<Path>, String>>()?,
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2004,17 +1955,11 @@ impl TryFrom<super::nodes::CallExpr> for CallExpr {
                 .into_iter()
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
             arg_list: Box::new(
-                item.arg_list()
-                    .ok_or(format!("{}", stringify!(arg_list)))
-                    .map(|it| ArgList::try_from(it))??,
+                item.arg_list().ok_or(stringify!(arg_list)).map(|it| ArgList::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2027,17 +1972,13 @@ impl TryFrom<super::nodes::CastExpr> for CastExpr {
                 .into_iter()
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
             as_token: item.as_token().is_some(),
             ty: match item.ty() {
                 Some(it) => Some(Box::new(Type::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2069,12 +2010,8 @@ impl TryFrom<super::nodes::ClosureExpr> for ClosureExpr {
                 Some(it) => Some(Box::new(RetType::try_from(it)?)),
                 None => None,
             },
-            body: Box::new(
-                item.body()
-                    .ok_or(format!("{}", stringify!(body)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
-            cst: Some(item.clone()),
+            body: Box::new(item.body().ok_or(stringify!(body)).map(|it| Expr::try_from(it))??),
+            cst: Some(item),
         })
     }
 }
@@ -2109,7 +2046,7 @@ impl TryFrom<super::nodes::Const> for Const {
                 None => None,
             },
             semicolon_token: item.semicolon_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2117,12 +2054,8 @@ impl TryFrom<super::nodes::ConstArg> for ConstArg {
     type Error = String;
     fn try_from(item: super::nodes::ConstArg) -> Result<Self, Self::Error> {
         Ok(Self {
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
-            cst: Some(item.clone()),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
+            cst: Some(item),
         })
     }
 }
@@ -2133,10 +2066,10 @@ impl TryFrom<super::nodes::ConstBlockPat> for ConstBlockPat {
             const_token: item.const_token().is_some(),
             block_expr: Box::new(
                 item.block_expr()
-                    .ok_or(format!("{}", stringify!(block_expr)))
+                    .ok_or(stringify!(block_expr))
                     .map(|it| BlockExpr::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2150,11 +2083,7 @@ impl TryFrom<super::nodes::ConstParam> for ConstParam {
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
             const_token: item.const_token().is_some(),
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             colon_token: item.colon_token().is_some(),
             ty: match item.ty() {
                 Some(it) => Some(Box::new(Type::try_from(it)?)),
@@ -2165,7 +2094,7 @@ impl TryFrom<super::nodes::ConstParam> for ConstParam {
                 Some(it) => Some(Box::new(ConstArg::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2183,7 +2112,7 @@ impl TryFrom<super::nodes::ContinueExpr> for ContinueExpr {
                 Some(it) => Some(Box::new(Lifetime::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2193,7 +2122,7 @@ impl TryFrom<super::nodes::DataMode> for DataMode {
         Ok(Self {
             ghost_token: item.ghost_token().is_some(),
             tracked_token: item.tracked_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2207,7 +2136,7 @@ impl TryFrom<super::nodes::DecreasesClause> for DecreasesClause {
                 .into_iter()
                 .map(Expr::try_from)
                 .collect::<Result<Vec<Expr>, String>>()?,
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2218,10 +2147,10 @@ impl TryFrom<super::nodes::DynTraitType> for DynTraitType {
             dyn_token: item.dyn_token().is_some(),
             type_bound_list: Box::new(
                 item.type_bound_list()
-                    .ok_or(format!("{}", stringify!(type_bound_list)))
+                    .ok_or(stringify!(type_bound_list))
                     .map(|it| TypeBoundList::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2235,7 +2164,7 @@ impl TryFrom<super::nodes::EnsuresClause> for EnsuresClause {
                 .into_iter()
                 .map(Expr::try_from)
                 .collect::<Result<Vec<Expr>, String>>()?,
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2257,11 +2186,7 @@ impl TryFrom<super::nodes::Enum> for Enum {
                 None => None,
             },
             enum_token: item.enum_token().is_some(),
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             generic_param_list: match item.generic_param_list() {
                 Some(it) => Some(Box::new(GenericParamList::try_from(it)?)),
                 None => None,
@@ -2272,10 +2197,10 @@ impl TryFrom<super::nodes::Enum> for Enum {
             },
             variant_list: Box::new(
                 item.variant_list()
-                    .ok_or(format!("{}", stringify!(variant_list)))
+                    .ok_or(stringify!(variant_list))
                     .map(|it| VariantList::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2283,13 +2208,9 @@ impl TryFrom<super::nodes::ExprStmt> for ExprStmt {
     type Error = String;
     fn try_from(item: super::nodes::ExprStmt) -> Result<Self, Self::Error> {
         Ok(Self {
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
             semicolon_token: item.semicolon_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2303,15 +2224,13 @@ impl TryFrom<super::nodes::ExternBlock> for ExternBlock {
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
             unsafe_token: item.unsafe_token().is_some(),
-            abi: Box::new(
-                item.abi().ok_or(format!("{}", stringify!(abi))).map(|it| Abi::try_from(it))??,
-            ),
+            abi: Box::new(item.abi().ok_or(stringify!(abi)).map(|it| Abi::try_from(it))??),
             extern_item_list: Box::new(
                 item.extern_item_list()
-                    .ok_or(format!("{}", stringify!(extern_item_list)))
+                    .ok_or(stringify!(extern_item_list))
                     .map(|it| ExternItemList::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2331,16 +2250,14 @@ impl TryFrom<super::nodes::ExternCrate> for ExternCrate {
             extern_token: item.extern_token().is_some(),
             crate_token: item.crate_token().is_some(),
             name_ref: Box::new(
-                item.name_ref()
-                    .ok_or(format!("{}", stringify!(name_ref)))
-                    .map(|it| NameRef::try_from(it))??,
+                item.name_ref().ok_or(stringify!(name_ref)).map(|it| NameRef::try_from(it))??,
             ),
             rename: match item.rename() {
                 Some(it) => Some(Box::new(Rename::try_from(it)?)),
                 None => None,
             },
             semicolon_token: item.semicolon_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2360,7 +2277,7 @@ impl TryFrom<super::nodes::ExternItemList> for ExternItemList {
                 .map(ExternItem::try_from)
                 .collect::<Result<Vec<ExternItem>, String>>()?,
             r_curly_token: item.r_curly_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2373,18 +2290,12 @@ impl TryFrom<super::nodes::FieldExpr> for FieldExpr {
                 .into_iter()
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
             dot_token: item.dot_token().is_some(),
             name_ref: Box::new(
-                item.name_ref()
-                    .ok_or(format!("{}", stringify!(name_ref)))
-                    .map(|it| NameRef::try_from(it))??,
+                item.name_ref().ok_or(stringify!(name_ref)).map(|it| NameRef::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2419,11 +2330,7 @@ impl TryFrom<super::nodes::Fn> for Fn {
                 None => None,
             },
             fn_token: item.fn_token().is_some(),
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             generic_param_list: match item.generic_param_list() {
                 Some(it) => Some(Box::new(GenericParamList::try_from(it)?)),
                 None => None,
@@ -2473,7 +2380,7 @@ impl TryFrom<super::nodes::Fn> for Fn {
                 None => None,
             },
             semicolon_token: item.semicolon_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2488,7 +2395,7 @@ impl TryFrom<super::nodes::FnMode> for FnMode {
                 Some(it) => Some(Box::new(ModeSpecChecked::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2512,7 +2419,7 @@ impl TryFrom<super::nodes::FnPtrType> for FnPtrType {
                 Some(it) => Some(Box::new(RetType::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2547,10 +2454,10 @@ impl TryFrom<super::nodes::ForExpr> for ForExpr {
                 .collect::<Result<Vec<LoopClause>, String>>()?,
             loop_body: Box::new(
                 item.loop_body()
-                    .ok_or(format!("{}", stringify!(loop_body)))
+                    .ok_or(stringify!(loop_body))
                     .map(|it| BlockExpr::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2561,14 +2468,14 @@ impl TryFrom<super::nodes::ForType> for ForType {
             for_token: item.for_token().is_some(),
             generic_param_list: Box::new(
                 item.generic_param_list()
-                    .ok_or(format!("{}", stringify!(generic_param_list)))
+                    .ok_or(stringify!(generic_param_list))
                     .map(|it| GenericParamList::try_from(it))??,
             ),
             ty: match item.ty() {
                 Some(it) => Some(Box::new(Type::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2581,12 +2488,8 @@ impl TryFrom<super::nodes::FormatArgsArg> for FormatArgsArg {
                 None => None,
             },
             eq_token: item.eq_token().is_some(),
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
-            cst: Some(item.clone()),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
+            cst: Some(item),
         })
     }
 }
@@ -2604,9 +2507,7 @@ impl TryFrom<super::nodes::FormatArgsExpr> for FormatArgsExpr {
             format_args_token: item.format_args_token().is_some(),
             l_paren_token: item.l_paren_token().is_some(),
             template: Box::new(
-                item.template()
-                    .ok_or(format!("{}", stringify!(template)))
-                    .map(|it| Expr::try_from(it))??,
+                item.template().ok_or(stringify!(template)).map(|it| Expr::try_from(it))??,
             ),
             comma_token: item.comma_token().is_some(),
             args: item
@@ -2615,7 +2516,7 @@ impl TryFrom<super::nodes::FormatArgsExpr> for FormatArgsExpr {
                 .map(FormatArgsArg::try_from)
                 .collect::<Result<Vec<FormatArgsArg>, String>>()?,
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2631,7 +2532,7 @@ impl TryFrom<super::nodes::GenericArgList> for GenericArgList {
                 .map(GenericArg::try_from)
                 .collect::<Result<Vec<GenericArg>, String>>()?,
             r_angle_token: item.r_angle_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2646,7 +2547,7 @@ impl TryFrom<super::nodes::GenericParamList> for GenericParamList {
                 .map(GenericParam::try_from)
                 .collect::<Result<Vec<GenericParam>, String>>()?,
             r_angle_token: item.r_angle_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2661,17 +2562,13 @@ impl TryFrom<super::nodes::IdentPat> for IdentPat {
                 .collect::<Result<Vec<Attr>, String>>()?,
             ref_token: item.ref_token().is_some(),
             mut_token: item.mut_token().is_some(),
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             at_token: item.at_token().is_some(),
             pat: match item.pat() {
                 Some(it) => Some(Box::new(Pat::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2704,10 +2601,10 @@ impl TryFrom<super::nodes::Impl> for Impl {
             },
             assoc_item_list: Box::new(
                 item.assoc_item_list()
-                    .ok_or(format!("{}", stringify!(assoc_item_list)))
+                    .ok_or(stringify!(assoc_item_list))
                     .map(|it| AssocItemList::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2718,10 +2615,10 @@ impl TryFrom<super::nodes::ImplTraitType> for ImplTraitType {
             impl_token: item.impl_token().is_some(),
             type_bound_list: Box::new(
                 item.type_bound_list()
-                    .ok_or(format!("{}", stringify!(type_bound_list)))
+                    .ok_or(stringify!(type_bound_list))
                     .map(|it| TypeBoundList::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2741,7 +2638,7 @@ impl TryFrom<super::nodes::InvariantClause> for InvariantClause {
                 .into_iter()
                 .map(Expr::try_from)
                 .collect::<Result<Vec<Expr>, String>>()?,
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2755,7 +2652,7 @@ impl TryFrom<super::nodes::InvariantExceptBreakClause> for InvariantExceptBreakC
                 .into_iter()
                 .map(Expr::try_from)
                 .collect::<Result<Vec<Expr>, String>>()?,
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2768,17 +2665,13 @@ impl TryFrom<super::nodes::IsExpr> for IsExpr {
                 .into_iter()
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
             is_token: item.is_token().is_some(),
             ty: match item.ty() {
                 Some(it) => Some(Box::new(Type::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2798,7 +2691,7 @@ impl TryFrom<super::nodes::ItemList> for ItemList {
                 .map(Item::try_from)
                 .collect::<Result<Vec<Item>, String>>()?,
             r_curly_token: item.r_curly_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2807,12 +2700,10 @@ impl TryFrom<super::nodes::Label> for Label {
     fn try_from(item: super::nodes::Label) -> Result<Self, Self::Error> {
         Ok(Self {
             lifetime: Box::new(
-                item.lifetime()
-                    .ok_or(format!("{}", stringify!(lifetime)))
-                    .map(|it| Lifetime::try_from(it))??,
+                item.lifetime().ok_or(stringify!(lifetime)).map(|it| Lifetime::try_from(it))??,
             ),
             colon_token: item.colon_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2823,10 +2714,10 @@ impl TryFrom<super::nodes::LetElse> for LetElse {
             else_token: item.else_token().is_some(),
             block_expr: Box::new(
                 item.block_expr()
-                    .ok_or(format!("{}", stringify!(block_expr)))
+                    .ok_or(stringify!(block_expr))
                     .map(|it| BlockExpr::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2845,12 +2736,8 @@ impl TryFrom<super::nodes::LetExpr> for LetExpr {
                 None => None,
             },
             eq_token: item.eq_token().is_some(),
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
-            cst: Some(item.clone()),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
+            cst: Some(item),
         })
     }
 }
@@ -2878,7 +2765,7 @@ impl TryFrom<super::nodes::LetStmt> for LetStmt {
             eq_token: item.eq_token().is_some(),
             initializer: Box::new(
                 item.initializer()
-                    .ok_or(format!("{}", stringify!(initializer)))
+                    .ok_or(stringify!(initializer))
                     .map(|it| Expr::try_from(it))??,
             ),
             let_else: match item.let_else() {
@@ -2886,7 +2773,7 @@ impl TryFrom<super::nodes::LetStmt> for LetStmt {
                 None => None,
             },
             semicolon_token: item.semicolon_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2894,8 +2781,8 @@ impl TryFrom<super::nodes::Lifetime> for Lifetime {
     type Error = String;
     fn try_from(item: super::nodes::Lifetime) -> Result<Self, Self::Error> {
         Ok(Self {
-            lifetime_ident_token: item.lifetime_ident_token().map(|it| it.text().to_string()),
-            cst: Some(item.clone()),
+            lifetime_ident_token: item.lifetime_ident_token().map(|it| it.text().into()),
+            cst: Some(item),
         })
     }
 }
@@ -2904,11 +2791,9 @@ impl TryFrom<super::nodes::LifetimeArg> for LifetimeArg {
     fn try_from(item: super::nodes::LifetimeArg) -> Result<Self, Self::Error> {
         Ok(Self {
             lifetime: Box::new(
-                item.lifetime()
-                    .ok_or(format!("{}", stringify!(lifetime)))
-                    .map(|it| Lifetime::try_from(it))??,
+                item.lifetime().ok_or(stringify!(lifetime)).map(|it| Lifetime::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2922,16 +2807,14 @@ impl TryFrom<super::nodes::LifetimeParam> for LifetimeParam {
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
             lifetime: Box::new(
-                item.lifetime()
-                    .ok_or(format!("{}", stringify!(lifetime)))
-                    .map(|it| Lifetime::try_from(it))??,
+                item.lifetime().ok_or(stringify!(lifetime)).map(|it| Lifetime::try_from(it))??,
             ),
             colon_token: item.colon_token().is_some(),
             type_bound_list: match item.type_bound_list() {
                 Some(it) => Some(Box::new(TypeBoundList::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2941,11 +2824,9 @@ impl TryFrom<super::nodes::LiteralPat> for LiteralPat {
         Ok(Self {
             minus_token: item.minus_token().is_some(),
             literal: Box::new(
-                item.literal()
-                    .ok_or(format!("{}", stringify!(literal)))
-                    .map(|it| Literal::try_from(it))??,
+                item.literal().ok_or(stringify!(literal)).map(|it| Literal::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2970,10 +2851,10 @@ impl TryFrom<super::nodes::LoopExpr> for LoopExpr {
                 .collect::<Result<Vec<LoopClause>, String>>()?,
             loop_body: Box::new(
                 item.loop_body()
-                    .ok_or(format!("{}", stringify!(loop_body)))
+                    .ok_or(stringify!(loop_body))
                     .map(|it| BlockExpr::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -2986,19 +2867,15 @@ impl TryFrom<super::nodes::MacroCall> for MacroCall {
                 .into_iter()
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
-            path: Box::new(
-                item.path()
-                    .ok_or(format!("{}", stringify!(path)))
-                    .map(|it| Path::try_from(it))??,
-            ),
+            path: Box::new(item.path().ok_or(stringify!(path)).map(|it| Path::try_from(it))??),
             excl_token: item.excl_token().is_some(),
             token_tree: Box::new(
                 item.token_tree()
-                    .ok_or(format!("{}", stringify!(token_tree)))
+                    .ok_or(stringify!(token_tree))
                     .map(|it| TokenTree::try_from(it))??,
             ),
             semicolon_token: item.semicolon_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3016,21 +2893,13 @@ impl TryFrom<super::nodes::MacroDef> for MacroDef {
                 None => None,
             },
             macro_token: item.macro_token().is_some(),
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             args: match item.args() {
                 Some(it) => Some(Box::new(TokenTree::try_from(it)?)),
                 None => None,
             },
-            body: Box::new(
-                item.body()
-                    .ok_or(format!("{}", stringify!(body)))
-                    .map(|it| TokenTree::try_from(it))??,
-            ),
-            cst: Some(item.clone()),
+            body: Box::new(item.body().ok_or(stringify!(body)).map(|it| TokenTree::try_from(it))??),
+            cst: Some(item),
         })
     }
 }
@@ -3049,7 +2918,7 @@ impl TryFrom<super::nodes::MacroEagerInput> for MacroEagerInput {
             r_curly_token: item.r_curly_token().is_some(),
             l_brack_token: item.l_brack_token().is_some(),
             r_brack_token: item.r_brack_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3059,10 +2928,10 @@ impl TryFrom<super::nodes::MacroExpr> for MacroExpr {
         Ok(Self {
             macro_call: Box::new(
                 item.macro_call()
-                    .ok_or(format!("{}", stringify!(macro_call)))
+                    .ok_or(stringify!(macro_call))
                     .map(|it| MacroCall::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3075,7 +2944,7 @@ impl TryFrom<super::nodes::MacroItems> for MacroItems {
                 .into_iter()
                 .map(Item::try_from)
                 .collect::<Result<Vec<Item>, String>>()?,
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3085,10 +2954,10 @@ impl TryFrom<super::nodes::MacroPat> for MacroPat {
         Ok(Self {
             macro_call: Box::new(
                 item.macro_call()
-                    .ok_or(format!("{}", stringify!(macro_call)))
+                    .ok_or(stringify!(macro_call))
                     .map(|it| MacroCall::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3107,17 +2976,13 @@ impl TryFrom<super::nodes::MacroRules> for MacroRules {
             },
             macro_rules_token: item.macro_rules_token().is_some(),
             excl_token: item.excl_token().is_some(),
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             token_tree: Box::new(
                 item.token_tree()
-                    .ok_or(format!("{}", stringify!(token_tree)))
+                    .ok_or(stringify!(token_tree))
                     .map(|it| TokenTree::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3134,7 +2999,7 @@ impl TryFrom<super::nodes::MacroStmts> for MacroStmts {
                 Some(it) => Some(Box::new(Expr::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3144,10 +3009,10 @@ impl TryFrom<super::nodes::MacroType> for MacroType {
         Ok(Self {
             macro_call: Box::new(
                 item.macro_call()
-                    .ok_or(format!("{}", stringify!(macro_call)))
+                    .ok_or(stringify!(macro_call))
                     .map(|it| MacroCall::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3169,13 +3034,9 @@ impl TryFrom<super::nodes::MatchArm> for MatchArm {
                 None => None,
             },
             fat_arrow_token: item.fat_arrow_token().is_some(),
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
             comma_token: item.comma_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3195,7 +3056,7 @@ impl TryFrom<super::nodes::MatchArmList> for MatchArmList {
                 .map(MatchArm::try_from)
                 .collect::<Result<Vec<MatchArm>, String>>()?,
             r_curly_token: item.r_curly_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3209,17 +3070,13 @@ impl TryFrom<super::nodes::MatchExpr> for MatchExpr {
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
             match_token: item.match_token().is_some(),
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
             match_arm_list: Box::new(
                 item.match_arm_list()
-                    .ok_or(format!("{}", stringify!(match_arm_list)))
+                    .ok_or(stringify!(match_arm_list))
                     .map(|it| MatchArmList::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3238,17 +3095,13 @@ impl TryFrom<super::nodes::MatchesExpr> for MatchesExpr {
                 .into_iter()
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
             matches_token: item.matches_token().is_some(),
             pat: match item.pat() {
                 Some(it) => Some(Box::new(Pat::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3258,11 +3111,7 @@ impl TryFrom<super::nodes::Meta> for Meta {
         Ok(Self {
             unsafe_token: item.unsafe_token().is_some(),
             l_paren_token: item.l_paren_token().is_some(),
-            path: Box::new(
-                item.path()
-                    .ok_or(format!("{}", stringify!(path)))
-                    .map(|it| Path::try_from(it))??,
-            ),
+            path: Box::new(item.path().ok_or(stringify!(path)).map(|it| Path::try_from(it))??),
             eq_token: item.eq_token().is_some(),
             expr: match item.expr() {
                 Some(it) => Some(Box::new(Expr::try_from(it)?)),
@@ -3273,7 +3122,7 @@ impl TryFrom<super::nodes::Meta> for Meta {
                 None => None,
             },
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3287,26 +3136,20 @@ impl TryFrom<super::nodes::MethodCallExpr> for MethodCallExpr {
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
             receiver: Box::new(
-                item.receiver()
-                    .ok_or(format!("{}", stringify!(receiver)))
-                    .map(|it| Expr::try_from(it))??,
+                item.receiver().ok_or(stringify!(receiver)).map(|it| Expr::try_from(it))??,
             ),
             dot_token: item.dot_token().is_some(),
             name_ref: Box::new(
-                item.name_ref()
-                    .ok_or(format!("{}", stringify!(name_ref)))
-                    .map(|it| NameRef::try_from(it))??,
+                item.name_ref().ok_or(stringify!(name_ref)).map(|it| NameRef::try_from(it))??,
             ),
             generic_arg_list: match item.generic_arg_list() {
                 Some(it) => Some(Box::new(GenericArgList::try_from(it)?)),
                 None => None,
             },
             arg_list: Box::new(
-                item.arg_list()
-                    .ok_or(format!("{}", stringify!(arg_list)))
-                    .map(|it| ArgList::try_from(it))??,
+                item.arg_list().ok_or(stringify!(arg_list)).map(|it| ArgList::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3318,7 +3161,7 @@ impl TryFrom<super::nodes::ModeSpecChecked> for ModeSpecChecked {
             l_paren_token: item.l_paren_token().is_some(),
             checked_token: item.checked_token().is_some(),
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3336,17 +3179,13 @@ impl TryFrom<super::nodes::Module> for Module {
                 None => None,
             },
             mod_token: item.mod_token().is_some(),
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             item_list: match item.item_list() {
                 Some(it) => Some(Box::new(ItemList::try_from(it)?)),
                 None => None,
             },
             semicolon_token: item.semicolon_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3354,9 +3193,9 @@ impl TryFrom<super::nodes::Name> for Name {
     type Error = String;
     fn try_from(item: super::nodes::Name) -> Result<Self, Self::Error> {
         Ok(Self {
-            ident_token: item.ident_token().map(|it| it.text().to_string()),
+            ident_token: item.ident_token().map(|it| it.text().into()),
             self_token: item.self_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3364,12 +3203,12 @@ impl TryFrom<super::nodes::NameRef> for NameRef {
     type Error = String;
     fn try_from(item: super::nodes::NameRef) -> Result<Self, Self::Error> {
         Ok(Self {
-            ident_token: item.ident_token().map(|it| it.text().to_string()),
+            ident_token: item.ident_token().map(|it| it.text().into()),
             self_token: item.self_token().is_some(),
             super_token: item.super_token().is_some(),
             crate_token: item.crate_token().is_some(),
             Self_token: item.Self_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3389,7 +3228,7 @@ impl TryFrom<super::nodes::NoUnwindClause> for NoUnwindClause {
                 Some(it) => Some(Box::new(Expr::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3417,7 +3256,7 @@ impl TryFrom<super::nodes::OffsetOfExpr> for OffsetOfExpr {
                 .map(NameRef::try_from)
                 .collect::<Result<Vec<NameRef>, String>>()?,
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3435,7 +3274,7 @@ impl TryFrom<super::nodes::OpensInvariantsClause> for OpensInvariantsClause {
                 .map(Expr::try_from)
                 .collect::<Result<Vec<Expr>, String>>()?,
             r_brack_token: item.r_brack_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3448,7 +3287,7 @@ impl TryFrom<super::nodes::OrPat> for OrPat {
                 .into_iter()
                 .map(Pat::try_from)
                 .collect::<Result<Vec<Pat>, String>>()?,
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3472,7 +3311,7 @@ impl TryFrom<super::nodes::Param> for Param {
                 None => None,
             },
             dotdotdot_token: item.dotdotdot_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3493,7 +3332,7 @@ impl TryFrom<super::nodes::ParamList> for ParamList {
                 .collect::<Result<Vec<Param>, String>>()?,
             r_paren_token: item.r_paren_token().is_some(),
             pipe_token: item.pipe_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3507,13 +3346,9 @@ impl TryFrom<super::nodes::ParenExpr> for ParenExpr {
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
             l_paren_token: item.l_paren_token().is_some(),
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3527,7 +3362,7 @@ impl TryFrom<super::nodes::ParenPat> for ParenPat {
                 None => None,
             },
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3541,7 +3376,7 @@ impl TryFrom<super::nodes::ParenType> for ParenType {
                 None => None,
             },
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3555,11 +3390,9 @@ impl TryFrom<super::nodes::Path> for Path {
             },
             coloncolon_token: item.coloncolon_token().is_some(),
             segment: Box::new(
-                item.segment()
-                    .ok_or(format!("{}", stringify!(segment)))
-                    .map(|it| PathSegment::try_from(it))??,
+                item.segment().ok_or(stringify!(segment)).map(|it| PathSegment::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3572,12 +3405,8 @@ impl TryFrom<super::nodes::PathExpr> for PathExpr {
                 .into_iter()
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
-            path: Box::new(
-                item.path()
-                    .ok_or(format!("{}", stringify!(path)))
-                    .map(|it| Path::try_from(it))??,
-            ),
-            cst: Some(item.clone()),
+            path: Box::new(item.path().ok_or(stringify!(path)).map(|it| Path::try_from(it))??),
+            cst: Some(item),
         })
     }
 }
@@ -3585,12 +3414,8 @@ impl TryFrom<super::nodes::PathPat> for PathPat {
     type Error = String;
     fn try_from(item: super::nodes::PathPat) -> Result<Self, Self::Error> {
         Ok(Self {
-            path: Box::new(
-                item.path()
-                    .ok_or(format!("{}", stringify!(path)))
-                    .map(|it| Path::try_from(it))??,
-            ),
-            cst: Some(item.clone()),
+            path: Box::new(item.path().ok_or(stringify!(path)).map(|it| Path::try_from(it))??),
+            cst: Some(item),
         })
     }
 }
@@ -3600,9 +3425,7 @@ impl TryFrom<super::nodes::PathSegment> for PathSegment {
         Ok(Self {
             coloncolon_token: item.coloncolon_token().is_some(),
             name_ref: Box::new(
-                item.name_ref()
-                    .ok_or(format!("{}", stringify!(name_ref)))
-                    .map(|it| NameRef::try_from(it))??,
+                item.name_ref().ok_or(stringify!(name_ref)).map(|it| NameRef::try_from(it))??,
             ),
             generic_arg_list: match item.generic_arg_list() {
                 Some(it) => Some(Box::new(GenericArgList::try_from(it)?)),
@@ -3627,7 +3450,7 @@ impl TryFrom<super::nodes::PathSegment> for PathSegment {
                 None => None,
             },
             r_angle_token: item.r_angle_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3635,30 +3458,8 @@ impl TryFrom<super::nodes::PathType> for PathType {
     type Error = String;
     fn try_from(item: super::nodes::PathType) -> Result<Self, Self::Error> {
         Ok(Self {
-            path: Box::new(
-                item.path()
-                    .ok_or(format!("{}", stringify!(path)))
-                    .map(|it| Path::try_from(it))??,
-            ),
-            cst: Some(item.clone()),
-        })
-    }
-}
-impl TryFrom<super::nodes::PrefixExpr> for PrefixExpr {
-    type Error = String;
-    fn try_from(item: super::nodes::PrefixExpr) -> Result<Self, Self::Error> {
-        Ok(Self {
-            attrs: item
-                .attrs()
-                .into_iter()
-                .map(Attr::try_from)
-                .collect::<Result<Vec<Attr>, String>>()?,
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
-            cst: Some(item.clone()),
+            path: Box::new(item.path().ok_or(stringify!(path)).map(|it| Path::try_from(it))??),
+            cst: Some(item),
         })
     }
 }
@@ -3668,13 +3469,9 @@ impl TryFrom<super::nodes::Prover> for Prover {
         Ok(Self {
             by_token: item.by_token().is_some(),
             l_paren_token: item.l_paren_token().is_some(),
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3689,7 +3486,7 @@ impl TryFrom<super::nodes::PtrType> for PtrType {
                 Some(it) => Some(Box::new(Type::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3699,7 +3496,7 @@ impl TryFrom<super::nodes::Publish> for Publish {
         Ok(Self {
             closed_token: item.closed_token().is_some(),
             open_token: item.open_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3712,7 +3509,7 @@ impl TryFrom<super::nodes::RangeExpr> for RangeExpr {
                 .into_iter()
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3737,7 +3534,7 @@ impl TryFrom<super::nodes::RecommendsClause> for RecommendsClause {
                 Some(it) => Some(Box::new(Expr::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3745,17 +3542,13 @@ impl TryFrom<super::nodes::RecordExpr> for RecordExpr {
     type Error = String;
     fn try_from(item: super::nodes::RecordExpr) -> Result<Self, Self::Error> {
         Ok(Self {
-            path: Box::new(
-                item.path()
-                    .ok_or(format!("{}", stringify!(path)))
-                    .map(|it| Path::try_from(it))??,
-            ),
+            path: Box::new(item.path().ok_or(stringify!(path)).map(|it| Path::try_from(it))??),
             record_expr_field_list: Box::new(
                 item.record_expr_field_list()
-                    .ok_or(format!("{}", stringify!(record_expr_field_list)))
+                    .ok_or(stringify!(record_expr_field_list))
                     .map(|it| RecordExprFieldList::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3773,12 +3566,8 @@ impl TryFrom<super::nodes::RecordExprField> for RecordExprField {
                 None => None,
             },
             colon_token: item.colon_token().is_some(),
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
-            cst: Some(item.clone()),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
+            cst: Some(item),
         })
     }
 }
@@ -3803,7 +3592,7 @@ impl TryFrom<super::nodes::RecordExprFieldList> for RecordExprFieldList {
                 None => None,
             },
             r_curly_token: item.r_curly_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3824,17 +3613,13 @@ impl TryFrom<super::nodes::RecordField> for RecordField {
                 Some(it) => Some(Box::new(DataMode::try_from(it)?)),
                 None => None,
             },
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             colon_token: item.colon_token().is_some(),
             ty: match item.ty() {
                 Some(it) => Some(Box::new(Type::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3849,7 +3634,7 @@ impl TryFrom<super::nodes::RecordFieldList> for RecordFieldList {
                 .map(RecordField::try_from)
                 .collect::<Result<Vec<RecordField>, String>>()?,
             r_curly_token: item.r_curly_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3857,17 +3642,13 @@ impl TryFrom<super::nodes::RecordPat> for RecordPat {
     type Error = String;
     fn try_from(item: super::nodes::RecordPat) -> Result<Self, Self::Error> {
         Ok(Self {
-            path: Box::new(
-                item.path()
-                    .ok_or(format!("{}", stringify!(path)))
-                    .map(|it| Path::try_from(it))??,
-            ),
+            path: Box::new(item.path().ok_or(stringify!(path)).map(|it| Path::try_from(it))??),
             record_pat_field_list: Box::new(
                 item.record_pat_field_list()
-                    .ok_or(format!("{}", stringify!(record_pat_field_list)))
+                    .ok_or(stringify!(record_pat_field_list))
                     .map(|it| RecordPatFieldList::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3889,7 +3670,7 @@ impl TryFrom<super::nodes::RecordPatField> for RecordPatField {
                 Some(it) => Some(Box::new(Pat::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3908,7 +3689,7 @@ impl TryFrom<super::nodes::RecordPatFieldList> for RecordPatFieldList {
                 None => None,
             },
             r_curly_token: item.r_curly_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3925,12 +3706,8 @@ impl TryFrom<super::nodes::RefExpr> for RefExpr {
             raw_token: item.raw_token().is_some(),
             const_token: item.const_token().is_some(),
             mut_token: item.mut_token().is_some(),
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
-            cst: Some(item.clone()),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
+            cst: Some(item),
         })
     }
 }
@@ -3944,7 +3721,7 @@ impl TryFrom<super::nodes::RefPat> for RefPat {
                 Some(it) => Some(Box::new(Pat::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3962,7 +3739,7 @@ impl TryFrom<super::nodes::RefType> for RefType {
                 Some(it) => Some(Box::new(Type::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3976,7 +3753,7 @@ impl TryFrom<super::nodes::Rename> for Rename {
                 None => None,
             },
             underscore_token: item.underscore_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -3990,7 +3767,7 @@ impl TryFrom<super::nodes::RequiresClause> for RequiresClause {
                 .into_iter()
                 .map(Expr::try_from)
                 .collect::<Result<Vec<Expr>, String>>()?,
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4004,7 +3781,7 @@ impl TryFrom<super::nodes::RestPat> for RestPat {
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
             dotdot_token: item.dotdot_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4025,7 +3802,7 @@ impl TryFrom<super::nodes::RetType> for RetType {
                 None => None,
             },
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4043,7 +3820,7 @@ impl TryFrom<super::nodes::ReturnExpr> for ReturnExpr {
                 Some(it) => Some(Box::new(Expr::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4062,17 +3839,13 @@ impl TryFrom<super::nodes::SelfParam> for SelfParam {
                 None => None,
             },
             mut_token: item.mut_token().is_some(),
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             colon_token: item.colon_token().is_some(),
             ty: match item.ty() {
                 Some(it) => Some(Box::new(Type::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4082,7 +3855,7 @@ impl TryFrom<super::nodes::SignatureDecreases> for SignatureDecreases {
         Ok(Self {
             decreases_clause: Box::new(
                 item.decreases_clause()
-                    .ok_or(format!("{}", stringify!(decreases_clause)))
+                    .ok_or(stringify!(decreases_clause))
                     .map(|it| DecreasesClause::try_from(it))??,
             ),
             when_token: item.when_token().is_some(),
@@ -4091,7 +3864,7 @@ impl TryFrom<super::nodes::SignatureDecreases> for SignatureDecreases {
                 None => None,
             },
             via_token: item.via_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4106,7 +3879,7 @@ impl TryFrom<super::nodes::SlicePat> for SlicePat {
                 .map(Pat::try_from)
                 .collect::<Result<Vec<Pat>, String>>()?,
             r_brack_token: item.r_brack_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4120,7 +3893,7 @@ impl TryFrom<super::nodes::SliceType> for SliceType {
                 None => None,
             },
             r_brack_token: item.r_brack_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4139,7 +3912,7 @@ impl TryFrom<super::nodes::SourceFile> for SourceFile {
                 .into_iter()
                 .map(Item::try_from)
                 .collect::<Result<Vec<Item>, String>>()?,
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4158,11 +3931,7 @@ impl TryFrom<super::nodes::Static> for Static {
             },
             static_token: item.static_token().is_some(),
             mut_token: item.mut_token().is_some(),
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             colon_token: item.colon_token().is_some(),
             ty: match item.ty() {
                 Some(it) => Some(Box::new(Type::try_from(it)?)),
@@ -4174,7 +3943,7 @@ impl TryFrom<super::nodes::Static> for Static {
                 None => None,
             },
             semicolon_token: item.semicolon_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4198,7 +3967,7 @@ impl TryFrom<super::nodes::StmtList> for StmtList {
                 None => None,
             },
             r_curly_token: item.r_curly_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4220,11 +3989,7 @@ impl TryFrom<super::nodes::Struct> for Struct {
                 None => None,
             },
             struct_token: item.struct_token().is_some(),
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             generic_param_list: match item.generic_param_list() {
                 Some(it) => Some(Box::new(GenericParamList::try_from(it)?)),
                 None => None,
@@ -4238,7 +4003,7 @@ impl TryFrom<super::nodes::Struct> for Struct {
                 Some(it) => Some(Box::new(FieldList::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4252,7 +4017,7 @@ impl TryFrom<super::nodes::TokenTree> for TokenTree {
             r_curly_token: item.r_curly_token().is_some(),
             l_brack_token: item.l_brack_token().is_some(),
             r_brack_token: item.r_brack_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4272,11 +4037,7 @@ impl TryFrom<super::nodes::Trait> for Trait {
             unsafe_token: item.unsafe_token().is_some(),
             auto_token: item.auto_token().is_some(),
             trait_token: item.trait_token().is_some(),
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             generic_param_list: match item.generic_param_list() {
                 Some(it) => Some(Box::new(GenericParamList::try_from(it)?)),
                 None => None,
@@ -4292,10 +4053,10 @@ impl TryFrom<super::nodes::Trait> for Trait {
             },
             assoc_item_list: Box::new(
                 item.assoc_item_list()
-                    .ok_or(format!("{}", stringify!(assoc_item_list)))
+                    .ok_or(stringify!(assoc_item_list))
                     .map(|it| AssocItemList::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4313,11 +4074,7 @@ impl TryFrom<super::nodes::TraitAlias> for TraitAlias {
                 None => None,
             },
             trait_token: item.trait_token().is_some(),
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             generic_param_list: match item.generic_param_list() {
                 Some(it) => Some(Box::new(GenericParamList::try_from(it)?)),
                 None => None,
@@ -4332,7 +4089,7 @@ impl TryFrom<super::nodes::TraitAlias> for TraitAlias {
                 None => None,
             },
             semicolon_token: item.semicolon_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4346,7 +4103,7 @@ impl TryFrom<super::nodes::TriggerAttribute> for TriggerAttribute {
                 .into_iter()
                 .map(Expr::try_from)
                 .collect::<Result<Vec<Expr>, String>>()?,
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4359,13 +4116,9 @@ impl TryFrom<super::nodes::TryExpr> for TryExpr {
                 .into_iter()
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
             question_mark_token: item.question_mark_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4385,7 +4138,7 @@ impl TryFrom<super::nodes::TupleExpr> for TupleExpr {
                 .map(Expr::try_from)
                 .collect::<Result<Vec<Expr>, String>>()?,
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4406,7 +4159,7 @@ impl TryFrom<super::nodes::TupleField> for TupleField {
                 Some(it) => Some(Box::new(Type::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4421,7 +4174,7 @@ impl TryFrom<super::nodes::TupleFieldList> for TupleFieldList {
                 .map(TupleField::try_from)
                 .collect::<Result<Vec<TupleField>, String>>()?,
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4436,7 +4189,7 @@ impl TryFrom<super::nodes::TuplePat> for TuplePat {
                 .map(Pat::try_from)
                 .collect::<Result<Vec<Pat>, String>>()?,
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4444,11 +4197,7 @@ impl TryFrom<super::nodes::TupleStructPat> for TupleStructPat {
     type Error = String;
     fn try_from(item: super::nodes::TupleStructPat) -> Result<Self, Self::Error> {
         Ok(Self {
-            path: Box::new(
-                item.path()
-                    .ok_or(format!("{}", stringify!(path)))
-                    .map(|it| Path::try_from(it))??,
-            ),
+            path: Box::new(item.path().ok_or(stringify!(path)).map(|it| Path::try_from(it))??),
             l_paren_token: item.l_paren_token().is_some(),
             fields: item
                 .fields()
@@ -4456,7 +4205,7 @@ impl TryFrom<super::nodes::TupleStructPat> for TupleStructPat {
                 .map(Pat::try_from)
                 .collect::<Result<Vec<Pat>, String>>()?,
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4471,7 +4220,7 @@ impl TryFrom<super::nodes::TupleType> for TupleType {
                 .map(Type::try_from)
                 .collect::<Result<Vec<Type>, String>>()?,
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4490,11 +4239,7 @@ impl TryFrom<super::nodes::TypeAlias> for TypeAlias {
             },
             default_token: item.default_token().is_some(),
             type_token: item.type_token().is_some(),
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             generic_param_list: match item.generic_param_list() {
                 Some(it) => Some(Box::new(GenericParamList::try_from(it)?)),
                 None => None,
@@ -4514,7 +4259,7 @@ impl TryFrom<super::nodes::TypeAlias> for TypeAlias {
                 None => None,
             },
             semicolon_token: item.semicolon_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4526,7 +4271,7 @@ impl TryFrom<super::nodes::TypeArg> for TypeArg {
                 Some(it) => Some(Box::new(Type::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4546,7 +4291,7 @@ impl TryFrom<super::nodes::TypeBound> for TypeBound {
                 Some(it) => Some(Box::new(Type::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4559,7 +4304,7 @@ impl TryFrom<super::nodes::TypeBoundList> for TypeBoundList {
                 .into_iter()
                 .map(TypeBound::try_from)
                 .collect::<Result<Vec<TypeBound>, String>>()?,
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4572,11 +4317,7 @@ impl TryFrom<super::nodes::TypeParam> for TypeParam {
                 .into_iter()
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             colon_token: item.colon_token().is_some(),
             type_bound_list: match item.type_bound_list() {
                 Some(it) => Some(Box::new(TypeBoundList::try_from(it)?)),
@@ -4587,7 +4328,7 @@ impl TryFrom<super::nodes::TypeParam> for TypeParam {
                 Some(it) => Some(Box::new(Type::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4601,7 +4342,7 @@ impl TryFrom<super::nodes::UnderscoreExpr> for UnderscoreExpr {
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
             underscore_token: item.underscore_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4619,11 +4360,7 @@ impl TryFrom<super::nodes::Union> for Union {
                 None => None,
             },
             union_token: item.union_token().is_some(),
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             generic_param_list: match item.generic_param_list() {
                 Some(it) => Some(Box::new(GenericParamList::try_from(it)?)),
                 None => None,
@@ -4634,10 +4371,10 @@ impl TryFrom<super::nodes::Union> for Union {
             },
             record_field_list: Box::new(
                 item.record_field_list()
-                    .ok_or(format!("{}", stringify!(record_field_list)))
+                    .ok_or(stringify!(record_field_list))
                     .map(|it| RecordFieldList::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4656,12 +4393,10 @@ impl TryFrom<super::nodes::Use> for Use {
             },
             use_token: item.use_token().is_some(),
             use_tree: Box::new(
-                item.use_tree()
-                    .ok_or(format!("{}", stringify!(use_tree)))
-                    .map(|it| UseTree::try_from(it))??,
+                item.use_tree().ok_or(stringify!(use_tree)).map(|it| UseTree::try_from(it))??,
             ),
             semicolon_token: item.semicolon_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4683,7 +4418,7 @@ impl TryFrom<super::nodes::UseTree> for UseTree {
                 Some(it) => Some(Box::new(Rename::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4698,7 +4433,7 @@ impl TryFrom<super::nodes::UseTreeList> for UseTreeList {
                 .map(UseTree::try_from)
                 .collect::<Result<Vec<UseTree>, String>>()?,
             r_curly_token: item.r_curly_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4715,11 +4450,7 @@ impl TryFrom<super::nodes::Variant> for Variant {
                 Some(it) => Some(Box::new(Visibility::try_from(it)?)),
                 None => None,
             },
-            name: Box::new(
-                item.name()
-                    .ok_or(format!("{}", stringify!(name)))
-                    .map(|it| Name::try_from(it))??,
-            ),
+            name: Box::new(item.name().ok_or(stringify!(name)).map(|it| Name::try_from(it))??),
             field_list: match item.field_list() {
                 Some(it) => Some(Box::new(FieldList::try_from(it)?)),
                 None => None,
@@ -4729,7 +4460,7 @@ impl TryFrom<super::nodes::Variant> for Variant {
                 Some(it) => Some(Box::new(Expr::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4744,7 +4475,7 @@ impl TryFrom<super::nodes::VariantList> for VariantList {
                 .map(Variant::try_from)
                 .collect::<Result<Vec<Variant>, String>>()?,
             r_curly_token: item.r_curly_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4769,7 +4500,7 @@ impl TryFrom<super::nodes::VerusGlobal> for VerusGlobal {
             comma_token: item.comma_token().is_some(),
             align_token: item.align_token().is_some(),
             semicolon_token: item.semicolon_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4782,13 +4513,9 @@ impl TryFrom<super::nodes::ViewExpr> for ViewExpr {
                 .into_iter()
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
-            expr: Box::new(
-                item.expr()
-                    .ok_or(format!("{}", stringify!(expr)))
-                    .map(|it| Expr::try_from(it))??,
-            ),
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
             at_token: item.at_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4804,7 +4531,7 @@ impl TryFrom<super::nodes::Visibility> for Visibility {
                 None => None,
             },
             r_paren_token: item.r_paren_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4818,7 +4545,7 @@ impl TryFrom<super::nodes::WhereClause> for WhereClause {
                 .into_iter()
                 .map(WherePred::try_from)
                 .collect::<Result<Vec<WherePred>, String>>()?,
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4844,35 +4571,7 @@ impl TryFrom<super::nodes::WherePred> for WherePred {
                 Some(it) => Some(Box::new(TypeBoundList::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
-        })
-    }
-}
-impl TryFrom<super::nodes::WhileExpr> for WhileExpr {
-    type Error = String;
-    fn try_from(item: super::nodes::WhileExpr) -> Result<Self, Self::Error> {
-        Ok(Self {
-            attrs: item
-                .attrs()
-                .into_iter()
-                .map(Attr::try_from)
-                .collect::<Result<Vec<Attr>, String>>()?,
-            label: match item.label() {
-                Some(it) => Some(Box::new(Label::try_from(it)?)),
-                None => None,
-            },
-            while_token: item.while_token().is_some(),
-            loop_clauses: item
-                .loop_clauses()
-                .into_iter()
-                .map(LoopClause::try_from)
-                .collect::<Result<Vec<LoopClause>, String>>()?,
-            loop_body: Box::new(
-                item.loop_body()
-                    .ok_or(format!("{}", stringify!(loop_body)))
-                    .map(|it| BlockExpr::try_from(it))??,
-            ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4897,7 +4596,7 @@ impl TryFrom<super::nodes::YeetExpr> for YeetExpr {
                 Some(it) => Some(Box::new(Expr::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -4915,7 +4614,7 @@ impl TryFrom<super::nodes::YieldExpr> for YieldExpr {
                 Some(it) => Some(Box::new(Expr::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -5935,7 +5634,7 @@ impl std::fmt::Display for DecreasesClause {
             s.push_str(token_ascii(&tmp));
             s.push_str(" ");
         }
-        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(", "));
         write!(f, "{s}")
     }
 }
@@ -6584,7 +6283,7 @@ impl std::fmt::Display for InvariantClause {
             s.push_str(token_ascii(&tmp));
             s.push_str(" ");
         }
-        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(", "));
         write!(f, "{s}")
     }
 }
@@ -7573,15 +7272,6 @@ impl std::fmt::Display for PathType {
         write!(f, "{s}")
     }
 }
-impl std::fmt::Display for PrefixExpr {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut s = String::new();
-        s.push_str(&self.attrs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
-        s.push_str(&self.expr.to_string());
-        s.push_str(" ");
-        write!(f, "{s}")
-    }
-}
 impl std::fmt::Display for Prover {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut s = String::new();
@@ -7967,7 +7657,7 @@ impl std::fmt::Display for RequiresClause {
             s.push_str(token_ascii(&tmp));
             s.push_str(" ");
         }
-        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        s.push_str(&self.exprs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(", "));
         write!(f, "{s}")
     }
 }
@@ -9029,28 +8719,6 @@ impl std::fmt::Display for WherePred {
         write!(f, "{s}")
     }
 }
-impl std::fmt::Display for WhileExpr {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut s = String::new();
-        s.push_str(&self.attrs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
-        if let Some(it) = &self.label {
-            s.push_str(&it.to_string());
-            s.push_str(" ");
-        }
-        if self.while_token {
-            let mut tmp = stringify!(while_token).to_string();
-            tmp.truncate(tmp.len() - 6);
-            s.push_str(token_ascii(&tmp));
-            s.push_str(" ");
-        }
-        s.push_str(
-            &self.loop_clauses.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "),
-        );
-        s.push_str(&self.loop_body.to_string());
-        s.push_str(" ");
-        write!(f, "{s}")
-    }
-}
 impl std::fmt::Display for WildcardPat {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut s = String::new();
@@ -10896,14 +10564,6 @@ impl PathSegment {
 impl PathType {
     pub fn new(path: Path) -> Self { Self { path: Box::new(path), cst: None } }
 }
-impl PrefixExpr {
-    pub fn new<ET0>(expr: ET0) -> Self
-    where
-        ET0: Into<Expr>,
-    {
-        Self { attrs: vec![], expr: Box::new(expr.into()), cst: None }
-    }
-}
 impl Prover {
     pub fn new(name: Name) -> Self {
         Self {
@@ -11398,18 +11058,6 @@ impl WherePred {
         }
     }
 }
-impl WhileExpr {
-    pub fn new(loop_body: BlockExpr) -> Self {
-        Self {
-            attrs: vec![],
-            label: None,
-            while_token: true,
-            loop_clauses: vec![],
-            loop_body: Box::new(loop_body),
-            cst: None,
-        }
-    }
-}
 impl WildcardPat {
     pub fn new() -> Self { Self { underscore_token: true, cst: None } }
 }
@@ -684,6 +684,18 @@ impl ast::Variant {
     }
 }
 
+impl ast::Fn {
+    /// verus: `assume_specification [path] (..)` items have no name, they specify the external
+    /// function at `path` instead.
+    pub fn assume_specification_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![assume_specification])
+    }
+
+    pub fn assume_specification_path(&self) -> Option<ast::Path> {
+        support::child(&self.syntax)
+    }
+}
+
 impl ast::Item {
     pub fn generic_param_list(&self) -> Option<ast::GenericParamList> {
         ast::AnyHasGenericParams::cast(self.syntax().clone())?.generic_param_list()
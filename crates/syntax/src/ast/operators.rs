@@ -111,6 +111,17 @@ impl fmt::Display for CmpOp {
     }
 }
 
+impl fmt::Display for UnaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let res = match self {
+            UnaryOp::Deref => "*",
+            UnaryOp::Not => "!",
+            UnaryOp::Neg => "-",
+        };
+        f.write_str(res)
+    }
+}
+
 impl fmt::Display for BinaryOp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
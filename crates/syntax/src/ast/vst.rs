@@ -1,8 +1,14 @@
 // defines VST handwritten nodes
 
-pub use crate::ast::{self, generated::vst_nodes::*, operators::BinaryOp};
+pub use crate::ast::{
+    self,
+    generated::vst_nodes::*,
+    operators::{BinaryOp, UnaryOp},
+};
 
 pub use super::{generated, HasAttrs};
+use super::HasLoopBody;
+use crate::SmolStr;
 
 pub(crate) fn token_ascii(name: &String) -> &str {
     match name.as_str() {
@@ -111,6 +117,83 @@ impl IfExpr {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WhileExpr {
+    pub attrs: Vec<Attr>,
+    pub label: Option<Box<Label>>,
+    while_token: bool,
+    pub condition: Box<Expr>,
+    pub loop_clauses: Vec<LoopClause>,
+    pub loop_body: Box<BlockExpr>,
+    pub cst: Option<generated::nodes::WhileExpr>,
+}
+
+impl std::fmt::Display for WhileExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut s = String::new();
+        s.push_str(&self.attrs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        if let Some(it) = &self.label {
+            s.push_str(&it.to_string());
+            s.push_str(" ");
+        }
+        if self.while_token {
+            s.push_str("while ");
+        }
+        s.push_str(&self.condition.to_string());
+        s.push_str(" ");
+        s.push_str(
+            &self.loop_clauses.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "),
+        );
+        s.push_str(&self.loop_body.to_string());
+        s.push_str(" ");
+        write!(f, "{s}")
+    }
+}
+
+impl WhileExpr {
+    pub fn new<ET0>(condition: ET0, loop_body: BlockExpr) -> Self
+    where
+        ET0: Into<Expr>,
+    {
+        WhileExpr {
+            attrs: vec![],
+            label: None,
+            while_token: true,
+            condition: Box::new(condition.into()),
+            loop_clauses: vec![],
+            loop_body: Box::new(loop_body),
+            cst: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PrefixExpr {
+    pub attrs: Vec<Attr>,
+    pub op: UnaryOp,
+    pub expr: Box<Expr>,
+    pub cst: Option<generated::nodes::PrefixExpr>,
+}
+
+impl std::fmt::Display for PrefixExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut s = String::new();
+        s.push_str(&self.attrs.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(" "));
+        s.push_str(&self.op.to_string());
+        s.push_str(&self.expr.to_string());
+        write!(f, "{s}")
+    }
+}
+
+impl PrefixExpr {
+    pub fn new<ET0>(op: UnaryOp, expr: ET0) -> Self
+    where
+        ET0: Into<Expr>,
+    {
+        PrefixExpr { attrs: vec![], op, expr: Box::new(expr.into()), cst: None }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ElseBranch {
     Block(Box<BlockExpr>),
@@ -143,7 +226,7 @@ impl ElseBranch {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Literal {
     pub attrs: Vec<Attr>,
-    pub literal: String,
+    pub literal: SmolStr,
     pub cst: Option<generated::nodes::Literal>,
 }
 
@@ -157,32 +240,22 @@ impl std::fmt::Display for Literal {
 }
 
 impl Literal {
-    pub fn new(id: String) -> Self {
-        Literal { attrs: vec![], literal: id.clone(), cst: None }
+    pub fn new(id: impl Into<SmolStr>) -> Self {
+        Literal { attrs: vec![], literal: id.into(), cst: None }
     }
 }
 
 impl TryFrom<generated::nodes::Literal> for Literal {
     type Error = String;
     fn try_from(item: generated::nodes::Literal) -> Result<Self, Self::Error> {
-        use ast::expr_ext::LiteralKind;
         Ok(Self {
             attrs: item
                 .attrs()
                 .into_iter()
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
-            literal: match item.kind() {
-                LiteralKind::String(it) => it.to_string(),
-                LiteralKind::ByteString(it) => it.to_string(),
-                LiteralKind::CString(it) => it.to_string(),
-                LiteralKind::IntNumber(it) => it.to_string(),
-                LiteralKind::FloatNumber(it) => it.to_string(),
-                LiteralKind::Char(it) => it.to_string(),
-                LiteralKind::Byte(it) => it.to_string(),
-                LiteralKind::Bool(it) => it.to_string(),
-            },
-            cst: Some(item.clone()),
+            literal: item.token().text().into(),
+            cst: Some(item),
         })
     }
 }
@@ -196,14 +269,10 @@ impl TryFrom<generated::nodes::BinExpr> for BinExpr {
                 .into_iter()
                 .map(Attr::try_from)
                 .collect::<Result<Vec<Attr>, String>>()?,
-            lhs: Box::new(
-                item.lhs().ok_or(format!("{}", stringify!(lhs))).map(|it| Expr::try_from(it))??,
-            ),
-            op: item.op_details().ok_or(format!("{}", stringify!(op_details))).map(|it| it.1)?,
-            rhs: Box::new(
-                item.rhs().ok_or(format!("{}", stringify!(rhs))).map(|it| Expr::try_from(it))??,
-            ),
-            cst: Some(item.clone()),
+            lhs: Box::new(item.lhs().ok_or(stringify!(lhs)).map(|it| Expr::try_from(it))??),
+            op: item.op_details().ok_or(stringify!(op_details)).map(|it| it.1)?,
+            rhs: Box::new(item.rhs().ok_or(stringify!(rhs)).map(|it| Expr::try_from(it))??),
+            cst: Some(item),
         })
     }
 }
@@ -245,13 +314,11 @@ impl TryFrom<generated::nodes::IfExpr> for IfExpr {
                 .collect::<Result<Vec<Attr>, String>>()?,
             if_token: item.if_token().is_some(),
             condition: Box::new(
-                item.condition()
-                    .ok_or(format!("{}", stringify!(condition)))
-                    .map(|it| Expr::try_from(it))??,
+                item.condition().ok_or(stringify!(condition)).map(|it| Expr::try_from(it))??,
             ),
             then_branch: Box::new(
                 item.then_branch()
-                    .ok_or(format!("{}", stringify!(then_branch)))
+                    .ok_or(stringify!(then_branch))
                     .map(|it| BlockExpr::try_from(it))??,
             ),
             else_token: item.else_token().is_some(),
@@ -259,7 +326,55 @@ impl TryFrom<generated::nodes::IfExpr> for IfExpr {
                 Some(it) => Some(Box::new(ElseBranch::try_from(it)?)),
                 None => None,
             },
-            cst: Some(item.clone()),
+            cst: Some(item),
+        })
+    }
+}
+
+impl TryFrom<generated::nodes::PrefixExpr> for PrefixExpr {
+    type Error = String;
+    fn try_from(item: generated::nodes::PrefixExpr) -> Result<Self, Self::Error> {
+        Ok(Self {
+            attrs: item
+                .attrs()
+                .into_iter()
+                .map(Attr::try_from)
+                .collect::<Result<Vec<Attr>, String>>()?,
+            op: item.op_kind().ok_or(stringify!(op_kind))?,
+            expr: Box::new(item.expr().ok_or(stringify!(expr)).map(|it| Expr::try_from(it))??),
+            cst: Some(item),
+        })
+    }
+}
+
+impl TryFrom<generated::nodes::WhileExpr> for WhileExpr {
+    type Error = String;
+    fn try_from(item: generated::nodes::WhileExpr) -> Result<Self, Self::Error> {
+        Ok(Self {
+            attrs: item
+                .attrs()
+                .into_iter()
+                .map(Attr::try_from)
+                .collect::<Result<Vec<Attr>, String>>()?,
+            label: match item.label() {
+                Some(it) => Some(Box::new(Label::try_from(it)?)),
+                None => None,
+            },
+            while_token: item.while_token().is_some(),
+            condition: Box::new(
+                item.condition().ok_or(stringify!(condition)).map(|it| Expr::try_from(it))??,
+            ),
+            loop_clauses: item
+                .loop_clauses()
+                .into_iter()
+                .map(LoopClause::try_from)
+                .collect::<Result<Vec<LoopClause>, String>>()?,
+            loop_body: Box::new(
+                item.loop_body()
+                    .ok_or(stringify!(loop_body))
+                    .map(|it| BlockExpr::try_from(it))??,
+            ),
+            cst: Some(item),
         })
     }
 }
@@ -404,7 +519,7 @@ impl TryFrom<generated::nodes::AssertForallExpr> for AssertForallExpr {
             assert_token: item.assert_token().is_some(),
             closure_expr: Box::new(
                 item.closure_expr()
-                    .ok_or(format!("{}", stringify!(closure_expr)))
+                    .ok_or(stringify!(closure_expr))
                     .map(|it| ClosureExpr::try_from(it))??,
             ),
             implies_token: item.implies_token().is_some(),
@@ -418,10 +533,10 @@ impl TryFrom<generated::nodes::AssertForallExpr> for AssertForallExpr {
             by_token: item.by_token().is_some(),
             block_expr: Box::new(
                 item.block_expr()
-                    .ok_or(format!("{}", stringify!(block_expr)))
+                    .ok_or(stringify!(block_expr))
                     .map(|it| BlockExpr::try_from(it))??,
             ),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
@@ -478,7 +593,7 @@ impl TryFrom<generated::nodes::IndexExpr> for IndexExpr {
                 None => return Err(String::from("no index for indexExpr")),
             },
             r_brack_token: item.r_brack_token().is_some(),
-            cst: Some(item.clone()),
+            cst: Some(item),
         })
     }
 }
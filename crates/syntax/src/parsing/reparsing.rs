@@ -5,6 +5,10 @@
 //!     letter), we replace only this token.
 //!   - otherwise, we search for the nearest `{}` block which contains the edit
 //!     and try to parse only this block.
+//!   - failing that, we reparse the top-level item containing the edit. Verus
+//!     files often consist of a single huge `verus!` block whose items are
+//!     top-level, so an edit to a signature or a specification would otherwise
+//!     reparse the whole file.
 
 use parser::Reparser;
 use text_edit::Indel;
@@ -31,6 +35,11 @@ pub(crate) fn incremental_reparse(
     {
         return Some((green, merge_errors(errors, new_errors, old_range, edit), old_range));
     }
+
+    if let Some((green, new_errors, old_range)) = reparse_item(node, edit, parser::Edition::CURRENT)
+    {
+        return Some((green, merge_errors(errors, new_errors, old_range, edit), old_range));
+    }
     None
 }
 
@@ -104,6 +113,40 @@ fn reparse_block(
     Some((node.replace_with(green), new_parser_errors, node.text_range()))
 }
 
+fn reparse_item(
+    root: &SyntaxNode,
+    edit: &Indel,
+    edition: parser::Edition,
+) -> Option<(GreenNode, Vec<SyntaxError>, TextRange)> {
+    let item = root
+        .covering_element(edit.delete)
+        .ancestors()
+        .find(|it| it.parent().map_or(false, |parent| parent.kind() == SOURCE_FILE))?;
+    // An edit at the boundary of the item may merge it with its neighbours.
+    let item_range = item.text_range();
+    if edit.delete.start() <= item_range.start() || edit.delete.end() >= item_range.end() {
+        return None;
+    }
+    let text = get_text_after_edit(item.clone().into(), edit);
+
+    // Unterminated strings or comments would swallow the following items.
+    let lexed = parser::LexedStr::new(text.as_str());
+    if lexed.errors().next().is_some() || !has_balanced_braces(&lexed, 0..lexed.len()) {
+        return None;
+    }
+    let parser_input = lexed.to_input();
+    let tree_traversal = parser::TopEntryPoint::SourceFile.parse(&parser_input, edition);
+    let (source_file, new_parser_errors, _eof) = build_tree(lexed, tree_traversal);
+
+    // The new text has to still be a single item.
+    let mut children = source_file.children();
+    let new_item = match (children.next(), children.next()) {
+        (Some(NodeOrToken::Node(it)), None) if it.text_len() == TextSize::of(&text) => it,
+        _ => return None,
+    };
+    Some((item.replace_with(new_item.to_owned()), new_parser_errors, item_range))
+}
+
 fn get_text_after_edit(element: SyntaxElement, edit: &Indel) -> String {
     let edit = Indel::replace(edit.delete - element.text_range().start(), edit.insert.clone());
 
@@ -133,8 +176,14 @@ fn is_balanced(lexed: &parser::LexedStr<'_>) -> bool {
     if lexed.is_empty() || lexed.kind(0) != T!['{'] || lexed.kind(lexed.len() - 1) != T!['}'] {
         return false;
     }
+    has_balanced_braces(lexed, 1..lexed.len() - 1)
+}
+
+/// Whether every brace among the tokens `range` of `lexed` is closed, and no brace is closed
+/// before it is opened.
+fn has_balanced_braces(lexed: &parser::LexedStr<'_>, range: std::ops::Range<usize>) -> bool {
     let mut balance = 0usize;
-    for i in 1..lexed.len() - 1 {
+    for i in range {
         match lexed.kind(i) {
             T!['{'] => balance += 1,
             T!['}'] => {
@@ -317,6 +366,57 @@ extern {
         );
     }
 
+    #[test]
+    fn reparse_item_tests() {
+        do_check(
+            r"
+verus! {
+spec fn f(x: int) -> int { x }
+proof fn lemma(x: int)
+    ensures f(x) == $0x$0,
+{
+}
+}
+",
+            "x + 0",
+            49,
+        );
+        do_check(
+            r"
+fn foo($0$0) {}
+fn bar() {}
+",
+            "x: i32",
+            11,
+        );
+        do_check(
+            r"
+verus! {
+proof fn lemma()
+    requires $0true$0,
+{
+}
+}
+",
+            "true,\n    false",
+            39,
+        );
+    }
+
+    #[test]
+    fn reparse_item_unbalanced_braces() {
+        // A full parse lets the unclosed block swallow `bar`, so the item alone is not reparsed.
+        for (before, replace_with) in [
+            ("\nfn foo() {\n    if x { y $0}$0\n}\nfn bar() {}\n", ""),
+            ("\nfn foo() {\n    if x $0$0\n}\nfn bar() {}\n", "{"),
+        ] {
+            let (range, before) = extract_range(before);
+            let edit = Indel::replace(range, replace_with.to_owned());
+            let before = SourceFile::parse(&before, Edition::CURRENT);
+            assert!(reparse_item(before.tree().syntax(), &edit, Edition::CURRENT).is_none());
+        }
+    }
+
     #[test]
     fn reparse_token_tests() {
         do_check(
@@ -48,6 +48,23 @@ fn benchmark_parser() {
     }
 }
 
+#[test]
+fn benchmark_vst_conversion() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let data = bench_fixture::glorious_old_parser();
+    let tree = SourceFile::parse(&data, Edition::CURRENT).tree();
+    let fns: Vec<ast::Fn> = tree.syntax().descendants().filter_map(ast::Fn::cast).collect();
+
+    let converted = {
+        let _b = bench("cst to vst conversion");
+        fns.iter().filter(|&it| ast::vst::Fn::try_from(it.clone()).is_ok()).count()
+    };
+    assert!(converted > 0);
+}
+
 #[test]
 fn validation_tests() {
     dir_tests(&test_data_dir(), &["parser/validation"], "rast", |text, path| {
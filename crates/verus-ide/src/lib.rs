@@ -0,0 +1,187 @@
+//! A stable API over the machinery of proof actions, for tools outside of the IDE.
+//!
+//! Proof repair and synthesis tools need what proof actions need: parse a file, lift one of its
+//! functions into a VST to rewrite it, verify the file with the rewritten function, and find out
+//! what failed. [`VerusFile`] does that without an editor or an assist context, so such tools do
+//! not need to fork the internals of `ide-assists`.
+//!
+//! Verus is found like for the editor: `VerusOptions::binary_path`, then `VERUS_BINARY_PATH`,
+//! then `verus` in the `PATH`.
+
+use std::{io, time::Duration};
+
+use paths::AbsPathBuf;
+use syntax::{
+    ast::{self, HasName},
+    AstNode, Edition, Parse, SourceFile, TextRange,
+};
+
+pub use flycheck::{Diagnostic, DiagnosticSpan, VerusOptions};
+pub use ide_assists::proof_plumber_api::verus_error::{
+    AssertFailure, PostFailure, PreFailure, VerusError,
+};
+pub use syntax::ast::vst;
+
+/// A file of Verus code, parsed on its own.
+#[derive(Debug, Clone)]
+pub struct VerusFile {
+    text: String,
+    parse: Parse<SourceFile>,
+}
+
+/// What Verus reported on a [`VerusFile`].
+#[derive(Debug, Clone)]
+pub struct Verification {
+    /// Whether everything compiled and verified.
+    pub success: bool,
+    /// The text Verus ran on, the ranges of `errors` point into it.
+    pub text: String,
+    /// The failures proof actions know how to read, see [`verus_error`]. Failures involving other
+    /// files, like `vstd`, are left out.
+    pub errors: Vec<VerusError>,
+    /// All the diagnostics of the run, including compile errors.
+    pub diagnostics: Vec<Diagnostic>,
+    pub elapsed: Duration,
+}
+
+impl VerusFile {
+    pub fn parse(text: &str) -> VerusFile {
+        VerusFile { text: text.to_owned(), parse: SourceFile::parse(text, Edition::CURRENT) }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The syntax errors of the file. The VST of a function with errors may not be available.
+    pub fn syntax_errors(&self) -> Vec<(TextRange, String)> {
+        self.parse.errors().iter().map(|it| (it.range(), it.to_string())).collect()
+    }
+
+    /// The functions of the file that can be lifted into a VST, including methods and the
+    /// functions of inline modules.
+    pub fn functions(&self) -> Vec<vst::Fn> {
+        self.fns().filter_map(|it| vst::Fn::try_from(it).ok()).collect()
+    }
+
+    /// The function named `name`, the first one if several functions share it.
+    pub fn function(&self, name: &str) -> Option<vst::Fn> {
+        vst::Fn::try_from(self.find_fn(name)?).ok()
+    }
+
+    /// The text of the file with `func` in place of the function of the same name.
+    pub fn with_function(&self, func: &vst::Fn) -> Option<String> {
+        let range = self.find_fn(func.name.to_string().trim())?.syntax().text_range();
+        let mut text = self.text.clone();
+        text.replace_range(std::ops::Range::<usize>::from(range), &func.to_string());
+        Some(text)
+    }
+
+    /// Runs Verus on the file, with `func` in place of the function of the same name if given.
+    /// The file is verified on its own, as the root of a library crate.
+    pub fn verify(
+        &self,
+        func: Option<&vst::Fn>,
+        options: &VerusOptions,
+    ) -> io::Result<Verification> {
+        let text = match func {
+            Some(func) => self.with_function(func).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::NotFound, format!("no function {}", func.name))
+            })?,
+            None => self.text.clone(),
+        };
+        let dir = tempfile::TempDir::new()?;
+        let file = dir.path().join("lib.rs");
+        std::fs::write(&file, &text)?;
+        let root = AbsPathBuf::try_from(dir.path().to_path_buf()).map_err(|dir| {
+            io::Error::new(io::ErrorKind::InvalidInput, format!("{} is not UTF-8", dir.display()))
+        })?;
+        let file = file.to_string_lossy();
+        let report = flycheck::verify(&root, options, &file, flycheck::VerusTarget::File)?;
+
+        // Spans into `vstd` and the like have no range in `text`.
+        let errors = report
+            .diagnostics
+            .iter()
+            .filter(|it| it.spans.iter().all(|span| span.file_name == *file))
+            .filter_map(verus_error)
+            .collect();
+        Ok(Verification {
+            success: report.success,
+            text,
+            errors,
+            diagnostics: report.diagnostics,
+            elapsed: report.elapsed,
+        })
+    }
+
+    fn fns(&self) -> impl Iterator<Item = ast::Fn> {
+        self.parse.tree().syntax().descendants().filter_map(ast::Fn::cast)
+    }
+
+    fn find_fn(&self, name: &str) -> Option<ast::Fn> {
+        self.fns().find(|it| it.name().map_or(false, |it| it.text() == name))
+    }
+}
+
+/// The Verus failure reported by `diagnostic`, if it is one proof actions know how to read.
+pub fn verus_error(diagnostic: &Diagnostic) -> Option<VerusError> {
+    let range =
+        |span: &DiagnosticSpan| TextRange::new(span.byte_start.into(), span.byte_end.into());
+    // The primary span is where the failure is reported, the other one is the failing clause.
+    let site_and_clause = match &*diagnostic.spans {
+        [first, second] if first.is_primary => Some((range(first), range(second))),
+        [first, second] => Some((range(second), range(first))),
+        _ => None,
+    };
+    if diagnostic.message.contains("precondition not satisfied") {
+        let (callsite, failing_pre) = site_and_clause?;
+        Some(VerusError::Pre(PreFailure { failing_pre, callsite }))
+    } else if diagnostic.message.contains("postcondition not satisfied") {
+        let (func_body, failing_post) = site_and_clause?;
+        Some(VerusError::Post(PostFailure { failing_post, func_body }))
+    } else if diagnostic.message.contains("assertion failed") {
+        // Only the first span is read.
+        let range = range(diagnostic.spans.first()?);
+        Some(VerusError::Assert(AssertFailure { range }))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VerusFile;
+
+    const FILE: &str = "\
+use vstd::prelude::*;
+verus! {
+proof fn lemma(x: int)
+    ensures x + x == 2 * x,
+{
+}
+
+mod m {
+    spec fn double(x: int) -> int { x + x }
+}
+}
+";
+
+    #[test]
+    fn functions_are_lifted_and_replaced() {
+        let file = VerusFile::parse(FILE);
+        assert!(file.syntax_errors().is_empty());
+        let names: Vec<_> =
+            file.functions().iter().map(|it| it.name.to_string().trim().to_owned()).collect();
+        assert_eq!(names, ["lemma", "double"]);
+        assert!(file.function("missing").is_none());
+
+        let mut lemma = file.function("lemma").unwrap();
+        lemma.ensures_clause = None;
+        let text = file.with_function(&lemma).unwrap();
+        assert!(text.starts_with("use vstd::prelude::*;\nverus! {\n"));
+        assert!(text.contains("proof fn lemma"));
+        assert!(!text.contains("ensures"));
+        assert!(text.ends_with("mod m {\n    spec fn double(x: int) -> int { x + x }\n}\n}\n"));
+    }
+}
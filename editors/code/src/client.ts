@@ -347,6 +347,7 @@ class ExperimentalFeatures implements lc.StaticFeature {
                     "verus-analyzer.debugSingle",
                     "verus-analyzer.showReferences",
                     "verus-analyzer.gotoLocation",
+                    "verus-analyzer.runFlycheck",
                     "editor.action.triggerParameterHints",
                 ],
             },
@@ -594,6 +594,15 @@ export function viewHir(ctx: CtxInit): Cmd {
     return viewHirOrMir(ctx, "hir");
 }
 
+// Opens the virtual file that will show the VST of the item containing the cursor position
+//
+// The contents of the file come from the `TextDocumentContentProvider`
+export function viewVst(ctx: CtxInit): Cmd {
+    const uri = `verus-analyzer-vst://viewVst/vst.rs`;
+    const scheme = `verus-analyzer-vst`;
+    return viewFileUsingTextDocumentContentProvider(ctx, ra.viewVst, uri, scheme, true);
+}
+
 // Opens the virtual file that will show the MIR of the function containing the cursor position
 //
 // The contents of the file come from the `TextDocumentContentProvider`
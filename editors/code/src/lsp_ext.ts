@@ -58,6 +58,14 @@ export const viewFileText = new lc.RequestType<lc.TextDocumentIdentifier, string
 export const viewHir = new lc.RequestType<lc.TextDocumentPositionParams, string, void>(
     "verus-analyzer/viewHir",
 );
+export const viewVst = new lc.RequestType<lc.TextDocumentPositionParams, string, void>(
+    "verus-analyzer/viewVst",
+);
+export const proofObligations = new lc.RequestType<
+    lc.TextDocumentPositionParams,
+    ProofObligation | null,
+    void
+>("verus-analyzer/proofObligations");
 export const viewMir = new lc.RequestType<lc.TextDocumentPositionParams, string, void>(
     "verus-analyzer/viewMir",
 );
@@ -68,6 +76,28 @@ export const viewItemTree = new lc.RequestType<ViewItemTreeParams, string, void>
     "verus-analyzer/viewItemTree",
 );
 
+export type ProofObligation = {
+    kind: "function" | "call" | "requires" | "ensures" | "assert";
+    label: string;
+    location: lc.Location;
+    status: "unknown" | "verified" | "failed";
+    children: ProofObligation[];
+};
+
+export const verificationDecorations = new lc.NotificationType<VerificationDecorationsParams>(
+    "verus-analyzer/verificationDecorations",
+);
+
+export type VerificationDecorationsParams = {
+    textDocument: lc.TextDocumentIdentifier;
+    functions: FunctionDecoration[];
+};
+export type FunctionDecoration = {
+    range: lc.Range;
+    state: "verified" | "failed" | "running";
+    failures: lc.Range[];
+};
+
 export type DiscoverTestParams = { testId?: string | undefined };
 export type RunTestParams = {
     include?: string[] | undefined;
@@ -223,7 +253,7 @@ export type OpenCargoTomlParams = {
 export type Runnable = {
     label: string;
     location?: lc.LocationLink;
-} & (RunnableCargo | RunnableShell);
+} & (RunnableCargo | RunnableShell | RunnableVerusVerify);
 
 type RunnableCargo = {
     kind: "cargo";
@@ -235,6 +265,11 @@ type RunnableShell = {
     args: ShellRunnableArgs;
 };
 
+type RunnableVerusVerify = {
+    kind: "verusVerify";
+    args: ShellRunnableArgs;
+};
+
 export type ShellRunnableArgs = {
     kind: string;
     program: string;
@@ -177,6 +177,7 @@ pub(crate) const KINDS_SRC: KindsSrc<'_> = KindsSrc {
         "open",
         "closed",
         "opens_invariants",
+        "assume_specification",
         // global-related keywords
         "size_of",
         "layout",
@@ -20,7 +20,8 @@ use crate::codegen;
 //     ("HasArgList", &["arg_list"]),
 // ];
 
-const HAND_WRITTEN: &[&str] = &["BinExpr", "IfExpr", "Literal", "IndexExpr"];
+const HAND_WRITTEN: &[&str] =
+    &["BinExpr", "IfExpr", "Literal", "IndexExpr", "WhileExpr", "PrefixExpr"];
 
 const HAND_WRITTEN_PRINT_ONLY: &[&str] = &["ParamList", "ArgList", "AssertExpr"];
 const HAND_WRITTEN_NEW_ONLY: &[&str] = &["ExprStmt", "MatchArm"];
@@ -32,6 +33,9 @@ const LIST_AUTO_GEN_SEP_COMMA: &[&str] = &[
     "TupleFieldList",
     "RecordExprFieldList",
     "RecordPatFieldList",
+    "InvariantClause",
+    "RequiresClause",
+    "DecreasesClause",
 ];
 const LIST_AUTO_GEN_SEP_NEWLINE: &[&str] = &["StmtList"];
 
@@ -64,14 +68,14 @@ pub(crate) fn generate_vst(_kinds: KindsSrc<'_>, grammar: &AstSrc) -> String {
                     }
                 } else if let Some(token_kind) = field.token_kind() {
                     // hacky for now
-                    // maybe special-case identifier to "#name : Option<String>"
+                    // maybe special-case identifier to "#name : Option<SmolStr>"
                     // 'ident, 'int_number', and 'lifetime_ident'.
                     if token_kind.to_string() == "T ! [ident]"
                         || token_kind.to_string() == "T ! [int_number]"
                         || token_kind.to_string() == "T ! [lifetime_ident]"
                     {
                         quote! {
-                            pub #name : Option<String>,
+                            pub #name : Option<SmolStr>,
                         }
                     } else {
                         quote! {
@@ -271,9 +275,9 @@ pub(crate) fn generate_vst(_kinds: KindsSrc<'_>, grammar: &AstSrc) -> String {
                         || token_kind.to_string() == "T ! [int_number]"
                         || token_kind.to_string() == "T ! [lifetime_ident]"
                     {
-                        // #name : Option<String>,
+                        // #name : Option<SmolStr>, short identifiers are stored inline
                         quote! {
-                            #name : item.#name().map(|it| it.text().to_string()),
+                            #name : item.#name().map(|it| it.text().into()),
                         }
                     } else {
                         // #name : bool,
@@ -284,8 +288,9 @@ pub(crate) fn generate_vst(_kinds: KindsSrc<'_>, grammar: &AstSrc) -> String {
                 } else {
                     if field.is_one() {
                         // pub #name : Box<#ty>,
+                        // the error message is only allocated if the field is missing
                         quote! {
-                            #name: Box::new(item.#name().ok_or(format!("{}", stringify!(#name))).map(|it| #ty::try_from(it))??),
+                            #name: Box::new(item.#name().ok_or(stringify!(#name)).map(|it| #ty::try_from(it))??),
                         }
                     } else {
                         // pub #name : Option<Box<#ty>>,
@@ -309,7 +314,7 @@ pub(crate) fn generate_vst(_kinds: KindsSrc<'_>, grammar: &AstSrc) -> String {
                         fn try_from(item: super::nodes::#name) -> Result<Self, Self::Error>  {
                             Ok(Self {
                                 #(#fields)*
-                                cst: Some(item.clone()),
+                                cst: Some(item),
                             })
                         }
                     }
@@ -543,6 +548,7 @@ pub(crate) fn generate_vst(_kinds: KindsSrc<'_>, grammar: &AstSrc) -> String {
         #![allow(non_snake_case)]
         use crate::{
             ast::{traits::*, vst::*},
+            SmolStr,
         };
 
         #(#node_defs)*